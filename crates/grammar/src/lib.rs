@@ -1,4 +1,5 @@
 pub mod english {
+    #[allow(unused_imports)]
     pub use grammar_english::*;
 }
 pub mod russian {
@@ -16,12 +16,63 @@ pub mod letters {
 }
 use letters::*;
 
+// Letter classes, stored as bit flags in the lookup table below
+const VOWEL: u8 = 1 << 0;
+const CONSONANT: u8 = 1 << 1;
+const HISSING: u8 = 1 << 2;
+const SIBILANT: u8 = 1 << 3;
+const VELAR: u8 = 1 << 4;
+const SOFT_ONLY: u8 = 1 << 5;
+const HARD_ONLY: u8 = 1 << 6;
+const PAIRED: u8 = 1 << 7;
+
+// The classes of the letters, indexed by the last bit of their first UTF-8 byte (D0 or D1) and
+// the low 6 bits of their second byte. Both cases are stored, so that the letters don't need to be
+// lowercased first: А–Я (D0 90–AF) are at 16–47, а–п (D0 B0–BF) at 48–63, р–я (D1 80–8F) at
+// 64–79, and Ё (D0 81) and ё (D1 91) at 1 and 81. The rest of the entries are other Cyrillic
+// letters (Ѐ, ђ, ѓ), that don't belong to any of the classes.
+const LETTER_CLASSES: [u8; 128] = {
+    const CLASSES: [(&str, u8); 8] = [
+        ("аеёиоуыэюя", VOWEL),
+        ("бвгджзйклмнпрстфхцчшщ", CONSONANT),
+        ("жчшщ", HISSING),
+        ("жцчшщ", SIBILANT),
+        ("гкх", VELAR),
+        ("йчщ", SOFT_ONLY),
+        ("жцш", HARD_ONLY),
+        ("бвгдзклмнпрстфх", PAIRED),
+    ];
+    let mut table = [0; 128];
+    let mut i = 0;
+    while i < CLASSES.len() {
+        let (letters, class) = CLASSES[i];
+        let bytes = letters.as_bytes();
+        let mut j = 0;
+        while j < bytes.len() {
+            let lower = Letter { utf8: [bytes[j], bytes[j + 1]] };
+            table[lower.class_index()] |= class;
+            table[lower.to_uppercase().class_index()] |= class;
+            j += 2;
+        }
+        i += 1;
+    }
+    table
+};
+
 impl Letter {
     pub const fn from(ch: char) -> Self {
         let mut utf8: [u8; 2] = [0; 2];
         ch.encode_utf8(&mut utf8);
         Letter { utf8 }
     }
+    /// Returns the letter for a Cyrillic character (lowercase or uppercase), or `None` for any
+    /// other character.
+    pub const fn try_from_char(ch: char) -> Option<Self> {
+        match ch {
+            'а'..='я' | 'А'..='Я' | 'ё' | 'Ё' => Some(Self::from(ch)),
+            _ => None,
+        }
+    }
     pub const fn from_str_unchecked(str: &str) -> Self {
         let mut utf8: [u8; 2] = [0; 2];
         utf8.copy_from_slice(str.as_bytes());
@@ -39,33 +90,245 @@ impl Letter {
         }
     }
 
+    /// Returns `true`, if the letter is uppercase (А–Я, Ё).
+    pub const fn is_uppercase(self) -> bool {
+        matches!(self.utf8, [0xD0, 0x81 | 0x90..=0xAF])
+    }
+    /// Returns the lowercase variant of the letter. Lowercase letters are returned as is.
+    pub const fn to_lowercase(self) -> Self {
+        let utf8 = match self.utf8 {
+            // А–П (D0 90–9F) → а–п (D0 B0–BF)
+            [0xD0, x @ 0x90..=0x9F] => [0xD0, x + 0x20],
+            // Р–Я (D0 A0–AF) → р–я (D1 80–8F)
+            [0xD0, x @ 0xA0..=0xAF] => [0xD1, x - 0x20],
+            // Ё (D0 81) → ё (D1 91)
+            [0xD0, 0x81] => [0xD1, 0x91],
+            utf8 => utf8,
+        };
+        Self { utf8 }
+    }
+    /// Returns `true`, if the letters are the same, ignoring their case (Ё and ё).
+    pub const fn eq_ignore_case(self, other: Self) -> bool {
+        let (x, y) = (self.to_lowercase().utf8, other.to_lowercase().utf8);
+        x[0] == y[0] && x[1] == y[1]
+    }
+    /// Returns the uppercase variant of the letter. Uppercase letters are returned as is.
+    pub const fn to_uppercase(self) -> Self {
+        let utf8 = match self.utf8 {
+            [0xD0, x @ 0xB0..=0xBF] => [0xD0, x - 0x20],
+            [0xD1, x @ 0x80..=0x8F] => [0xD0, x + 0x20],
+            [0xD1, 0x91] => [0xD0, 0x81],
+            utf8 => utf8,
+        };
+        Self { utf8 }
+    }
+
+    // The letter classification methods ignore the case of the letter
+    const fn class_index(self) -> usize {
+        (((self.utf8[0] & 1) << 6) | (self.utf8[1] & 0x3F)) as usize
+    }
+    const fn classes(self) -> u8 {
+        // Only the 2-byte Cyrillic letters (D0 and D1) are in the table
+        if self.utf8[0] & 0xFE == 0xD0 { LETTER_CLASSES[self.class_index()] } else { 0 }
+    }
     pub const fn is_vowel(self) -> bool {
-        matches!(self, а | е | и | о | у | ы | э | ю | я | ё)
+        self.classes() & VOWEL != 0
     }
     pub const fn is_hissing(self) -> bool {
-        matches!(self, ж | ч | ш | щ)
+        self.classes() & HISSING != 0
     }
     pub const fn is_sibilant(self) -> bool {
-        matches!(self, ж | ц | ч | ш | щ)
+        self.classes() & SIBILANT != 0
     }
     pub const fn is_non_sibilant_consonant(self) -> bool {
-        matches!(self, б | в | г | д | з | й | к | л | м | н | п | р | с | т | ф | х)
+        self.classes() & (CONSONANT | SIBILANT) == CONSONANT
     }
-    #[rustfmt::skip]
     pub const fn is_consonant(self) -> bool {
-        matches!(self, б | в | г | д | ж | з | й | к | л | м | н | п | р | с | т | ф | х | ц | ч | ш | щ)
+        self.classes() & CONSONANT != 0
+    }
+    /// Returns `true`, if the letter is a velar consonant: г, к or х.
+    pub const fn is_velar(self) -> bool {
+        self.classes() & VELAR != 0
+    }
+    /// Returns `true`, if the letter is a consonant, that is always soft: й, ч or щ.
+    pub const fn is_soft_only(self) -> bool {
+        self.classes() & SOFT_ONLY != 0
+    }
+    /// Returns `true`, if the letter is a consonant, that is always hard: ж, ш or ц.
+    pub const fn is_hard_only(self) -> bool {
+        self.classes() & HARD_ONLY != 0
+    }
+    /// Returns `true`, if the letter is a consonant, that can be both hard and soft: all of the
+    /// consonants, except for й, ч, щ, ж, ш and ц.
+    pub const fn is_paired_consonant(self) -> bool {
+        self.classes() & PAIRED != 0
     }
 
-    pub const fn from_bytes(slice: &[u8]) -> &[Letter] {
+    /// Returns the vowel, that softens the preceding consonant, corresponding to this one:
+    /// а → я, о → ё, у → ю, ы → и, э → е. The soft vowels are returned as is, and other letters
+    /// return `None`. The case of the letter is preserved.
+    pub const fn soften(self) -> Option<Self> {
+        let soft = match self.to_lowercase() {
+            а | я => я,
+            о | ё => ё,
+            у | ю => ю,
+            ы | и => и,
+            э | е => е,
+            _ => return None,
+        };
+        Some(if self.is_uppercase() { soft.to_uppercase() } else { soft })
+    }
+    /// Returns the vowel, that doesn't soften the preceding consonant, corresponding to this one:
+    /// я → а, ё → о, ю → у, и → ы, е → э. The hard vowels are returned as is, and other letters
+    /// return `None`. The case of the letter is preserved.
+    pub const fn harden(self) -> Option<Self> {
+        let hard = match self.to_lowercase() {
+            а | я => а,
+            о | ё => о,
+            у | ю => у,
+            ы | и => ы,
+            э | е => э,
+            _ => return None,
+        };
+        Some(if self.is_uppercase() { hard.to_uppercase() } else { hard })
+    }
+
+    /// Returns an iterator over the Cyrillic letters of a string, decoding them on the fly.
+    /// The string doesn't need to be validated first: all non-Cyrillic characters (spaces,
+    /// hyphens, stress marks) are skipped.
+    pub fn iter(s: &str) -> Letters<'_> {
+        Letters { bytes: s.as_bytes() }
+    }
+
+    // The bytes must be a valid UTF-8 string of 2-byte characters, since the letters can be
+    // converted back into strings without checking
+    pub(crate) const fn from_bytes(slice: &[u8]) -> &[Letter] {
         unsafe {
-            let ptr: *const Letter = std::mem::transmute(slice.as_ptr());
-            std::slice::from_raw_parts(ptr, slice.len() >> 1)
+            let ptr: *const Letter = core::mem::transmute(slice.as_ptr());
+            core::slice::from_raw_parts(ptr, slice.len() >> 1)
         }
     }
-    pub const fn from_bytes_mut(slice: &mut [u8]) -> &mut [Letter] {
+    pub(crate) const fn from_bytes_mut(slice: &mut [u8]) -> &mut [Letter] {
         unsafe {
-            let ptr: *mut Letter = std::mem::transmute(slice.as_mut_ptr());
-            std::slice::from_raw_parts_mut(ptr, slice.len() >> 1)
+            let ptr: *mut Letter = core::mem::transmute(slice.as_mut_ptr());
+            core::slice::from_raw_parts_mut(ptr, slice.len() >> 1)
+        }
+    }
+}
+
+/// An iterator over the Cyrillic letters of a string, returned by [`Letter::iter`].
+#[derive(Debug, Clone)]
+pub struct Letters<'a> {
+    // Always a valid UTF-8 string, since it's only ever split at Cyrillic letters' boundaries
+    bytes: &'a [u8],
+}
+
+impl Letters<'_> {
+    /// Returns the remaining part of the string, including any skipped characters.
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.bytes) }
+    }
+
+    // Returns the letter, if the two bytes encode a Cyrillic letter in UTF-8
+    const fn decode(utf8: [u8; 2]) -> Option<Letter> {
+        match utf8 {
+            [0xD0, 0x81 | 0x90..=0xBF] | [0xD1, 0x80..=0x8F | 0x91] => Some(Letter { utf8 }),
+            _ => None,
+        }
+    }
+}
+
+impl Iterator for Letters<'_> {
+    type Item = Letter;
+
+    fn next(&mut self) -> Option<Letter> {
+        // Bytes 0xD0 and 0xD1 can only be leading bytes, so the string can be searched bytewise
+        while let [first, second, rest @ ..] = self.bytes {
+            if let Some(letter) = Letters::decode([*first, *second]) {
+                self.bytes = rest;
+                return Some(letter);
+            }
+            // Skip the entire character, to keep the remaining string valid
+            let len = match first {
+                0x00..0x80 => 1,
+                0x80..0xE0 => 2,
+                0xE0..0xF0 => 3,
+                _ => 4,
+            };
+            self.bytes = &self.bytes[len..];
+        }
+        self.bytes = &[];
+        None
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Cyrillic letters are 2 bytes long in UTF-8
+        (0, Some(self.bytes.len() / 2))
+    }
+}
+impl DoubleEndedIterator for Letters<'_> {
+    fn next_back(&mut self) -> Option<Letter> {
+        while let [rest @ .., first, second] = self.bytes {
+            if let Some(letter) = Letters::decode([*first, *second]) {
+                self.bytes = rest;
+                return Some(letter);
+            }
+            // Skip the entire character, to keep the remaining string valid
+            let start = self.bytes.iter().rposition(|&x| !(0x80..0xC0).contains(&x)).unwrap();
+            self.bytes = &self.bytes[..start];
+        }
+        self.bytes = &[];
+        None
+    }
+}
+impl core::iter::FusedIterator for Letters<'_> {}
+
+/// The capitalization of a word, that can be restored after inflecting its lowercase stem: Москва
+/// - Москве, МГУ - МГУ.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Capitalization {
+    /// All letters are lowercase: стол.
+    #[default]
+    Lowercase,
+    /// The first letter is uppercase, and the other ones aren't all uppercase: Москва, Ёж.
+    Capitalized,
+    /// All letters are uppercase, and there's more than one of them: СССР.
+    Uppercase,
+}
+
+impl Capitalization {
+    /// Detects the capitalization of the letters. A single uppercase letter is capitalized.
+    pub const fn of(letters: &[Letter]) -> Self {
+        match letters {
+            [first, rest @ ..] if first.is_uppercase() => {
+                let mut i = 0;
+                while i < rest.len() {
+                    if !rest[i].is_uppercase() {
+                        return Self::Capitalized;
+                    }
+                    i += 1;
+                }
+                if rest.is_empty() { Self::Capitalized } else { Self::Uppercase }
+            },
+            _ => Self::Lowercase,
+        }
+    }
+    /// Applies the capitalization to lowercase letters: capitalizes the first letter, or all of
+    /// them. Lowercase capitalization leaves the letters unchanged.
+    pub const fn apply(self, letters: &mut [Letter]) {
+        match self {
+            Self::Lowercase => {},
+            Self::Capitalized => {
+                if let [first, ..] = letters {
+                    *first = first.to_uppercase();
+                }
+            },
+            Self::Uppercase => {
+                let mut i = 0;
+                while i < letters.len() {
+                    letters[i] = letters[i].to_uppercase();
+                    i += 1;
+                }
+            },
         }
     }
 }
@@ -77,18 +340,20 @@ pub const trait LetterSliceExt {
 impl const LetterSliceExt for [Letter] {
     fn as_bytes(&self) -> &[u8] {
         unsafe {
-            let ptr: *const u8 = std::mem::transmute(self.as_ptr());
-            std::slice::from_raw_parts(ptr, self.len() << 1)
+            let ptr: *const u8 = core::mem::transmute(self.as_ptr());
+            core::slice::from_raw_parts(ptr, self.len() << 1)
         }
     }
     fn as_str(&self) -> &str {
-        unsafe { std::str::from_utf8_unchecked(self.as_bytes()) }
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec::Vec;
+    use test::{Bencher, black_box};
 
     #[test]
     fn convert() {
@@ -108,4 +373,181 @@ mod tests {
         let letters: &[Letter] = Letter::from_bytes(bytes);
         assert_eq!(letters, [а, п, р, я, ё]);
     }
+
+    #[test]
+    fn letter_case() {
+        for lower in Letter::iter("абвгдеёжзийклмнопрстуфхцчшщъыьэюя")
+        {
+            let upper = lower.to_uppercase();
+            assert_eq!(upper.as_char(), lower.as_char().to_uppercase().next().unwrap());
+            assert!(upper.is_uppercase() && !lower.is_uppercase(), "{}", lower.as_str());
+            assert_eq!((upper.to_lowercase(), upper.to_uppercase()), (lower, upper));
+            assert_eq!(lower.to_lowercase(), lower);
+
+            // Classification ignores the case
+            assert_eq!(upper.is_vowel(), lower.is_vowel());
+            assert_eq!(upper.is_consonant(), lower.is_consonant());
+            assert_eq!(upper.is_hissing(), lower.is_hissing());
+            assert_eq!(upper.is_sibilant(), lower.is_sibilant());
+            assert_eq!(upper.is_non_sibilant_consonant(), lower.is_non_sibilant_consonant());
+            assert_eq!(upper.is_velar(), lower.is_velar());
+            assert_eq!(upper.is_soft_only(), lower.is_soft_only());
+            assert_eq!(upper.is_hard_only(), lower.is_hard_only());
+            assert_eq!(upper.is_paired_consonant(), lower.is_paired_consonant());
+            assert_eq!(upper.soften(), lower.soften().map(Letter::to_uppercase));
+            assert_eq!(upper.harden(), lower.harden().map(Letter::to_uppercase));
+        }
+        let upper = |ch: char| Letter::from(ch);
+        assert!(upper('А').is_vowel() && upper('Ё').is_vowel() && !upper('Б').is_vowel());
+        assert!(upper('Ж').is_hissing() && upper('Ц').is_sibilant() && !upper('Ц').is_hissing());
+        assert!(upper('Й').is_consonant() && !upper('Ь').is_consonant());
+
+        assert!(
+            upper('П').eq_ignore_case(п) && п.eq_ignore_case(upper('П')) && п.eq_ignore_case(п)
+        );
+        assert!(upper('Ё').eq_ignore_case(ё) && !upper('Ё').eq_ignore_case(е));
+        assert!(!upper('М').eq_ignore_case(с));
+    }
+
+    #[test]
+    fn classify() {
+        let classes = [
+            ("аеёиоуыэюя", "vowel"),
+            ("бвгджзйклмнпрстфхцчшщ", "consonant"),
+            ("жчшщ", "hissing"),
+            ("жцчшщ", "sibilant"),
+            ("бвгдзйклмнпрстфх", "non-sibilant consonant"),
+            ("гкх", "velar"),
+            ("йчщ", "soft-only"),
+            ("жцш", "hard-only"),
+            ("бвгдзклмнпрстфх", "paired consonant"),
+        ];
+        for x in Letter::iter("абвгдеёжзийклмнопрстуфхцчшщъыьэюя")
+        {
+            let actual = [
+                x.is_vowel(),
+                x.is_consonant(),
+                x.is_hissing(),
+                x.is_sibilant(),
+                x.is_non_sibilant_consonant(),
+                x.is_velar(),
+                x.is_soft_only(),
+                x.is_hard_only(),
+                x.is_paired_consonant(),
+            ];
+            for ((letters, class), actual) in classes.into_iter().zip(actual) {
+                assert_eq!(actual, letters.contains(x.as_char()), "{} ({class})", x.as_str());
+            }
+        }
+
+        // Only Cyrillic letters are classified
+        for x in [Letter::default(), Letter::from('Ѐ'), Letter::from('ѐ'), Letter::from('ґ')] {
+            assert!(!x.is_vowel() && !x.is_consonant() && x.soften().is_none(), "{x:?}");
+        }
+    }
+
+    #[test]
+    fn soften_harden() {
+        let pairs = [(а, я), (о, ё), (у, ю), (ы, и), (э, е)];
+        for (hard, soft) in pairs {
+            assert_eq!((hard.soften(), soft.soften()), (Some(soft), Some(soft)));
+            assert_eq!((hard.harden(), soft.harden()), (Some(hard), Some(hard)));
+        }
+        assert_eq!(Letter::from('Ы').soften(), Some(Letter::from('И')));
+        assert_eq!(Letter::from('Ё').harden(), Some(Letter::from('О')));
+
+        for x in Letter::iter("абвгдеёжзийклмнопрстуфхцчшщъыьэюя")
+        {
+            let is_paired = pairs.iter().any(|&(hard, soft)| x == hard || x == soft);
+            assert_eq!(x.soften().is_some(), is_paired, "{}", x.as_str());
+            assert_eq!(x.harden().is_some(), is_paired, "{}", x.as_str());
+        }
+    }
+
+    #[test]
+    fn capitalization() {
+        let of = |s: &str| Capitalization::of(Letter::from_bytes(s.as_bytes()));
+        assert_eq!(of("стол"), Capitalization::Lowercase);
+        assert_eq!(of(""), Capitalization::Lowercase);
+        assert_eq!(of("Москва"), Capitalization::Capitalized);
+        assert_eq!(of("Ё"), Capitalization::Capitalized);
+        assert_eq!(of("ЛуАЗ"), Capitalization::Capitalized);
+        assert_eq!(of("СССР"), Capitalization::Uppercase);
+        assert_eq!(of("мВт"), Capitalization::Lowercase);
+
+        let apply = |capitalization: Capitalization, s: &str| {
+            let mut bytes = s.as_bytes().to_vec();
+            capitalization.apply(Letter::from_bytes_mut(&mut bytes));
+            alloc::string::String::from_utf8(bytes).unwrap()
+        };
+        assert_eq!(apply(Capitalization::Lowercase, "ёлка"), "ёлка");
+        assert_eq!(apply(Capitalization::Capitalized, "ёлка"), "Ёлка");
+        assert_eq!(apply(Capitalization::Uppercase, "ёлка"), "ЁЛКА");
+    }
+
+    #[test]
+    fn iter() {
+        let collect = |s| Letter::iter(s).collect::<Vec<_>>();
+
+        assert_eq!(collect("апряё"), [а, п, р, я, ё]);
+        assert_eq!(collect(""), []);
+        assert_eq!(collect("abc 123"), []);
+        // Non-Cyrillic characters are skipped, uppercase letters are preserved
+        assert_eq!(collect("п-р о\u{301}!ёx"), [п, р, о, ё]);
+        assert_eq!(collect("Ёж"), [Letter::from('Ё'), ж]);
+        assert_eq!(collect("ǅ€😀я"), [я]);
+        assert_eq!(Letter::iter("яǅ€😀").rev().collect::<Vec<_>>(), [я]);
+
+        // The remaining string is split only at characters' boundaries
+        let mut iter = Letter::iter("€а😀б\u{301}");
+        assert_eq!((iter.next(), iter.as_str()), (Some(а), "😀б\u{301}"));
+        assert_eq!((iter.next_back(), iter.as_str()), (Some(б), "😀"));
+
+        // Reverse iteration, and iteration from both ends
+        let rev = Letter::iter("к-о-т!").rev().collect::<Vec<_>>();
+        assert_eq!(rev, [т, о, к]);
+        let mut iter = Letter::iter("a сон b");
+        assert_eq!((iter.next(), iter.next_back()), (Some(с), Some(н)));
+        assert_eq!(iter.as_str(), "о");
+        assert_eq!((iter.next_back(), iter.next(), iter.next_back()), (Some(о), None, None));
+
+        // Same as an rposition search over the validated slice
+        let word = "колокольчик";
+        let slice = Letter::from_bytes(word.as_bytes());
+        let last_vowel = Letter::iter(word).rev().position(|x| x.is_vowel());
+        assert_eq!(
+            last_vowel.map(|x| slice.len() - 1 - x),
+            slice.iter().rposition(|x| x.is_vowel())
+        );
+    }
+
+    const BENCH_WORDS: [&str; 4] = ["колокольчик", "достопримечательность", "кот", "воскресенье"];
+
+    #[bench]
+    fn bench_last_vowel_from_bytes(b: &mut Bencher) {
+        b.iter(|| {
+            for word in black_box(BENCH_WORDS) {
+                black_box(Letter::from_bytes(word.as_bytes()).iter().rposition(|x| x.is_vowel()));
+            }
+        });
+    }
+    #[bench]
+    fn bench_last_vowel_iter(b: &mut Bencher) {
+        b.iter(|| {
+            for word in black_box(BENCH_WORDS) {
+                black_box(Letter::iter(word).rev().position(|x| x.is_vowel()));
+            }
+        });
+    }
+    #[bench]
+    fn bench_classify(b: &mut Bencher) {
+        b.iter(|| {
+            for word in black_box(BENCH_WORDS) {
+                for &x in Letter::from_bytes(word.as_bytes()) {
+                    black_box((x.is_vowel(), x.is_consonant(), x.is_sibilant(), x.is_hissing()));
+                    black_box(x.is_non_sibilant_consonant());
+                }
+            }
+        });
+    }
 }
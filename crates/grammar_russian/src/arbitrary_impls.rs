@@ -0,0 +1,162 @@
+//! [`Arbitrary`] implementations, enabled by the `arbitrary` feature.
+//!
+//! Only valid values are generated: the stem types and stresses are chosen from their `VALUES`,
+//! and the flags are limited to the ones supported by each kind of declension. So every generated
+//! value can be formatted, and parsed back into the same value.
+
+use crate::{
+    declension::{
+        AdjectiveDeclension, AdjectivePrefix, AdjectiveStemType, Declension, DeclensionFlags,
+        NounDeclension, NounStemType, PronounDeclension, PronounStemType,
+    },
+    stress::{
+        AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, AnyDualStress, AnyStress,
+        NounStress, PronounStress, VerbPastStress, VerbPresentStress, VerbStress,
+    },
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+macro_rules! impl_arbitrary_values {
+    ($($t:ty),+ $(,)?) => ($(
+        impl<'a> Arbitrary<'a> for $t {
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                u.choose(&<$t>::VALUES).copied()
+            }
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (0, Some(4))
+            }
+        }
+    )+);
+}
+impl_arbitrary_values! {
+    AnyStress, NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress,
+    VerbPresentStress, VerbPastStress, NounStemType, PronounStemType, AdjectiveStemType,
+    AdjectivePrefix,
+}
+
+impl<'a> Arbitrary<'a> for DeclensionFlags {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_bits_retain(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AnyDualStress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+impl<'a> Arbitrary<'a> for AdjectiveStress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+impl<'a> Arbitrary<'a> for VerbStress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for NounDeclension {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let flags = DeclensionFlags::arbitrary(u)?.intersection(Self::SUPPORTED_FLAGS);
+        Ok(Self { stem_type: u.arbitrary()?, flags, stress: u.arbitrary()? })
+    }
+}
+impl<'a> Arbitrary<'a> for PronounDeclension {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let flags = DeclensionFlags::arbitrary(u)?.intersection(Self::SUPPORTED_FLAGS);
+        Ok(Self { stem_type: u.arbitrary()?, flags, stress: u.arbitrary()? })
+    }
+}
+impl<'a> Arbitrary<'a> for AdjectiveDeclension {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let flags = DeclensionFlags::arbitrary(u)?.intersection(Self::SUPPORTED_FLAGS);
+        Ok(Self {
+            stem_type: u.arbitrary()?,
+            flags,
+            stress: u.arbitrary()?,
+            is_reflexive: u.arbitrary()?,
+            prefix: u.arbitrary()?,
+        })
+    }
+}
+impl<'a> Arbitrary<'a> for Declension {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2)? {
+            0 => Self::Noun(u.arbitrary()?),
+            1 => Self::Pronoun(u.arbitrary()?),
+            _ => Self::Adjective(u.arbitrary()?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::{fmt::Debug, str::FromStr};
+    use std::{string::String, vec::Vec};
+
+    // A deterministic source of pseudo-random bytes (xorshift64)
+    fn random_bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        (0..len).map(|_| next() as u8).collect()
+    }
+
+    #[track_caller]
+    fn round_trip<T>()
+    where T: for<'a> Arbitrary<'a>
+            + FromStr<Err: Debug + PartialEq>
+            + core::fmt::Display
+            + PartialEq
+            + Debug {
+        for seed in 0..2000 {
+            let bytes = random_bytes(seed, 64);
+            let value = T::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            let s = value.to_string();
+            assert_eq!(s.parse::<T>(), Ok(value), "{s}");
+        }
+    }
+
+    #[test]
+    fn format_parse() {
+        round_trip::<Declension>();
+        round_trip::<NounDeclension>();
+        round_trip::<PronounDeclension>();
+        round_trip::<AdjectiveDeclension>();
+        round_trip::<DeclensionFlags>();
+        round_trip::<AnyDualStress>();
+        round_trip::<AdjectiveStress>();
+        round_trip::<VerbStress>();
+        round_trip::<NounStress>();
+        round_trip::<AnyStress>();
+    }
+
+    #[test]
+    fn parse_format() {
+        const TOKENS: [&str; 30] = [
+            "п ", "мс ", "1", "2", "3", "4", "5", "6", "7", "8", "°", "*", "a", "b", "c", "d", "e",
+            "f", "′", "″", "'", "/", "①", "②", "③", "④", "⑤", "(1)", ", ё", ", -ся",
+        ];
+
+        // Valid strings, built from the notation's tokens, are formatted the same way every time
+        let mut valid = 0;
+        for seed in 0..20000 {
+            let bytes = random_bytes(seed, 8);
+            let len = 1 + bytes[0] as usize % 7;
+            let s: String = bytes[1..=len].iter().map(|x| TOKENS[*x as usize % 30]).collect();
+
+            let Ok(decl) = s.parse::<Declension>() else { continue };
+            let formatted = decl.to_string();
+            assert_eq!(formatted.parse(), Ok(decl), "{s}");
+            assert_eq!(formatted.parse::<Declension>().unwrap().to_string(), formatted, "{s}");
+            valid += 1;
+        }
+        assert!(valid > 100, "{valid}");
+    }
+}
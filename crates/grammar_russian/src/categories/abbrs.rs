@@ -1,6 +1,7 @@
 use super::{
-    Animacy, Case, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Number,
-    traits::{HasAnimacy, HasGender, HasGenderEx, HasNumber},
+    Animacy, Aspect, Case, CaseAndNumber, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy,
+    GenderOrPlural, Number, Person, PersonAndNumber,
+    traits::{HasAnimacy, HasCase, HasGender, HasGenderEx, HasNumber},
 };
 
 // Case[Ex] abbreviations
@@ -56,6 +57,19 @@ impl CaseEx {
             Self::Locative => "ʟᴏᴄ",
         }
     }
+    pub const fn abbr_russian(self) -> &'static str {
+        match self {
+            Self::Nominative => "им.",
+            Self::Genitive => "род.",
+            Self::Dative => "дат.",
+            Self::Accusative => "вин.",
+            Self::Instrumental => "тв.",
+            Self::Prepositional => "пред.",
+            Self::Partitive => "парт.",
+            Self::Translative => "превр.",
+            Self::Locative => "местн.",
+        }
+    }
 }
 impl Case {
     pub const NOM: Self = Self::Nominative;
@@ -74,6 +88,9 @@ impl Case {
     pub const fn abbr_smcp(self) -> &'static str {
         CaseEx::from(self).abbr_smcp()
     }
+    pub const fn abbr_russian(self) -> &'static str {
+        CaseEx::from(self).abbr_russian()
+    }
 }
 
 // Gender[Ex] abbreviations
@@ -108,6 +125,14 @@ impl GenderEx {
             Self::Common => "ᴍᴀꜱᴄ/ꜰᴇᴍ",
         }
     }
+    pub const fn abbr_zaliznyak(self) -> &'static str {
+        match self {
+            Self::Masculine => "м",
+            Self::Neuter => "с",
+            Self::Feminine => "ж",
+            Self::Common => "м-ж",
+        }
+    }
 }
 impl Gender {
     pub const MASC: Self = Self::Masculine;
@@ -123,6 +148,9 @@ impl Gender {
     pub const fn abbr_smcp(self) -> &'static str {
         GenderEx::from(self).abbr_smcp()
     }
+    pub const fn abbr_zaliznyak(self) -> &'static str {
+        GenderEx::from(self).abbr_zaliznyak()
+    }
 }
 
 // Animacy abbreviations
@@ -139,6 +167,10 @@ impl Animacy {
     pub const fn abbr_smcp(self) -> &'static str {
         if self.is_inanimate() { "ɪɴᴀɴ" } else { "ᴀɴ" }
     }
+    /// Returns the animacy's Russian abbreviation: од (одушевлённое) or неод (неодушевлённое).
+    pub const fn abbr_russian(self) -> &'static str {
+        if self.is_inanimate() { "неод" } else { "од" }
+    }
 }
 
 // Number abbreviations
@@ -155,6 +187,107 @@ impl Number {
     pub const fn abbr_smcp(self) -> &'static str {
         if self.is_singular() { "ꜱɢ" } else { "ᴘʟ" }
     }
+    /// Returns the number's Russian abbreviation: ед (единственное) or мн (множественное).
+    pub const fn abbr_russian(self) -> &'static str {
+        if self.is_singular() { "ед" } else { "мн" }
+    }
+}
+
+// Person abbreviations
+impl Person {
+    pub const fn abbr_upper(self) -> &'static str {
+        match self {
+            Self::First => "1",
+            Self::Second => "2",
+            Self::Third => "3",
+        }
+    }
+    pub const fn abbr_lower(self) -> &'static str {
+        self.abbr_upper()
+    }
+    pub const fn abbr_smcp(self) -> &'static str {
+        self.abbr_upper()
+    }
+}
+
+// Aspect abbreviations
+impl Aspect {
+    pub const IPFV: Self = Self::Imperfective;
+    pub const PFV: Self = Self::Perfective;
+
+    pub const fn abbr_upper(self) -> &'static str {
+        if matches!(self, Self::Imperfective) { "IPFV" } else { "PFV" }
+    }
+    pub const fn abbr_lower(self) -> &'static str {
+        if matches!(self, Self::Imperfective) { "ipfv" } else { "pfv" }
+    }
+    pub const fn abbr_smcp(self) -> &'static str {
+        if matches!(self, Self::Imperfective) { "ɪᴘꜰᴠ" } else { "ᴘꜰᴠ" }
+    }
+    /// Returns the aspect's abbreviation, as used in Zaliznyak's dictionary: нсв or св.
+    pub const fn abbr_zaliznyak(self) -> &'static str {
+        if matches!(self, Self::Imperfective) { "нсв" } else { "св" }
+    }
+}
+
+impl PersonAndNumber {
+    pub const fn abbr_upper(self) -> &'static str {
+        match self {
+            Self::FirstSingular => "1SG",
+            Self::FirstPlural => "1PL",
+            Self::SecondSingular => "2SG",
+            Self::SecondPlural => "2PL",
+            Self::ThirdSingular => "3SG",
+            Self::ThirdPlural => "3PL",
+        }
+    }
+    pub const fn abbr_lower(self) -> &'static str {
+        match self {
+            Self::FirstSingular => "1sg",
+            Self::FirstPlural => "1pl",
+            Self::SecondSingular => "2sg",
+            Self::SecondPlural => "2pl",
+            Self::ThirdSingular => "3sg",
+            Self::ThirdPlural => "3pl",
+        }
+    }
+    pub const fn abbr_smcp(self) -> &'static str {
+        match self {
+            Self::FirstSingular => "1ꜱɢ",
+            Self::FirstPlural => "1ᴘʟ",
+            Self::SecondSingular => "2ꜱɢ",
+            Self::SecondPlural => "2ᴘʟ",
+            Self::ThirdSingular => "3ꜱɢ",
+            Self::ThirdPlural => "3ᴘʟ",
+        }
+    }
+}
+
+// GenderOrPlural abbreviations
+impl GenderOrPlural {
+    pub const MASC: Self = Self::Masculine;
+    pub const NEUT: Self = Self::Neuter;
+    pub const FEM: Self = Self::Feminine;
+    pub const PL: Self = Self::Plural;
+
+    pub const fn abbr_upper(self) -> &'static str {
+        match self.gender() {
+            Some(gender) => gender.abbr_upper(),
+            None => Number::Plural.abbr_upper(),
+        }
+    }
+    pub const fn abbr_lower(self) -> &'static str {
+        match self.gender() {
+            Some(gender) => gender.abbr_lower(),
+            None => Number::Plural.abbr_lower(),
+        }
+    }
+    pub const fn abbr_smcp(self) -> &'static str {
+        match self.gender() {
+            Some(gender) => gender.abbr_smcp(),
+            None => Number::Plural.abbr_smcp(),
+        }
+    }
 }
 
 // Gender[Ex]Animacy abbreviation constants
@@ -191,44 +324,69 @@ impl GenderAnimacy {
     }
 }
 
-impl std::fmt::Display for CaseEx {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for CaseEx {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
-impl std::fmt::Display for Case {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Case {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
-impl std::fmt::Display for GenderEx {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GenderEx {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
-impl std::fmt::Display for Gender {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Gender {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
-impl std::fmt::Display for Animacy {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Animacy {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
-impl std::fmt::Display for Number {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Number {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.abbr_upper().fmt(f)
+    }
+}
+impl core::fmt::Display for Person {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.abbr_upper().fmt(f)
+    }
+}
+impl core::fmt::Display for Aspect {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr_upper().fmt(f)
     }
 }
 
-impl std::fmt::Display for GenderExAnimacy {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GenderExAnimacy {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{} {}", self.gender_ex(), self.animacy())
     }
 }
-impl std::fmt::Display for GenderAnimacy {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for GenderAnimacy {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{} {}", self.gender(), self.animacy())
     }
 }
+impl core::fmt::Display for CaseAndNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "{} {}", self.case(), self.number())
+    }
+}
+impl core::fmt::Display for GenderOrPlural {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.abbr_upper().fmt(f)
+    }
+}
+impl core::fmt::Display for PersonAndNumber {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.abbr_upper().fmt(f)
+    }
+}
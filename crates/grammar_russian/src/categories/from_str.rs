@@ -0,0 +1,533 @@
+use super::{
+    Animacy, Case, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, GenderOrPlural,
+    Number, Person,
+};
+use crate::{
+    letters,
+    util::{PartialParse, UnsafeParser},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGenderError {
+    #[error("invalid gender abbreviation")]
+    Invalid,
+    /// A hyphenated compound gender is incomplete, or isn't supported by the type: "мо-ж", "м-",
+    /// or "м-ж" for a type with animacy.
+    #[error("incomplete or unsupported compound gender abbreviation")]
+    InvalidCompound,
+}
+
+type Error = ParseGenderError;
+
+// Parses a Zaliznyak gender abbreviation (м, мо, с, со, ж, жо, м-ж or мо-жо), and returns the
+// gender and whether it was marked as animate.
+const fn parse_abbr(parser: &mut UnsafeParser) -> Result<(GenderEx, bool), ParseGenderError> {
+    let gender = match parser.peek_letters::<1>() {
+        Some([letters::м]) => GenderEx::Masculine,
+        Some([letters::с]) => GenderEx::Neuter,
+        Some([letters::ж]) => GenderEx::Feminine,
+        _ => return Err(Error::Invalid),
+    };
+    parser.forward(2);
+    let is_animate = parser.skip('о');
+
+    if !parser.skip('-') {
+        return Ok((gender, is_animate));
+    }
+
+    // Don't stop in the middle of a hyphenated compound, and leave "-ж" to the next parser.
+    // Both parts must be marked with the same animacy: м-ж, or мо-жо.
+    if matches!(gender, GenderEx::Masculine) && parser.skip('ж') && parser.skip('о') == is_animate
+    {
+        return Ok((GenderEx::Common, is_animate));
+    }
+    Err(Error::InvalidCompound)
+}
+
+impl const PartialParse for GenderEx {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        match parse_abbr(parser) {
+            Ok((gender, false)) => Ok(gender),
+            Ok((_, true)) => Err(Error::Invalid),
+            Err(err) => Err(err),
+        }
+    }
+}
+impl const PartialParse for Gender {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        match GenderEx::partial_parse(parser) {
+            Ok(GenderEx::Common) => Err(Error::InvalidCompound),
+            Ok(gender) => Ok(gender.normalize()),
+            Err(err) => Err(err),
+        }
+    }
+}
+impl const PartialParse for GenderExAnimacy {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        match parse_abbr(parser) {
+            // Zaliznyak's common gender is always animate (мо-жо)
+            Ok((GenderEx::Common, false)) => Err(Error::InvalidCompound),
+            Ok((gender, is_animate)) => Ok(gender.with_an(match is_animate {
+                true => Animacy::Animate,
+                false => Animacy::Inanimate,
+            })),
+            Err(err) => Err(err),
+        }
+    }
+}
+impl const PartialParse for GenderAnimacy {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        match GenderExAnimacy::partial_parse(parser) {
+            Ok(GenderExAnimacy::CommonAnimate) => Err(Error::InvalidCompound),
+            Ok(x) => {
+                let (gender, animacy) = x.parts();
+                Ok(gender.normalize().with_an(animacy))
+            },
+            Err(err) => Err(err),
+        }
+    }
+}
+
+macro_rules! impl_from_str {
+    ($($t:ty),+ $(,)?) => ($(
+        impl $t {
+            /// Parses the gender abbreviation at the start of the string, and returns it along
+            /// with the number of bytes read. Hyphenated compounds are either read completely,
+            /// or result in an error ("мо-ж" isn't read as "мо").
+            pub const fn from_str_partial(s: &str) -> Result<(Self, usize), ParseGenderError> {
+                let mut parser = UnsafeParser::new(s);
+                match Self::partial_parse(&mut parser) {
+                    Ok(result) => Ok((result, s.len() - parser.remaining_len())),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+        impl core::str::FromStr for $t {
+            type Err = ParseGenderError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_str_or(s, Error::Invalid)
+            }
+        }
+    )+);
+}
+impl_from_str!(Gender, GenderEx, GenderAnimacy, GenderExAnimacy);
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error("invalid person, expected 1, 2, 3, 1st, 2nd or 3rd")]
+pub struct ParsePersonError;
+
+impl const PartialParse for Person {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        let (person, suffix) = match parser.read_one() {
+            Some(b'1') => (Person::First, "st"),
+            Some(b'2') => (Person::Second, "nd"),
+            Some(b'3') => (Person::Third, "rd"),
+            _ => return Err(ParsePersonError),
+        };
+        // The ordinal suffix is optional: "1" and "1st" are both the first person
+        parser.skip_str(suffix);
+        Ok(person)
+    }
+}
+impl core::str::FromStr for Person {
+    type Err = ParsePersonError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_or(s, ParsePersonError)
+    }
+}
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error("invalid animacy, expected an, inan, AN, INAN, од or неод")]
+pub struct ParseAnimacyError;
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error("invalid number, expected sg, pl, SG, PL, ед or мн")]
+pub struct ParseNumberError;
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error(
+    "invalid gender or plural, expected masc, neut, fem, pl, MASC, NEUT, FEM, PL, м, с, ж or мн"
+)]
+pub struct ParseGenderOrPluralError;
+
+// Reads the first of the abbreviations, that the remaining string starts with
+const fn parse_one_of<T: Copy>(parser: &mut UnsafeParser, abbrs: &[(&str, T)]) -> Option<T> {
+    let mut i = 0;
+    while i < abbrs.len() {
+        let (abbr, value) = abbrs[i];
+        if parser.skip_str(abbr) {
+            return Some(value);
+        }
+        i += 1;
+    }
+    None
+}
+
+impl const PartialParse for Animacy {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        const ABBRS: [(&str, Animacy); 6] = [
+            ("inan", Animacy::Inanimate),
+            ("INAN", Animacy::Inanimate),
+            ("неод", Animacy::Inanimate),
+            ("an", Animacy::Animate),
+            ("AN", Animacy::Animate),
+            ("од", Animacy::Animate),
+        ];
+        parse_one_of(parser, &ABBRS).ok_or(ParseAnimacyError)
+    }
+}
+impl const PartialParse for Number {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        const ABBRS: [(&str, Number); 6] = [
+            ("sg", Number::Singular),
+            ("SG", Number::Singular),
+            ("ед", Number::Singular),
+            ("pl", Number::Plural),
+            ("PL", Number::Plural),
+            ("мн", Number::Plural),
+        ];
+        parse_one_of(parser, &ABBRS).ok_or(ParseNumberError)
+    }
+}
+impl const PartialParse for GenderOrPlural {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        // Note: мн must be checked before м
+        const ABBRS: [(&str, GenderOrPlural); 12] = [
+            ("masc", GenderOrPlural::Masculine),
+            ("MASC", GenderOrPlural::Masculine),
+            ("neut", GenderOrPlural::Neuter),
+            ("NEUT", GenderOrPlural::Neuter),
+            ("fem", GenderOrPlural::Feminine),
+            ("FEM", GenderOrPlural::Feminine),
+            ("pl", GenderOrPlural::Plural),
+            ("PL", GenderOrPlural::Plural),
+            ("мн", GenderOrPlural::Plural),
+            ("м", GenderOrPlural::Masculine),
+            ("с", GenderOrPlural::Neuter),
+            ("ж", GenderOrPlural::Feminine),
+        ];
+        parse_one_of(parser, &ABBRS).ok_or(ParseGenderOrPluralError)
+    }
+}
+
+macro_rules! impl_from_str_abbr {
+    ($($t:ty => $err:ident),+ $(,)?) => ($(
+        impl $t {
+            /// Parses the abbreviation at the start of the string, and returns it along with the
+            /// number of bytes read. The rest of the string is left for the next parser.
+            pub const fn from_str_partial(s: &str) -> Result<(Self, usize), $err> {
+                let mut parser = UnsafeParser::new(s);
+                match Self::partial_parse(&mut parser) {
+                    Ok(result) => Ok((result, s.len() - parser.remaining_len())),
+                    Err(err) => Err(err),
+                }
+            }
+        }
+        impl core::str::FromStr for $t {
+            type Err = $err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::from_str_or(s, $err)
+            }
+        }
+    )+);
+}
+impl_from_str_abbr!(
+    Animacy => ParseAnimacyError,
+    Number => ParseNumberError,
+    GenderOrPlural => ParseGenderOrPluralError,
+);
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseCaseError {
+    /// The string isn't an abbreviation of any case: "xyz", "", "им.п".
+    #[error("unknown case abbreviation, expected one like nom, gen, им. or род.")]
+    Unknown,
+    /// The string is the start of the abbreviations of several cases: "п." (пред., парт., превр.),
+    /// "p" (prp, prt).
+    #[error("ambiguous case abbreviation, matches several cases")]
+    Ambiguous,
+    /// The case exists, but isn't supported: "зват." (vocative).
+    #[error("the vocative case isn't supported")]
+    Unsupported,
+    /// The case is a secondary one, and the type only supports the main 6 cases.
+    #[error("the {0} case isn't one of the main 6 cases")]
+    NotMainCase(CaseEx),
+}
+
+// The English and Russian abbreviations of the cases, in lowercase and without the dots
+const CASE_ABBRS: [(CaseEx, &[&str]); 9] = [
+    (CaseEx::Nominative, &["nom", "им"]),
+    (CaseEx::Genitive, &["gen", "род"]),
+    (CaseEx::Dative, &["dat", "дат"]),
+    (CaseEx::Accusative, &["acc", "вин"]),
+    (CaseEx::Instrumental, &["ins", "тв", "твор"]),
+    (CaseEx::Prepositional, &["prp", "пр", "пред", "предл"]),
+    (CaseEx::Partitive, &["prt", "парт", "р2"]),
+    (CaseEx::Translative, &["transl", "превр"]),
+    (CaseEx::Locative, &["loc", "местн", "мест", "п2"]),
+];
+const VOCATIVE_ABBRS: [&str; 2] = ["voc", "зват"];
+
+impl core::str::FromStr for CaseEx {
+    type Err = ParseCaseError;
+
+    /// Parses an English (nom, gen, …, prt, transl, loc) or a Russian (им., род., …, парт., превр.,
+    /// местн.) case abbreviation, or Zaliznyak's Р2 and П2. The abbreviations are case-insensitive,
+    /// and the trailing dot is optional: "GEN", "gen.", "Род." and "род" are all the genitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_suffix('.').unwrap_or(s);
+        let lower = || s.chars().flat_map(char::to_lowercase);
+
+        let found =
+            CASE_ABBRS.iter().find(|(_, abbrs)| abbrs.iter().any(|x| lower().eq(x.chars())));
+        if let Some((case, _)) = found {
+            return Ok(*case);
+        }
+        if VOCATIVE_ABBRS.iter().any(|x| lower().eq(x.chars())) {
+            return Err(ParseCaseError::Unsupported);
+        }
+
+        // Check if the string is the start of several cases' abbreviations
+        let is_prefix =
+            |abbr: &&str| !s.is_empty() && abbr.chars().take(s.chars().count()).eq(lower());
+        let prefixed = CASE_ABBRS.iter().filter(|(_, abbrs)| abbrs.iter().any(is_prefix)).count();
+        Err(if prefixed > 1 { ParseCaseError::Ambiguous } else { ParseCaseError::Unknown })
+    }
+}
+impl core::str::FromStr for Case {
+    type Err = ParseCaseError;
+
+    /// Parses a case abbreviation of one of the main 6 cases. See [`CaseEx`]'s `FromStr` for the
+    /// accepted abbreviations.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let case = s.parse::<CaseEx>()?;
+        case.try_into().map_err(|_| ParseCaseError::NotMainCase(case))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{HasNumber, HasPerson, Number, PersonAndNumber},
+        declension::{Declension, NounDeclension},
+    };
+    use std::{
+        borrow::ToOwned,
+        format,
+        string::{String, ToString},
+    };
+
+    #[test]
+    fn from_str() {
+        assert_eq!("м".parse(), Ok(Gender::Masculine));
+        assert_eq!("с".parse(), Ok(Gender::Neuter));
+        assert_eq!("ж".parse(), Ok(Gender::Feminine));
+        assert_eq!("м-ж".parse::<Gender>(), Err(Error::InvalidCompound));
+        assert_eq!("мо".parse::<Gender>(), Err(Error::Invalid));
+
+        assert_eq!("м-ж".parse(), Ok(GenderEx::Common));
+        assert_eq!("мо-жо".parse::<GenderEx>(), Err(Error::Invalid));
+
+        for x in GenderExAnimacy::VALUES {
+            assert_eq!(x.abbr_zaliznyak().parse(), Ok(x));
+        }
+        for x in GenderAnimacy::VALUES {
+            assert_eq!(x.abbr_zaliznyak().parse(), Ok(x));
+        }
+        assert_eq!("мо-жо".parse::<GenderAnimacy>(), Err(Error::InvalidCompound));
+
+        assert_eq!("".parse::<GenderExAnimacy>(), Err(Error::Invalid));
+        assert_eq!("п".parse::<GenderExAnimacy>(), Err(Error::Invalid));
+        assert_eq!("м ".parse::<GenderExAnimacy>(), Err(Error::Invalid));
+        assert_eq!("мо-жоо".parse::<GenderExAnimacy>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn compound() {
+        // Incomplete or mismatched compounds are errors, and aren't read as м/мо
+        for s in ["мо-ж", "мо-", "м-", "м-жо", "мо-с", "с-ж", "жо-мо"] {
+            assert_eq!(s.parse::<GenderExAnimacy>(), Err(Error::InvalidCompound), "{s}");
+            assert_eq!(GenderExAnimacy::from_str_partial(s), Err(Error::InvalidCompound), "{s}");
+        }
+        assert_eq!("м-ж".parse::<GenderExAnimacy>(), Err(Error::InvalidCompound));
+        assert_eq!("м-".parse::<GenderEx>(), Err(Error::InvalidCompound));
+        assert_eq!("м-жо".parse::<GenderEx>(), Err(Error::InvalidCompound));
+
+        // Complete compounds at the end of input
+        assert_eq!("мо-жо".parse(), Ok(GenderExAnimacy::CommonAnimate));
+        assert_eq!(
+            GenderExAnimacy::from_str_partial("мо-жо"),
+            Ok((GenderExAnimacy::CommonAnimate, 9))
+        );
+        assert_eq!(GenderEx::from_str_partial("м-ж"), Ok((GenderEx::Common, 5)));
+
+        // Trailing characters are left for the next parser, but aren't accepted by from_str
+        assert_eq!(GenderEx::from_str_partial("м-ж "), Ok((GenderEx::Common, 5)));
+        assert_eq!("м-ж ".parse::<GenderEx>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn embedded() {
+        // Parses a gender followed by a space and a declension, like in dictionary entries
+        fn parse(s: &str) -> Result<(GenderExAnimacy, NounDeclension), String> {
+            let (gender, len) = GenderExAnimacy::from_str_partial(s).map_err(|e| e.to_string())?;
+            let rest = s[len..].strip_prefix(' ').ok_or("expected a space")?;
+            let decl = rest.parse::<Declension>().map_err(|e| format!("{e:?}"))?;
+            Ok((gender, decl.as_noun().ok_or("expected a noun declension")?))
+        }
+
+        let decl = "1a".parse().unwrap();
+        assert_eq!(parse("мо-жо 1a"), Ok((GenderExAnimacy::CommonAnimate, decl)));
+        assert_eq!(parse("мо 1a"), Ok((GenderExAnimacy::MasculineAnimate, decl)));
+        // The gender error is reported, instead of a confusing error about "-ж 1a"
+        assert_eq!(parse("мо-ж 1a"), Err(Error::InvalidCompound.to_string()));
+        assert_eq!(parse("мо-жо"), Err("expected a space".to_owned()));
+
+        let (gender, len) = GenderEx::from_str_partial("м-ж 1a").unwrap();
+        assert_eq!((gender, &"м-ж 1a"[len..]), (GenderEx::Common, " 1a"));
+    }
+
+    #[test]
+    fn person() {
+        for (s, person) in [("1", Person::First), ("2", Person::Second), ("3", Person::Third)] {
+            assert_eq!(s.parse(), Ok(person));
+            assert_eq!(person.to_string(), s);
+        }
+        assert_eq!("1st".parse(), Ok(Person::First));
+        assert_eq!("2nd".parse(), Ok(Person::Second));
+        assert_eq!("3rd".parse(), Ok(Person::Third));
+
+        for s in ["", "0", "4", "1nd", "2st", "3r", "1st ", " 1", "first"] {
+            assert_eq!(s.parse::<Person>(), Err(ParsePersonError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn animacy_and_number() {
+        for animacy in Animacy::VALUES {
+            for abbr in [animacy.abbr_lower(), animacy.abbr_upper(), animacy.abbr_russian()] {
+                assert_eq!(abbr.parse(), Ok(animacy), "{abbr}");
+                assert_eq!(Animacy::from_str_partial(abbr), Ok((animacy, abbr.len())), "{abbr}");
+            }
+            assert_eq!(animacy.to_string().parse(), Ok(animacy));
+        }
+        for number in Number::VALUES {
+            for abbr in [number.abbr_lower(), number.abbr_upper(), number.abbr_russian()] {
+                assert_eq!(abbr.parse(), Ok(number), "{abbr}");
+                assert_eq!(Number::from_str_partial(abbr), Ok((number, abbr.len())), "{abbr}");
+            }
+            assert_eq!(number.to_string().parse(), Ok(number));
+        }
+
+        // The abbreviations can be followed by other tags
+        assert_eq!(Number::from_str_partial("pl.gen"), Ok((Number::Plural, 2)));
+        assert_eq!(Animacy::from_str_partial("неод м"), Ok((Animacy::Inanimate, 8)));
+
+        for s in ["", "An", "inanimate", "an ", " sg", "Sg", "ед.", "мн.ч", "s"] {
+            assert!(s.parse::<Animacy>().is_err() && s.parse::<Number>().is_err(), "{s:?}");
+        }
+        assert_eq!("pl".parse::<Animacy>(), Err(ParseAnimacyError));
+        assert_eq!("an".parse::<Number>(), Err(ParseNumberError));
+    }
+
+    #[test]
+    fn gender_or_plural() {
+        assert_eq!(GenderOrPlural::VALUES.map(|x| x.to_string()), ["MASC", "NEUT", "FEM", "PL"]);
+
+        for value in GenderOrPlural::VALUES {
+            for abbr in [value.abbr_lower(), value.abbr_upper()] {
+                assert_eq!(abbr.parse(), Ok(value), "{abbr}");
+                assert_eq!(GenderOrPlural::from_str_partial(abbr), Ok((value, abbr.len())));
+            }
+            assert_eq!(value.to_string().parse(), Ok(value));
+        }
+        assert_eq!("м".parse(), Ok(GenderOrPlural::Masculine));
+        assert_eq!("с".parse(), Ok(GenderOrPlural::Neuter));
+        assert_eq!("ж".parse(), Ok(GenderOrPlural::Feminine));
+        assert_eq!("мн".parse(), Ok(GenderOrPlural::Plural));
+        assert_eq!(GenderOrPlural::from_str_partial("мн.ч"), Ok((GenderOrPlural::Plural, 4)));
+
+        for s in ["", "sg", "Fem", "m", "мо", " pl", "plural"] {
+            assert_eq!(s.parse::<GenderOrPlural>(), Err(ParseGenderOrPluralError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn person_and_number() {
+        let expected = ["1SG", "2SG", "3SG", "1PL", "2PL", "3PL"];
+        assert_eq!(PersonAndNumber::VALUES.map(|x| x.to_string()), expected);
+        assert_eq!(
+            PersonAndNumber::VALUES.map(|x| x.abbr_lower()),
+            expected.map(|x| x.to_lowercase())
+        );
+
+        for number in Number::VALUES {
+            for person in Person::VALUES {
+                let x = person.with(number);
+                assert_eq!(x, PersonAndNumber::from((person, number)));
+                assert_eq!(x.parts(), (person, number));
+                assert_eq!((x.person(), x.number()), (person, number));
+
+                // The person is formatted the same way it's parsed
+                assert_eq!(x.abbr_upper(), format!("{person}{}", number.abbr_upper()));
+                assert_eq!(x.abbr_smcp(), format!("{}{}", person.abbr_smcp(), number.abbr_smcp()));
+                assert_eq!(x.person().to_string().parse(), Ok(person));
+            }
+        }
+    }
+
+    #[test]
+    fn case() {
+        let assert = |s: &str, expected: Result<CaseEx, ParseCaseError>| {
+            assert_eq!(s.parse::<CaseEx>(), expected, "{s}");
+        };
+
+        // English abbreviations, case-insensitive, with or without the dot
+        for case in CaseEx::VALUES {
+            assert(case.abbr_lower(), Ok(case));
+            assert(case.abbr_upper(), Ok(case));
+            assert(&format!("{}.", case.abbr_lower()), Ok(case));
+        }
+        assert("Gen.", Ok(CaseEx::Genitive));
+        // Russian abbreviations, round-tripping through abbr_russian
+        for case in CaseEx::VALUES {
+            let abbr = case.abbr_russian();
+            assert(abbr, Ok(case));
+            assert(abbr.strip_suffix('.').unwrap(), Ok(case));
+            assert(&abbr.to_uppercase(), Ok(case));
+        }
+        assert("Род.", Ok(CaseEx::Genitive));
+        assert("твор.", Ok(CaseEx::Instrumental));
+        assert("предл.", Ok(CaseEx::Prepositional));
+        assert("пр.", Ok(CaseEx::Prepositional));
+        assert("мест.", Ok(CaseEx::Locative));
+        assert("Р2", Ok(CaseEx::Partitive));
+        assert("П2", Ok(CaseEx::Locative));
+
+        // Ambiguous, unsupported and unknown abbreviations
+        assert("п.", Err(ParseCaseError::Ambiguous));
+        assert("пре", Err(ParseCaseError::Ambiguous));
+        assert("р.", Err(ParseCaseError::Ambiguous));
+        assert("p", Err(ParseCaseError::Ambiguous));
+        assert("зват.", Err(ParseCaseError::Unsupported));
+        assert("VOC", Err(ParseCaseError::Unsupported));
+        assert("", Err(ParseCaseError::Unknown));
+        assert(".", Err(ParseCaseError::Unknown));
+        assert("им..", Err(ParseCaseError::Unknown));
+        assert("им.п", Err(ParseCaseError::Unknown));
+        assert("nominative", Err(ParseCaseError::Unknown));
+        assert("т.", Err(ParseCaseError::Unknown));
+
+        // Case only accepts the main 6 cases
+        for case in Case::VALUES {
+            assert_eq!(case.abbr_russian().parse(), Ok(case));
+            assert_eq!(case.abbr_lower().parse(), Ok(case));
+        }
+        assert_eq!("вин".parse(), Ok(Case::Accusative));
+        assert_eq!("парт.".parse::<Case>(), Err(ParseCaseError::NotMainCase(CaseEx::Partitive)));
+        assert_eq!("LOC".parse::<Case>(), Err(ParseCaseError::NotMainCase(CaseEx::Locative)));
+        assert_eq!("п.".parse::<Case>(), Err(ParseCaseError::Ambiguous));
+    }
+}
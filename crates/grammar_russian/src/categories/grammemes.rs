@@ -0,0 +1,417 @@
+use super::{
+    Animacy, Case, CaseAndNumber, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Number,
+    Person, PersonAndNumber,
+};
+use crate::declension::DeclInfo;
+use thiserror::Error;
+
+/// A bundle of grammemes: an optional case, number, gender, animacy and person, packed into two
+/// bytes. Used to represent the grammatical tags of corpora and morphological analyzers, where
+/// any of the categories may be missing.
+///
+/// The grammemes are formatted as comma-separated lowercase abbreviations, in the order of the
+/// categories above, skipping the unset ones: "nom,sg,masc,inan", "pl,3". `FromStr` accepts
+/// the abbreviations in any order, in lowercase or uppercase.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::categories::*;
+///
+/// let tag = Grammemes::new().with_case(CaseEx::Genitive).with_number(Number::Plural);
+/// assert_eq!(tag.to_string(), "gen,pl");
+/// assert_eq!("pl,gen".parse(), Ok(tag));
+///
+/// // Unset categories match any grammeme
+/// let form = Grammemes::from(CaseAndNumber::GenitivePlural).with_gender(GenderEx::Feminine);
+/// assert!(tag.matches(&form));
+/// assert!(!tag.matches(&form.with_number(Number::Singular)));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Grammemes {
+    // Each field stores the category's discriminant plus one, or 0 if the category isn't set
+    bits: u16,
+}
+
+// The (shift, mask) pairs of the categories' fields in the bits
+type Field = (u32, u16);
+const CASE: Field = (0, 0b1111);
+const NUMBER: Field = (4, 0b11);
+const GENDER: Field = (6, 0b111);
+const ANIMACY: Field = (9, 0b11);
+const PERSON: Field = (11, 0b11);
+const FIELDS: [Field; 5] = [CASE, NUMBER, GENDER, ANIMACY, PERSON];
+
+impl Grammemes {
+    /// Grammemes with none of the categories set.
+    pub const EMPTY: Self = Self { bits: 0 };
+
+    /// Creates grammemes with none of the categories set.
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+    /// Returns `true`, if none of the categories are set.
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    const fn get(self, (shift, mask): Field) -> Option<u8> {
+        match (self.bits >> shift) & mask {
+            0 => None,
+            x => Some(x as u8 - 1),
+        }
+    }
+    const fn set(mut self, (shift, mask): Field, value: Option<u8>) -> Self {
+        let value = match value {
+            Some(x) => x as u16 + 1,
+            None => 0,
+        };
+        self.bits = (self.bits & !(mask << shift)) | (value << shift);
+        self
+    }
+
+    /// Returns the case, if it's set.
+    pub const fn case(self) -> Option<CaseEx> {
+        match self.get(CASE) {
+            Some(x) => Some(unsafe { core::mem::transmute::<u8, CaseEx>(x) }),
+            None => None,
+        }
+    }
+    /// Returns the number, if it's set.
+    pub const fn number(self) -> Option<Number> {
+        match self.get(NUMBER) {
+            Some(x) => Some(unsafe { core::mem::transmute::<u8, Number>(x) }),
+            None => None,
+        }
+    }
+    /// Returns the gender, if it's set.
+    pub const fn gender(self) -> Option<GenderEx> {
+        match self.get(GENDER) {
+            Some(x) => Some(unsafe { core::mem::transmute::<u8, GenderEx>(x) }),
+            None => None,
+        }
+    }
+    /// Returns the animacy, if it's set.
+    pub const fn animacy(self) -> Option<Animacy> {
+        match self.get(ANIMACY) {
+            Some(x) => Some(unsafe { core::mem::transmute::<u8, Animacy>(x) }),
+            None => None,
+        }
+    }
+    /// Returns the person, if it's set.
+    pub const fn person(self) -> Option<Person> {
+        match self.get(PERSON) {
+            Some(x) => Some(unsafe { core::mem::transmute::<u8, Person>(x) }),
+            None => None,
+        }
+    }
+
+    /// Returns the grammemes with the specified case.
+    pub const fn with_case(self, case: CaseEx) -> Self {
+        self.set(CASE, Some(case as u8))
+    }
+    /// Returns the grammemes with the specified number.
+    pub const fn with_number(self, number: Number) -> Self {
+        self.set(NUMBER, Some(number as u8))
+    }
+    /// Returns the grammemes with the specified gender.
+    pub const fn with_gender(self, gender: GenderEx) -> Self {
+        self.set(GENDER, Some(gender as u8))
+    }
+    /// Returns the grammemes with the specified animacy.
+    pub const fn with_animacy(self, animacy: Animacy) -> Self {
+        self.set(ANIMACY, Some(animacy as u8))
+    }
+    /// Returns the grammemes with the specified person.
+    pub const fn with_person(self, person: Person) -> Self {
+        self.set(PERSON, Some(person as u8))
+    }
+
+    /// Sets or unsets the case.
+    pub const fn set_case(&mut self, case: Option<CaseEx>) {
+        *self = self.set(CASE, if let Some(x) = case { Some(x as u8) } else { None });
+    }
+    /// Sets or unsets the number.
+    pub const fn set_number(&mut self, number: Option<Number>) {
+        *self = self.set(NUMBER, if let Some(x) = number { Some(x as u8) } else { None });
+    }
+    /// Sets or unsets the gender.
+    pub const fn set_gender(&mut self, gender: Option<GenderEx>) {
+        *self = self.set(GENDER, if let Some(x) = gender { Some(x as u8) } else { None });
+    }
+    /// Sets or unsets the animacy.
+    pub const fn set_animacy(&mut self, animacy: Option<Animacy>) {
+        *self = self.set(ANIMACY, if let Some(x) = animacy { Some(x as u8) } else { None });
+    }
+    /// Sets or unsets the person.
+    pub const fn set_person(&mut self, person: Option<Person>) {
+        *self = self.set(PERSON, if let Some(x) = person { Some(x as u8) } else { None });
+    }
+
+    /// Returns `true`, if the grammemes of every category set in both are the same. The unset
+    /// categories are wildcards, that match any grammeme: "gen" matches "gen,pl,fem", and
+    /// "gen,pl" matches "gen", but "gen,pl" doesn't match "gen,sg".
+    pub const fn matches(&self, other: &Grammemes) -> bool {
+        let mut i = 0;
+        while i < FIELDS.len() {
+            if let (Some(a), Some(b)) = (self.get(FIELDS[i]), other.get(FIELDS[i]))
+                && a != b
+            {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+}
+
+impl const From<DeclInfo> for Grammemes {
+    fn from(value: DeclInfo) -> Self {
+        Self::new()
+            .with_case(value.case.into())
+            .with_number(value.number)
+            .with_gender(value.gender.into())
+            .with_animacy(value.animacy)
+    }
+}
+impl const From<CaseAndNumber> for Grammemes {
+    fn from(value: CaseAndNumber) -> Self {
+        let (case, number) = value.parts();
+        Self::new().with_case(case.into()).with_number(number)
+    }
+}
+impl const From<GenderAnimacy> for Grammemes {
+    fn from(value: GenderAnimacy) -> Self {
+        let (gender, animacy) = value.parts();
+        Self::new().with_gender(gender.into()).with_animacy(animacy)
+    }
+}
+impl const From<GenderExAnimacy> for Grammemes {
+    fn from(value: GenderExAnimacy) -> Self {
+        let (gender, animacy) = value.parts();
+        Self::new().with_gender(gender).with_animacy(animacy)
+    }
+}
+impl const From<PersonAndNumber> for Grammemes {
+    fn from(value: PersonAndNumber) -> Self {
+        let (person, number) = value.parts();
+        Self::new().with_person(person).with_number(number)
+    }
+}
+impl const From<Case> for Grammemes {
+    fn from(value: Case) -> Self {
+        Self::new().with_case(value.into())
+    }
+}
+impl const From<Gender> for Grammemes {
+    fn from(value: Gender) -> Self {
+        Self::new().with_gender(value.into())
+    }
+}
+
+impl core::fmt::Display for Grammemes {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let abbrs = [
+            self.case().map(CaseEx::abbr_lower),
+            self.number().map(Number::abbr_lower),
+            self.gender().map(GenderEx::abbr_lower),
+            self.animacy().map(Animacy::abbr_lower),
+            self.person().map(Person::abbr_lower),
+        ];
+        let mut sep = "";
+        for abbr in abbrs.into_iter().flatten() {
+            f.write_str(sep)?;
+            f.write_str(abbr)?;
+            sep = ",";
+        }
+        Ok(())
+    }
+}
+impl core::fmt::Debug for Grammemes {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Grammemes")
+            .field("case", &self.case())
+            .field("number", &self.number())
+            .field("gender", &self.gender())
+            .field("animacy", &self.animacy())
+            .field("person", &self.person())
+            .finish()
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseGrammemesError {
+    /// One of the comma-separated parts isn't an abbreviation of any grammeme: "xyz", "nom,",
+    /// "nom, sg".
+    #[error("unknown grammeme abbreviation")]
+    Unknown,
+    /// Several grammemes of the same category are specified: "nom,gen", "sg,sg".
+    #[error("several grammemes of the same category")]
+    Duplicate,
+}
+
+impl core::str::FromStr for Grammemes {
+    type Err = ParseGrammemesError;
+
+    /// Parses comma-separated lowercase or uppercase abbreviations of grammemes, in any order:
+    /// "nom,sg,masc,inan", "PL,GEN". An empty string is parsed as [`Grammemes::EMPTY`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        macro_rules! find_abbr {
+            ($tag:expr, $t:ty) => {
+                <$t>::VALUES.into_iter().find(|x| $tag == x.abbr_lower() || $tag == x.abbr_upper())
+            };
+        }
+        fn set<T>(field: &mut Option<T>, value: T) -> Result<(), ParseGrammemesError> {
+            match field.replace(value) {
+                Some(_) => Err(ParseGrammemesError::Duplicate),
+                None => Ok(()),
+            }
+        }
+
+        let mut result = Self::EMPTY;
+        if s.is_empty() {
+            return Ok(result);
+        }
+        let (mut case, mut number, mut gender, mut animacy, mut person) =
+            (None, None, None, None, None);
+
+        for tag in s.split(',') {
+            if let Some(x) = find_abbr!(tag, CaseEx) {
+                set(&mut case, x)?;
+            } else if let Some(x) = find_abbr!(tag, Number) {
+                set(&mut number, x)?;
+            } else if let Some(x) = find_abbr!(tag, GenderEx) {
+                set(&mut gender, x)?;
+            } else if let Some(x) = find_abbr!(tag, Animacy) {
+                set(&mut animacy, x)?;
+            } else if let Some(x) = find_abbr!(tag, Person) {
+                set(&mut person, x)?;
+            } else {
+                return Err(ParseGrammemesError::Unknown);
+            }
+        }
+
+        result.set_case(case);
+        result.set_number(number);
+        result.set_gender(gender);
+        result.set_animacy(animacy);
+        result.set_person(person);
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::HasNumber;
+    use std::string::ToString;
+
+    #[test]
+    fn fields() {
+        let empty = Grammemes::new();
+        assert!(empty.is_empty());
+        assert_eq!(empty, Grammemes::default());
+        assert_eq!((empty.case(), empty.number(), empty.gender()), (None, None, None));
+        assert_eq!((empty.animacy(), empty.person()), (None, None));
+
+        // Every value of every category is stored without affecting the others
+        let full = Grammemes::new()
+            .with_case(CaseEx::Locative)
+            .with_number(Number::Plural)
+            .with_gender(GenderEx::Common)
+            .with_animacy(Animacy::Animate)
+            .with_person(Person::Third);
+        for case in CaseEx::VALUES {
+            let x = full.with_case(case);
+            assert_eq!(
+                (x.case(), x.number(), x.person()),
+                (Some(case), full.number(), full.person())
+            );
+        }
+        for gender in GenderEx::VALUES {
+            let x = full.with_gender(gender);
+            assert_eq!(
+                (x.gender(), x.case(), x.animacy()),
+                (Some(gender), full.case(), full.animacy())
+            );
+        }
+
+        let mut x = full;
+        x.set_gender(None);
+        x.set_case(None);
+        assert_eq!(x.to_string(), "pl,an,3");
+        x.set_number(None);
+        x.set_animacy(None);
+        x.set_person(None);
+        assert!(x.is_empty());
+    }
+
+    #[test]
+    fn conversions() {
+        let info = DeclInfo::new(Case::Dative, Number::Plural, Gender::Feminine, Animacy::Animate);
+        assert_eq!(Grammemes::from(info).to_string(), "dat,pl,fem,an");
+        assert_eq!(Grammemes::from(CaseAndNumber::InstrumentalSingular).to_string(), "ins,sg");
+        assert_eq!(Grammemes::from(GenderAnimacy::NeuterInanimate).to_string(), "neut,inan");
+        assert_eq!(Grammemes::from(GenderExAnimacy::CommonAnimate).to_string(), "masc/fem,an");
+        assert_eq!(Grammemes::from(PersonAndNumber::SecondPlural).to_string(), "pl,2");
+
+        const TAG: Grammemes = Grammemes::from(Case::Genitive).with_number(Number::Singular);
+        assert_eq!((TAG.case(), TAG.number()), (Some(CaseEx::Genitive), Some(Number::Singular)));
+    }
+
+    #[test]
+    fn fmt_and_parse() {
+        for s in ["", "nom", "sg", "nom,sg,masc,inan", "loc,pl,masc/fem,an,3", "transl,neut", "1"] {
+            let x: Grammemes = s.parse().unwrap();
+            assert_eq!(x.to_string(), s);
+        }
+        for info in DeclInfo::iter_all() {
+            let x = Grammemes::from(info);
+            assert_eq!(x.to_string().parse(), Ok(x));
+        }
+
+        // Any order and letter case are accepted, but the output is canonical
+        let x: Grammemes = "INAN,masc,SG,nom".parse().unwrap();
+        assert_eq!(x.to_string(), "nom,sg,masc,inan");
+
+        type E = ParseGrammemesError;
+        for (s, err) in [
+            ("nom,gen", E::Duplicate),
+            ("sg,pl", E::Duplicate),
+            ("an,inan", E::Duplicate),
+            ("nom,", E::Unknown),
+            (",nom", E::Unknown),
+            ("nom, sg", E::Unknown),
+            ("Nom", E::Unknown),
+            ("им.", E::Unknown),
+            ("voc", E::Unknown),
+        ] {
+            assert_eq!(s.parse::<Grammemes>(), Err(err), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn matches() {
+        let tag = |s: &str| s.parse::<Grammemes>().unwrap();
+
+        // Unset categories are wildcards on both sides
+        assert!(tag("gen").matches(&tag("gen,pl,fem")));
+        assert!(tag("gen,pl,fem").matches(&tag("gen")));
+        assert!(tag("").matches(&tag("nom,sg,masc,inan")));
+        assert!(tag("pl,an").matches(&tag("acc,pl,masc")));
+
+        // Categories set on both sides must be the same
+        assert!(!tag("gen,pl").matches(&tag("gen,sg")));
+        assert!(!tag("masc").matches(&tag("masc/fem")));
+        assert!(!tag("nom,an").matches(&tag("inan")));
+        assert!(!tag("1").matches(&tag("sg,2")));
+
+        for info in DeclInfo::iter_all() {
+            let x = Grammemes::from(info);
+            assert!(x.matches(&x));
+            assert!(Grammemes::from(info.case_and_number()).matches(&x));
+            let other_number =
+                if info.number.is_singular() { Number::Plural } else { Number::Singular };
+            assert!(!x.matches(&x.with_number(other_number)));
+        }
+    }
+}
@@ -1,13 +1,22 @@
 mod abbrs;
 mod convert;
+mod from_str;
+mod grammemes;
 mod ops;
 mod traits;
 
 pub use convert::*;
+pub use from_str::*;
+pub use grammemes::*;
 pub use traits::*;
 
 /// A main or secondary Russian grammatical case.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+///
+/// The cases are ordered as in the paradigm tables: the main 6 cases in the canonical order
+/// (nominative, genitive, dative, accusative, instrumental, prepositional), followed by the
+/// partitive, translative and locative cases.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum CaseEx {
     #[default]
@@ -21,8 +30,10 @@ pub enum CaseEx {
     Translative = 7,
     Locative = 8,
 }
-/// One of the main 6 Russian grammatical cases.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// One of the main 6 Russian grammatical cases, ordered in the canonical order: nominative,
+/// genitive, dative, accusative, instrumental, prepositional.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Case {
     #[default]
     Nominative = 0,
@@ -35,7 +46,7 @@ pub enum Case {
 
 /// A main or secondary Russian grammatical gender: [`Masculine`][GenderEx::Masculine],
 /// [`Neuter`][GenderEx::Neuter], [`Feminine`][GenderEx::Feminine] or [`Common`][GenderEx::Common].
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum GenderEx {
     #[default]
     Masculine = 0,
@@ -45,7 +56,7 @@ pub enum GenderEx {
 }
 /// One of the main 3 Russian grammatical genders: [`Masculine`][Gender::Masculine],
 /// [`Neuter`][Gender::Neuter], [`Feminine`][Gender::Feminine].
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Gender {
     #[default]
     Masculine = 0,
@@ -54,21 +65,42 @@ pub enum Gender {
 }
 
 /// A Russian grammatical animacy: [`Inanimate`][Animacy::Inanimate] or [`Animate`][Animacy::Animate].
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Animacy {
     #[default]
     Inanimate = 0,
     Animate = 1,
 }
 /// A Russian grammatical number: [`Singular`][Number::Singular] or [`Plural`][Number::Plural].
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Number {
     #[default]
     Singular = 0,
     Plural = 1,
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+/// A Russian grammatical person: [`First`][Person::First], [`Second`][Person::Second] or
+/// [`Third`][Person::Third].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Person {
+    #[default]
+    First = 0,
+    Second = 1,
+    Third = 2,
+}
+
+/// A Russian verb aspect: [`Imperfective`][Aspect::Imperfective] or
+/// [`Perfective`][Aspect::Perfective].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Aspect {
+    #[default]
+    Imperfective = 0,
+    Perfective = 1,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum GenderExAnimacy {
     #[default]
     MasculineInanimate = 0,
@@ -81,7 +113,7 @@ pub enum GenderExAnimacy {
     // just so that CommonAnimate has the animacy bit set to 1.
     CommonAnimate = 7,
 }
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum GenderAnimacy {
     #[default]
     MasculineInanimate = 0,
@@ -92,6 +124,44 @@ pub enum GenderAnimacy {
     FeminineAnimate = 5,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CaseAndNumber {
+    #[default]
+    NominativeSingular = 0,
+    NominativePlural = 1,
+    GenitiveSingular = 2,
+    GenitivePlural = 3,
+    DativeSingular = 4,
+    DativePlural = 5,
+    AccusativeSingular = 6,
+    AccusativePlural = 7,
+    InstrumentalSingular = 8,
+    InstrumentalPlural = 9,
+    PrepositionalSingular = 10,
+    PrepositionalPlural = 11,
+}
+/// A gender in the singular, or the plural, where the gender isn't distinguished: the forms of
+/// short adjectives (нов, нова, ново, новы) and past tense verbs (брал, брала, брало, брали).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GenderOrPlural {
+    #[default]
+    Masculine = 0,
+    Neuter = 1,
+    Feminine = 2,
+    Plural = 3,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PersonAndNumber {
+    #[default]
+    FirstSingular = 0,
+    FirstPlural = 1,
+    SecondSingular = 2,
+    SecondPlural = 3,
+    ThirdSingular = 4,
+    ThirdPlural = 5,
+}
+
 impl CaseEx {
     pub const VALUES: [CaseEx; 9] = [
         Self::NOM,
@@ -123,6 +193,12 @@ impl Animacy {
 impl Number {
     pub const VALUES: [Number; 2] = [Self::Singular, Self::Plural];
 }
+impl Person {
+    pub const VALUES: [Person; 3] = [Self::First, Self::Second, Self::Third];
+}
+impl Aspect {
+    pub const VALUES: [Aspect; 2] = [Self::Imperfective, Self::Perfective];
+}
 
 impl GenderExAnimacy {
     pub const VALUES: [GenderExAnimacy; 7] = [
@@ -135,6 +211,22 @@ impl GenderExAnimacy {
         Self::CommonAnimate,
     ];
 }
+impl CaseAndNumber {
+    pub const VALUES: [CaseAndNumber; 12] = [
+        Self::NominativeSingular,
+        Self::GenitiveSingular,
+        Self::DativeSingular,
+        Self::AccusativeSingular,
+        Self::InstrumentalSingular,
+        Self::PrepositionalSingular,
+        Self::NominativePlural,
+        Self::GenitivePlural,
+        Self::DativePlural,
+        Self::AccusativePlural,
+        Self::InstrumentalPlural,
+        Self::PrepositionalPlural,
+    ];
+}
 impl GenderAnimacy {
     pub const VALUES: [GenderAnimacy; 6] = [
         Self::MasculineInanimate,
@@ -145,3 +237,60 @@ impl GenderAnimacy {
         Self::FeminineAnimate,
     ];
 }
+impl GenderOrPlural {
+    pub const VALUES: [GenderOrPlural; 4] =
+        [Self::Masculine, Self::Neuter, Self::Feminine, Self::Plural];
+}
+impl PersonAndNumber {
+    pub const VALUES: [PersonAndNumber; 6] = [
+        Self::FirstSingular,
+        Self::SecondSingular,
+        Self::ThirdSingular,
+        Self::FirstPlural,
+        Self::SecondPlural,
+        Self::ThirdPlural,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn assert_sorted<T: Ord + core::fmt::Debug>(values: &[T]) {
+        assert!(values.is_sorted_by(|a, b| a < b), "{values:?}");
+    }
+
+    #[test]
+    fn ordering() {
+        use Case::*;
+        assert_sorted(&[Nominative, Genitive, Dative, Accusative, Instrumental, Prepositional]);
+        assert_sorted(&CaseEx::VALUES);
+        assert!(CaseEx::Prepositional < CaseEx::Partitive);
+        assert!(CaseEx::Translative < CaseEx::Locative);
+
+        assert_sorted(&Gender::VALUES);
+        assert_sorted(&GenderEx::VALUES);
+        assert_sorted(&Animacy::VALUES);
+        assert_sorted(&Number::VALUES);
+        assert_sorted(&Person::VALUES);
+        // Gender and animacy pairs are ordered by gender first
+        assert_sorted(&GenderAnimacy::VALUES);
+        assert_sorted(&GenderExAnimacy::VALUES);
+        assert_sorted(&GenderOrPlural::VALUES);
+    }
+
+    #[test]
+    fn gender_or_plural() {
+        for gender in Gender::VALUES {
+            let form = GenderOrPlural::new(gender, Number::Singular);
+            assert_eq!((form.gender(), form.number()), (Some(gender), Number::Singular));
+            assert_eq!(GenderOrPlural::from(gender), form);
+
+            // The gender is ignored in the plural
+            let form = GenderOrPlural::from((gender, Number::Plural));
+            assert_eq!(form, GenderOrPlural::Plural);
+            assert_eq!((form.gender(), form.number()), (None, Number::Plural));
+        }
+    }
+}
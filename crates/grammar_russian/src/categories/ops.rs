@@ -1,6 +1,7 @@
 use super::{
-    Animacy, Case, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Number,
-    traits::{HasAnimacy, HasGender, HasGenderEx},
+    Animacy, Case, CaseAndNumber, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy,
+    GenderOrPlural, Number, Person, PersonAndNumber,
+    traits::{HasAnimacy, HasCase, HasGender, HasGenderEx, HasNumber, HasPerson},
 };
 
 impl CaseEx {
@@ -9,13 +10,13 @@ impl CaseEx {
             CaseEx::Partitive => (Case::Genitive, number),
             CaseEx::Translative => (Case::Nominative, Number::Plural),
             CaseEx::Locative => (Case::Prepositional, number),
-            _ => (unsafe { std::mem::transmute::<CaseEx, Case>(self) }, number),
+            _ => (unsafe { core::mem::transmute::<CaseEx, Case>(self) }, number),
         }
     }
 }
 impl Case {
     pub const fn acc_is_nom<A>(self, animacy: A) -> Option<bool>
-    where A: [const] HasAnimacy + [const] std::marker::Destruct {
+    where A: [const] HasAnimacy + [const] core::marker::Destruct {
         match self {
             Self::Nominative => Some(true),
             Self::Genitive => Some(false),
@@ -24,11 +25,11 @@ impl Case {
         }
     }
     pub const fn is_nom_or_acc_inan<A>(self, animacy: A) -> bool
-    where A: [const] HasAnimacy + [const] std::marker::Destruct {
+    where A: [const] HasAnimacy + [const] core::marker::Destruct {
         self.acc_is_nom(animacy) == Some(true)
     }
     pub const fn is_gen_or_acc_an<A>(self, animacy: A) -> bool
-    where A: [const] HasAnimacy + [const] std::marker::Destruct {
+    where A: [const] HasAnimacy + [const] core::marker::Destruct {
         self.acc_is_nom(animacy) == Some(false)
     }
 }
@@ -52,12 +53,23 @@ impl Animacy {
 impl GenderExAnimacy {
     pub const fn new(gender_ex: GenderEx, animacy: Animacy) -> Self {
         let result = ((gender_ex as u8) << 1) | animacy as u8;
-        unsafe { std::mem::transmute(if result == 6 { 7 } else { result }) }
+        unsafe { core::mem::transmute(if result == 6 { 7 } else { result }) }
     }
 }
 impl GenderAnimacy {
     pub const fn new(gender: Gender, animacy: Animacy) -> Self {
-        unsafe { std::mem::transmute(((gender as u8) << 1) | animacy as u8) }
+        unsafe { core::mem::transmute(((gender as u8) << 1) | animacy as u8) }
+    }
+}
+
+impl CaseAndNumber {
+    pub const fn new(case: Case, number: Number) -> Self {
+        unsafe { core::mem::transmute(((case as u8) << 1) | number as u8) }
+    }
+}
+impl PersonAndNumber {
+    pub const fn new(person: Person, number: Number) -> Self {
+        unsafe { core::mem::transmute(((person as u8) << 1) | number as u8) }
     }
 }
 
@@ -92,3 +104,68 @@ impl GenderAnimacy {
         (self.gender(), self.animacy())
     }
 }
+
+// Compose/decompose CaseAndNumber values
+impl const From<(Case, Number)> for CaseAndNumber {
+    fn from(value: (Case, Number)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+impl Case {
+    pub const fn with(self, number: Number) -> CaseAndNumber {
+        CaseAndNumber::new(self, number)
+    }
+}
+impl CaseAndNumber {
+    pub const fn parts(self) -> (Case, Number) {
+        (self.case(), self.number())
+    }
+}
+
+// Compose/decompose PersonAndNumber values
+impl const From<(Person, Number)> for PersonAndNumber {
+    fn from(value: (Person, Number)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+impl Person {
+    pub const fn with(self, number: Number) -> PersonAndNumber {
+        PersonAndNumber::new(self, number)
+    }
+}
+impl PersonAndNumber {
+    pub const fn parts(self) -> (Person, Number) {
+        (self.person(), self.number())
+    }
+}
+
+// Compose/decompose GenderOrPlural values
+impl GenderOrPlural {
+    /// Creates the form of the specified gender in the singular, or the plural, ignoring the
+    /// gender.
+    pub const fn new(gender: Gender, number: Number) -> Self {
+        match number {
+            Number::Singular => unsafe { core::mem::transmute::<Gender, Self>(gender) },
+            Number::Plural => Self::Plural,
+        }
+    }
+    /// Returns the gender of the singular form, or `None` for the plural.
+    pub const fn gender(self) -> Option<Gender> {
+        match self {
+            Self::Masculine => Some(Gender::Masculine),
+            Self::Neuter => Some(Gender::Neuter),
+            Self::Feminine => Some(Gender::Feminine),
+            Self::Plural => None,
+        }
+    }
+}
+impl const From<(Gender, Number)> for GenderOrPlural {
+    fn from(value: (Gender, Number)) -> Self {
+        Self::new(value.0, value.1)
+    }
+}
+impl const From<Gender> for GenderOrPlural {
+    fn from(value: Gender) -> Self {
+        Self::new(value, Number::Singular)
+    }
+}
@@ -1,4 +1,7 @@
-use super::{Animacy, Case, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Number};
+use super::{
+    Animacy, Case, CaseAndNumber, CaseEx, Gender, GenderAnimacy, GenderEx, GenderExAnimacy,
+    GenderOrPlural, Number, Person, PersonAndNumber,
+};
 
 // Traits providing CaseEx and Case values
 pub const trait HasCaseEx {
@@ -38,6 +41,11 @@ pub const trait HasNumber {
     }
 }
 
+// Trait providing Person values
+pub const trait HasPerson {
+    fn person(&self) -> Person;
+}
+
 // All values provide themselves
 impl const HasCaseEx for CaseEx {
     fn case_ex(&self) -> CaseEx {
@@ -69,26 +77,65 @@ impl const HasNumber for Number {
         *self
     }
 }
+impl const HasPerson for Person {
+    fn person(&self) -> Person {
+        *self
+    }
+}
 
 // Gender[Ex]Animacy provide Gender[Ex] and Animacy values
 impl const HasGenderEx for GenderExAnimacy {
     fn gender_ex(&self) -> GenderEx {
-        unsafe { std::mem::transmute((*self as u8) >> 1) }
+        unsafe { core::mem::transmute((*self as u8) >> 1) }
     }
 }
 impl const HasGender for GenderAnimacy {
     fn gender(&self) -> Gender {
-        unsafe { std::mem::transmute((*self as u8) >> 1) }
+        unsafe { core::mem::transmute((*self as u8) >> 1) }
     }
 }
 impl const HasAnimacy for GenderExAnimacy {
     fn animacy(&self) -> Animacy {
-        unsafe { std::mem::transmute((*self as u8) & 1) }
+        unsafe { core::mem::transmute((*self as u8) & 1) }
     }
 }
 impl const HasAnimacy for GenderAnimacy {
     fn animacy(&self) -> Animacy {
-        unsafe { std::mem::transmute((*self as u8) & 1) }
+        unsafe { core::mem::transmute((*self as u8) & 1) }
+    }
+}
+
+// CaseAndNumber provides Case and Number values
+impl const HasCase for CaseAndNumber {
+    fn case(&self) -> Case {
+        unsafe { core::mem::transmute((*self as u8) >> 1) }
+    }
+}
+impl const HasNumber for CaseAndNumber {
+    fn number(&self) -> Number {
+        unsafe { core::mem::transmute((*self as u8) & 1) }
+    }
+}
+
+// PersonAndNumber provides Person and Number values
+impl const HasPerson for PersonAndNumber {
+    fn person(&self) -> Person {
+        unsafe { core::mem::transmute((*self as u8) >> 1) }
+    }
+}
+impl const HasNumber for PersonAndNumber {
+    fn number(&self) -> Number {
+        unsafe { core::mem::transmute((*self as u8) & 1) }
+    }
+}
+
+// GenderOrPlural provides Number values
+impl const HasNumber for GenderOrPlural {
+    fn number(&self) -> Number {
+        match self {
+            Self::Plural => Number::Plural,
+            _ => Number::Singular,
+        }
     }
 }
 
@@ -0,0 +1,110 @@
+use thiserror::Error;
+
+/// One of Zaliznyak's 16 verb conjugation types, identified by the shape of the infinitive and
+/// present tense stems: `1` (делать - делаю), `2` (рисовать - рисую), `4` (говорить - говорю),
+/// `5` (смотреть - смотрю), etc.
+///
+/// Types 1–13 are productive or semi-productive classes, while types 14–16 are small groups of
+/// irregular verbs (жать - жму, деть - дену, жить - живу).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConjugationType {
+    Type1 = 1,
+    Type2 = 2,
+    Type3 = 3,
+    Type4 = 4,
+    Type5 = 5,
+    Type6 = 6,
+    Type7 = 7,
+    Type8 = 8,
+    Type9 = 9,
+    Type10 = 10,
+    Type11 = 11,
+    Type12 = 12,
+    Type13 = 13,
+    Type14 = 14,
+    Type15 = 15,
+    Type16 = 16,
+}
+
+impl ConjugationType {
+    pub const VALUES: [ConjugationType; 16] = [
+        Self::Type1,
+        Self::Type2,
+        Self::Type3,
+        Self::Type4,
+        Self::Type5,
+        Self::Type6,
+        Self::Type7,
+        Self::Type8,
+        Self::Type9,
+        Self::Type10,
+        Self::Type11,
+        Self::Type12,
+        Self::Type13,
+        Self::Type14,
+        Self::Type15,
+        Self::Type16,
+    ];
+
+    /// Returns the conjugation type with the specified number, or `None`, if it's not in 1–16.
+    pub const fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1..=16 => Some(Self::VALUES[number as usize - 1]),
+            _ => None,
+        }
+    }
+    /// Returns the number of the conjugation type, 1–16.
+    pub const fn to_number(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns whether verbs of this type are conjugated by the second conjugation (говоришь,
+    /// говорят), instead of the first one (делаешь, делают). Only types 4 and 5 are.
+    pub const fn is_second_conjugation(self) -> bool {
+        matches!(self, Self::Type4 | Self::Type5)
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("invalid conjugation type")]
+pub struct ParseConjugationTypeError;
+
+impl core::fmt::Display for ConjugationType {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.to_number().fmt(f)
+    }
+}
+
+impl core::str::FromStr for ConjugationType {
+    type Err = ParseConjugationTypeError;
+
+    /// Parses the conjugation type's number: "1" through "16".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Don't accept signs or leading zeros, that u8::from_str would allow
+        if !s.bytes().all(|x| x.is_ascii_digit()) || s.starts_with('0') {
+            return Err(ParseConjugationTypeError);
+        }
+        s.parse().ok().and_then(Self::from_number).ok_or(ParseConjugationTypeError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn numbers() {
+        for (i, ty) in ConjugationType::VALUES.into_iter().enumerate() {
+            assert_eq!(ty.to_number() as usize, i + 1);
+            assert_eq!(ConjugationType::from_number(i as u8 + 1), Some(ty));
+            assert_eq!(ty.to_string().parse(), Ok(ty));
+        }
+        assert_eq!(ConjugationType::from_number(0), None);
+        assert_eq!(ConjugationType::from_number(17), None);
+
+        for s in ["", "0", "01", "17", "+1", "1a", "a"] {
+            assert_eq!(s.parse::<ConjugationType>(), Err(ParseConjugationTypeError), "{s:?}");
+        }
+    }
+}
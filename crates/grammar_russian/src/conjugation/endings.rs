@@ -0,0 +1,43 @@
+use crate::categories::{Gender, Number, Person, PersonAndNumber};
+
+// Each cell is (unstressed, stressed). Endings of the first conjugation with 'е' surface as 'ё'
+// under stress (куёшь, несёт).
+#[rustfmt::skip]
+const PRESENT_LOOKUP: [(&str, &str); 12] = [
+    //   conjugations: I,            II
+    /* 1 sg */ ("ю",   "ю"),   ("ю",   "ю"),
+    /* 1 pl */ ("ем",  "ём"),  ("им",  "им"),
+    /* 2 sg */ ("ешь", "ёшь"), ("ишь", "ишь"),
+    /* 2 pl */ ("ете", "ёте"), ("ите", "ите"),
+    /* 3 sg */ ("ет",  "ёт"),  ("ит",  "ит"),
+    /* 3 pl */ ("ют",  "ют"),  ("ят",  "ят"),
+];
+
+#[rustfmt::skip]
+const PAST_LOOKUP: [&str; 4] = [
+    /* masc */ "л",
+    /* n    */ "ло",
+    /* fem  */ "ла",
+    /* pl   */ "ли",
+];
+
+/// Returns the present tense ending of the first or the second conjugation, before the spelling
+/// rules are applied (ю → у after hissing consonants).
+pub(super) const fn present_ending(
+    second_conjugation: bool,
+    person: Person,
+    number: Number,
+    stressed: bool,
+) -> &'static str {
+    let index = PersonAndNumber::new(person, number) as usize * 2 + second_conjugation as usize;
+    let (un_str, str) = PRESENT_LOOKUP[index];
+    if stressed { str } else { un_str }
+}
+
+/// Returns the past tense ending. The gender is ignored in the plural.
+pub(super) const fn past_ending(gender: Gender, number: Number) -> &'static str {
+    match number {
+        Number::Singular => PAST_LOOKUP[gender as usize],
+        Number::Plural => PAST_LOOKUP[3],
+    }
+}
@@ -0,0 +1,383 @@
+use crate::{
+    InflectionBuffer, Letter, LetterSliceExt, StemError,
+    categories::{Gender, Number, Person},
+    conjugation::{
+        ConjugationType, Verb,
+        endings::{past_ending, present_ending},
+    },
+    letters,
+};
+use alloc::{borrow::ToOwned, string::String};
+use thiserror::Error;
+
+/// An error returned when a verb can't be conjugated.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ConjugateError {
+    /// The stem contains a character that isn't a Cyrillic letter.
+    #[error(transparent)]
+    InvalidStem(#[from] StemError),
+    /// The verb's conjugation type isn't supported yet. Only types 1, 2, 4 and 5 are supported.
+    #[error("conjugation type {0} isn't supported")]
+    UnsupportedConjugation(ConjugationType),
+    /// The stem doesn't end the way the conjugation type requires: in 'а', 'я' or 'е' for type 1,
+    /// in "ова" or "ева" for type 2, in 'и' for type 4, and in 'е', 'а' or 'я' for type 5.
+    #[error("the stem doesn't match the conjugation type")]
+    StemMismatch,
+}
+
+// Consonant alternations in the 1st person singular of the second conjugation (люблю, вожу,
+// пишу). Longer clusters are checked first.
+const ALTERNATIONS: [(&str, &str); 11] = [
+    ("ст", "щ"),
+    ("зд", "зж"),
+    ("б", "бл"),
+    ("п", "пл"),
+    ("в", "вл"),
+    ("ф", "фл"),
+    ("м", "мл"),
+    ("д", "ж"),
+    ("з", "ж"),
+    ("с", "ш"),
+    ("т", "ч"),
+];
+
+impl Verb<'_> {
+    /// Returns the verb's infinitive: делать, рисоваться.
+    pub fn infinitive(&self) -> Result<String, ConjugateError> {
+        let mut buf = self.stem_buf()?;
+        buf.append_to_ending_unchecked("ть");
+        Ok(self.finish(buf))
+    }
+
+    /// Returns the present tense form of the verb in the specified person and number, or the
+    /// simple future tense form, if the verb is perfective.
+    pub fn present(&self, person: Person, number: Number) -> Result<String, ConjugateError> {
+        let mut buf = self.present_stem()?;
+        let second = self.info.conjugation.is_second_conjugation();
+        let stressed = self.info.stress.present.is_ending_stressed(person, number);
+
+        if second && matches!((person, number), (Person::First, Number::Singular)) {
+            alternate_consonant(&mut buf);
+        }
+
+        let ending = present_ending(second, person, number, stressed);
+        match (buf.stem().last().is_some_and(|x| x.is_hissing()), ending) {
+            (true, "ю") => buf.append_to_ending_unchecked("у"),
+            (true, "ят") => buf.append_to_ending_unchecked("ат"),
+            (true, "ют") => buf.append_to_ending_unchecked("ут"),
+            _ => buf.append_to_ending_unchecked(ending),
+        }
+        Ok(self.finish(buf))
+    }
+
+    /// Returns the past tense form of the verb in the specified gender and number. The gender is
+    /// ignored in the plural.
+    ///
+    /// The past stress schema isn't used: the forms aren't stress-marked, and the past endings
+    /// don't have an 'е' that would become 'ё' under stress, unlike the present ones (куёшь).
+    pub fn past(&self, gender: Gender, number: Number) -> Result<String, ConjugateError> {
+        let mut buf = self.stem_buf()?;
+        buf.append_to_ending_unchecked(past_ending(gender, number));
+        Ok(self.finish(buf))
+    }
+
+    /// Returns the imperative form of the verb in the specified number: делай, делайте.
+    pub fn imperative(&self, number: Number) -> Result<String, ConjugateError> {
+        let mut buf = self.present_stem()?;
+
+        if buf.stem().last().is_some_and(|x| x.is_vowel()) {
+            // Stems ending in a vowel take -й, unless the ending is stressed (стой, but пои́)
+            let stressed = self.info.conjugation.is_second_conjugation()
+                && self.info.stress.present.is_imperative_ending_stressed();
+            buf.append_to_ending_unchecked(if stressed { "и" } else { "й" });
+        } else if self.info.stress.present.is_imperative_ending_stressed()
+            || ends_in_cluster(buf.stem())
+        {
+            // Stressed endings, and stems ending in a consonant cluster, take -и (говори, помни)
+            buf.append_to_ending_unchecked("и");
+        } else {
+            buf.append_to_ending_unchecked("ь");
+        }
+
+        if matches!(number, Number::Plural) {
+            buf.append_to_ending_unchecked("те");
+        }
+        Ok(self.finish(buf))
+    }
+
+    // Returns the buffer with the verb's stem, making sure that it consists of Cyrillic letters,
+    // and ends the way the conjugation type requires
+    fn stem_buf(&self) -> Result<InflectionBuffer, ConjugateError> {
+        let buf = InflectionBuffer::from_stem(self.stem)?;
+
+        let matches = match self.info.conjugation {
+            ConjugationType::Type1 => {
+                matches!(buf.stem(), [.., letters::а | letters::я | letters::е])
+            },
+            ConjugationType::Type2 => {
+                matches!(buf.stem(), [.., letters::о | letters::е, letters::в, letters::а])
+            },
+            ConjugationType::Type4 => matches!(buf.stem(), [.., letters::и]),
+            ConjugationType::Type5 => {
+                matches!(buf.stem(), [.., letters::е | letters::а | letters::я])
+            },
+            ty => return Err(ConjugateError::UnsupportedConjugation(ty)),
+        };
+        if !matches {
+            return Err(ConjugateError::StemMismatch);
+        }
+        Ok(buf)
+    }
+
+    // Returns the buffer with the stem of the present tense forms, without the 1st person
+    // singular alternations
+    fn present_stem(&self) -> Result<InflectionBuffer, ConjugateError> {
+        let mut buf = self.stem_buf()?;
+
+        match self.info.conjugation {
+            ConjugationType::Type1 => {},
+            ConjugationType::Type2 => {
+                let ova = matches!(buf.stem(), [.., letters::о, _, _]);
+                buf.shrink_stem_by("ова".len());
+                // -ева- becomes -ю-, or -у- after hissing consonants and 'ц' (воюю, but танцую)
+                let hard = ova || buf.stem().last().is_some_and(|x| x.is_sibilant());
+                buf.append_to_stem_unchecked(if hard { "у" } else { "ю" });
+            },
+            // The stem's last vowel is checked by stem_buf
+            _ => buf.shrink_stem_by("и".len()),
+        }
+        Ok(buf)
+    }
+
+    // Appends the reflexive postfix, if the verb is reflexive: -ся after consonants, -сь after
+    // vowels (делался, but делалась)
+    fn finish(&self, mut buf: InflectionBuffer) -> String {
+        if self.info.is_reflexive {
+            let vowel = buf.as_letters().last().is_some_and(|x| x.is_vowel());
+            buf.append_to_ending_unchecked(if vowel { "сь" } else { "ся" });
+        }
+        buf.as_str().to_owned()
+    }
+}
+
+fn ends_in_cluster(stem: &[Letter]) -> bool {
+    matches!(stem, [.., a, b] if a.is_consonant() && b.is_consonant())
+}
+
+fn alternate_consonant(buf: &mut InflectionBuffer) {
+    for (from, to) in ALTERNATIONS {
+        if buf.stem().as_str().ends_with(from) {
+            buf.shrink_stem_by(from.len());
+            buf.append_to_stem_unchecked(to);
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::Aspect,
+        conjugation::VerbInfo,
+        stress::{VerbPastStress, VerbPresentStress, VerbStress},
+    };
+    use std::vec::Vec;
+
+    fn verb(stem: &str, ty: u8, present: VerbPresentStress) -> Verb<'_> {
+        let info = VerbInfo {
+            conjugation: ConjugationType::from_number(ty).unwrap(),
+            stress: VerbStress::new(present, VerbPastStress::A),
+            is_reflexive: false,
+            aspect: Aspect::Imperfective,
+        };
+        Verb { stem, info }
+    }
+
+    #[track_caller]
+    fn assert_present(verb: Verb, expected: [&str; 6]) {
+        let mut forms = Vec::new();
+        for number in Number::VALUES {
+            for person in Person::VALUES {
+                forms.push(verb.present(person, number).unwrap());
+            }
+        }
+        assert_eq!(forms, expected);
+    }
+
+    #[test]
+    fn present() {
+        use VerbPresentStress::*;
+
+        assert_present(verb("дела", 1, A), [
+            "делаю",
+            "делаешь",
+            "делает",
+            "делаем",
+            "делаете",
+            "делают",
+        ]);
+        assert_present(verb("гуля", 1, A), [
+            "гуляю",
+            "гуляешь",
+            "гуляет",
+            "гуляем",
+            "гуляете",
+            "гуляют",
+        ]);
+        assert_present(verb("рисова", 2, A), [
+            "рисую",
+            "рисуешь",
+            "рисует",
+            "рисуем",
+            "рисуете",
+            "рисуют",
+        ]);
+        assert_present(verb("воева", 2, A), [
+            "воюю",
+            "воюешь",
+            "воюет",
+            "воюем",
+            "воюете",
+            "воюют",
+        ]);
+        assert_present(verb("танцева", 2, A), [
+            "танцую",
+            "танцуешь",
+            "танцует",
+            "танцуем",
+            "танцуете",
+            "танцуют",
+        ]);
+        assert_present(verb("кова", 2, B), ["кую", "куёшь", "куёт", "куём", "куёте", "куют"]);
+
+        assert_present(verb("говори", 4, B), [
+            "говорю",
+            "говоришь",
+            "говорит",
+            "говорим",
+            "говорите",
+            "говорят",
+        ]);
+        assert_present(verb("люби", 4, C), [
+            "люблю",
+            "любишь",
+            "любит",
+            "любим",
+            "любите",
+            "любят",
+        ]);
+        assert_present(verb("ходи", 4, C), [
+            "хожу",
+            "ходишь",
+            "ходит",
+            "ходим",
+            "ходите",
+            "ходят",
+        ]);
+        assert_present(verb("чисти", 4, A), [
+            "чищу",
+            "чистишь",
+            "чистит",
+            "чистим",
+            "чистите",
+            "чистят",
+        ]);
+        assert_present(verb("смотре", 5, C), [
+            "смотрю",
+            "смотришь",
+            "смотрит",
+            "смотрим",
+            "смотрите",
+            "смотрят",
+        ]);
+        assert_present(verb("держа", 5, C), [
+            "держу",
+            "держишь",
+            "держит",
+            "держим",
+            "держите",
+            "держат",
+        ]);
+        assert_present(verb("виде", 5, A), [
+            "вижу",
+            "видишь",
+            "видит",
+            "видим",
+            "видите",
+            "видят",
+        ]);
+        assert_present(verb("стоя", 5, B), [
+            "стою",
+            "стоишь",
+            "стоит",
+            "стоим",
+            "стоите",
+            "стоят",
+        ]);
+    }
+
+    #[test]
+    fn other_forms() {
+        use VerbPresentStress::*;
+
+        let forms = |verb: Verb| {
+            let sg = verb.imperative(Number::Singular).unwrap();
+            let pl = verb.imperative(Number::Plural).unwrap();
+            let past = Gender::VALUES.map(|x| verb.past(x, Number::Singular).unwrap());
+            let past_pl = verb.past(Gender::Masculine, Number::Plural).unwrap();
+            (verb.infinitive().unwrap(), sg, pl, past, past_pl)
+        };
+
+        let (inf, sg, pl, past, past_pl) = forms(verb("дела", 1, A));
+        assert_eq!((inf.as_str(), sg.as_str(), pl.as_str()), ("делать", "делай", "делайте"));
+        assert_eq!(past, ["делал", "делало", "делала"]);
+        assert_eq!(past_pl, "делали");
+
+        // Imperative of the second conjugation: -и, -ь or -й
+        let imperative = |stem, ty, stress| verb(stem, ty, stress).imperative(Number::Singular);
+        assert_eq!(imperative("говори", 4, B).unwrap(), "говори");
+        assert_eq!(imperative("люби", 4, C).unwrap(), "люби");
+        assert_eq!(imperative("помни", 4, A).unwrap(), "помни");
+        assert_eq!(imperative("вери", 4, A).unwrap(), "верь");
+        assert_eq!(imperative("стави", 4, A).unwrap(), "ставь");
+        assert_eq!(imperative("стои", 4, A).unwrap(), "стой");
+        assert_eq!(imperative("пои", 4, B).unwrap(), "пои");
+        assert_eq!(imperative("рисова", 2, A).unwrap(), "рисуй");
+    }
+
+    #[test]
+    fn reflexive() {
+        let mut verb = verb("учи", 4, VerbPresentStress::C);
+        verb.info.is_reflexive = true;
+
+        assert_eq!(verb.infinitive().unwrap(), "учиться");
+        assert_eq!(verb.present(Person::First, Number::Singular).unwrap(), "учусь");
+        assert_eq!(verb.present(Person::Third, Number::Plural).unwrap(), "учатся");
+        assert_eq!(verb.past(Gender::Masculine, Number::Singular).unwrap(), "учился");
+        assert_eq!(verb.past(Gender::Feminine, Number::Singular).unwrap(), "училась");
+        assert_eq!(verb.imperative(Number::Singular).unwrap(), "учись");
+        assert_eq!(verb.imperative(Number::Plural).unwrap(), "учитесь");
+    }
+
+    #[test]
+    fn errors() {
+        let stress = VerbPresentStress::A;
+        assert_eq!(
+            verb("нес", 7, stress).infinitive(),
+            Err(ConjugateError::UnsupportedConjugation(ConjugationType::Type7))
+        );
+        assert_eq!(verb("дела", 2, stress).infinitive(), Err(ConjugateError::StemMismatch));
+        assert_eq!(verb("смотре", 4, stress).infinitive(), Err(ConjugateError::StemMismatch));
+        assert_eq!(verb("говори", 1, stress).infinitive(), Err(ConjugateError::StemMismatch));
+
+        // The stem must consist only of Cyrillic letters
+        let err = StemError { ch: 'd', index: 0 };
+        assert_eq!(verb("dela", 1, stress).infinitive(), Err(ConjugateError::InvalidStem(err)));
+        let err = StemError { ch: 'x', index: 4 };
+        assert_eq!(
+            verb("деxа", 1, stress).past(Gender::Masculine, Number::Plural),
+            Err(err.into())
+        );
+    }
+}
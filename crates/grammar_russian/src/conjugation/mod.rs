@@ -0,0 +1,50 @@
+//! Conjugation of verbs, by Zaliznyak's conjugation types.
+//!
+//! Only the regular types 1, 2, 4 and 5 with stress schemas `a`, `b`, `c` and `c′` are
+//! supported so far. Verbs of the other types return
+//! [`ConjugateError::UnsupportedConjugation`].
+
+mod conjugation_type;
+mod endings;
+mod impl_verb;
+
+pub use conjugation_type::*;
+pub use impl_verb::*;
+
+use crate::{categories::Aspect, stress::VerbStress};
+
+/// The conjugation type, stress schema, reflexivity and aspect of a verb.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VerbInfo {
+    pub conjugation: ConjugationType,
+    pub stress: VerbStress,
+    /// Whether the verb is reflexive, and takes the -ся/-сь postfix in all of its forms.
+    pub is_reflexive: bool,
+    pub aspect: Aspect,
+}
+
+/// A verb, with the stem of its infinitive (without -ть and -ся), and its conjugation info.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, conjugation::*, stress::*};
+///
+/// let info = VerbInfo {
+///     conjugation: ConjugationType::Type4,
+///     stress: VerbStress::new(VerbPresentStress::C, VerbPastStress::A),
+///     is_reflexive: false,
+///     aspect: Aspect::Imperfective,
+/// };
+/// let verb = Verb { stem: "люби", info };
+///
+/// assert_eq!(verb.infinitive().unwrap(), "любить");
+/// assert_eq!(verb.present(Person::First, Number::Singular).unwrap(), "люблю");
+/// assert_eq!(verb.present(Person::Third, Number::Plural).unwrap(), "любят");
+/// assert_eq!(verb.past(Gender::Feminine, Number::Singular).unwrap(), "любила");
+/// assert_eq!(verb.imperative(Number::Singular).unwrap(), "люби");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Verb<'a> {
+    pub stem: &'a str,
+    pub info: VerbInfo,
+}
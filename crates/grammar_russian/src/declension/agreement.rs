@@ -0,0 +1,210 @@
+use crate::{
+    categories::{CaseEx, Gender, GenderEx, Number},
+    declension::{Adjective, DeclInfo, InflectError, Noun},
+};
+use alloc::string::String;
+
+impl Noun<'_> {
+    /// Returns the info that an adjective or a pronoun agreeing with the noun in the specified
+    /// case and number is inflected with.
+    ///
+    /// The number is replaced with the noun's tantum, the same way as in the noun's inflection,
+    /// and the secondary cases are resolved into the main ones (в лесу → в густом лесу). The
+    /// gender is the noun's own gender, and not the gender of its declension (высокого мужчины).
+    /// Nouns of common gender (сирота) are resolved as masculine (круглый сирота), use
+    /// [`agreement_info_as`][Self::agreement_info_as] for a female referent. The accusative is
+    /// kept, along with the noun's animacy, so that the adjective takes the same form as the noun.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::new("врач", "мо 4b".parse().unwrap());
+    /// let info = noun.agreement_info(CaseEx::Accusative, Number::Singular);
+    /// assert_eq!(info.acc_case(), Case::Genitive);
+    /// ```
+    pub const fn agreement_info(&self, case: CaseEx, number: Number) -> DeclInfo {
+        self.agreement_info_as(case, number, Gender::Masculine)
+    }
+    /// Same as [`agreement_info`][Self::agreement_info], but resolves the common gender into the
+    /// gender of the referent: круглый сирота about a boy, круглая сирота about a girl. The
+    /// referent is ignored, if the noun isn't of common gender.
+    ///
+    /// The referent only affects the agreement, and not the noun's own forms, which are always
+    /// inflected by its declension gender (see [`NounInfo::declension_gender`][crate::declension::NounInfo::declension_gender]): вижу круглого
+    /// сироту, вижу круглую сироту.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::new("сирот", "мо-жо 1d".parse().unwrap());
+    /// let info = noun.agreement_info_as(CaseEx::Accusative, Number::Singular, Gender::Feminine);
+    /// assert_eq!(info.gender, Gender::Feminine);
+    ///
+    /// // The noun's own gender takes precedence over the referent's
+    /// let noun = Noun::new("врач", "мо 4b".parse().unwrap());
+    /// let info = noun.agreement_info_as(CaseEx::Accusative, Number::Singular, Gender::Feminine);
+    /// assert_eq!((info.gender, info.acc_case()), (Gender::Masculine, Case::Genitive));
+    /// ```
+    pub const fn agreement_info_as(
+        &self,
+        case: CaseEx,
+        number: Number,
+        referent: Gender,
+    ) -> DeclInfo {
+        let number = match self.info.tantum {
+            Some(tantum) => tantum,
+            None => number,
+        };
+        let (case, number) = case.normalize_with(number);
+        let gender = match self.info.gender {
+            GenderEx::Common => referent,
+            gender => gender.normalize(),
+        };
+        DeclInfo { case, number, gender, animacy: self.info.animacy }
+    }
+}
+
+/// Returns the forms of the adjective and the noun, that agree with each other in the specified
+/// case and number: нового дома, новым домам. See [`Noun::agreement_info`].
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*};
+///
+/// let info = AdjectiveInfo {
+///     declension: Some("п 1a".parse().unwrap()),
+///     short_forms: ShortFormAvailability::Available,
+/// };
+/// let adj = Adjective { stem: "нов", info };
+/// let noun = Noun::new("врач", "мо 4b".parse().unwrap());
+///
+/// let (adj_form, noun_form) = agree(&adj, &noun, CaseEx::Accusative, Number::Singular).unwrap();
+/// assert_eq!((adj_form.as_str(), noun_form.as_str()), ("нового", "врача"));
+/// ```
+pub fn agree(
+    adj: &Adjective,
+    noun: &Noun,
+    case: CaseEx,
+    number: Number,
+) -> Result<(String, String), InflectError> {
+    agree_as(adj, noun, case, number, Gender::Masculine)
+}
+/// Same as [`agree`], but resolves the common gender of the noun into the gender of the referent.
+/// See [`Noun::agreement_info_as`].
+pub fn agree_as(
+    adj: &Adjective,
+    noun: &Noun,
+    case: CaseEx,
+    number: Number,
+    referent: Gender,
+) -> Result<(String, String), InflectError> {
+    let info = noun.agreement_info_as(case, number, referent);
+    let adj_form =
+        adj.inflect_to_string(info.case.into(), info.gender, info.number, info.animacy)?;
+    Ok((adj_form, noun.inflect_to_string(case, number)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Case},
+        declension::{AdjectiveInfo, ShortFormAvailability},
+    };
+
+    #[test]
+    fn agreement_info() {
+        // Animate masculine nouns: вижу нового врача
+        let noun = Noun::new("врач", "мо 4b".parse().unwrap());
+        let info = noun.agreement_info(CaseEx::Accusative, Number::Singular);
+        assert_eq!(
+            info,
+            DeclInfo::new(Case::Accusative, Number::Singular, Gender::Masculine, Animacy::Animate)
+        );
+        assert_eq!(info.acc_case(), Case::Genitive);
+
+        // Common gender nouns are masculine: круглого сироты
+        let noun = Noun::new("сирот", "мо-жо 1d".parse().unwrap());
+        let info = noun.agreement_info(CaseEx::Genitive, Number::Singular);
+        assert_eq!(info.gender, Gender::Masculine);
+
+        // The tantum and the secondary cases are resolved
+        let noun = Noun::new("ножниц", "мн. ж 5a".parse().unwrap());
+        let info = noun.agreement_info(CaseEx::Locative, Number::Singular);
+        assert_eq!((info.case, info.number), (Case::Prepositional, Number::Plural));
+        let noun = Noun::new("солдат", "мо 1a".parse().unwrap());
+        let info = noun.agreement_info(CaseEx::Translative, Number::Singular);
+        assert_eq!((info.case, info.number), (Case::Nominative, Number::Plural));
+    }
+
+    #[test]
+    fn agree() {
+        let assert = |adj: &str, noun: &Noun, case: CaseEx, number: Number, expected: [&str; 2]| {
+            let declension = Some("п 1a".parse().unwrap());
+            let info = AdjectiveInfo { declension, short_forms: ShortFormAvailability::Available };
+            let adj = Adjective { stem: adj, info };
+            let (adj_form, noun_form) = super::agree(&adj, noun, case, number).unwrap();
+            assert_eq!([adj_form.as_str(), noun_form.as_str()], expected);
+        };
+        use {CaseEx::*, Number::*};
+
+        let dom = Noun::new("дом", "м 1c①".parse().unwrap());
+        assert("нов", &dom, Nominative, Singular, ["новый", "дом"]);
+        assert("нов", &dom, Genitive, Singular, ["нового", "дома"]);
+        assert("нов", &dom, Dative, Plural, ["новым", "домам"]);
+        assert("нов", &dom, Accusative, Plural, ["новые", "дома"]);
+
+        let vrach = Noun::new("врач", "мо 4b".parse().unwrap());
+        assert("нов", &vrach, Accusative, Singular, ["нового", "врача"]);
+        assert("нов", &vrach, Accusative, Plural, ["новых", "врачей"]);
+
+        let sirota = Noun::new("сирот", "мо-жо 1d".parse().unwrap());
+        assert("кругл", &sirota, Nominative, Singular, ["круглый", "сирота"]);
+        assert("кругл", &sirota, Accusative, Singular, ["круглого", "сироту"]);
+        assert("кругл", &sirota, Instrumental, Plural, ["круглыми", "сиротами"]);
+
+        let kniga = Noun::new("книг", "ж 3a".parse().unwrap());
+        assert("нов", &kniga, Accusative, Singular, ["новую", "книгу"]);
+        let muzhchina = Noun::new("мужчин", "мо <жо 1a>".parse().unwrap());
+        assert("высок", &muzhchina, Genitive, Singular, ["высокого", "мужчины"]);
+        let kofe = Noun::new("кофе", "м 0".parse().unwrap());
+        assert("чёрн", &kofe, Instrumental, Singular, ["чёрным", "кофе"]);
+        let sani = Noun::new("сан", "мн. ж 2e".parse().unwrap());
+        assert("нов", &sani, Dative, Singular, ["новым", "саням"]);
+    }
+
+    #[test]
+    fn agree_as() {
+        let assert = |noun: &Noun, referent: Gender, case: CaseEx, number: Number, expected| {
+            let declension = Some("п 1a".parse().unwrap());
+            let info = AdjectiveInfo { declension, short_forms: ShortFormAvailability::Available };
+            let adj = Adjective { stem: "кругл", info };
+            let (adj_form, noun_form) =
+                super::agree_as(&adj, noun, case, number, referent).unwrap();
+            assert_eq!([adj_form.as_str(), noun_form.as_str()], expected);
+        };
+        use {CaseEx::*, Gender::*, Number::*};
+
+        // The referent affects only the adjective, and the noun is declined as feminine
+        let sirota = Noun::new("сирот", "мо-жо 1d".parse().unwrap());
+        assert(&sirota, Masculine, Accusative, Singular, ["круглого", "сироту"]);
+        assert(&sirota, Feminine, Accusative, Singular, ["круглую", "сироту"]);
+        assert(&sirota, Masculine, Genitive, Plural, ["круглых", "сирот"]);
+        assert(&sirota, Feminine, Genitive, Plural, ["круглых", "сирот"]);
+        assert(&sirota, Feminine, Instrumental, Singular, ["круглой", "сиротой"]);
+
+        let sudya = Noun::new("судь", "мо-жо 6*e".parse().unwrap());
+        assert(&sudya, Masculine, Accusative, Singular, ["круглого", "судью"]);
+        assert(&sudya, Feminine, Accusative, Singular, ["круглую", "судью"]);
+        assert(&sudya, Masculine, Genitive, Plural, ["круглых", "судей"]);
+        assert(&sudya, Feminine, Genitive, Plural, ["круглых", "судей"]);
+
+        // The referent is ignored for nouns that aren't of common gender
+        let muzhchina = Noun::new("мужчин", "мо <жо 1a>".parse().unwrap());
+        assert(&muzhchina, Feminine, Accusative, Singular, ["круглого", "мужчину"]);
+        let kniga = Noun::new("книг", "ж 3a".parse().unwrap());
+        assert(&kniga, Masculine, Nominative, Singular, ["круглая", "книга"]);
+    }
+}
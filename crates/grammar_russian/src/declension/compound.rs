@@ -0,0 +1,180 @@
+use crate::{
+    InflectionBuffer,
+    categories::{CaseEx, Number},
+    declension::{InflectError, Noun},
+};
+use alloc::string::String;
+
+/// A noun made of several parts, that are inflected separately and joined together: both parts
+/// of диван-кровать (диваны-кровати), or only the last one of Санкт-Петербург
+/// (Санкт-Петербурга) and онлайн-курс (онлайн-курсы).
+///
+/// The separators between the parts are kept as [fixed parts](CompoundPart::Fixed), along with
+/// the parts that aren't inflected.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*};
+///
+/// let parts = [
+///     CompoundPart::Declined(Noun::new("диван", "м 1a".parse().unwrap())),
+///     CompoundPart::Fixed("-"),
+///     CompoundPart::Declined(Noun::new("кроват", "ж 8a".parse().unwrap())),
+/// ];
+/// let form = CompoundNoun::new(&parts).inflect_to_string(CaseEx::Genitive, Number::Plural);
+/// assert_eq!(form.unwrap(), "диванов-кроватей");
+///
+/// let parts = [
+///     CompoundPart::Fixed("Санкт-"),
+///     CompoundPart::Declined(Noun::new("Петербург", "м 3a".parse().unwrap())),
+/// ];
+/// let form = CompoundNoun::new(&parts).inflect_to_string(CaseEx::Locative, Number::Singular);
+/// assert_eq!(form.unwrap(), "Санкт-Петербурге");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompoundNoun<'a> {
+    pub parts: &'a [CompoundPart<'a>],
+}
+
+/// A part of a [`CompoundNoun`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompoundPart<'a> {
+    /// A noun, that is inflected in the same case and number as the whole compound. Its
+    /// capitalization is kept (Петербург - Петербурга).
+    Declined(Noun<'a>),
+    /// A part, that stays the same in all forms: a separator, or an indeclinable word (онлайн-,
+    /// Санкт-).
+    Fixed(&'a str),
+}
+
+impl<'a> CompoundNoun<'a> {
+    /// Creates a compound noun from its parts.
+    pub const fn new(parts: &'a [CompoundPart<'a>]) -> Self {
+        Self { parts }
+    }
+
+    /// Returns the first inflected part, that determines the gender and animacy of the compound
+    /// (новый диван-кровать), or `None`, if none of the parts are inflected.
+    pub fn head(&self) -> Option<&Noun<'a>> {
+        self.parts.iter().find_map(|part| match part {
+            CompoundPart::Declined(noun) => Some(noun),
+            CompoundPart::Fixed(_) => None,
+        })
+    }
+
+    /// Returns the form of the compound in the specified case and number. See
+    /// [`Noun::inflect_to_string`].
+    pub fn inflect_to_string(&self, case: CaseEx, number: Number) -> Result<String, InflectError> {
+        let mut dst = String::new();
+        self.inflect_into(case, number, &mut dst)?;
+        Ok(dst)
+    }
+    /// Appends the form of the compound in the specified case and number to the string. On an
+    /// error, the string may contain a part of the form.
+    pub fn inflect_into(
+        &self,
+        case: CaseEx,
+        number: Number,
+        dst: &mut String,
+    ) -> Result<(), InflectError> {
+        let mut buf = InflectionBuffer::default();
+
+        for part in self.parts {
+            match part {
+                CompoundPart::Declined(noun) => {
+                    dst.push_str(noun.inflect_to(case, number, &mut buf)?)
+                },
+                CompoundPart::Fixed(text) => dst.push_str(text),
+            }
+        }
+        Ok(())
+    }
+    /// Returns the compound's dictionary form: the nominative singular, or the nominative plural
+    /// for plurale tantum parts.
+    pub fn lemma(&self) -> Result<String, InflectError> {
+        self.inflect_to_string(CaseEx::Nominative, Number::Singular)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Case, CaseAndNumber};
+
+    fn forms(noun: CompoundNoun) -> [String; 12] {
+        CaseAndNumber::VALUES.map(|x| {
+            let (case, number) = x.parts();
+            noun.inflect_to_string(case.into(), number).unwrap()
+        })
+    }
+
+    #[test]
+    fn both_parts() {
+        let parts = [
+            CompoundPart::Declined(Noun::new("ракет", "ж 1a".parse().unwrap())),
+            CompoundPart::Fixed("-"),
+            CompoundPart::Declined(Noun::new("носител", "м 2a".parse().unwrap())),
+        ];
+        #[rustfmt::skip]
+        assert_eq!(forms(CompoundNoun::new(&parts)), [
+            "ракета-носитель", "ракеты-носителя", "ракете-носителю",
+            "ракету-носитель", "ракетой-носителем", "ракете-носителе",
+            "ракеты-носители", "ракет-носителей", "ракетам-носителям",
+            "ракеты-носители", "ракетами-носителями", "ракетах-носителях",
+        ]);
+
+        let parts = [
+            CompoundPart::Declined(Noun::new("диван", "м 1a".parse().unwrap())),
+            CompoundPart::Fixed("-"),
+            CompoundPart::Declined(Noun::new("кроват", "ж 8a".parse().unwrap())),
+        ];
+        let noun = CompoundNoun::new(&parts);
+        assert_eq!(noun.lemma().unwrap(), "диван-кровать");
+        assert_eq!(
+            noun.inflect_to_string(CaseEx::Dative, Number::Plural).unwrap(),
+            "диванам-кроватям"
+        );
+        assert_eq!(noun.head().unwrap().stem, "диван");
+    }
+
+    #[test]
+    fn last_part() {
+        let parts = [
+            CompoundPart::Fixed("Санкт-"),
+            CompoundPart::Declined(Noun::new("Петербург", "м 3a".parse().unwrap())),
+        ];
+        let noun = CompoundNoun::new(&parts);
+        assert_eq!(
+            noun.inflect_to_string(CaseEx::Genitive, Number::Singular).unwrap(),
+            "Санкт-Петербурга"
+        );
+        assert_eq!(
+            noun.inflect_to_string(CaseEx::Instrumental, Number::Singular).unwrap(),
+            "Санкт-Петербургом"
+        );
+
+        // The indeclinable parts can be nouns too
+        let parts = [
+            CompoundPart::Declined(Noun::new("онлайн", "м 0".parse().unwrap())),
+            CompoundPart::Fixed("-"),
+            CompoundPart::Declined(Noun::new("курс", "м 1a".parse().unwrap())),
+        ];
+        let noun = CompoundNoun::new(&parts);
+        assert_eq!(
+            noun.inflect_to_string(CaseEx::Genitive, Number::Plural).unwrap(),
+            "онлайн-курсов"
+        );
+        let mut dst = String::from("для ");
+        noun.inflect_into(Case::Genitive.into(), Number::Singular, &mut dst).unwrap();
+        assert_eq!(dst, "для онлайн-курса");
+
+        // Errors of the parts are returned
+        let parts = [
+            CompoundPart::Fixed("-"),
+            CompoundPart::Declined(Noun::new("штр", "м 1*a".parse().unwrap())),
+        ];
+        let err = CompoundNoun::new(&parts).inflect_to_string(CaseEx::Genitive, Number::Singular);
+        assert_eq!(err, Err(InflectError::NoVowelInStem));
+        assert_eq!(CompoundNoun::new(&[CompoundPart::Fixed("-")]).head(), None);
+    }
+}
@@ -1,38 +1,145 @@
 use crate::{
-    declension::{AdjectiveStemType, AnyStemType, DeclensionFlags, NounStemType, PronounStemType},
+    Letter,
+    declension::{
+        AdjectiveStemType, AnyStemType, DeclensionFlags, NounStemType, PronounStemType,
+        StemMismatchError,
+    },
     stress::{AdjectiveStress, AnyDualStress, NounStress, PronounStress},
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A declension of a noun, pronoun or adjective.
+///
+/// Unlike the stresses and flags, declensions don't implement [`Default`]: there's no sensible
+/// default kind of declension, and no stem type is unmarked in the dictionary either.
+///
+/// Declensions are ordered by kind first (nouns, pronouns, adjectives), and then as the typed
+/// declensions: by stem type, then by stress, and then by flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Declension {
     Noun(NounDeclension),
     Pronoun(PronounDeclension),
     Adjective(AdjectiveDeclension),
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum DeclensionKind {
     Noun,
     Pronoun,
     Adjective,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A noun declension. Ordered by stem type, then by stress, and then by flags.
+///
+/// The gender and animacy that the noun is declined in aren't a part of the declension, and are
+/// stored in the [`NounInfo`](crate::declension::NounInfo), along with the gender it agrees in:
+/// "мо <жо 1a>" (мужчина). See [`NounInfo::declension_gender`].
+///
+/// [`NounInfo::declension_gender`]: crate::declension::NounInfo::declension_gender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NounDeclension {
     pub stem_type: NounStemType,
     pub flags: DeclensionFlags,
     pub stress: NounStress,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A pronoun declension. Ordered by stem type, then by stress, and then by flags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PronounDeclension {
     pub stem_type: PronounStemType,
     pub flags: DeclensionFlags,
     pub stress: PronounStress,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// An adjective declension. Ordered by stem type, then by stress, then by flags, and the
+/// reflexive declensions after the non-reflexive ones, and then by prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AdjectiveDeclension {
     pub stem_type: AdjectiveStemType,
     pub flags: DeclensionFlags,
     pub stress: AdjectiveStress,
+    /// Whether the reflexive postfix '-ся' is appended after every ending (трудящийся, учащаяся).
+    /// Zaliznyak writes it as part of the headword, so it's marked with ", -ся" at the end of the
+    /// declension here: "п 4a, -ся".
+    pub is_reflexive: bool,
+    /// The part of speech prefix, that the declension is written with: "п", "мс-п" or "числ.-п".
+    /// It's only used in the prefixed notation, and doesn't affect the inflection.
+    pub prefix: AdjectivePrefix,
+}
+/// The part of speech prefix of an adjective declension in Zaliznyak's entries. Pronominal and
+/// numeral adjectives are declined the same way as regular ones.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AdjectivePrefix {
+    /// A regular adjective: "п 1a" (новый).
+    #[default]
+    Adjective,
+    /// A pronominal adjective: "мс-п 3b" (такой, какой).
+    Pronominal,
+    /// A numeral (ordinal) adjective: "числ.-п 1b" (второй).
+    Numeral,
+}
+
+impl AdjectivePrefix {
+    pub const VALUES: [AdjectivePrefix; 3] = [Self::Adjective, Self::Pronominal, Self::Numeral];
+
+    /// Returns the prefix, as it's written in the notation: "п", "мс-п" or "числ.-п".
+    pub const fn abbr(self) -> &'static str {
+        match self {
+            Self::Adjective => "п",
+            Self::Pronominal => "мс-п",
+            Self::Numeral => "числ.-п",
+        }
+    }
+}
+
+// The declensions are ordered by their components in the order of importance, not in the order
+// of the fields, so that the dictionary's indices are sorted naturally: 1a < 1b < 1*a < 2a.
+macro_rules! impl_declension_ord {
+    ($($T:ty => |$x:ident| $key:expr),+ $(,)?) => ($(
+        impl Ord for $T {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                let key = |$x: &Self| $key;
+                key(self).cmp(&key(other))
+            }
+        }
+        impl PartialOrd for $T {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    )+);
+}
+impl_declension_ord! {
+    NounDeclension => |x| (x.stem_type, x.stress, x.flags),
+    PronounDeclension => |x| (x.stem_type, x.stress, x.flags),
+    AdjectiveDeclension => |x| (x.stem_type, x.stress, x.flags, x.is_reflexive, x.prefix),
+}
+
+impl Ord for Declension {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (self, other) {
+            (Self::Noun(a), Self::Noun(b)) => a.cmp(b),
+            (Self::Pronoun(a), Self::Pronoun(b)) => a.cmp(b),
+            (Self::Adjective(a), Self::Adjective(b)) => a.cmp(b),
+            _ => self.kind().cmp(&other.kind()),
+        }
+    }
+}
+impl PartialOrd for Declension {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Flags that are accepted by the parsers of each declension kind. The circle flag (°) only
+// drives the unique alternations of nouns (-ёнок, -мя, мать/дочь), and its other meanings in
+// Zaliznyak's dictionary (adjectives and pronouns) aren't implemented, so it's rejected for them.
+impl NounDeclension {
+    pub const SUPPORTED_FLAGS: DeclensionFlags = DeclensionFlags::all();
+}
+impl PronounDeclension {
+    pub const SUPPORTED_FLAGS: DeclensionFlags =
+        DeclensionFlags::all().difference(DeclensionFlags::CIRCLE);
+}
+impl AdjectiveDeclension {
+    pub const SUPPORTED_FLAGS: DeclensionFlags =
+        DeclensionFlags::all().difference(DeclensionFlags::CIRCLE);
 }
 
 impl Declension {
@@ -85,6 +192,36 @@ impl Declension {
     }
 }
 
+// Stem validation isn't done during inflection, since the stems are usually extracted by the
+// declension itself, or come from the dictionary, and have already been checked.
+macro_rules! impl_validate {
+    ($($T:ty => |$x:ident| $stem_type:expr),+ $(,)?) => ($(
+        impl $T {
+            /// Checks that the stem's final letter is allowed in the declension's stem type. The
+            /// stem may contain stress marks and other non-Cyrillic characters, which are ignored.
+            /// See [`AnyStemType::matches_stem`].
+            pub fn validate(self, stem: &str) -> Result<(), StemMismatchError> {
+                let $x = self;
+                validate_stem($stem_type, stem)
+            }
+        }
+    )+);
+}
+impl_validate! {
+    Declension => |x| x.stem_type(),
+    NounDeclension => |x| x.stem_type.into(),
+    PronounDeclension => |x| x.stem_type.into(),
+    AdjectiveDeclension => |x| x.stem_type.into(),
+}
+
+fn validate_stem(stem_type: AnyStemType, stem: &str) -> Result<(), StemMismatchError> {
+    let last = Letter::iter(stem).next_back();
+    match last {
+        Some(x) if stem_type.matches_stem(&[x]) => Ok(()),
+        _ => Err(StemMismatchError { stem_type, last }),
+    }
+}
+
 impl const From<NounDeclension> for Declension {
     fn from(value: NounDeclension) -> Self {
         Self::Noun(value)
@@ -121,7 +258,7 @@ impl const TryFrom<Declension> for AdjectiveDeclension {
 }
 
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MaybeZeroDeclension(Option<Declension>);
 
 impl MaybeZeroDeclension {
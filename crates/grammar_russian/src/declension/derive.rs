@@ -0,0 +1,102 @@
+use crate::declension::NounDeclension;
+use alloc::string::String;
+use thiserror::Error;
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error("prefix must consist only of cyrillic letters")]
+pub struct InvalidPrefixError;
+
+/// Derives the stem and declension of a prefixed noun from its base noun (город - пригород,
+/// ход - переход, сон - полусон). Prefixed nouns decline the same way as their base nouns, with
+/// the prefix prepended to every form, including the ones with fleeting vowels (полусна).
+///
+/// The declension is currently returned as is, since stress positions within the stem aren't
+/// tracked yet. Retraction of the stress onto the prefix (го́род - при́город) should be reflected
+/// in the specified declension by the caller.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::declension::*;
+///
+/// let (stem, decl) = derive_prefixed("сон", "1*b".parse().unwrap(), "полу").unwrap();
+/// assert_eq!((stem.as_str(), decl.to_string().as_str()), ("полусон", "1*b"));
+/// ```
+pub fn derive_prefixed(
+    base_stem: &str,
+    base_decl: NounDeclension,
+    prefix: &str,
+) -> Result<(String, NounDeclension), InvalidPrefixError> {
+    if prefix.is_empty()
+        || !prefix.chars().all(|ch| matches!(ch, 'а'..='я' | 'А'..='Я' | 'ё' | 'Ё'))
+    {
+        return Err(InvalidPrefixError);
+    }
+
+    let mut stem = String::with_capacity(prefix.len() + base_stem.len());
+    stem.push_str(prefix);
+    stem.push_str(base_stem);
+    Ok((stem, base_decl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, CaseAndNumber, Gender, GenderEx},
+        declension::{Noun, NounInfo},
+    };
+    use std::{format, string::ToString, vec::Vec};
+
+    fn forms(stem: &str, decl: NounDeclension, gender: Gender) -> Vec<String> {
+        let info = NounInfo {
+            declension: Some(decl.into()),
+            declension_gender: gender,
+            gender: GenderEx::from(gender),
+            animacy: Animacy::Inanimate,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        let noun = Noun::new(stem, info);
+
+        CaseAndNumber::VALUES
+            .map(|key| {
+                let (case, number) = key.parts();
+                core::fmt::from_fn(|f| noun.inflect(case.into(), number, f)).to_string()
+            })
+            .to_vec()
+    }
+
+    #[test]
+    fn paradigms() {
+        let table = [
+            ("город", "1c", Gender::Masculine, "при"),
+            ("ход", "1c", Gender::Masculine, "пере"),
+            // Fleeting vowels: полусна, полуколец
+            ("сон", "1*b", Gender::Masculine, "полу"),
+            ("кольц", "5*d", Gender::Neuter, "полу"),
+        ];
+
+        for (base_stem, decl, gender, prefix) in table {
+            let base_decl: NounDeclension = decl.parse().unwrap();
+            let (stem, derived_decl) = derive_prefixed(base_stem, base_decl, prefix).unwrap();
+            assert_eq!(stem, format!("{prefix}{base_stem}"));
+            assert_eq!(derived_decl, base_decl);
+
+            let base_forms = forms(base_stem, base_decl, gender);
+            let expected: Vec<_> = base_forms.iter().map(|x| format!("{prefix}{x}")).collect();
+            assert_eq!(forms(&stem, derived_decl, gender), expected, "{stem}");
+        }
+    }
+
+    #[test]
+    fn invalid_prefix() {
+        let decl: NounDeclension = "1c".parse().unwrap();
+
+        assert_eq!(derive_prefixed("ход", decl, ""), Err(InvalidPrefixError));
+        assert_eq!(derive_prefixed("ход", decl, "pre"), Err(InvalidPrefixError));
+        assert_eq!(derive_prefixed("ход", decl, "пере-"), Err(InvalidPrefixError));
+        assert_eq!(derive_prefixed("ход", decl, "пере "), Err(InvalidPrefixError));
+        assert_eq!(derive_prefixed("ход", decl, "Пере").unwrap().0, "Переход");
+    }
+}
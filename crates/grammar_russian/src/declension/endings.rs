@@ -1,18 +1,24 @@
 use crate::{
-    categories::{Case, HasNumber},
-    declension::{AdjectiveDeclension, DeclInfo, NounDeclension, PronounDeclension},
+    categories::{Animacy, Case, CaseAndNumber, Gender, GenderOrPlural, Number},
+    declension::{
+        AdjectiveDeclension, DeclInfo, Declension, DeclensionFlags, NounDeclension, NounStemType,
+        PronounDeclension,
+    },
+    stress::NounStress,
     util::slice_find,
 };
 
 // All endings of nouns, adjectives and pronouns in one 55-char span
 const ENDINGS: &[u8] = "оегоговыеейёмойёйамийаямиемуююахяяхыйыхымихомуимиевёвью".as_bytes();
 
+// Each cell is (unstressed, stressed). Endings with 'е' that surface as 'ё' under stress (остриё,
+// копьём, ружьё) have both variants in the pair, e.g. е_ё and ем_ём.
 #[rustfmt::skip]
 const NOUN_LOOKUP: [(u8, u8); 288] = [
-    //    stem types: 1, 2,   3,    4,    5,    6,   7,   8
-    /* nom sg masc */ е, ь,   null, null, null, й,   й,   ь,
-    /* nom sg n    */ о, е_ё, о,    е_о,  е_о,  е_ё, е_ё, о,
-    /* nom sg fem  */ а, я,   а,    а,    а,    я,   я,   ь,
+    //    stem types: 1,    2,   3,    4,    5,    6,   7,   8
+    /* nom sg masc */ null, ь,   null, null, null, й,   й,   ь,
+    /* nom sg n    */ о,    е_ё, о,    е_о,  е_о,  е_ё, е_ё, о,
+    /* nom sg fem  */ а,    я,   а,    а,    а,    я,   я,   ь,
     //    stem types: 1, 2, 3, 4, 5, 6, 7, 8
     /* nom pl masc */ ы, и, и, и, ы, и, и, и,
     /* nom pl n    */ а, я, а, а, а, я, я, а,
@@ -148,6 +154,56 @@ const ADJ_LOOKUP: [(u8, u8); 196] = [
     /* srt pl   */ ы,    и,   и,    и,    ы,    и,   и,
 ];
 
+// Noun table dimensions: case, number, gender, stem type
+const NOUN_DIMS: [usize; 4] = [6, 2, 3, 8];
+// Pronoun table dimensions: case, gender or plural, stem type
+const PRO_DIMS: [usize; 3] = [6, 4, 7];
+// Adjective table dimensions: case or short form, gender or plural, stem type
+const ADJ_DIMS: [usize; 3] = [6 + 1, 4, 7];
+
+const _: () = assert!(NOUN_LOOKUP.len() == table_len(NOUN_DIMS));
+const _: () = assert!(PRO_LOOKUP.len() == table_len(PRO_DIMS));
+const _: () = assert!(ADJ_LOOKUP.len() == table_len(ADJ_DIMS));
+
+const fn table_len<const N: usize>(dims: [usize; N]) -> usize {
+    let mut len = 1;
+    let mut i = 0;
+    while i < N {
+        len *= dims[i];
+        i += 1;
+    }
+    len
+}
+const fn table_index<const N: usize>(coords: [usize; N], dims: [usize; N]) -> usize {
+    let mut x = 0;
+    let mut i = 0;
+    while i < N {
+        debug_assert!(coords[i] < dims[i], "lookup table coordinate out of range");
+        x = x * dims[i] + coords[i];
+        i += 1;
+    }
+    x
+}
+
+const fn noun_index(case: Case, number: Number, gender: Gender, stem_type: usize) -> usize {
+    table_index([case as usize, number as usize, gender as usize, stem_type - 1], NOUN_DIMS)
+}
+const fn noun_has_own_acc(number: Number, gender: Gender) -> bool {
+    matches!((number, gender), (Number::Singular, Gender::Feminine))
+}
+const fn pro_index(case: Case, number: Number, gender: Gender, stem_type: usize) -> usize {
+    let column = if matches!(number, Number::Singular) { gender as usize } else { 3 };
+    table_index([case as usize, column, stem_type - 1], PRO_DIMS)
+}
+const fn adj_index(case: Case, number: Number, gender: Gender, stem_type: usize) -> usize {
+    let column = if matches!(number, Number::Singular) { gender as usize } else { 3 };
+    table_index([case as usize, column, stem_type - 1], ADJ_DIMS)
+}
+const fn adj_short_index(number: Number, gender: Gender, stem_type: usize) -> usize {
+    let column = if matches!(number, Number::Singular) { gender as usize } else { 3 };
+    table_index([6, column, stem_type - 1], ADJ_DIMS)
+}
+
 macro_rules! define_endings {
     ($($ident:ident)*) => ($(
         const $ident: (u8, u8) = find_ending_indices(stringify!($ident));
@@ -191,61 +247,417 @@ const fn get_ending_by_index(index: u8) -> &'static str {
 
 impl NounDeclension {
     pub const fn get_ending(self, info: DeclInfo) -> &'static str {
-        let (mut un_str, mut str) = self.lookup(info, info.case);
-
-        if un_str == 0 {
-            let case = info.animacy.acc_case();
-            (un_str, str) = self.lookup(info, case);
-        }
+        let (un_str, str) = self.lookup(info);
 
         let stressed = un_str == str || self.stress.is_ending_stressed(info);
         get_ending_by_index(if stressed { str } else { un_str })
     }
-    const fn lookup(self, info: DeclInfo, case: Case) -> (u8, u8) {
-        let mut x = case as usize;
-        x = x * 2 + info.number as usize;
-        x = x * 3 + info.gender as usize;
-        x = x * 8 + (self.stem_type as usize - 1);
-        NOUN_LOOKUP[x]
+    /// Returns the unstressed and the stressed variants of the ending, before the stress schema
+    /// picks one of them. Most endings have only one variant, that is returned twice ("ами",
+    /// "ами"), but some have two ("ей", "ёй" in the feminine instrumental of stem type 2).
+    pub const fn ending_pair(self, info: DeclInfo) -> (&'static str, &'static str) {
+        let (un_str, str) = self.lookup(info);
+        (get_ending_by_index(un_str), get_ending_by_index(str))
+    }
+    // Looks up the ending's indices, resolving the accusative into the nominative or genitive.
+    // The cell is resolved before the lookup, so that only one table access is needed.
+    const fn lookup(self, info: DeclInfo) -> (u8, u8) {
+        // Only the feminine singular has its own accusative endings, see the acc_cells test
+        let case = match info.case {
+            Case::Accusative if !noun_has_own_acc(info.number, info.gender) => info.acc_case(),
+            case => case,
+        };
+        let gender = match info.number {
+            Number::Plural => match self.irregular_plural_gender(case, info.gender) {
+                Some(gender) => gender,
+                None => info.gender,
+            },
+            Number::Singular => info.gender,
+        };
+        NOUN_LOOKUP[noun_index(case, info.number, gender, self.stem_type as usize)]
+    }
+    // Returns the gender, whose plural ending is taken instead, if the case is marked irregular
+    // with ① (nominative) or ② (genitive). Masculine nouns take the neuter endings (доктора,
+    // солдат), and neuter and feminine nouns - the masculine ones (яблоки, облаков).
+    const fn irregular_plural_gender(self, case: Case, gender: Gender) -> Option<Gender> {
+        let is_irregular = match case {
+            Case::Nominative => self.flags.has_circled_one(),
+            Case::Genitive => self.flags.has_circled_two(),
+            _ => false,
+        };
+        if !is_irregular {
+            return None;
+        }
+        Some(match gender {
+            Gender::Masculine => Gender::Neuter,
+            Gender::Neuter | Gender::Feminine => Gender::Masculine,
+        })
     }
 }
 
-impl PronounDeclension {
-    pub const fn get_ending(self, info: DeclInfo) -> &'static str {
-        let (mut un_str, mut str) = self.lookup(info, info.case);
+/// The complete tables of endings, for dumping and validating them against the dictionary.
+/// See also [`NounDeclension::ending_pair`], [`PronounDeclension::ending_pair`] and
+/// [`AdjectiveDeclension::ending_pair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endings;
+
+impl Endings {
+    /// Returns the unstressed and the stressed variants of all noun endings, by case, number,
+    /// gender and stem type, in that order. The accusative is resolved like for inanimate nouns,
+    /// so animate nouns take the genitive endings instead, except in the feminine singular.
+    pub fn iter_noun()
+    -> impl Iterator<Item = (Case, Number, Gender, NounStemType, &'static str, &'static str)> {
+        Case::VALUES.into_iter().flat_map(|case| {
+            Number::VALUES.into_iter().flat_map(move |number| {
+                Gender::VALUES.into_iter().flat_map(move |gender| {
+                    (1..=8).map(move |digit| {
+                        let stem_type = NounStemType::from_digit(digit).unwrap();
+                        let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+
+                        // The stress doesn't affect the pair, and the flags are regular
+                        let decl = NounDeclension {
+                            stem_type,
+                            stress: NounStress::A,
+                            flags: DeclensionFlags::empty(),
+                        };
+                        let (unstressed, stressed) = decl.ending_pair(info);
+                        (case, number, gender, stem_type, unstressed, stressed)
+                    })
+                })
+            })
+        })
+    }
+}
 
-        if un_str == 0 {
-            let case = info.animacy.acc_case();
-            (un_str, str) = self.lookup(info, case);
+/// Noun endings for all cases and numbers, precomputed for a specific gender and animacy.
+///
+/// Looking up an ending with [`NounDeclension::get_ending`] only takes a couple of table lookups
+/// and a stress check, and precomputing costs about as much as all 12 lookups. So it only pays
+/// off when the endings are reused for many words of the same declension, gender and animacy
+/// (e.g. generating paradigms in bulk), and even then the ending lookup is a small part of the
+/// cost of [`inflect`](NounDeclension::inflect), compared to the stem alternations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrecomputedEndings {
+    endings: [&'static str; 12],
+    gender: Gender,
+    animacy: Animacy,
+}
+
+impl PrecomputedEndings {
+    pub const fn gender(&self) -> Gender {
+        self.gender
+    }
+    pub const fn animacy(&self) -> Animacy {
+        self.animacy
+    }
+    pub const fn get_by(&self, key: CaseAndNumber) -> &'static str {
+        self.endings[key as usize]
+    }
+    pub const fn get(&self, info: DeclInfo) -> &'static str {
+        debug_assert!(info.gender as u8 == self.gender as u8);
+        debug_assert!(info.animacy as u8 == self.animacy as u8);
+        self.get_by(CaseAndNumber::new(info.case, info.number))
+    }
+}
+
+impl NounDeclension {
+    /// Precomputes the endings of all cases and numbers for the specified gender and animacy.
+    /// See [`PrecomputedEndings`] and [`NounDeclension::inflect_precomputed`].
+    pub const fn precompute(self, gender: Gender, animacy: Animacy) -> PrecomputedEndings {
+        let mut endings = [""; 12];
+
+        let mut i = 0;
+        while i < CaseAndNumber::VALUES.len() {
+            let (case, number) = CaseAndNumber::VALUES[i].parts();
+            let info = DeclInfo { case, number, gender, animacy };
+            endings[CaseAndNumber::VALUES[i] as usize] = self.get_ending(info);
+            i += 1;
         }
+        PrecomputedEndings { endings, gender, animacy }
+    }
+}
+
+impl PronounDeclension {
+    pub const fn get_ending(self, info: DeclInfo) -> &'static str {
+        let (un_str, str) = self.lookup(info);
 
         let stressed = un_str == str || self.stress.is_ending_stressed(info);
         get_ending_by_index(if stressed { str } else { un_str })
     }
-    const fn lookup(self, info: DeclInfo, case: Case) -> (u8, u8) {
-        let mut x = case as usize;
-        x = x * 4 + (if info.is_singular() { info.gender as usize } else { 3 });
-        x = x * 7 + (self.stem_type as usize - 1);
-        PRO_LOOKUP[x]
+    /// Returns the unstressed and the stressed variants of the ending, before the stress schema
+    /// picks one of them. Most endings have only one variant, that is returned twice ("ым",
+    /// "ым"), but some have two ("его", "ого" in the masculine genitive of stem type 4).
+    pub const fn ending_pair(self, info: DeclInfo) -> (&'static str, &'static str) {
+        let (un_str, str) = self.lookup(info);
+        (get_ending_by_index(un_str), get_ending_by_index(str))
+    }
+    // Looks up the ending's indices, resolving the accusative into the nominative or genitive
+    const fn lookup(self, info: DeclInfo) -> (u8, u8) {
+        let pair =
+            PRO_LOOKUP[pro_index(info.case, info.number, info.gender, self.stem_type as usize)];
+        if pair.0 != acc.0 {
+            return pair;
+        }
+        PRO_LOOKUP[pro_index(info.acc_case(), info.number, info.gender, self.stem_type as usize)]
     }
 }
 
 impl AdjectiveDeclension {
     pub const fn get_ending(self, info: DeclInfo) -> &'static str {
-        let (mut un_str, mut str) = self.lookup(info, info.case);
+        let (un_str, str) = self.lookup(info);
 
-        if un_str == 0 {
-            let case = info.animacy.acc_case();
-            (un_str, str) = self.lookup(info, case);
+        let stressed = un_str == str || self.stress.full.is_ending_stressed();
+        get_ending_by_index(if stressed { str } else { un_str })
+    }
+    /// Returns the unstressed and the stressed variants of the ending, before the stress schema
+    /// picks one of them. Most endings have only one variant, that is returned twice ("ым",
+    /// "ым"), but some have two ("ый", "ой" in the masculine nominative of stem type 1).
+    pub const fn ending_pair(self, info: DeclInfo) -> (&'static str, &'static str) {
+        let (un_str, str) = self.lookup(info);
+        (get_ending_by_index(un_str), get_ending_by_index(str))
+    }
+    // Looks up the ending's indices, resolving the accusative into the nominative or genitive
+    const fn lookup(self, info: DeclInfo) -> (u8, u8) {
+        let pair =
+            ADJ_LOOKUP[adj_index(info.case, info.number, info.gender, self.stem_type as usize)];
+        if pair.0 != acc.0 {
+            return pair;
         }
+        ADJ_LOOKUP[adj_index(info.acc_case(), info.number, info.gender, self.stem_type as usize)]
+    }
 
-        let stressed = un_str == str || self.stress.full.is_ending_stressed();
+    /// Returns the short form ending in the specified gender and number, as listed in the table.
+    /// The stem-dependent exceptions (-ен in 2*, искренна) are handled by
+    /// [`AdjectiveDeclension::inflect_short`].
+    pub const fn get_short_ending(self, gender: Gender, number: Number) -> &'static str {
+        let (un_str, str) = ADJ_LOOKUP[adj_short_index(number, gender, self.stem_type as usize)];
+
+        let stressed = un_str == str
+            || self.stress.short.is_ending_stressed(GenderOrPlural::new(gender, number));
         get_ending_by_index(if stressed { str } else { un_str })
     }
-    const fn lookup(self, info: DeclInfo, case: Case) -> (u8, u8) {
-        let mut x = case as usize;
-        x = x * 4 + (if info.is_singular() { info.gender as usize } else { 3 });
-        x = x * 7 + (self.stem_type as usize - 1);
-        ADJ_LOOKUP[x]
+}
+
+impl Declension {
+    /// Returns the ending in the specified form, as listed in the declension's table. The stem
+    /// alternations may change it during the inflection, see [`Declension::inflect`].
+    pub const fn get_ending(self, info: DeclInfo) -> &'static str {
+        match self {
+            Self::Noun(x) => x.get_ending(info),
+            Self::Pronoun(x) => x.get_ending(info),
+            Self::Adjective(x) => x.get_ending(info),
+        }
+    }
+    /// Returns the unstressed and the stressed variants of the ending, before the stress schema
+    /// picks one of them. See [`NounDeclension::ending_pair`].
+    pub const fn ending_pair(self, info: DeclInfo) -> (&'static str, &'static str) {
+        match self {
+            Self::Noun(x) => x.ending_pair(info),
+            Self::Pronoun(x) => x.ending_pair(info),
+            Self::Adjective(x) => x.ending_pair(info),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{vec, vec::Vec};
+    use test::{Bencher, black_box};
+
+    fn all_declensions() -> impl Iterator<Item = NounDeclension> {
+        (b'1'..=b'8').flat_map(|x| {
+            let stem_type = NounStemType::from_ascii_digit(x).unwrap();
+            let flags = DeclensionFlags::empty();
+            NounStress::VALUES.map(|stress| NounDeclension { stem_type, flags, stress })
+        })
+    }
+
+    #[test]
+    fn precomputed() {
+        for decl in all_declensions() {
+            for info in DeclInfo::iter_all() {
+                let endings = decl.precompute(info.gender, info.animacy);
+                assert_eq!(endings.get(info), decl.get_ending(info), "{decl} {info:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_coverage() {
+        type Index = fn(Case, Number, Gender, usize) -> usize;
+
+        // Walks every valid coordinate, and returns the cells that were reached
+        let walk = |len: usize, index: Index, stem_types: &[usize]| {
+            let mut reached = vec![false; len];
+            for key in CaseAndNumber::VALUES {
+                let (case, number) = key.parts();
+                for gender in Gender::VALUES {
+                    for &stem_type in stem_types {
+                        let x = index(case, number, gender, stem_type);
+                        assert!(x < len, "{case:?} {number:?} {gender:?} {stem_type}");
+                        reached[x] = true;
+                    }
+                }
+            }
+            reached
+        };
+
+        // All noun cells are reachable
+        let reached = walk(NOUN_LOOKUP.len(), noun_index, &[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert!(reached.iter().all(|x| *x));
+
+        // Pronouns only have stem types 1, 2, 4 and 6
+        let reached = walk(PRO_LOOKUP.len(), pro_index, &[1, 2, 4, 6]);
+        for (x, reached) in reached.into_iter().enumerate() {
+            assert_eq!(reached, matches!(x % 7, 0 | 1 | 3 | 5), "{x}");
+        }
+
+        // The short form row of adjectives isn't reachable by case, only by adj_short_index
+        let mut reached = walk(ADJ_LOOKUP.len(), adj_index, &[1, 2, 3, 4, 5, 6, 7]);
+        for (x, reached) in reached.iter().enumerate() {
+            assert_eq!(*reached, x < 6 * 4 * 7, "{x}");
+        }
+        for number in Number::VALUES {
+            for gender in Gender::VALUES {
+                for stem_type in 1..=7 {
+                    reached[adj_short_index(number, gender, stem_type)] = true;
+                }
+            }
+        }
+        assert!(reached.iter().all(|x| *x));
+    }
+
+    #[test]
+    fn accusative() {
+        let noun: NounDeclension = "1a".parse().unwrap();
+        let pro: PronounDeclension = "1a".parse().unwrap();
+        let adj: AdjectiveDeclension = "1a".parse().unwrap();
+
+        for number in Number::VALUES {
+            for gender in Gender::VALUES {
+                for animacy in Animacy::VALUES {
+                    let info = DeclInfo { case: Case::Accusative, number, gender, animacy };
+
+                    // Feminine singulars have endings of their own
+                    let expected_case = match (number, gender, animacy) {
+                        (Number::Singular, Gender::Feminine, _) => continue,
+                        (Number::Singular, Gender::Neuter, _) => Case::Nominative,
+                        (_, _, Animacy::Inanimate) => Case::Nominative,
+                        (_, _, Animacy::Animate) => Case::Genitive,
+                    };
+                    assert_eq!(info.acc_case(), expected_case, "{info:?}");
+
+                    let expected = DeclInfo { case: expected_case, ..info };
+                    assert_eq!(noun.get_ending(info), noun.get_ending(expected), "{info:?}");
+                    assert_eq!(pro.get_ending(info), pro.get_ending(expected), "{info:?}");
+                    assert_eq!(adj.get_ending(info), adj.get_ending(expected), "{info:?}");
+                }
+            }
+        }
+
+        // вижу большое животное, вижу чудовище
+        let info = DeclInfo {
+            case: Case::Accusative,
+            number: Number::Singular,
+            gender: Gender::Neuter,
+            animacy: Animacy::Animate,
+        };
+        let adj: AdjectiveDeclension = "4b".parse().unwrap();
+        assert_eq!(adj.get_ending(info), "ое");
+        let noun: NounDeclension = "4a".parse().unwrap();
+        assert_eq!(noun.get_ending(info), "е");
+    }
+
+    #[test]
+    fn ending_pairs() {
+        let info =
+            |case, number, gender| DeclInfo { case, number, gender, animacy: Animacy::Animate };
+        let noun = |s: &str| s.parse::<NounDeclension>().unwrap();
+        use {Case::*, Gender::*, Number::*};
+
+        // The stress schema picks one of the variants: баней (2a), but землёй (2b)
+        assert_eq!(noun("2a").ending_pair(info(Instrumental, Singular, Feminine)), ("ей", "ёй"));
+        assert_eq!(noun("2b").ending_pair(info(Instrumental, Singular, Feminine)), ("ей", "ёй"));
+        assert_eq!(noun("4a").ending_pair(info(Instrumental, Singular, Masculine)), ("ем", "ом"));
+        assert_eq!(noun("5a").ending_pair(info(Genitive, Plural, Masculine)), ("ев", "ов"));
+        assert_eq!(noun("2a").ending_pair(info(Genitive, Plural, Neuter)), ("ь", "ей"));
+        assert_eq!(noun("8a").ending_pair(info(Instrumental, Singular, Feminine)), ("ью", "ью"));
+        assert_eq!(noun("1a").ending_pair(info(Nominative, Singular, Masculine)), ("", ""));
+        // The accusative is resolved by the animacy
+        assert_eq!(noun("1a").ending_pair(info(Accusative, Plural, Masculine)), ("ов", "ов"));
+
+        let pro: PronounDeclension = "4a".parse().unwrap();
+        assert_eq!(pro.ending_pair(info(Genitive, Singular, Masculine)), ("его", "ого"));
+        assert_eq!(pro.ending_pair(info(Accusative, Singular, Feminine)), ("у", "у"));
+        let adj: AdjectiveDeclension = "1a".parse().unwrap();
+        assert_eq!(adj.ending_pair(info(Nominative, Singular, Masculine)), ("ый", "ой"));
+        assert_eq!(adj.ending_pair(info(Accusative, Singular, Masculine)), ("ого", "ого"));
+    }
+
+    #[test]
+    fn iter_noun() {
+        let cells: Vec<_> = Endings::iter_noun().collect();
+        assert_eq!(cells.len(), NOUN_LOOKUP.len());
+
+        let find = |case, number, gender, stem_type: u8| {
+            let stem_type = NounStemType::from_digit(stem_type).unwrap();
+            let cell = cells
+                .iter()
+                .find(|x| x.0 == case && x.1 == number && x.2 == gender && x.3 == stem_type);
+            cell.map(|x| (x.4, x.5)).unwrap()
+        };
+        use {Case::*, Gender::*, Number::*};
+        assert_eq!(find(Instrumental, Singular, Feminine, 2), ("ей", "ёй"));
+        assert_eq!(find(Genitive, Plural, Masculine, 1), ("ов", "ов"));
+        assert_eq!(find(Dative, Singular, Feminine, 7), ("и", "е"));
+        assert_eq!(find(Accusative, Singular, Feminine, 8), ("ь", "ь"));
+        assert_eq!(find(Accusative, Plural, Masculine, 1), ("ы", "ы"));
+
+        // The pairs are the endings of the stem-stressed and the ending-stressed schemas
+        for (case, number, gender, stem_type, unstressed, stressed) in cells {
+            let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+            let flags = DeclensionFlags::empty();
+            let a = NounDeclension { stem_type, flags, stress: NounStress::A };
+            let b = NounDeclension { stem_type, flags, stress: NounStress::B };
+            assert_eq!((a.get_ending(info), b.get_ending(info)), (unstressed, stressed));
+        }
+    }
+
+    #[test]
+    fn acc_cells() {
+        // The noun lookup resolves the accusative without checking the table's cells
+        for number in Number::VALUES {
+            for gender in Gender::VALUES {
+                for stem_type in 1..=8 {
+                    let pair = NOUN_LOOKUP[noun_index(Case::Accusative, number, gender, stem_type)];
+                    assert_eq!(pair.0 != acc.0, noun_has_own_acc(number, gender));
+                }
+            }
+        }
+    }
+
+    const BENCH_INFO: DeclInfo = DeclInfo {
+        case: Case::Accusative,
+        number: Number::Plural,
+        gender: Gender::Masculine,
+        animacy: Animacy::Animate,
+    };
+
+    #[bench]
+    fn bench_get_ending(b: &mut Bencher) {
+        let decl: NounDeclension = "2f".parse().unwrap();
+        b.iter(|| black_box(decl).get_ending(black_box(BENCH_INFO)));
+    }
+    #[bench]
+    fn bench_get_ending_precomputed(b: &mut Bencher) {
+        let decl: NounDeclension = "2f".parse().unwrap();
+        let endings = decl.precompute(Gender::Masculine, Animacy::Animate);
+        b.iter(|| black_box(&endings).get(black_box(BENCH_INFO)));
+    }
+    #[bench]
+    fn bench_precompute(b: &mut Bencher) {
+        let decl: NounDeclension = "2f".parse().unwrap();
+        b.iter(|| black_box(decl).precompute(Gender::Masculine, Animacy::Animate));
     }
 }
@@ -0,0 +1,562 @@
+use crate::{
+    Letter,
+    categories::{Animacy, Case, Gender, Number},
+    declension::{
+        Adjective, AdjectiveInfo, DeclInfo, Declension, Noun, NounDeclension, NounInfo,
+        NounStemType, STRESS_MARK, split_stress_mark,
+    },
+    letters,
+    stem::NormalizedStem,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ExtractStemError {
+    #[error("word must consist only of cyrillic letters")]
+    InvalidLetters,
+    #[error("word's ending doesn't match any stem type of its gender")]
+    UnknownEnding,
+    #[error("word's ending doesn't match the nominative ending of stem type {0}")]
+    IncompatibleEnding(NounStemType),
+    #[error("word's stem implies stem type {expected}, but {found} was specified")]
+    IncompatibleStemType { expected: NounStemType, found: NounStemType },
+    /// The word's stem type can't be inferred from its form alone: masculine nouns in '-ь' can
+    /// be of stem type 2 or 8 (конь, путь).
+    #[error("word's stem type is ambiguous, it can be any of {0:?}")]
+    Ambiguous(&'static [NounStemType]),
+}
+
+type Error = ExtractStemError;
+
+impl NounStemType {
+    /// Splits a noun's nominative singular form into its stem and stem type.
+    ///
+    /// The stem type is determined by the ending and the stem's final letter. In particular,
+    /// '-ия', '-ие' and '-ий' imply stem type 7 (Мария, собрание, гербарий), while '-ья', '-ье'
+    /// and '-ей' imply stem type 6 (Марья, воскресенье, музей). Masculine nouns in '-ь' are
+    /// considered to be of stem type 2 (конь, but not путь, which is the only masculine 8).
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::Gender, declension::NounStemType};
+    ///
+    /// let (stem, stem_type) = NounStemType::extract_stem("статья", Gender::Feminine).unwrap();
+    /// assert_eq!((stem, stem_type), ("стать", NounStemType::Type6));
+    ///
+    /// let (stem, stem_type) = NounStemType::extract_stem("гербарий", Gender::Masculine).unwrap();
+    /// assert_eq!((stem, stem_type), ("гербари", NounStemType::Type7));
+    /// ```
+    pub fn extract_stem(word: &str, gender: Gender) -> Result<(&str, Self), ExtractStemError> {
+        use letters as lt;
+
+        Self::check_letters(word)?;
+
+        // Only the last two letters of the word are needed to determine its stem type
+        let mut rev = Letter::iter(word).rev();
+        let (last, stem_last) = (rev.next(), rev.next());
+
+        let (ending_len, stem_type) = match (gender, stem_last, last) {
+            // Masculine: null ending, or '-ь'/'-й'
+            (Gender::Masculine, _, Some(lt::ь)) => (1, Self::Type2),
+            (Gender::Masculine, Some(stem_last), Some(lt::й)) if stem_last.is_vowel() => {
+                (1, Self::by_soft_final(stem_last)?)
+            },
+            (Gender::Masculine, _, Some(last)) if last.is_consonant() => (0, Self::by_final(last)),
+
+            // Neuter: '-о', '-е' or '-ё'
+            (Gender::Neuter, Some(stem_last), Some(lt::о)) => (1, Self::by_final(stem_last)),
+            (Gender::Neuter, Some(stem_last), Some(lt::е | lt::ё)) => {
+                (1, Self::by_soft_final(stem_last)?)
+            },
+
+            // Feminine: '-а', '-я' or '-ь'
+            (Gender::Feminine, Some(stem_last), Some(lt::а)) => (1, Self::by_final(stem_last)),
+            (Gender::Feminine, Some(stem_last), Some(lt::я)) => {
+                (1, Self::by_soft_final(stem_last)?)
+            },
+            (Gender::Feminine, _, Some(lt::ь)) => (1, Self::Type8),
+
+            _ => return Err(Error::UnknownEnding),
+        };
+
+        let stem = &word[..(word.len() - ending_len * 2)];
+
+        // Make sure the stem's final letter is allowed in the detected stem type (конь, but not *нь)
+        match Letter::iter(stem).next_back() {
+            Some(last) if stem_type.allows_stem_final(last) => Ok((stem, stem_type)),
+            _ => Err(Error::UnknownEnding),
+        }
+    }
+
+    /// Splits a dictionary headword into its stem and stem type. Unlike
+    /// [`extract_stem`][Self::extract_stem], ambiguous endings are reported as an error listing
+    /// the candidates, instead of being resolved to the most common stem type, and neuter nouns in
+    /// '-мя' with unique stem alternation (время, знамя) are split as stem type 8.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::Gender, declension::*};
+    ///
+    /// let (stem, stem_type) = NounStemType::split_headword("время", Gender::Neuter).unwrap();
+    /// assert_eq!((stem, stem_type), ("врем", NounStemType::Type8));
+    ///
+    /// let err = NounStemType::split_headword("конь", Gender::Masculine).unwrap_err();
+    /// assert_eq!(err, ExtractStemError::Ambiguous(&[NounStemType::Type2, NounStemType::Type8]));
+    /// ```
+    pub fn split_headword(word: &str, gender: Gender) -> Result<(&str, Self), ExtractStemError> {
+        Self::check_letters(word)?;
+
+        match gender {
+            Gender::Masculine if word.ends_with('ь') => {
+                Err(Error::Ambiguous(&[Self::Type2, Self::Type8]))
+            },
+            Gender::Neuter if word.len() > 4 && word.ends_with("мя") => {
+                Ok((&word[..(word.len() - 2)], Self::Type8))
+            },
+            _ => Self::extract_stem(word, gender),
+        }
+    }
+
+    fn check_letters(word: &str) -> Result<(), ExtractStemError> {
+        if word.is_empty()
+            || !word.chars().all(|ch| matches!(ch, 'а'..='я' | 'А'..='Я' | 'ё' | 'Ё'))
+        {
+            return Err(Error::InvalidLetters);
+        }
+        Ok(())
+    }
+
+    // Stem type of a stem ending in a consonant, with a "hard" ending (null, -а, -о)
+    const fn by_final(last: Letter) -> Self {
+        use letters as lt;
+
+        match last {
+            lt::к | lt::г | lt::х => Self::Type3,
+            lt::ц => Self::Type5,
+            _ if last.is_hissing() => Self::Type4,
+            _ => Self::Type1,
+        }
+    }
+    // Stem type of a stem followed by a "soft" ending (-й, -я, -е, -ё)
+    const fn by_soft_final(last: Letter) -> Result<Self, ExtractStemError> {
+        use letters as lt;
+
+        Ok(match last {
+            lt::и => Self::Type7,
+            lt::ь => Self::Type6,
+            _ if last.is_vowel() => Self::Type6,
+            lt::ц => Self::Type5,
+            _ if last.is_hissing() => Self::Type4,
+            lt::к | lt::г | lt::х | lt::й => return Err(Error::UnknownEnding),
+            _ => Self::Type2,
+        })
+    }
+}
+
+impl NounDeclension {
+    /// Extracts the stem of a noun's nominative singular form, making sure that it's compatible
+    /// with this declension's stem type. For example, "Мария" can't have stem type 6, and "Марья"
+    /// can't have stem type 7, since the dative and prepositional singular forms would be wrong.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::Gender, declension::*};
+    ///
+    /// let decl: NounDeclension = "7a".parse().unwrap();
+    /// assert_eq!(decl.extract_stem("Мария", Gender::Feminine), Ok("Мари"));
+    ///
+    /// let decl: NounDeclension = "6a".parse().unwrap();
+    /// assert_eq!(
+    ///     decl.extract_stem("Мария", Gender::Feminine),
+    ///     Err(ExtractStemError::IncompatibleStemType {
+    ///         expected: NounStemType::Type7,
+    ///         found: NounStemType::Type6,
+    ///     }),
+    /// );
+    /// ```
+    pub fn extract_stem(self, word: &str, gender: Gender) -> Result<&str, ExtractStemError> {
+        NounStemType::check_letters(word)?;
+
+        let info = DeclInfo {
+            case: Case::Nominative,
+            number: Number::Singular,
+            gender,
+            animacy: Animacy::Inanimate,
+        };
+        // Make sure the word ends with the stem type's nominative ending
+        let Some(stem) = word.strip_suffix(self.get_ending(info)).filter(|x| !x.is_empty()) else {
+            return Err(Error::IncompatibleEnding(self.stem_type));
+        };
+
+        // Make sure the stem's final letter is allowed in this stem type
+        let last = Letter::iter(stem).next_back().unwrap();
+        if !self.stem_type.allows_stem_final(last) {
+            let expected = match NounStemType::extract_stem(word, gender) {
+                Ok((_, expected)) => expected,
+                Err(_) => return Err(Error::IncompatibleEnding(self.stem_type)),
+            };
+            return Err(Error::IncompatibleStemType { expected, found: self.stem_type });
+        }
+
+        Ok(stem)
+    }
+}
+
+impl<'a> Noun<'a> {
+    /// Creates a noun from its dictionary headword, extracting the stem by the noun's declension:
+    /// the nominative singular ending is stripped, or the nominative plural one for plurale
+    /// tantum nouns. Indeclinable nouns keep the whole word as the stem. Neuter nouns in '-мя'
+    /// with unique stem alternation (время) lose only the '-я', and other unique alternations
+    /// (ребёнок, боярин) keep the nominative form as the stem.
+    ///
+    /// The headword must consist only of Cyrillic letters. Stress marks and other irregularities,
+    /// such as decomposed letters, must be removed beforehand, see [`Noun::from_normalized`].
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::from_headword("статья", "ж 6*b".parse().unwrap()).unwrap();
+    /// assert_eq!(noun.stem, "стать");
+    /// assert_eq!(noun.inflect_to_string(CaseEx::Genitive, Number::Plural).unwrap(), "статей");
+    ///
+    /// let noun = Noun::from_headword("ножницы", "мн. ж 5a".parse().unwrap()).unwrap();
+    /// assert_eq!(noun.stem, "ножниц");
+    /// ```
+    pub fn from_headword(word: &'a str, info: NounInfo) -> Result<Self, ExtractStemError> {
+        let stem_len = Self::extract_stem_len(word, info)?;
+        Ok(Self::new(&word[..stem_len], info))
+    }
+    /// Creates a noun from its normalized dictionary headword, the same way as
+    /// [`from_headword`][Self::from_headword]. The headword's stress position is kept as the
+    /// noun's [`stress`][Noun::stress], if it's in the stem, and used in the stressed forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*, stem};
+    ///
+    /// let word = stem::normalize("мо́лот").unwrap();
+    /// let noun = Noun::from_normalized(&word, "м 1a".parse().unwrap()).unwrap();
+    /// assert_eq!((noun.stem, noun.stress), ("молот", Some(1)));
+    /// assert_eq!(noun.inflect_stressed(CaseEx::Dative, Number::Plural).unwrap(), "мо́лотам");
+    /// ```
+    pub fn from_normalized(
+        word: &'a NormalizedStem,
+        info: NounInfo,
+    ) -> Result<Self, ExtractStemError> {
+        let noun = Self::from_headword(word.as_str(), info)?;
+        let stress = word.stress.filter(|&i| i < noun.stem.len() / 2);
+        Ok(noun.with_stress(stress))
+    }
+
+    // Returns the length of the stem of the unmarked headword, in bytes
+    fn extract_stem_len(word: &str, info: NounInfo) -> Result<usize, ExtractStemError> {
+        NounStemType::check_letters(word)?;
+
+        let number = if info.is_plurale_tantum() { Number::Plural } else { Number::Singular };
+        let gender = info.declension_gender;
+        let nom = DeclInfo { case: Case::Nominative, number, gender, animacy: info.animacy };
+
+        let strip = |ending: &str| word.strip_suffix(ending).filter(|x| !x.is_empty());
+
+        let stem = match info.declension {
+            None => Some(word),
+            Some(Declension::Noun(decl)) if number == Number::Singular => {
+                if decl.flags.has_circle() && gender == Gender::Neuter && word.ends_with("мя") {
+                    strip("я")
+                } else {
+                    Some(decl.extract_stem(word, gender)?)
+                }
+            },
+            Some(Declension::Noun(decl)) => strip(decl.get_ending(nom)),
+            Some(Declension::Pronoun(decl)) => strip(decl.get_ending(nom)),
+            Some(Declension::Adjective(decl)) => {
+                let word = if decl.is_reflexive { word.strip_suffix("ся") } else { Some(word) };
+                word.and_then(|x| x.strip_suffix(decl.get_ending(nom))).filter(|x| !x.is_empty())
+            },
+        };
+
+        Ok(stem.ok_or(Error::UnknownEnding)?.len())
+    }
+}
+
+impl<'a> Adjective<'a> {
+    /// Creates an adjective from its dictionary headword, the masculine nominative singular,
+    /// extracting the stem by the adjective's declension: the ending is stripped, along with the
+    /// '-ся' of reflexive adjectives. Indeclinable adjectives keep the whole word as the stem.
+    ///
+    /// The headword may have a stress mark (U+0301), that is kept in the stem, if it's not in the
+    /// ending.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = AdjectiveInfo {
+    ///     declension: Some("п 3*a/c′".parse().unwrap()),
+    ///     short_forms: ShortFormAvailability::Available,
+    /// };
+    /// let adj = Adjective::from_headword("го́рький", info).unwrap();
+    /// assert_eq!(adj.stem, "го́рьк");
+    /// assert_eq!(adj.short_forms(Gender::Masculine, Number::Singular).unwrap(), ["го́рек"]);
+    /// ```
+    pub fn from_headword(word: &'a str, info: AdjectiveInfo) -> Result<Self, ExtractStemError> {
+        let (unmarked, _) = split_stress_mark(word);
+        NounStemType::check_letters(&unmarked)?;
+
+        let nom = DeclInfo {
+            case: Case::Nominative,
+            number: Number::Singular,
+            gender: Gender::Masculine,
+            animacy: Animacy::Inanimate,
+        };
+        let stem = match info.declension {
+            None => Some(&*unmarked),
+            Some(Declension::Noun(_)) => None,
+            Some(decl) => {
+                let word = if info.is_reflexive() {
+                    unmarked.strip_suffix("ся")
+                } else {
+                    Some(&*unmarked)
+                };
+                word.and_then(|x| x.strip_suffix(decl.get_ending(nom))).filter(|x| !x.is_empty())
+            },
+        };
+        let stem_len = stem.ok_or(Error::UnknownEnding)?.len();
+
+        Ok(Self { stem: marked_stem(word, stem_len), info })
+    }
+}
+
+// Returns the stem of the marked headword, along with the stress mark right after it, by the
+// length of the stem in the unmarked headword, in bytes
+fn marked_stem(word: &str, stem_len: usize) -> &str {
+    let mut letters_len = 0;
+    for (i, ch) in word.char_indices() {
+        if ch == STRESS_MARK {
+            continue;
+        }
+        if letters_len == stem_len {
+            return &word[..i];
+        }
+        letters_len += ch.len_utf8();
+    }
+    word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{CaseEx, GenderEx},
+        util::assert_snapshot,
+    };
+    use std::format;
+    use test::{Bencher, black_box};
+
+    fn noun<'a>(word: &'a str, gender: Gender, animacy: Animacy, decl: &str) -> Noun<'a> {
+        let decl: NounDeclension = decl.parse().unwrap();
+        let stem = decl.extract_stem(word, gender).unwrap();
+
+        let info = NounInfo {
+            declension: Some(decl.into()),
+            declension_gender: gender,
+            gender: GenderEx::from(gender),
+            animacy,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+
+    #[test]
+    fn extract() {
+        let assert = |word: &str, gender: Gender, stem: &str, stem_type: u8| {
+            let expected = (stem, NounStemType::from_digit(stem_type).unwrap());
+            assert_eq!(NounStemType::extract_stem(word, gender), Ok(expected));
+        };
+
+        use Gender::*;
+        assert("стол", Masculine, "стол", 1);
+        assert("конь", Masculine, "кон", 2);
+        assert("враг", Masculine, "враг", 3);
+        assert("нож", Masculine, "нож", 4);
+        assert("отец", Masculine, "отец", 5);
+        assert("музей", Masculine, "музе", 6);
+        assert("гербарий", Masculine, "гербари", 7);
+        assert("окно", Neuter, "окн", 1);
+        assert("поле", Neuter, "пол", 2);
+        assert("облако", Neuter, "облак", 3);
+        assert("жилище", Neuter, "жилищ", 4);
+        assert("сердце", Neuter, "сердц", 5);
+        assert("воскресенье", Neuter, "воскресень", 6);
+        assert("бельё", Neuter, "бель", 6);
+        assert("собрание", Neuter, "собрани", 7);
+        assert("остриё", Neuter, "остри", 7);
+        assert("книга", Feminine, "книг", 3);
+        assert("земля", Feminine, "земл", 2);
+        assert("Марья", Feminine, "Марь", 6);
+        assert("статья", Feminine, "стать", 6);
+        assert("Мария", Feminine, "Мари", 7);
+        assert("ночь", Feminine, "ноч", 8);
+
+        let err = |word: &str, gender: Gender, err: ExtractStemError| {
+            assert_eq!(NounStemType::extract_stem(word, gender), Err(err));
+        };
+        err("", Masculine, Error::InvalidLetters);
+        err("table", Masculine, Error::InvalidLetters);
+        err("стол", Feminine, Error::UnknownEnding);
+        err("а", Feminine, Error::UnknownEnding);
+        err("книга", Neuter, Error::UnknownEnding);
+        // The stem's final letter doesn't match the stem type implied by the ending
+        err("гь", Masculine, Error::UnknownEnding);
+        err("ваь", Feminine, Error::UnknownEnding);
+        err("мй", Masculine, Error::UnknownEnding);
+    }
+
+    #[test]
+    fn split_headword() {
+        use Gender::*;
+
+        let assert = |word: &str, gender: Gender, stem: &str, stem_type: u8| {
+            let expected = (stem, NounStemType::from_digit(stem_type).unwrap());
+            assert_eq!(NounStemType::split_headword(word, gender), Ok(expected), "{word}");
+        };
+        assert("стол", Masculine, "стол", 1);
+        assert("земля", Feminine, "земл", 2);
+        assert("волчонок", Masculine, "волчонок", 3);
+        assert("нож", Masculine, "нож", 4);
+        assert("сердце", Neuter, "сердц", 5);
+        assert("семья", Feminine, "семь", 6);
+        assert("собрание", Neuter, "собрани", 7);
+        assert("ночь", Feminine, "ноч", 8);
+        assert("время", Neuter, "врем", 8);
+        assert("знамя", Neuter, "знам", 8);
+
+        let ambiguous = Err(Error::Ambiguous(&[NounStemType::Type2, NounStemType::Type8]));
+        assert_eq!(NounStemType::split_headword("конь", Masculine), ambiguous);
+        assert_eq!(NounStemType::split_headword("путь", Masculine), ambiguous);
+        assert_eq!(NounStemType::split_headword("мя", Neuter), Err(Error::UnknownEnding));
+        assert_eq!(NounStemType::split_headword("time", Neuter), Err(Error::InvalidLetters));
+    }
+
+    #[test]
+    fn from_headword() {
+        let assert = |word: &str, info: &str, stem: &str, lemma: &str| {
+            let noun = Noun::from_headword(word, info.parse().unwrap()).unwrap();
+            assert_eq!(noun.stem, stem, "{word}");
+            assert_eq!(noun.lemma().unwrap(), word);
+            let form = noun.inflect_to_string(CaseEx::Nominative, Number::Plural).unwrap();
+            assert_eq!(form, lemma, "{word}");
+        };
+        assert("стол", "м 1b", "стол", "столы");
+        assert("волчонок", "мо 3°d", "волчонок", "волчата");
+        assert("время", "с 8°c, ё", "врем", "времена");
+        assert("мать", "жо 8°e", "мат", "матери");
+        assert("путь", "м 8b", "пут", "пути");
+        assert("ножницы", "мн. ж 5a", "ножниц", "ножницы");
+        assert("кофе", "м 0", "кофе", "кофе");
+        assert("рабочий", "мо п 4a", "рабоч", "рабочие");
+
+        let err = |word: &str, info: &str, expected: ExtractStemError| {
+            assert_eq!(Noun::from_headword(word, info.parse().unwrap()), Err(expected), "{word}");
+        };
+        err("стол", "ж 1a", Error::IncompatibleEnding(NounStemType::Type1));
+        err("ножницы", "мн. ж 3a", Error::UnknownEnding);
+        err("рабочий", "мо п 1a", Error::UnknownEnding);
+        err("", "м 0", Error::InvalidLetters);
+    }
+
+    #[test]
+    fn adjective_from_headword() {
+        let info = |decl: &str| AdjectiveInfo {
+            declension: Some(decl.parse().unwrap()),
+            short_forms: crate::declension::ShortFormAvailability::Available,
+        };
+        let stem = |word, decl| Adjective::from_headword(word, info(decl)).map(|x| x.stem);
+
+        assert_eq!(stem("новый", "п 1a"), Ok("нов"));
+        // The stress mark is kept only if it's in the stem
+        assert_eq!(stem("но́вый", "п 1a"), Ok("но́в"));
+        assert_eq!(stem("молодо́й", "п 1b"), Ok("молод"));
+        assert_eq!(stem("си́ний", "п 2a"), Ok("си́н"));
+        assert_eq!(stem("трудящийся", "п 4a, -ся"), Ok("трудящ"));
+        assert_eq!(stem("чей", "мс 6*b"), Ok("че"));
+
+        assert_eq!(stem("новый", "п 2a"), Err(Error::UnknownEnding));
+        assert_eq!(stem("новый", "1a"), Err(Error::UnknownEnding));
+        assert_eq!(stem("ый", "п 1a"), Err(Error::UnknownEnding));
+        assert_eq!(stem("new", "п 1a"), Err(Error::InvalidLetters));
+    }
+
+    #[test]
+    fn validate() {
+        fn check<'a>(decl: &str, word: &'a str, gender: Gender) -> Result<&'a str, Error> {
+            decl.parse::<NounDeclension>().unwrap().extract_stem(word, gender)
+        }
+        let incompatible = |expected: u8, found: u8| Error::IncompatibleStemType {
+            expected: NounStemType::from_digit(expected).unwrap(),
+            found: NounStemType::from_digit(found).unwrap(),
+        };
+
+        assert_eq!(check("7a", "Мария", Gender::Feminine), Ok("Мари"));
+        assert_eq!(check("6a", "Мария", Gender::Feminine), Err(incompatible(7, 6)));
+        assert_eq!(check("6*a", "Марья", Gender::Feminine), Ok("Марь"));
+        assert_eq!(check("7a", "Марья", Gender::Feminine), Err(incompatible(6, 7)));
+        assert_eq!(check("2a", "Марья", Gender::Feminine), Err(incompatible(6, 2)));
+        assert_eq!(check("7a", "собрание", Gender::Neuter), Ok("собрани"));
+        assert_eq!(check("6a", "собрание", Gender::Neuter), Err(incompatible(7, 6)));
+        assert_eq!(check("7a", "воскресенье", Gender::Neuter), Err(incompatible(6, 7)));
+        assert_eq!(check("6a", "гербарий", Gender::Masculine), Err(incompatible(7, 6)));
+        assert_eq!(check("1a", "гербарий", Gender::Masculine), Err(incompatible(7, 1)));
+        assert_eq!(
+            check("1a", "книга", Gender::Feminine),
+            Err(Error::IncompatibleStemType {
+                expected: NounStemType::Type3,
+                found: NounStemType::Type1
+            }),
+        );
+        assert_eq!(
+            check("2a", "книга", Gender::Feminine),
+            Err(Error::IncompatibleEnding(NounStemType::Type2)),
+        );
+    }
+
+    #[test]
+    fn paradigms() {
+        use Animacy::*;
+        use Gender::*;
+
+        let table = [
+            ("Мария", Feminine, Animate, "7a"),
+            ("Марья", Feminine, Animate, "6*a"),
+            ("статья", Feminine, Inanimate, "6*b"),
+            ("гербарий", Masculine, Inanimate, "7a"),
+            ("собрание", Neuter, Inanimate, "7a"),
+            ("воскресенье", Neuter, Inanimate, "6*a"),
+        ];
+
+        let paradigms = table.map(|(word, gender, animacy, decl)| {
+            let paradigm = noun(word, gender, animacy, decl).format_paradigm().unwrap();
+            assert!(paradigm.starts_with(&format!("{word} ")), "{word} {decl}");
+            paradigm
+        });
+        assert_snapshot("noun_extract", &paradigms.join("\n"));
+    }
+
+    #[bench]
+    fn bench_extract_stem(b: &mut Bencher) {
+        let words = [
+            ("достопримечательность", Gender::Feminine),
+            ("воскресенье", Gender::Neuter),
+            ("гербарий", Gender::Masculine),
+            ("кот", Gender::Masculine),
+        ];
+        b.iter(|| {
+            for (word, gender) in black_box(words) {
+                black_box(NounStemType::extract_stem(word, gender).unwrap());
+            }
+        });
+    }
+}
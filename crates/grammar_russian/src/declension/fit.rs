@@ -0,0 +1,304 @@
+use crate::{
+    InflectionBuffer, Letter,
+    categories::{CaseAndNumber, Gender, GenderAnimacy, HasGender},
+    declension::{DeclInfo, DeclensionFlags, NounDeclension, NounStemType},
+    letters,
+    stress::NounStress,
+};
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+/// The maximum number of mismatching forms, for a candidate to be considered a near-miss.
+pub const NEAR_MISS_MAX_MISMATCHES: usize = 2;
+
+/// A declension candidate found by [`fit_declension`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FitCandidate<'a> {
+    pub stem: &'a str,
+    pub declension: NounDeclension,
+    /// Forms generated by the declension that didn't match the provided ones.
+    pub mismatches: Vec<CaseAndNumber>,
+}
+
+/// The result of [`fit_declension`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FitResult<'a> {
+    /// The declension generates all the provided forms exactly.
+    Match(FitCandidate<'a>),
+    /// The declension generates all but a few of the provided forms (irregular forms).
+    NearMiss(FitCandidate<'a>),
+    /// None of the declensions came close. Contains the closest candidate, if there was any.
+    NoMatch(Option<FitCandidate<'a>>),
+}
+
+/// Finds a noun declension that generates the specified forms, e.g. from an imported inflection
+/// table. The forms should be marked with stress marks (U+0301 or U+0300), since otherwise the
+/// stress schemas can't be told apart. The letter 'ё' is considered stressed, if it's unmarked.
+///
+/// The search is pruned by the lemma's ending: only the stem types compatible with it are tried
+/// (see [`NounStemType::extract_stem`]), and flags are only tried if they can apply to the stem.
+/// Words with unique stem alternations (°) or declined like adjectives aren't considered.
+///
+/// Among the equally good candidates, the simplest one is returned: without flags over with
+/// flags, and with the alphabetically first stress schema.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*};
+/// use std::collections::BTreeMap;
+///
+/// let forms = BTreeMap::from([
+///     (Case::Nominative.with(Number::Plural), "столы́".to_string()),
+///     (Case::Genitive.with(Number::Singular), "стола́".to_string()),
+/// ]);
+///
+/// let FitResult::Match(fit) = fit_declension("стол", GenderAnimacy::MASC_INAN, &forms) else {
+///     panic!()
+/// };
+/// assert_eq!(fit.declension.to_string(), "1b");
+/// ```
+pub fn fit_declension<'a>(
+    lemma: &'a str,
+    gender: GenderAnimacy,
+    forms: &BTreeMap<CaseAndNumber, String>,
+) -> FitResult<'a> {
+    let Ok((stem, stem_type)) = NounStemType::extract_stem(lemma, gender.gender()) else {
+        return FitResult::NoMatch(None);
+    };
+    let forms: Vec<(CaseAndNumber, String, Option<usize>)> = forms
+        .iter()
+        .map(|(key, form)| {
+            let (form, stress) = strip_stress_marks(form);
+            (*key, form, stress)
+        })
+        .collect();
+
+    let mut closest: Option<FitCandidate> = None;
+    let Ok(mut buf) = InflectionBuffer::from_stem(stem) else { return FitResult::NoMatch(None) };
+
+    for declension in candidates(stem, stem_type, gender.gender()) {
+        let mut mismatches = vec![];
+
+        for (key, form, stress) in &forms {
+            let info = DeclInfo::from_parts(*key, gender);
+
+            // The candidate filter should rule out failing alternations, but a form that
+            // can't be inflected is just counted as a mismatch
+            // SAFETY: The stem was checked when the buffer was created
+            unsafe { buf.reset_to_stem_unchecked(stem) };
+            let is_inflected = declension.inflect(info, &mut buf).is_ok();
+
+            // Check the stress only if the ending has a vowel, and could receive it
+            let stress_matches = match stress {
+                Some(index) if buf.ending().iter().any(|x| x.is_vowel()) => {
+                    let is_stem_stressed = *index < buf.stem_len() / 2;
+                    is_stem_stressed == declension.stress.is_stem_stressed(info)
+                },
+                _ => true,
+            };
+
+            if !is_inflected || buf.as_str() != form || !stress_matches {
+                mismatches.push(*key);
+
+                // Stop early, if it's already worse than the closest candidate
+                if closest.as_ref().is_some_and(|x| x.mismatches.len() <= mismatches.len()) {
+                    break;
+                }
+            }
+        }
+
+        if closest.as_ref().is_none_or(|x| x.mismatches.len() > mismatches.len()) {
+            let is_match = mismatches.is_empty();
+            closest = Some(FitCandidate { stem, declension, mismatches });
+            if is_match {
+                break;
+            }
+        }
+    }
+
+    match closest {
+        Some(x) if x.mismatches.is_empty() => FitResult::Match(x),
+        Some(x) if x.mismatches.len() <= NEAR_MISS_MAX_MISMATCHES => FitResult::NearMiss(x),
+        closest => FitResult::NoMatch(closest),
+    }
+}
+
+// Removes stress marks from the form, and returns the index of the stressed letter
+fn strip_stress_marks(form: &str) -> (String, Option<usize>) {
+    let mut stripped = String::with_capacity(form.len());
+    let (mut stress, mut yo) = (None, None);
+
+    for ch in form.chars() {
+        match ch {
+            '\u{0301}' | '\u{0300}' => stress = stripped.chars().count().checked_sub(1),
+            'ё' | 'Ё' => {
+                yo = Some(stripped.chars().count());
+                stripped.push(ch);
+            },
+            _ => stripped.push(ch),
+        }
+    }
+    (stripped, stress.or(yo))
+}
+
+fn candidates(
+    stem: &str,
+    stem_type: NounStemType,
+    gender: Gender,
+) -> impl Iterator<Item = NounDeclension> {
+    let letters = Letter::from_bytes(stem.as_bytes());
+
+    // Masculine nouns in '-ь' can be of stem type 2 or 8 (конь, путь)
+    let is_masc_soft = stem_type == NounStemType::Type2 && gender == Gender::Masculine;
+    let stem_types = [Some(stem_type), is_masc_soft.then_some(NounStemType::Type8)];
+
+    let flag_sets = [
+        DeclensionFlags::empty(),
+        DeclensionFlags::STAR,
+        DeclensionFlags::ALTERNATING_YO,
+        DeclensionFlags::STAR.union(DeclensionFlags::ALTERNATING_YO),
+    ];
+
+    stem_types.into_iter().flatten().flat_map(move |stem_type| {
+        flag_sets
+            .into_iter()
+            .filter(move |&flags| {
+                (!flags.has_star() || allows_star(letters, stem_type, gender))
+                    && (!flags.has_alternating_yo() || allows_ye_yo(letters, flags))
+            })
+            .flat_map(move |flags| {
+                NounStress::VALUES.map(|stress| NounDeclension { stem_type, flags, stress })
+            })
+    })
+}
+
+// Whether the vowel alternation can be applied to the stem (see apply_vowel_alternation)
+fn allows_star(stem: &[Letter], stem_type: NounStemType, gender: Gender) -> bool {
+    if gender == Gender::Masculine || stem_type == NounStemType::Type8 {
+        // The last vowel of the stem is removed or replaced
+        let last_vowel = stem.iter().rposition(|x| x.is_vowel());
+        last_vowel.is_some_and(|i| i > 0 && matches!(stem[i], letters::о | letters::е | letters::ё))
+    } else {
+        // A vowel is inserted between the last two consonants
+        let last_consonant = stem.iter().rposition(|x| x.is_consonant());
+        last_consonant.is_some_and(|i| i > 0) || matches!(stem, [.., letters::ь])
+    }
+}
+// Whether the е/ё alternation can be applied to the stem (see apply_ye_yo_alternation)
+fn allows_ye_yo(stem: &[Letter], flags: DeclensionFlags) -> bool {
+    let search_stem = match stem {
+        [search_stem @ .., _, _] if flags.has_star() => search_stem,
+        _ => stem,
+    };
+    stem.contains(&letters::ё) || search_stem.contains(&letters::е)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Case, Number};
+    use std::string::ToString;
+
+    fn table(forms: [&str; 12]) -> BTreeMap<CaseAndNumber, String> {
+        CaseAndNumber::VALUES.into_iter().zip(forms.map(str::to_string)).collect()
+    }
+
+    #[test]
+    fn perfect_fits() {
+        let assert = |lemma: &str, gender: GenderAnimacy, forms: [&str; 12], expected: &str| {
+            match fit_declension(lemma, gender, &table(forms)) {
+                FitResult::Match(fit) => assert_eq!(fit.declension.to_string(), expected),
+                result => panic!("{lemma}: {result:?}"),
+            }
+        };
+
+        #[rustfmt::skip]
+        assert("стол", GenderAnimacy::MASC_INAN, [
+            "стол", "стола́", "столу́", "стол", "столо́м", "столе́",
+            "столы́", "столо́в", "стола́м", "столы́", "стола́ми", "стола́х",
+        ], "1b");
+        #[rustfmt::skip]
+        assert("книга", GenderAnimacy::FEM_INAN, [
+            "кни́га", "кни́ги", "кни́ге", "кни́гу", "кни́гой", "кни́ге",
+            "кни́ги", "кни́г", "кни́гам", "кни́ги", "кни́гами", "кни́гах",
+        ], "3a");
+        #[rustfmt::skip]
+        assert("окно", GenderAnimacy::NEUT_INAN, [
+            "окно́", "окна́", "окну́", "окно́", "окно́м", "окне́",
+            "о́кна", "о́кон", "о́кнам", "о́кна", "о́кнами", "о́кнах",
+        ], "1*d");
+        #[rustfmt::skip]
+        assert("статья", GenderAnimacy::FEM_INAN, [
+            "статья́", "статьи́", "статье́", "статью́", "статьёй", "статье́",
+            "статьи́", "стате́й", "статья́м", "статьи́", "статья́ми", "статья́х",
+        ], "6*b");
+        #[rustfmt::skip]
+        assert("конь", GenderAnimacy::MASC_AN, [
+            "конь", "коня́", "коню́", "коня́", "конём", "коне́",
+            "ко́ни", "коне́й", "коня́м", "коне́й", "коня́ми", "коня́х",
+        ], "2f");
+    }
+
+    #[test]
+    fn partial_table() {
+        // Only the forms that are provided are compared
+        let forms = BTreeMap::from([
+            (Case::Genitive.with(Number::Singular), "са́да".to_string()),
+            (Case::Genitive.with(Number::Plural), "садо́в".to_string()),
+        ]);
+        let FitResult::Match(fit) = fit_declension("сад", GenderAnimacy::MASC_INAN, &forms)
+        else {
+            panic!()
+        };
+        assert_eq!(fit.declension.to_string(), "1c");
+    }
+
+    #[test]
+    fn near_miss() {
+        // чулок (3*b②) has an irregular genitive plural, and the search doesn't try ①②③
+        #[rustfmt::skip]
+        let forms = table([
+            "чуло́к", "чулка́", "чулку́", "чуло́к", "чулко́м", "чулке́",
+            "чулки́", "чуло́к", "чулка́м", "чулки́", "чулка́ми", "чулка́х",
+        ]);
+
+        let FitResult::NearMiss(fit) = fit_declension("чулок", GenderAnimacy::MASC_INAN, &forms)
+        else {
+            panic!()
+        };
+        assert_eq!(fit.stem, "чулок");
+        assert_eq!(fit.declension.to_string(), "3*b");
+        assert_eq!(fit.mismatches, [Case::Genitive.with(Number::Plural)]);
+
+        // The mismatching form is generated with the ② flag added
+        let mut declension = fit.declension;
+        declension.flags = declension.flags.union(DeclensionFlags::CIRCLED_TWO);
+        let info = DeclInfo::from_parts(fit.mismatches[0], GenderAnimacy::MASC_INAN);
+        let mut buf = InflectionBuffer::from_stem(fit.stem).unwrap();
+        declension.inflect(info, &mut buf).unwrap();
+        assert_eq!(buf.as_str(), "чулок");
+    }
+
+    #[test]
+    fn no_match() {
+        // человек - люди is suppletive
+        #[rustfmt::skip]
+        let forms = table([
+            "челове́к", "челове́ка", "челове́ку", "челове́ка", "челове́ком", "челове́ке",
+            "лю́ди", "люде́й", "лю́дям", "люде́й", "людьми́", "лю́дях",
+        ]);
+
+        let FitResult::NoMatch(Some(closest)) =
+            fit_declension("человек", GenderAnimacy::MASC_AN, &forms)
+        else {
+            panic!()
+        };
+        assert_eq!(closest.declension.to_string(), "3a");
+        assert_eq!(closest.mismatches.len(), 6);
+
+        let forms = table(["x"; 12]);
+        assert_eq!(
+            fit_declension("stol", GenderAnimacy::MASC_INAN, &forms),
+            FitResult::NoMatch(None)
+        );
+    }
+}
@@ -1,11 +1,17 @@
 use crate::{
-    declension::ParseDeclensionError,
-    util::{UnsafeBuf, UnsafeParser, utf8_bytes},
+    declension::{DECLENSION_INPUT_MAX_LEN, ParseDeclensionError},
+    util::{UnsafeBuf, UnsafeParser, impl_fmt_to_buf, utf8_bytes},
 };
 use bitflags::bitflags;
+use thiserror::Error;
 
 bitflags! {
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    /// The flags of a declension: °, *, ①-⑤ and ё.
+    ///
+    /// The flags are ordered by their notation, as in the dictionary: the sets are compared flag by
+    /// flag in the canonical order (°*①②③④⑤, ё), and a set with an earlier flag is greater. Fewer
+    /// and later flags sort first: "" < ", ё" < "①" < "①②" < "*" < "°".
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct DeclensionFlags: u8 {
         const STAR = 1 << 0;
         const CIRCLE = 1 << 1;
@@ -13,6 +19,8 @@ bitflags! {
         const CIRCLED_TWO = 1 << 3;
         const CIRCLED_THREE = 1 << 4;
         const ALTERNATING_YO = 1 << 5;
+        const CIRCLED_FOUR = 1 << 6;
+        const CIRCLED_FIVE = 1 << 7;
     }
 }
 
@@ -32,14 +40,27 @@ impl DeclensionFlags {
     pub const fn has_circled_three(self) -> bool {
         self.intersects(Self::CIRCLED_THREE)
     }
+    /// Returns `true`, if the declension is marked with ④. The flag is only parsed and formatted,
+    /// and doesn't affect the inflection, so the marked forms must be overridden by the caller.
+    pub const fn has_circled_four(self) -> bool {
+        self.intersects(Self::CIRCLED_FOUR)
+    }
+    /// Returns `true`, if the declension is marked with ⑤. The flag is only parsed and formatted,
+    /// and doesn't affect the inflection, so the marked forms must be overridden by the caller.
+    pub const fn has_circled_five(self) -> bool {
+        self.intersects(Self::CIRCLED_FIVE)
+    }
     pub const fn has_alternating_yo(self) -> bool {
         self.intersects(Self::ALTERNATING_YO)
     }
 
     const ALL_LEADING_FLAGS: Self = Self::STAR.union(Self::CIRCLE);
     const ALL_TRAILING_FLAGS: Self = Self::ALL_CIRCLED_DIGITS.union(Self::ALTERNATING_YO);
-    const ALL_CIRCLED_DIGITS: Self =
-        Self::CIRCLED_ONE.union(Self::CIRCLED_TWO).union(Self::CIRCLED_THREE);
+    const ALL_CIRCLED_DIGITS: Self = Self::CIRCLED_ONE
+        .union(Self::CIRCLED_TWO)
+        .union(Self::CIRCLED_THREE)
+        .union(Self::CIRCLED_FOUR)
+        .union(Self::CIRCLED_FIVE);
 
     pub const fn has_any_leading_flags(self) -> bool {
         self.intersects(Self::ALL_LEADING_FLAGS)
@@ -52,9 +73,10 @@ impl DeclensionFlags {
     }
 }
 
-// Longest form: °*①②③, ё (16 bytes, 8 chars)
-pub const DECLENSION_FLAGS_MAX_LEN: usize = 16;
-pub const DECLENSION_FLAGS_MAX_CHARS: usize = 8;
+/// The maximum length of formatted [`DeclensionFlags`], in bytes.
+// Longest form: °*①②③④⑤, ё (22 bytes, 10 chars)
+pub const DECLENSION_FLAGS_MAX_LEN: usize = "°*①②③④⑤, ё".len();
+pub const DECLENSION_FLAGS_MAX_CHARS: usize = 10;
 
 impl DeclensionFlags {
     #[inline]
@@ -78,6 +100,12 @@ impl DeclensionFlags {
             if self.has_circled_three() {
                 dst.push('③');
             }
+            if self.has_circled_four() {
+                dst.push('④');
+            }
+            if self.has_circled_five() {
+                dst.push('⑤');
+            }
             if self.has_alternating_yo() {
                 dst.push_str(", ё");
             }
@@ -91,12 +119,115 @@ impl DeclensionFlags {
     }
 }
 
-impl std::fmt::Display for DeclensionFlags {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for DeclensionFlags {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.fmt_to(&mut [0; DECLENSION_FLAGS_MAX_LEN]).fmt(f)
     }
 }
 
+impl_fmt_to_buf!(DeclensionFlags => DECLENSION_FLAGS_MAX_LEN);
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDeclensionFlagsError {
+    /// A flag is repeated: "**", "①(1)".
+    #[error("repeated declension flag {0}")]
+    Duplicate(char),
+    /// A flag isn't in the canonical order (°*①②③④⑤, ё): "*°", "②①", ", ё①".
+    #[error("declension flag {0} is out of order")]
+    OutOfOrder(char),
+    /// The input has something other than the flags: "1a", "ё", " ".
+    #[error("invalid declension flags")]
+    Invalid,
+    /// The input is longer than [`DECLENSION_INPUT_MAX_LEN`].
+    #[error("declension flags input is too long")]
+    InputTooLong,
+}
+
+impl DeclensionFlags {
+    // Returns the flags as bits in the canonical order, with the first flag (°) as the highest bit
+    const fn ordering_key(self) -> u8 {
+        let mut key = 0;
+        let mut i = 0;
+        while i < FLAG_NOTATION.len() {
+            key = (key << 1) | self.contains(FLAG_NOTATION[i].0) as u8;
+            i += 1;
+        }
+        key
+    }
+}
+impl Ord for DeclensionFlags {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.ordering_key().cmp(&other.ordering_key())
+    }
+}
+impl PartialOrd for DeclensionFlags {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The flags in the canonical order, with their notation and ASCII fallbacks
+const FLAG_NOTATION: [(DeclensionFlags, char, &str, &str); 8] = [
+    (DeclensionFlags::CIRCLE, '°', "°", "°"),
+    (DeclensionFlags::STAR, '*', "*", "*"),
+    (DeclensionFlags::CIRCLED_ONE, '①', "①", "(1)"),
+    (DeclensionFlags::CIRCLED_TWO, '②', "②", "(2)"),
+    (DeclensionFlags::CIRCLED_THREE, '③', "③", "(3)"),
+    (DeclensionFlags::CIRCLED_FOUR, '④', "④", "(4)"),
+    (DeclensionFlags::CIRCLED_FIVE, '⑤', "⑤", "(5)"),
+    (DeclensionFlags::ALTERNATING_YO, 'ё', ", ё", ", ё"),
+];
+
+impl DeclensionFlags {
+    /// Parses the flags the same way as their `FromStr` implementation, but can be used in const
+    /// contexts.
+    pub const fn parse_const(s: &str) -> Result<Self, ParseDeclensionFlagsError> {
+        if s.len() > DECLENSION_INPUT_MAX_LEN {
+            return Err(ParseDeclensionFlagsError::InputTooLong);
+        }
+        let mut flags = Self::empty();
+        let mut parser = UnsafeParser::new(s);
+        let mut last_index = 0;
+
+        while !parser.finished() {
+            // FIXME(const-hack): Replace with an iterator over the notations.
+            let mut index = 0;
+            loop {
+                if index == FLAG_NOTATION.len() {
+                    return Err(ParseDeclensionFlagsError::Invalid);
+                }
+                let (_, _, notation, ascii) = FLAG_NOTATION[index];
+                if parser.skip_str(notation) || parser.skip_str(ascii) {
+                    break;
+                }
+                index += 1;
+            }
+            let (flag, ch, ..) = FLAG_NOTATION[index];
+
+            if flags.contains(flag) {
+                return Err(ParseDeclensionFlagsError::Duplicate(ch));
+            }
+            if index < last_index {
+                return Err(ParseDeclensionFlagsError::OutOfOrder(ch));
+            }
+            flags = flags.union(flag);
+            last_index = index;
+        }
+        Ok(flags)
+    }
+}
+
+impl core::str::FromStr for DeclensionFlags {
+    type Err = ParseDeclensionFlagsError;
+
+    /// Parses the flags in the same format as they're displayed: "°*①②", "*, ё" or "". The flags
+    /// must be in the canonical order (°*①②③④⑤, ё), and the circled digits may be written as
+    /// "(1)"-"(5)".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_const(s)
+    }
+}
+
 impl DeclensionFlags {
     #[inline]
     pub(crate) const fn partial_parse_leading(flags: &mut Self, parser: &mut UnsafeParser) {
@@ -115,7 +246,11 @@ impl DeclensionFlags {
         const CircledOne_Bytes: [u8; 3] = utf8_bytes!('①');
         const CircledTwo_Bytes: [u8; 3] = utf8_bytes!('②');
         const CircledThree_Bytes: [u8; 3] = utf8_bytes!('③');
+        const CircledFour_Bytes: [u8; 3] = utf8_bytes!('④');
+        const CircledFive_Bytes: [u8; 3] = utf8_bytes!('⑤');
 
+        // Each iteration either advances the parser, or fails on a repeated flag, or breaks out,
+        // so the loop runs at most 6 times
         loop {
             match parser.peek::<3>() {
                 Some(&CircledOne_Bytes | b"(1)") => {
@@ -136,6 +271,18 @@ impl DeclensionFlags {
                     }
                     *flags = flags.union(DeclensionFlags::CIRCLED_THREE);
                 },
+                Some(&CircledFour_Bytes | b"(4)") => {
+                    if flags.intersects(DeclensionFlags::CIRCLED_FOUR) {
+                        return Err(ParseDeclensionError::InvalidFlags);
+                    }
+                    *flags = flags.union(DeclensionFlags::CIRCLED_FOUR);
+                },
+                Some(&CircledFive_Bytes | b"(5)") => {
+                    if flags.intersects(DeclensionFlags::CIRCLED_FIVE) {
+                        return Err(ParseDeclensionError::InvalidFlags);
+                    }
+                    *flags = flags.union(DeclensionFlags::CIRCLED_FIVE);
+                },
                 _ => break,
             };
             parser.forward(3);
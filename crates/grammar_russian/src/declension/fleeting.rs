@@ -0,0 +1,88 @@
+use crate::{
+    InflectionBuffer,
+    declension::{AnyStemType, InflectError},
+    letters,
+};
+
+/// Removes the fleeting vowel from the last syllable of the stem (сон - сна, боец - бойца,
+/// лев - льва, зверёк - зверька). Shared by the vowel alternations of nouns and pronouns.
+///
+/// The vowel 'о' is removed, and 'е'/'ё' is replaced with 'й' after a vowel, and with 'ь' in
+/// stem type 6, after 'л', and in stem type 3 after a non-sibilant consonant. Otherwise, it's
+/// removed too (отец - отца, день - дня).
+pub(crate) fn remove_fleeting_vowel(
+    stem_type: AnyStemType,
+    buf: &mut InflectionBuffer,
+) -> Result<(), InflectError> {
+    let Some(last_vowel_index) = buf.stem().iter().rposition(|x| x.is_vowel()) else {
+        return Err(InflectError::NoVowelInStem);
+    };
+
+    let last_vowel = buf.stem()[last_vowel_index];
+    match last_vowel {
+        letters::о => {
+            buf.remove_from_stem((last_vowel_index * 2)..((last_vowel_index + 1) * 2));
+        },
+        letters::е | letters::ё => {
+            let preceding = last_vowel_index.checked_sub(1).map(|i| buf.stem()[i]);
+
+            match preceding {
+                Some(preceding) if preceding.is_vowel() => {
+                    buf.stem_mut()[last_vowel_index] = letters::й;
+                },
+                Some(preceding)
+                    if stem_type == AnyStemType::Type6
+                        || stem_type == AnyStemType::Type3
+                            && preceding.is_non_sibilant_consonant()
+                        || preceding == letters::л =>
+                {
+                    buf.stem_mut()[last_vowel_index] = letters::ь;
+                },
+                _ => buf.remove_from_stem((last_vowel_index * 2)..((last_vowel_index + 1) * 2)),
+            }
+        },
+        _ => return Err(InflectError::UnknownFleetingVowel),
+    }
+    Ok(())
+}
+
+/// Inserts a fleeting vowel between the last two consonants of the stem (окно - окон,
+/// сосна - сосен, кошка - кошек), or replaces 'ь'/'й' before the last consonant with 'е'/'ё'
+/// (серьга - серёг, чайка - чаек). Shared by the vowel alternations of nouns and adjectives.
+///
+/// Next to 'к', 'г' or 'х' the vowel is 'о', unless preceded by a sibilant. Otherwise, it's 'ё'
+/// (or 'о' after a hissing consonant) if stressed, and 'е' if unstressed or before 'ц'.
+pub(crate) fn insert_fleeting_vowel(
+    stressed: bool,
+    buf: &mut InflectionBuffer,
+) -> Result<(), InflectError> {
+    // The vowel is inserted before the last consonant, so there must be a letter before it
+    let Some(last_cons_index @ 1..) = buf.stem().iter().rposition(|x| x.is_consonant()) else {
+        return Err(InflectError::NoConsonantCluster);
+    };
+
+    let last = buf.stem()[last_cons_index];
+    let pre_last = buf.stem_mut().get_mut(last_cons_index - 1);
+
+    if let Some(pre_last @ &mut (letters::ь | letters::й)) = pre_last {
+        *pre_last = if last != letters::ц && stressed { letters::ё } else { letters::е };
+        return Ok(());
+    };
+
+    let pre_last = pre_last.copied();
+
+    if matches!(pre_last, Some(letters::к | letters::г | letters::х))
+        || matches!(last, letters::к | letters::г | letters::х)
+            && pre_last.is_some_and(|x| !x.is_sibilant())
+    {
+        buf.insert_between_last_two_stem_letters(letters::о);
+        return Ok(());
+    }
+
+    buf.insert_between_last_two_stem_letters(if last != letters::ц && stressed {
+        if pre_last.is_some_and(|x| x.is_hissing()) { letters::о } else { letters::ё }
+    } else {
+        letters::е
+    });
+    Ok(())
+}
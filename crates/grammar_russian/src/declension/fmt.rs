@@ -1,17 +1,26 @@
 use crate::{
     declension::{
-        AdjectiveDeclension, AnyStemType, Declension, DeclensionFlags, NounDeclension,
-        PronounDeclension,
+        AdjectiveDeclension, AdjectivePrefix, AnyStemType, Declension, DeclensionFlags,
+        MaybeZeroDeclension, NounDeclension, PronounDeclension,
         flags::{DECLENSION_FLAGS_MAX_CHARS, DECLENSION_FLAGS_MAX_LEN},
     },
     stress::{AnyDualStress, DUAL_STRESS_MAX_CHARS, DUAL_STRESS_MAX_LEN},
-    util::UnsafeBuf,
+    util::{UnsafeBuf, impl_fmt_to_buf},
 };
+use alloc::string::String;
 
-// Longest form (w/ prefix): п 7°*f″/f″①②③, ё (29 bytes, 16 chars)
+/// The maximum length of a formatted declension, in bytes.
+// Longest parsable form: числ.-п 7*a/c″①②③④⑤, ё, -ся (47 bytes, 28 chars).
+// The bound also fits constructed values that don't parse, like числ.-п 7°*f″/f″①②③④⑤, ё, -ся
+// (prefixed adjectives don't accept ° or stresses past c″, nouns aren't prefixed).
 pub const DECLENSION_MAX_LEN: usize =
-    "п ".len() + 1 + DECLENSION_FLAGS_MAX_LEN + DUAL_STRESS_MAX_LEN;
-pub const DECLENSION_MAX_CHARS: usize = 2 + 1 + DECLENSION_FLAGS_MAX_CHARS + DUAL_STRESS_MAX_CHARS;
+    "числ.-п ".len() + 1 + DECLENSION_FLAGS_MAX_LEN + DUAL_STRESS_MAX_LEN + REFLEXIVE_MARKER.len();
+pub const DECLENSION_MAX_CHARS: usize =
+    8 + 1 + DECLENSION_FLAGS_MAX_CHARS + DUAL_STRESS_MAX_CHARS + REFLEXIVE_MARKER_CHARS;
+
+// The marker of reflexive adjective declensions, see AdjectiveDeclension::is_reflexive
+pub(crate) const REFLEXIVE_MARKER: &str = ", -ся";
+const REFLEXIVE_MARKER_CHARS: usize = 5;
 
 const fn fmt_declension_any(
     dst: &mut [u8; DECLENSION_MAX_LEN],
@@ -44,138 +53,332 @@ impl PronounDeclension {
     }
 }
 impl AdjectiveDeclension {
+    /// Formats the declension without the "п" prefix, but with the "мс-п" and "числ.-п" ones,
+    /// since they can't be inferred from the type: "1a/c′", "мс-п 3b".
     pub const fn fmt_to(self, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
-        fmt_declension_any(dst, self.stem_type.into(), self.flags, self.stress.abbr())
+        let mut dst = UnsafeBuf::new(dst);
+
+        if !matches!(self.prefix, AdjectivePrefix::Adjective) {
+            dst.push_str(self.prefix.abbr());
+            dst.push_byte(b' ');
+        }
+        let len = self.fmt_to_unprefixed(dst.chunk()).len();
+        dst.forward(len);
+        dst.finish()
+    }
+    const fn fmt_to_unprefixed(self, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+
+        let len =
+            fmt_declension_any(dst.chunk(), self.stem_type.into(), self.flags, self.stress.abbr())
+                .len();
+        dst.forward(len);
+
+        if self.is_reflexive {
+            dst.push_str(REFLEXIVE_MARKER);
+        }
+        dst.finish()
     }
 }
 impl Declension {
+    /// Formats the declension with the prefix of its kind: "1a" for nouns, "мс 6*b" for pronouns,
+    /// and "п 1a/c′" for adjectives (or "мс-п 3b" and "числ.-п 1b", see [`AdjectivePrefix`]). The
+    /// prefixed form is parsed by [`Declension`]'s `FromStr`, and by the `FromStr` of the
+    /// corresponding typed declension.
     pub const fn fmt_to(self, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
         let mut dst = UnsafeBuf::new(dst);
 
-        let (stem_type, flags, stress) = match self {
-            Self::Noun(decl) => {
-                // no prefix for nouns
-                (decl.stem_type.into(), decl.flags, decl.stress.into())
-            },
-            Self::Pronoun(decl) => {
-                dst.push_str("мс ");
-                (decl.stem_type.into(), decl.flags, decl.stress.into())
-            },
+        match self {
+            Self::Noun(_) => {}, // no prefix for nouns
+            Self::Pronoun(_) => dst.push_str("мс "),
             Self::Adjective(decl) => {
-                dst.push_str("п ");
-                (decl.stem_type.into(), decl.flags, decl.stress.into())
+                dst.push_str(decl.prefix.abbr());
+                dst.push_byte(b' ');
             },
         };
 
-        let len = fmt_declension_any(dst.chunk(), stem_type, flags, stress).len();
+        let len = self.fmt_to_unprefixed(dst.chunk()).len();
         dst.forward(len);
+        dst.finish()
+    }
+    /// Formats the declension without the prefix of its kind, the same way as the typed
+    /// declension is formatted: "6*b" instead of "мс 6*b". Useful for embedding the declension
+    /// where its kind is already known.
+    pub const fn fmt_to_unprefixed(self, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
+        match self {
+            Self::Noun(decl) => decl.fmt_to(dst),
+            Self::Pronoun(decl) => decl.fmt_to(dst),
+            Self::Adjective(decl) => decl.fmt_to_unprefixed(dst),
+        }
+    }
+}
+
+/// The maximum length of a declension formatted in ASCII, in bytes.
+// Longest form (w/ prefix): num-adj 7o*f''/f''(1)(2)(3)(4)(5), yo, -sya (43 bytes)
+pub const DECLENSION_ASCII_MAX_LEN: usize =
+    "num-adj ".len() + 1 + "o*(1)(2)(3)(4)(5), yo".len() + "f''/f''".len() + ", -sya".len();
+
+// The ASCII transliterations of the notation's symbols, see Declension::fmt_ascii_to
+const ASCII_NOTATION: [(&str, &str); 14] = [
+    ("мс-п ", "pro-adj "),
+    ("числ.-п ", "num-adj "),
+    ("мс ", "pro "),
+    ("п ", "adj "),
+    ("°", "o"),
+    ("′", "'"),
+    ("″", "''"),
+    ("①", "(1)"),
+    ("②", "(2)"),
+    ("③", "(3)"),
+    ("④", "(4)"),
+    ("⑤", "(5)"),
+    ("ё", "yo"),
+    ("-ся", "-sya"),
+];
+
+impl Declension {
+    /// Formats the declension using only ASCII characters: "pro 6*b" for "мс 6*b", and
+    /// "adj 1*a/c''(1), yo, -sya" for "п 1*a/c″①, ё, -ся". The ASCII form is parsed by
+    /// [`Declension::parse_relaxed`].
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::declension::*;
+    ///
+    /// let decl: Declension = "7°*b′①, ё".parse().unwrap();
+    /// let mut buf = [0; DECLENSION_ASCII_MAX_LEN];
+    /// let ascii = decl.fmt_ascii_to(&mut buf);
+    /// assert_eq!(ascii, "7o*b'(1), yo");
+    /// assert_eq!(Declension::parse_relaxed(ascii).unwrap().0, decl);
+    /// ```
+    pub fn fmt_ascii_to(self, dst: &mut [u8; DECLENSION_ASCII_MAX_LEN]) -> &mut str {
+        let mut dst = UnsafeBuf::new(dst);
+        let mut buf = [0; DECLENSION_MAX_LEN];
+        let mut rest = &*self.fmt_to(&mut buf);
 
+        while !rest.is_empty() {
+            match ASCII_NOTATION.iter().find(|x| rest.starts_with(x.0)) {
+                Some((notation, ascii)) => {
+                    dst.push_str(ascii);
+                    rest = &rest[notation.len()..];
+                },
+                None => {
+                    // Everything else in the notation is already ASCII
+                    dst.push_byte(rest.as_bytes()[0]);
+                    rest = &rest[1..];
+                },
+            }
+        }
         dst.finish()
     }
+    /// Formats the declension using only ASCII characters. See [`Declension::fmt_ascii_to`].
+    pub fn to_ascii_string(self) -> String {
+        String::from(&*self.fmt_ascii_to(&mut [0; DECLENSION_ASCII_MAX_LEN]))
+    }
+}
+
+impl MaybeZeroDeclension {
+    /// Formats the declension like [`Declension::fmt_to`], or as "0", if it's the indeclinable
+    /// zero declension.
+    pub const fn fmt_to(self, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
+        match self.as_option() {
+            Some(decl) => decl.fmt_to(dst),
+            None => {
+                let mut dst = UnsafeBuf::new(dst);
+                dst.push_byte(b'0');
+                dst.finish()
+            },
+        }
+    }
 }
 
-impl std::fmt::Display for NounDeclension {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for NounDeclension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.fmt_to(&mut [0; DECLENSION_MAX_LEN]).fmt(f)
     }
 }
-impl std::fmt::Display for PronounDeclension {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for PronounDeclension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.fmt_to(&mut [0; DECLENSION_MAX_LEN]).fmt(f)
     }
 }
-impl std::fmt::Display for AdjectiveDeclension {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for AdjectiveDeclension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.fmt_to(&mut [0; DECLENSION_MAX_LEN]).fmt(f)
     }
 }
-impl std::fmt::Display for Declension {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for Declension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.fmt_to(&mut [0; DECLENSION_MAX_LEN]).fmt(f)
     }
 }
+impl core::fmt::Display for MaybeZeroDeclension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.fmt_to(&mut [0; DECLENSION_MAX_LEN]).fmt(f)
+    }
+}
+
+impl_fmt_to_buf! {
+    NounDeclension => DECLENSION_MAX_LEN,
+    PronounDeclension => DECLENSION_MAX_LEN,
+    AdjectiveDeclension => DECLENSION_MAX_LEN,
+    Declension => DECLENSION_MAX_LEN,
+    MaybeZeroDeclension => DECLENSION_MAX_LEN,
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::{declension::*, stress::*};
+    use crate::{declension::*, stress::*, util::assert_snapshot};
+    use std::string::ToString;
 
     #[test]
     fn fmt() {
-        assert_eq!(
+        let formatted = [
             NounDeclension {
                 stem_type: NounStemType::Type4,
                 flags: DeclensionFlags::empty(),
                 stress: NounStress::B,
             }
             .to_string(),
-            "4b",
-        );
-        assert_eq!(
             NounDeclension {
                 stem_type: NounStemType::Type7,
                 flags: DeclensionFlags::STAR | DeclensionFlags::CIRCLED_ONE,
                 stress: NounStress::Bp,
             }
             .to_string(),
-            "7*b′①",
-        );
-        assert_eq!(
             NounDeclension {
                 stem_type: NounStemType::Type8,
                 flags: DeclensionFlags::all(),
                 stress: NounStress::Fpp,
             }
             .to_string(),
-            "8°*f″①②③, ё",
-        );
-
-        assert_eq!(
             PronounDeclension {
                 stem_type: PronounStemType::Type1,
                 flags: DeclensionFlags::STAR,
                 stress: PronounStress::A,
             }
             .to_string(),
-            "1*a",
-        );
-        assert_eq!(
             PronounDeclension {
                 stem_type: PronounStemType::Type6,
                 flags: DeclensionFlags::all(),
                 stress: PronounStress::F,
             }
             .to_string(),
-            "6°*f①②③, ё",
-        );
-
-        assert_eq!(
             AdjectiveDeclension {
                 stem_type: AdjectiveStemType::Type1,
                 flags: DeclensionFlags::empty(),
                 stress: AdjectiveStress::B,
+                is_reflexive: false,
+                prefix: AdjectivePrefix::Adjective,
             }
             .to_string(),
-            "1b",
-        );
-        assert_eq!(
             AdjectiveDeclension {
                 stem_type: AdjectiveStemType::Type4,
                 flags: DeclensionFlags::STAR
                     | DeclensionFlags::CIRCLED_ONE
                     | DeclensionFlags::CIRCLED_TWO,
                 stress: AdjectiveStress::Ap,
+                is_reflexive: false,
+                prefix: AdjectivePrefix::Adjective,
             }
             .to_string(),
-            "4*a′①②",
-        );
-        assert_eq!(
             AdjectiveDeclension {
                 stem_type: AdjectiveStemType::Type7,
                 flags: DeclensionFlags::all(),
                 stress: AdjectiveStress::A_Cpp,
+                is_reflexive: false,
+                prefix: AdjectivePrefix::Adjective,
             }
             .to_string(),
-            "7°*a/c″①②③, ё",
-        );
+            AdjectiveDeclension {
+                stem_type: AdjectiveStemType::Type4,
+                flags: DeclensionFlags::empty(),
+                stress: AdjectiveStress::A,
+                is_reflexive: true,
+                prefix: AdjectivePrefix::Adjective,
+            }
+            .to_string(),
+        ];
+        assert_snapshot("declension_fmt", &(formatted.join("\n") + "\n"));
+
+        assert_eq!("1a".parse::<Declension>().unwrap().to_string(), "1a");
+        assert_eq!("мс 6*b".parse::<Declension>().unwrap().to_string(), "мс 6*b");
+        assert_eq!("п 1a".parse::<Declension>().unwrap().to_string(), "п 1a");
+        assert_eq!("п 4a/c′".parse::<Declension>().unwrap().to_string(), "п 4a/c′");
+        assert_eq!("п 4a, -ся".parse::<Declension>().unwrap().to_string(), "п 4a, -ся");
+        let s = "п 1*a/c′①, ё, -ся";
+        assert_eq!(s.parse::<Declension>().unwrap().to_string(), s);
+    }
+
+    #[test]
+    fn fmt_ascii() {
+        for s in ["1a", "мс 6*b", "п 4a/c′", "7°*b′①②, ё", "8*f″④⑤", "п 1*a/c″①②, ё, -ся"]
+        {
+            let decl: Declension = s.parse().unwrap();
+            let ascii = decl.to_ascii_string();
+            assert!(ascii.is_ascii(), "{ascii}");
+            assert_eq!(Declension::parse_relaxed(&ascii).unwrap().0, decl, "{ascii}");
+        }
+
+        let decl: Declension = "п 1*a/c″①②, ё, -ся".parse().unwrap();
+        let mut buf = [0; DECLENSION_ASCII_MAX_LEN];
+        let ascii = decl.fmt_ascii_to(&mut buf);
+        assert_eq!(ascii, "adj 1*a/c''(1)(2), yo, -sya");
+        assert_eq!("мс 1a".parse::<Declension>().unwrap().to_ascii_string(), "pro 1a");
+    }
+
+    #[test]
+    fn adjective_prefixes() {
+        for (s, prefix, ascii) in [
+            ("п 1a", AdjectivePrefix::Adjective, "adj 1a"),
+            ("мс-п 3b", AdjectivePrefix::Pronominal, "pro-adj 3b"),
+            ("числ.-п 1*b", AdjectivePrefix::Numeral, "num-adj 1*b"),
+            (
+                "мс-п 1*a/c″①②, ё, -ся",
+                AdjectivePrefix::Pronominal,
+                "pro-adj 1*a/c''(1)(2), yo, -sya",
+            ),
+        ] {
+            let decl: Declension = s.parse().unwrap();
+            assert_eq!(decl.as_adjective().map(|x| x.prefix), Some(prefix), "{s}");
+            assert_eq!(decl.to_string(), s);
+            assert_eq!(decl.to_ascii_string(), ascii);
+            assert_eq!(Declension::parse_relaxed(ascii).unwrap().0, decl, "{ascii}");
+
+            // The typed declension omits only the "п" prefix
+            let adj: AdjectiveDeclension = s.parse().unwrap();
+            assert_eq!(Declension::Adjective(adj), decl);
+            assert_eq!(adj.to_string(), s.strip_prefix("п ").unwrap_or(s));
+            assert_eq!(adj.to_string().parse(), Ok(adj));
+            assert_eq!(decl.fmt_to_unprefixed(&mut [0; _]), s.split_once(' ').unwrap().1);
+        }
+
+        // The prefix is required to be followed by a space
+        for s in ["мс-п1a", "числ.-п", "числ. 1a", "мс-1a"] {
+            assert!(s.parse::<Declension>().is_err(), "{s}");
+        }
+        assert_eq!("1a".parse::<AdjectiveDeclension>().unwrap().prefix, AdjectivePrefix::Adjective);
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(DeclensionFlags::default(), DeclensionFlags::empty());
+
+        // Default flags and stresses are the unmarked ones in the notation
+        let noun = NounDeclension {
+            stem_type: NounStemType::Type1,
+            flags: Default::default(),
+            stress: Default::default(),
+        };
+        assert_eq!(noun.to_string(), "1a");
+
+        let adj = AdjectiveDeclension {
+            stem_type: AdjectiveStemType::Type1,
+            flags: Default::default(),
+            stress: Default::default(),
+            is_reflexive: false,
+            prefix: Default::default(),
+        };
+        assert_eq!(Declension::Adjective(adj).to_string(), "п 1a");
     }
 }
@@ -1,13 +1,20 @@
 use crate::{
     declension::{
-        AdjectiveDeclension, AnyStemType, Declension, DeclensionFlags, DeclensionKind,
-        NounDeclension, PronounDeclension,
+        AdjectiveDeclension, AdjectivePrefix, AnyStemType, DECLENSION_MAX_LEN, Declension,
+        DeclensionFlags, MaybeZeroDeclension, NounDeclension, PronounDeclension,
+        fmt::REFLEXIVE_MARKER,
     },
     letters,
     stress::{AnyDualStress, ParseStressError},
     util::{PartialParse, UnsafeParser, const_traits::*},
 };
 
+/// The maximum length of a declension accepted by the parsers, in bytes. Longer inputs are
+/// rejected with [`ParseDeclensionError::InputTooLong`] before being scanned, so that a huge
+/// user-submitted string can't tie up a worker.
+pub const DECLENSION_INPUT_MAX_LEN: usize = 64;
+const _: () = assert!(DECLENSION_MAX_LEN <= DECLENSION_INPUT_MAX_LEN);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseDeclensionError {
     InvalidStemType,
@@ -15,31 +22,104 @@ pub enum ParseDeclensionError {
     InvalidFlags,
     IncompatibleStemType,
     IncompatibleStress,
+    /// The declension kind doesn't support one of the flags: "п 1°a", "мс 1°a".
+    /// See [`AdjectiveDeclension::SUPPORTED_FLAGS`] and [`PronounDeclension::SUPPORTED_FLAGS`].
     IncompatibleFlags,
+    /// A flag was found before the stem type: "°3a", "*3a".
+    FlagBeforeStemType(char),
+    /// A leading flag (° or *) was found after the stress: "3a*", "3a①°".
+    LeadingFlagAfterStress(char),
+    /// A trailing flag (①-⑤) was found before the stress: "3①a".
+    TrailingFlagBeforeStress(char),
+    /// The input is longer than [`DECLENSION_INPUT_MAX_LEN`].
+    InputTooLong,
     Invalid,
 }
 
+/// A [`ParseDeclensionError`], along with the byte offset of the invalid part of the input.
+/// Returned by the `from_str_partial` methods of declensions, e.g.
+/// [`NounDeclension::from_str_partial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDeclensionErrorAt {
+    pub kind: ParseDeclensionError,
+    /// The byte offset of the invalid part: the start of the stress for stress errors, the flag
+    /// for flag errors, and the start of the declension (after the kind's prefix) for the
+    /// incompatible stem types, stresses and flags.
+    pub offset: usize,
+}
+
 type Error = ParseDeclensionError;
 
 const fn parse_declension_any(
     parser: &mut UnsafeParser,
 ) -> Result<(AnyStemType, DeclensionFlags, AnyDualStress), ParseDeclensionError> {
-    let stem_type = match parser.read_one() {
-        Some(ch @ b'1'..=b'8') => AnyStemType::from_ascii_digit(*ch).unwrap(),
-        _ => return Err(Error::InvalidStemType),
+    let stem_type = match parser.peek_one() {
+        Some(ch) => AnyStemType::from_ascii_digit(*ch),
+        None => None,
+    };
+    let Some(stem_type) = stem_type else {
+        // Check if the stem type was preceded by a flag ("°3a", "*3a")
+        return Err(match starting_flag(parser.remaining()) {
+            Some(flag) => Error::FlagBeforeStemType(flag),
+            None => Error::InvalidStemType,
+        });
     };
+    parser.forward(1);
 
     let mut flags = DeclensionFlags::empty();
 
     DeclensionFlags::partial_parse_leading(&mut flags, parser);
 
-    let stress = const_try!(AnyDualStress::partial_parse(parser), Error::InvalidStress);
+    let before_stress = *parser;
+    let stress = match AnyDualStress::partial_parse(parser) {
+        Ok(stress) => stress,
+        Err(err) => {
+            // Point to the start of the stress, and not where its parsing stopped
+            *parser = before_stress;
+
+            // Check if the stress was preceded by a trailing flag ("3①a")
+            return Err(match starting_flag(before_stress.remaining()) {
+                Some(flag @ ('①' | '②' | '③' | '④' | '⑤')) => {
+                    Error::TrailingFlagBeforeStress(flag)
+                },
+                _ => Error::InvalidStress(err),
+            });
+        },
+    };
 
     DeclensionFlags::partial_parse_trailing(&mut flags, parser)?;
 
+    // Check if the declension is followed by a leading flag ("3a*", "3a①°")
+    if !parser.finished()
+        && let Some(flag @ ('°' | '*')) = starting_flag(parser.remaining())
+    {
+        return Err(Error::LeadingFlagAfterStress(flag));
+    }
+
     Ok((stem_type, flags, stress))
 }
 
+const fn check_flags(
+    flags: DeclensionFlags,
+    supported: DeclensionFlags,
+) -> Result<DeclensionFlags, ParseDeclensionError> {
+    if supported.contains(flags) { Ok(flags) } else { Err(Error::IncompatibleFlags) }
+}
+
+// Returns the declension flag, that the string starts with
+const fn starting_flag(s: &[u8]) -> Option<char> {
+    Some(match s {
+        [0xC2, 0xB0, ..] => '°',
+        [b'*', ..] => '*',
+        [0xE2, 0x91, 0xA0, ..] | [b'(', b'1', b')', ..] => '①',
+        [0xE2, 0x91, 0xA1, ..] | [b'(', b'2', b')', ..] => '②',
+        [0xE2, 0x91, 0xA2, ..] | [b'(', b'3', b')', ..] => '③',
+        [0xE2, 0x91, 0xA3, ..] | [b'(', b'4', b')', ..] => '④',
+        [0xE2, 0x91, 0xA4, ..] | [b'(', b'5', b')', ..] => '⑤',
+        _ => return None,
+    })
+}
+
 impl const PartialParse for NounDeclension {
     fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, ParseDeclensionError> {
         let (stem_type, flags, stress) = parse_declension_any(parser)?;
@@ -58,78 +138,633 @@ impl const PartialParse for PronounDeclension {
         Ok(PronounDeclension {
             stem_type: const_try!(stem_type.try_into(), Error::IncompatibleStemType {}),
             stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
-            flags,
+            flags: const_try!(check_flags(flags, PronounDeclension::SUPPORTED_FLAGS)),
         })
     }
 }
 impl const PartialParse for AdjectiveDeclension {
     fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, ParseDeclensionError> {
-        let (stem_type, flags, stress) = parse_declension_any(parser)?;
-
-        Ok(AdjectiveDeclension {
-            stem_type: const_try!(stem_type.try_into(), Error::IncompatibleStemType {}),
-            stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
-            flags,
-        })
+        // The prefix is optional in the typed declension
+        let mut rest = *parser;
+        let mut prefix = AdjectivePrefix::Adjective;
+        if let Some(parsed) = parse_adjective_prefix(&mut rest)
+            && rest.skip(' ')
+        {
+            *parser = rest;
+            prefix = parsed;
+        }
+        parse_adjective(parser, prefix)
     }
 }
 impl const PartialParse for Declension {
     fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
-        let (kind, len) = match parser.peek_letters::<2>() {
-            Some([letters::м, letters::с]) => (DeclensionKind::Pronoun, 4),
-            Some([letters::п, _]) => (DeclensionKind::Adjective, 2),
-            _ => (DeclensionKind::Noun, 0),
+        // The prefixes are case-insensitive: "П 1a" and "МС 6a" are parsed too
+        let is_pronoun = match parser.peek_letters::<2>() {
+            Some(&[a, b])
+                if matches!(
+                    [a.to_lowercase(), b.to_lowercase()],
+                    [letters::м, letters::с] | [letters::п, _] | [letters::ч, letters::и]
+                ) =>
+            {
+                let prefix = parse_adjective_prefix(parser);
+                if prefix.is_none() && !parser.skip_str_ignore_case("мс") || !parser.skip(' ') {
+                    return Err(Error::Invalid);
+                }
+                if let Some(prefix) = prefix {
+                    return Ok(Declension::Adjective(const_try!(parse_adjective(parser, prefix))));
+                }
+                true
+            },
+            _ => false,
         };
-        if len > 0 {
-            parser.forward(len);
-            if !parser.skip(' ') {
-                return Err(Error::Invalid);
-            }
-        }
 
         let (stem_type, flags, stress) = parse_declension_any(parser)?;
 
-        Ok(match kind {
-            DeclensionKind::Noun => Declension::Noun(NounDeclension {
-                stem_type: stem_type.into(),
-                stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
-                flags,
-            }),
-            DeclensionKind::Pronoun => Declension::Pronoun(PronounDeclension {
+        Ok(if is_pronoun {
+            Declension::Pronoun(PronounDeclension {
                 stem_type: const_try!(stem_type.try_into(), Error::IncompatibleStemType {}),
                 stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
-                flags,
-            }),
-            DeclensionKind::Adjective => Declension::Adjective(AdjectiveDeclension {
-                stem_type: const_try!(stem_type.try_into(), Error::IncompatibleStemType {}),
+                flags: const_try!(check_flags(flags, PronounDeclension::SUPPORTED_FLAGS)),
+            })
+        } else {
+            Declension::Noun(NounDeclension {
+                stem_type: stem_type.into(),
                 stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
                 flags,
-            }),
+            })
         })
     }
 }
 
-impl std::str::FromStr for NounDeclension {
+// Parses one of the adjective prefixes: "п", "мс-п" or "числ.-п"
+const fn parse_adjective_prefix(parser: &mut UnsafeParser) -> Option<AdjectivePrefix> {
+    let mut i = 0;
+    while i < AdjectivePrefix::VALUES.len() {
+        let prefix = AdjectivePrefix::VALUES[i];
+        if parser.skip_str_ignore_case(prefix.abbr()) {
+            return Some(prefix);
+        }
+        i += 1;
+    }
+    None
+}
+const fn parse_adjective(
+    parser: &mut UnsafeParser,
+    prefix: AdjectivePrefix,
+) -> Result<AdjectiveDeclension, ParseDeclensionError> {
+    let (stem_type, flags, stress) = const_try!(parse_declension_any(parser));
+
+    Ok(AdjectiveDeclension {
+        stem_type: const_try!(stem_type.try_into(), Error::IncompatibleStemType {}),
+        stress: const_try!(stress.try_into(), Error::IncompatibleStress {}),
+        flags: const_try!(check_flags(flags, AdjectiveDeclension::SUPPORTED_FLAGS)),
+        is_reflexive: parser.skip_str(REFLEXIVE_MARKER),
+        prefix,
+    })
+}
+
+impl const PartialParse for MaybeZeroDeclension {
+    fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
+        // Indeclinable words are marked with a bare "0"
+        if parser.skip('0') {
+            return Ok(MaybeZeroDeclension::ZERO);
+        }
+        Ok(const_try!(Declension::partial_parse(parser)).into())
+    }
+}
+
+// Returns the offset of the error in the string, that the declension was partially parsed from
+const fn error_offset(s: &str, parser: &UnsafeParser, err: ParseDeclensionError) -> usize {
+    if matches!(
+        err,
+        Error::IncompatibleStemType | Error::IncompatibleStress | Error::IncompatibleFlags
+    ) {
+        // Point to the start of the declension, after the kind's prefix
+        let mut start = UnsafeParser::new(s);
+        if parse_adjective_prefix(&mut start).is_none() {
+            start.skip_str_ignore_case("мс");
+        }
+        start.skip(' ');
+        return s.len() - start.remaining_len();
+    }
+    s.len() - parser.remaining_len()
+}
+
+macro_rules! impl_from_str_partial {
+    ($($t:ty => $prefix:literal),+ $(,)?) => ($(
+        impl $t {
+            /// Parses the declension at the start of the string, and returns it along with the
+            /// number of bytes read. Unlike `FromStr`, the declension may be followed by anything,
+            /// e.g. the rest of a dictionary entry. Errors carry the offset of the invalid part.
+            pub const fn from_str_partial(s: &str) -> Result<(Self, usize), ParseDeclensionErrorAt> {
+                let mut parser = UnsafeParser::new(s);
+                if !$prefix.is_empty() {
+                    parser.skip_str_ignore_case($prefix);
+                }
+                match Self::partial_parse(&mut parser) {
+                    Ok(result) => Ok((result, s.len() - parser.remaining_len())),
+                    Err(kind) => {
+                        Err(ParseDeclensionErrorAt { kind, offset: error_offset(s, &parser, kind) })
+                    },
+                }
+            }
+        }
+    )+);
+}
+impl_from_str_partial! {
+    NounDeclension => "",
+    PronounDeclension => "мс ",
+    AdjectiveDeclension => "",
+    Declension => "",
+    MaybeZeroDeclension => "",
+}
+
+macro_rules! impl_parse_const {
+    ($($t:ty => $prefix:literal),+ $(,)?) => ($(
+        impl $t {
+            /// Parses the declension the same way as its `FromStr` implementation, but can be
+            /// used in const contexts, e.g. to build lookup tables at compile time.
+            pub const fn parse_const(s: &str) -> Result<Self, ParseDeclensionError> {
+                if s.len() > DECLENSION_INPUT_MAX_LEN {
+                    return Err(Error::InputTooLong);
+                }
+                let mut parser = UnsafeParser::new(s);
+                if !$prefix.is_empty() {
+                    parser.skip_str_ignore_case($prefix);
+                }
+                // FIXME(const-hack): Replace with `s.strip_prefix($prefix).unwrap_or(s)`.
+                let rest = unsafe { str::from_utf8_unchecked(parser.remaining()) };
+                Self::from_str_or(rest, Error::Invalid)
+            }
+        }
+    )+);
+}
+impl_parse_const! {
+    NounDeclension => "",
+    PronounDeclension => "мс ",
+    AdjectiveDeclension => "",
+    Declension => "",
+    MaybeZeroDeclension => "",
+}
+
+impl core::str::FromStr for NounDeclension {
     type Err = ParseDeclensionError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Error::Invalid)
+        Self::parse_const(s)
     }
 }
-impl std::str::FromStr for PronounDeclension {
+impl core::str::FromStr for PronounDeclension {
     type Err = ParseDeclensionError;
+
+    /// Parses a pronoun declension, with or without the "мс " prefix: "6*b" or "мс 6*b".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Error::Invalid)
+        Self::parse_const(s)
     }
 }
-impl std::str::FromStr for AdjectiveDeclension {
+impl core::str::FromStr for AdjectiveDeclension {
     type Err = ParseDeclensionError;
+
+    /// Parses an adjective declension, with or without one of the prefixes: "1a/c′", "п 1a/c′",
+    /// "мс-п 3b" or "числ.-п 1b".
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Error::Invalid)
+        Self::parse_const(s)
     }
 }
-impl std::str::FromStr for Declension {
+impl core::str::FromStr for Declension {
     type Err = ParseDeclensionError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Error::Invalid)
+        Self::parse_const(s)
+    }
+}
+impl core::str::FromStr for MaybeZeroDeclension {
+    type Err = ParseDeclensionError;
+
+    /// Parses a declension like [`Declension`]'s `FromStr`, or the indeclinable marker "0".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_const(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{format, string::ToString, vec::Vec};
+    use test::{Bencher, black_box};
+
+    #[test]
+    fn parse_const() {
+        use crate::{
+            declension::{
+                AdjectiveStemType, NounStemType, ParseDeclensionFlagsError, PronounStemType,
+            },
+            stress::{AdjectiveFullStress, AdjectiveShortStress, NounStress, PronounStress},
+        };
+
+        const NOUN: Result<NounDeclension, Error> = NounDeclension::parse_const("1*b′");
+        const PRONOUN: Result<PronounDeclension, Error> = PronounDeclension::parse_const("мс 6*b");
+        const ADJECTIVE: Result<AdjectiveDeclension, Error> =
+            AdjectiveDeclension::parse_const("п 4a/c″, -ся");
+        const ANY: Result<Declension, Error> = Declension::parse_const("мс 2*f");
+        const ZERO: Result<MaybeZeroDeclension, Error> = MaybeZeroDeclension::parse_const("0");
+        const FLAGS: Result<DeclensionFlags, ParseDeclensionFlagsError> =
+            DeclensionFlags::parse_const("°*(1)②, ё");
+
+        const _: () = assert!(matches!(
+            NOUN,
+            Ok(NounDeclension { stem_type: NounStemType::Type1, flags, stress: NounStress::Bp })
+                if flags.bits() == DeclensionFlags::STAR.bits()
+        ));
+        const _: () = assert!(matches!(
+            PRONOUN,
+            Ok(PronounDeclension {
+                stem_type: PronounStemType::Type6,
+                stress: PronounStress::B,
+                ..
+            })
+        ));
+        const _: () = assert!(matches!(
+            ADJECTIVE,
+            Ok(AdjectiveDeclension {
+                stem_type: AdjectiveStemType::Type4,
+                stress,
+                is_reflexive: true,
+                ..
+            }) if matches!(stress.full, AdjectiveFullStress::A)
+                && matches!(stress.short, AdjectiveShortStress::Cpp)
+        ));
+        const _: () = assert!(matches!(
+            ANY,
+            Ok(Declension::Pronoun(PronounDeclension { stress: PronounStress::F, .. }))
+        ));
+        const _: () = assert!(matches!(ZERO, Ok(decl) if decl.is_zero()));
+        const _: () = assert!(matches!(FLAGS, Ok(flags) if flags.bits() == DeclensionFlags::CIRCLE
+            .union(DeclensionFlags::STAR)
+            .union(DeclensionFlags::CIRCLED_ONE)
+            .union(DeclensionFlags::CIRCLED_TWO)
+            .union(DeclensionFlags::ALTERNATING_YO)
+            .bits()));
+
+        // Errors are the same as from FromStr
+        const _: () = assert!(NounDeclension::parse_const("мс 1a").is_err());
+        const _: () = assert!(matches!(
+            DeclensionFlags::parse_const("②①"),
+            Err(ParseDeclensionFlagsError::OutOfOrder('①'))
+        ));
+        for s in ["1a", "1*b′", "мс 6*b", "п 4a/c″, -ся", "п 1a", "0", "9a", "", "1a "] {
+            assert_eq!(Declension::parse_const(s), s.parse(), "{s}");
+            assert_eq!(MaybeZeroDeclension::parse_const(s), s.parse(), "{s}");
+            assert_eq!(AdjectiveDeclension::parse_const(s), s.parse(), "{s}");
+        }
+        for s in ["", "°", "*①", "①*", "(1)(1)", ", ё", "x"] {
+            assert_eq!(DeclensionFlags::parse_const(s), s.parse(), "{s}");
+        }
+    }
+
+    #[test]
+    fn misplaced_flags() {
+        let assert = |s: &str, expected: Error| {
+            assert_eq!(s.parse::<Declension>(), Err(expected), "{s}");
+        };
+
+        assert("°3a", Error::FlagBeforeStemType('°'));
+        assert("*3a", Error::FlagBeforeStemType('*'));
+        assert("①3a", Error::FlagBeforeStemType('①'));
+        assert("мс *1a", Error::FlagBeforeStemType('*'));
+        assert("3a*", Error::LeadingFlagAfterStress('*'));
+        assert("8a°", Error::LeadingFlagAfterStress('°'));
+        assert("3a①*", Error::LeadingFlagAfterStress('*'));
+        assert("п 1a/c′*", Error::LeadingFlagAfterStress('*'));
+        assert("3①a", Error::TrailingFlagBeforeStress('①'));
+        assert("3*②a", Error::TrailingFlagBeforeStress('②'));
+        assert("3(3)a", Error::TrailingFlagBeforeStress('③'));
+
+        // Other errors are unaffected
+        assert("9a", Error::InvalidStemType);
+        assert("", Error::InvalidStemType);
+        assert("3z", Error::InvalidStress(ParseStressError::InvalidLetter));
+        assert("3a!", Error::Invalid);
+
+        // The canonical order still parses
+        for s in ["8°a", "3*a", "3a①", "3*a①", "8°*a①②③, ё", "п 1*a/c′①"] {
+            assert!(s.parse::<Declension>().is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    fn input_too_long() {
+        let huge = "1".repeat(10 << 20);
+        assert_eq!(huge.parse::<Declension>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<NounDeclension>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<PronounDeclension>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<AdjectiveDeclension>(), Err(Error::InputTooLong));
+        assert_eq!(Declension::parse_relaxed(&huge), Err(Error::InputTooLong));
+        let huge_slashes = format!("4a{}c", " / ".repeat(1 << 20));
+        assert_eq!(Declension::parse_relaxed(&huge_slashes), Err(Error::InputTooLong));
+
+        // Inputs up to the limit are scanned as usual
+        let padded = format!("1a{}", "*".repeat(DECLENSION_INPUT_MAX_LEN - 2));
+        assert_eq!(padded.parse::<Declension>(), Err(Error::LeadingFlagAfterStress('*')));
+        let padded = format!("1a{}", "*".repeat(DECLENSION_INPUT_MAX_LEN - 1));
+        assert_eq!(padded.parse::<Declension>(), Err(Error::InputTooLong));
+        assert_eq!("п 7*a/c″①②③, ё".parse::<Declension>().map(|_| ()), Ok(()));
+    }
+
+    #[test]
+    fn adversarial() {
+        let assert = |s: &str, expected: Error| {
+            assert_eq!(s.parse::<Declension>(), Err(expected), "{s}");
+        };
+
+        // Repeated and partial trailing flags end the loop right away
+        assert("3a①①", Error::InvalidFlags);
+        assert("3a(1)(1)", Error::InvalidFlags);
+        assert("3a①②③①", Error::InvalidFlags);
+        assert("3a(1(1(1", Error::Invalid);
+        assert("3a((((((((", Error::Invalid);
+        assert("3a, ё, ё", Error::Invalid);
+        // Repeated prefixes and leading flags
+        assert("мс мс 1a", Error::InvalidStemType);
+        assert("°°°3a", Error::FlagBeforeStemType('°'));
+        assert("3°°a", Error::InvalidStress(ParseStressError::InvalidLetter));
+        assert("3**a", Error::InvalidStress(ParseStressError::InvalidLetter));
+        assert("°", Error::FlagBeforeStemType('°'));
+        assert("", Error::InvalidStemType);
+    }
+
+    #[test]
+    fn circle_flag() {
+        // The circle flag is supported only by nouns
+        assert!("1°a".parse::<Declension>().unwrap().flags().has_circle());
+        assert!("3°*a".parse::<NounDeclension>().unwrap().flags.has_circle());
+
+        for s in ["п 1°a", "п 7°*a/c″①②③, ё", "мс 1°a", "мс 6°*b"] {
+            assert_eq!(s.parse::<Declension>(), Err(Error::IncompatibleFlags), "{s}");
+        }
+        assert_eq!("1°a".parse::<AdjectiveDeclension>(), Err(Error::IncompatibleFlags));
+        assert_eq!("1°a".parse::<PronounDeclension>(), Err(Error::IncompatibleFlags));
+
+        // All the other flags are supported by all declension kinds
+        assert!("4*a①②, ё".parse::<AdjectiveDeclension>().is_ok());
+        assert!("6*b①, ё".parse::<PronounDeclension>().is_ok());
+        assert_eq!(AdjectiveDeclension::SUPPORTED_FLAGS, PronounDeclension::SUPPORTED_FLAGS);
+        assert!(!AdjectiveDeclension::SUPPORTED_FLAGS.has_circle());
+    }
+
+    #[test]
+    fn circled_four_and_five() {
+        let decl: NounDeclension = "1a④".parse().unwrap();
+        assert!(decl.flags.has_circled_four() && !decl.flags.has_circled_five());
+        let decl: NounDeclension = "3*b②⑤, ё".parse().unwrap();
+        assert_eq!(decl.flags, "3*b(2)(5), ё".parse::<NounDeclension>().unwrap().flags);
+        assert!(decl.flags.has_circled_five() && decl.flags.has_alternating_yo());
+
+        // The flags are formatted in order, after the first three
+        for s in ["1a④", "1a⑤", "1a①④⑤", "8°*f″①②③④⑤, ё", "п 1*a/c′④"]
+        {
+            assert_eq!(s.parse::<Declension>().unwrap().to_string(), s);
+        }
+        assert_eq!("1a(4)(5)".parse::<Declension>().unwrap().to_string(), "1a④⑤");
+
+        // Repeated and misplaced flags
+        assert_eq!("1a④④".parse::<Declension>(), Err(Error::InvalidFlags));
+        assert_eq!("1a⑤(5)".parse::<Declension>(), Err(Error::InvalidFlags));
+        assert_eq!("3④a".parse::<Declension>(), Err(Error::TrailingFlagBeforeStress('④')));
+        assert_eq!("3(5)a".parse::<Declension>(), Err(Error::TrailingFlagBeforeStress('⑤')));
+        assert_eq!("⑤3a".parse::<Declension>(), Err(Error::FlagBeforeStemType('⑤')));
+    }
+
+    #[test]
+    fn reflexive() {
+        let decl: AdjectiveDeclension = "4a, -ся".parse().unwrap();
+        assert!(decl.is_reflexive);
+        assert!(!"4a".parse::<AdjectiveDeclension>().unwrap().is_reflexive);
+        let decl: Declension = "п 1*a/c′①, ё, -ся".parse().unwrap();
+        assert!(matches!(decl, Declension::Adjective(x) if x.is_reflexive && x.flags.has_star()));
+
+        // Only adjective declensions can be reflexive, and the marker must come last
+        assert_eq!("1a, -ся".parse::<Declension>(), Err(Error::Invalid));
+        assert_eq!("мс 1a, -ся".parse::<Declension>(), Err(Error::Invalid));
+        assert_eq!("1a, -ся".parse::<NounDeclension>(), Err(Error::Invalid));
+        assert_eq!("п 4a, -ся, ё".parse::<Declension>(), Err(Error::Invalid));
+        assert_eq!("п 4a, -ся①".parse::<Declension>(), Err(Error::Invalid));
+        assert_eq!("п 4a -ся".parse::<Declension>(), Err(Error::Invalid));
+    }
+
+    #[test]
+    fn prefixes() {
+        for s in ["1a", "3*b", "7°*f″①②③④⑤, ё"] {
+            let decl: NounDeclension = s.parse().unwrap();
+            assert_eq!(decl.to_string(), s);
+            assert_eq!(decl.to_string().parse(), Ok(decl));
+            assert_eq!(Declension::Noun(decl).to_string(), s);
+            assert_eq!(Declension::Noun(decl).fmt_to_unprefixed(&mut [0; _]), s);
+        }
+        for s in ["1a", "6*b", "4a①②, ё"] {
+            let decl: PronounDeclension = s.parse().unwrap();
+            assert_eq!(decl.to_string(), s);
+            assert_eq!(decl.to_string().parse(), Ok(decl));
+            assert_eq!(format!("мс {s}").parse(), Ok(decl));
+            assert_eq!(Declension::Pronoun(decl).to_string(), format!("мс {s}"));
+            assert_eq!(Declension::Pronoun(decl).fmt_to_unprefixed(&mut [0; _]), s);
+            assert_eq!(Declension::Pronoun(decl).to_string().parse(), Ok(decl));
+        }
+        for s in ["1a", "1*a/c′", "4a, -ся", "7*a/c″①②③④⑤, ё, -ся"] {
+            let decl: AdjectiveDeclension = s.parse().unwrap();
+            assert_eq!(decl.to_string(), s);
+            assert_eq!(decl.to_string().parse(), Ok(decl));
+            assert_eq!(format!("п {s}").parse(), Ok(decl));
+            assert_eq!(Declension::Adjective(decl).to_string(), format!("п {s}"));
+            assert_eq!(Declension::Adjective(decl).fmt_to_unprefixed(&mut [0; _]), s);
+            assert_eq!(Declension::Adjective(decl).to_string().parse(), Ok(decl));
+        }
+
+        // Only the declension's own prefix is accepted, and only once
+        assert_eq!("мс 1a".parse::<NounDeclension>(), Err(Error::InvalidStemType));
+        assert_eq!("п 1a".parse::<PronounDeclension>(), Err(Error::InvalidStemType));
+        assert_eq!("мс 1a".parse::<AdjectiveDeclension>(), Err(Error::InvalidStemType));
+        assert_eq!("п п 1a".parse::<AdjectiveDeclension>(), Err(Error::InvalidStemType));
+        assert_eq!("мс6*b".parse::<PronounDeclension>(), Err(Error::InvalidStemType));
+    }
+
+    #[test]
+    fn uppercase() {
+        // The prefixes and the stress letters are parsed in either case
+        let pairs = [
+            ("П 1a", "п 1a"),
+            ("п 1A/C′", "п 1a/c′"),
+            ("МС 6*b", "мс 6*b"),
+            ("Мс 6*b", "мс 6*b"),
+            ("мС 4a", "мс 4a"),
+            ("МС-П 1a", "мс-п 1a"),
+            ("Числ.-П 1b", "числ.-п 1b"),
+            ("3*B", "3*b"),
+            ("П 4A, -ся", "п 4a, -ся"),
+        ];
+        for (upper, lower) in pairs {
+            let decl: Declension = upper.parse().unwrap();
+            assert_eq!(Ok(decl), lower.parse(), "{upper}");
+            // Formatting still emits the canonical lowercase notation
+            assert_eq!(decl.to_string(), lower);
+        }
+
+        assert_eq!("МС 6*b".parse::<PronounDeclension>(), "мс 6*b".parse());
+        assert_eq!("П 1a".parse::<AdjectiveDeclension>(), "п 1a".parse());
+        assert_eq!("3*B".parse::<MaybeZeroDeclension>(), "3*b".parse());
+        assert_eq!(PronounDeclension::from_str_partial("МС 6*b"), Ok(("6*b".parse().unwrap(), 8)));
+        assert_eq!(PronounDeclension::parse_const("Мс 6*b"), "6*b".parse());
+
+        // Only the letters' case is ignored
+        assert_eq!("МЗ 1a".parse::<Declension>(), Err(Error::InvalidStemType));
+        assert_eq!("P 1a".parse::<Declension>(), Err(Error::InvalidStemType));
+    }
+
+    #[test]
+    fn ordering() {
+        #[track_caller]
+        fn assert_sorted<T: core::str::FromStr<Err: core::fmt::Debug> + Ord>(values: &[&str]) {
+            let parsed: Vec<T> = values.iter().map(|x| x.parse().unwrap()).collect();
+            assert!(parsed.is_sorted_by(|a, b| a < b), "{values:?}");
+        }
+
+        // Flags with an earlier flag in the canonical order (°*①②③④⑤, ё) are greater
+        let flags = ["", ", ё", "⑤", "④", "③", "②", "②③", "①", "①②③④⑤, ё", "*", "*①", "°", "°*"];
+        assert_sorted::<DeclensionFlags>(&flags);
+
+        // Declensions are ordered by stem type, then by stress, and then by flags
+        assert_sorted::<NounDeclension>(&["1a", "1a①", "1*a", "1°a", "1b", "1f″", "2a", "8°*f″"]);
+        assert_sorted::<PronounDeclension>(&["1a", "1*a", "1b", "2a", "6*b", "6f"]);
+        assert_sorted::<AdjectiveDeclension>(&["1a", "1*a", "1a/c", "1b", "4a", "4a, -ся", "4b"]);
+
+        // and by kind first
+        assert_sorted::<Declension>(&["8°*f″", "мс 1a", "мс 6*b", "п 1a", "п 7*b"]);
+        let zero = crate::declension::MaybeZeroDeclension::ZERO;
+        assert!(zero < Some("1a".parse::<Declension>().unwrap()).into());
+    }
+
+    #[test]
+    fn from_str_partial() {
+        let noun = |s: &str| s.parse::<NounDeclension>().unwrap();
+        fn at<T>(kind: Error, offset: usize) -> Result<(T, usize), ParseDeclensionErrorAt> {
+            Err(ParseDeclensionErrorAt { kind, offset })
+        }
+
+        // The declension may be followed by anything
+        let s = "7*b′①xyz";
+        assert_eq!(NounDeclension::from_str_partial(s), Ok((noun("7*b′①"), "7*b′①".len())));
+        assert_eq!(s.parse::<NounDeclension>(), Err(Error::Invalid));
+        assert_eq!(NounDeclension::from_str_partial("1a Р2"), Ok((noun("1a"), 2)));
+        let (decl, len) = Declension::from_str_partial("п 1a/c′, -ся рест").unwrap();
+        assert_eq!((decl, len), ("п 1a/c′, -ся".parse().unwrap(), "п 1a/c′, -ся".len()));
+        let (decl, len) = PronounDeclension::from_str_partial("мс 6*b, ...").unwrap();
+        assert_eq!((decl, len), ("6*b".parse().unwrap(), "мс 6*b".len()));
+        let (decl, len) = AdjectiveDeclension::from_str_partial("4a ...").unwrap();
+        assert_eq!((decl, len), ("4a".parse().unwrap(), 2));
+        let zero = MaybeZeroDeclension::from_str_partial("0 Р2");
+        assert_eq!(zero, Ok((MaybeZeroDeclension::ZERO, 1)));
+
+        // Errors point to the invalid part
+        assert_eq!(NounDeclension::from_str_partial("9a"), at(Error::InvalidStemType, 0));
+        assert_eq!(NounDeclension::from_str_partial("°3a"), at(Error::FlagBeforeStemType('°'), 0));
+        let err = Error::InvalidStress(ParseStressError::InvalidLetter);
+        assert_eq!(NounDeclension::from_str_partial("3*z"), at(err, 2));
+        let err = Error::InvalidStress(ParseStressError::InvalidPrime);
+        assert_eq!(Declension::from_str_partial("п 3a/b″"), at(err, 4));
+        let err = Error::TrailingFlagBeforeStress('①');
+        assert_eq!(NounDeclension::from_str_partial("3①a"), at(err, 1));
+        assert_eq!(NounDeclension::from_str_partial("3a①①"), at(Error::InvalidFlags, 5));
+        let err = Error::LeadingFlagAfterStress('*');
+        assert_eq!(NounDeclension::from_str_partial("3b′*"), at(err, 5));
+        assert_eq!(Declension::from_str_partial("п1a"), at(Error::Invalid, 2));
+        assert_eq!(Declension::from_str_partial("мс-п1a"), at(Error::Invalid, 7));
+
+        // and to the start of the declension, if its parts are incompatible with its kind
+        assert_eq!(Declension::from_str_partial("мс 8a"), at(Error::IncompatibleStemType, 5));
+        assert_eq!(PronounDeclension::from_str_partial("1c"), at(Error::IncompatibleStress, 0));
+        assert_eq!(NounDeclension::from_str_partial("1a/c"), at(Error::IncompatibleStress, 0));
+        assert_eq!(Declension::from_str_partial("п 1°a"), at(Error::IncompatibleFlags, 3));
+        let err = at(Error::IncompatibleStemType, "числ.-п ".len());
+        assert_eq!(Declension::from_str_partial("числ.-п 8a"), err);
+
+        // FromStr succeeds only if the whole string was read
+        for s in ["1a", "3*b①", "1a ", "3a!", "8°*f″①②③④⑤, ё", "1a, ё, ё"] {
+            let full = NounDeclension::from_str_partial(s).is_ok_and(|(_, len)| len == s.len());
+            assert_eq!(full, s.parse::<NounDeclension>().is_ok(), "{s}");
+        }
+    }
+
+    #[test]
+    fn zero() {
+        let zero: MaybeZeroDeclension = "0".parse().unwrap();
+        assert_eq!(zero, MaybeZeroDeclension::ZERO);
+        assert_eq!(zero.to_string(), "0");
+
+        for s in ["0", "1a", "3*b", "8°*f″①②③④⑤, ё", "мс 6*b", "п 1*a/c′④, -ся"]
+        {
+            let decl: MaybeZeroDeclension = s.parse().unwrap();
+            assert_eq!(decl.is_zero(), s == "0");
+            assert_eq!(decl.to_string(), s);
+            assert_eq!(decl.as_option().map(|x| x.to_string()).as_deref(), (s != "0").then_some(s));
+        }
+
+        // Only a bare "0" is the indeclinable marker
+        assert_eq!("0a".parse::<MaybeZeroDeclension>(), Err(Error::Invalid));
+        assert_eq!("00".parse::<MaybeZeroDeclension>(), Err(Error::Invalid));
+        assert_eq!("0*".parse::<MaybeZeroDeclension>(), Err(Error::Invalid));
+        assert_eq!("п 0".parse::<MaybeZeroDeclension>(), Err(Error::InvalidStemType));
+        assert_eq!("".parse::<MaybeZeroDeclension>(), Err(Error::InvalidStemType));
+        // and the typed declensions can't be zero
+        assert_eq!("0".parse::<Declension>(), Err(Error::InvalidStemType));
+        assert_eq!("0".parse::<NounDeclension>(), Err(Error::InvalidStemType));
+    }
+
+    #[test]
+    fn flags() {
+        for s in ["", "°", "*", "°*", "①", "*②③", ", ё", "°*①②③④⑤, ё"] {
+            let flags: DeclensionFlags = s.parse().unwrap();
+            assert_eq!(flags.to_string(), s);
+        }
+        assert_eq!("*(1)".parse(), Ok(DeclensionFlags::STAR | DeclensionFlags::CIRCLED_ONE));
+
+        // All the combinations of flags round-trip, with and without the ASCII fallbacks
+        for bits in 0..=u8::MAX {
+            let flags = DeclensionFlags::from_bits(bits).unwrap();
+            assert_eq!(flags.to_string().parse(), Ok(flags), "{flags}");
+
+            let ascii = ['①', '②', '③', '④', '⑤']
+                .iter()
+                .zip(1..)
+                .fold(flags.to_string(), |s, (ch, digit)| s.replace(*ch, &format!("({digit})")));
+            assert_eq!(ascii.parse(), Ok(flags), "{ascii}");
+        }
+
+        // Repeated flags, flags out of order, and anything else
+        use crate::declension::ParseDeclensionFlagsError as E;
+        let assert = |s: &str, expected: E| {
+            assert_eq!(s.parse::<DeclensionFlags>(), Err(expected), "{s:?}");
+        };
+
+        assert("**", E::Duplicate('*'));
+        assert("①①", E::Duplicate('①'));
+        assert("①(1)", E::Duplicate('①'));
+        assert("*, ё, ё", E::Duplicate('ё'));
+        assert("*°", E::OutOfOrder('°'));
+        assert("①*", E::OutOfOrder('*'));
+        assert("②①", E::OutOfOrder('①'));
+        assert("(5)(4)", E::OutOfOrder('④'));
+        assert(", ё①", E::OutOfOrder('①'));
+        for s in ["ё", "1a", " ", "(6)", ",ё", "①, е"] {
+            assert(s, E::Invalid);
+        }
+        assert(&"*".repeat(100), E::InputTooLong);
+    }
+
+    #[bench]
+    fn bench_parse_declension(b: &mut Bencher) {
+        let inputs = ["1a", "6*b", "3*d, ё", "мс 6*b", "п 1*a/c″①②, ё", "8°*f″①②③④⑤, ё"];
+        b.iter(|| {
+            for s in black_box(inputs) {
+                black_box(s.parse::<Declension>().unwrap());
+            }
+        });
     }
 }
@@ -1,49 +1,825 @@
 use crate::{
-    InflectionBuffer,
-    declension::{AdjectiveDeclension, DeclInfo, Declension},
+    InflectionBuffer, StemError,
+    categories::{Animacy, Case, CaseAndNumber, CaseEx, Gender, GenderOrPlural, Number},
+    declension::{
+        AdjectiveDeclension, AdjectiveStemType, DeclInfo, Declension, InflectError,
+        fleeting::insert_fleeting_vowel, push_stressed, split_stress_mark,
+    },
+    letters,
 };
-use std::fmt::Display;
+use alloc::{borrow::ToOwned, string::String, vec, vec::Vec};
+use core::fmt::Display;
+use thiserror::Error;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Adjective<'a> {
     pub stem: &'a str,
     pub info: AdjectiveInfo,
     // exceptions: &'a [(CaseAndNumber, &'a str)],
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AdjectiveInfo {
     pub declension: Option<Declension>,
-    pub is_reflexive: bool,
+    pub short_forms: ShortFormAvailability,
 }
 
+impl AdjectiveInfo {
+    /// Returns `true`, if the adjective is declined by a reflexive adjective declension, and ends
+    /// in '-ся' in all of its forms (трудящийся, учащийся).
+    pub const fn is_reflexive(&self) -> bool {
+        matches!(self.declension, Some(Declension::Adjective(decl)) if decl.is_reflexive)
+    }
+}
+
+/// Availability of an adjective's short forms.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ShortFormAvailability {
+    /// All short forms are available.
+    #[default]
+    Available,
+    /// The short forms are difficult to form, or are rarely used (marked with ⌧).
+    Difficult,
+    /// The masculine short form isn't used (marked with —).
+    NoMasculine,
+    /// The adjective doesn't have short forms (marked with ✕), e.g. осенний.
+    Unavailable,
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ComparativeError {
+    /// The adjective isn't declined by adjective declension (pronominal adjectives, indeclinable
+    /// adjectives), and doesn't have a comparative.
+    #[error("only adjectives declined by adjective declension have comparatives")]
+    IncompatibleDeclension,
+    /// The adjective doesn't have a synthetic comparative: relative adjectives without short
+    /// forms (осенний, деревянный), reflexive adjectives, and stem types 6 and 7.
+    #[error("the adjective doesn't have a synthetic comparative")]
+    Unavailable,
+    /// The stem contains a character that isn't a Cyrillic letter.
+    #[error(transparent)]
+    InvalidStem(#[from] StemError),
+}
+
+// Comparatives that aren't formed by the regular rules: suppletive ones, the ones in -ше, the ones
+// that drop the suffix -к-/-ок- before the consonant mutation, and the ones where the soft sign
+// before it is added or dropped (мелкий - мельче, горький - горче).
+const IRREGULAR_COMPARATIVES: [(&str, &str); 36] = [
+    ("хорош", "лучше"),
+    ("плох", "хуже"),
+    ("больш", "больше"),
+    ("велик", "больше"),
+    ("мал", "меньше"),
+    ("маленьк", "меньше"),
+    ("стар", "старше"),
+    ("ранн", "раньше"),
+    ("поздн", "позже"),
+    ("тонк", "тоньше"),
+    ("мелк", "мельче"),
+    ("горьк", "горче"),
+    ("долг", "дольше"),
+    ("далёк", "дальше"),
+    ("глубок", "глубже"),
+    ("широк", "шире"),
+    ("высок", "выше"),
+    ("низк", "ниже"),
+    ("узк", "уже"),
+    ("близк", "ближе"),
+    ("коротк", "короче"),
+    ("редк", "реже"),
+    ("сладк", "слаще"),
+    ("гладк", "глаже"),
+    ("жидк", "жиже"),
+    ("лёгк", "легче"),
+    ("дешёв", "дешевле"),
+    // Stems in -д, -т and -ст mostly take -ее (седее, сытее, пустее), except for these
+    ("молод", "моложе"),
+    ("твёрд", "твёрже"),
+    ("богат", "богаче"),
+    ("крут", "круче"),
+    ("прост", "проще"),
+    ("толст", "толще"),
+    ("част", "чаще"),
+    ("чист", "чище"),
+    ("густ", "гуще"),
+];
+
 impl<'a> Adjective<'a> {
-    pub fn inflect(&self, info: DeclInfo, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// Writes the full form of the adjective in the specified case, gender, number and animacy
+    /// into the formatter. If the adjective can't be inflected, returns [`core::fmt::Error`], so
+    /// use one of the other methods to handle the [`InflectError`].
+    pub fn inflect(
+        &self,
+        case: CaseEx,
+        gender: Gender,
+        number: Number,
+        animacy: Animacy,
+        f: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        let mut buf = InflectionBuffer::default();
+        match self.inflect_to(case, gender, number, animacy, &mut buf) {
+            Ok(form) => form.fmt(f),
+            Err(_) => Err(core::fmt::Error),
+        }
+    }
+    /// Returns the full form of the adjective in the specified case, gender, number and animacy.
+    /// The gender is ignored in the plural, and the animacy is only used in the accusative.
+    /// Indeclinable adjectives (without a declension) are returned as is.
+    ///
+    /// Returns an error, if the adjective is declined by noun declension.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = AdjectiveInfo {
+    ///     declension: Some("п 1a".parse().unwrap()),
+    ///     short_forms: ShortFormAvailability::Available,
+    /// };
+    /// let adj = Adjective { stem: "нов", info };
+    ///
+    /// let form = adj.inflect_to_string(CaseEx::Dative, Gender::Feminine, Number::Singular, Animacy::Inanimate);
+    /// assert_eq!(form.unwrap(), "новой");
+    /// let form = adj.inflect_to_string(CaseEx::Accusative, Gender::Masculine, Number::Plural, Animacy::Animate);
+    /// assert_eq!(form.unwrap(), "новых");
+    /// ```
+    pub fn inflect_to_string(
+        &self,
+        case: CaseEx,
+        gender: Gender,
+        number: Number,
+        animacy: Animacy,
+    ) -> Result<String, InflectError> {
+        let mut buf = InflectionBuffer::default();
+        Ok(self.inflect_to(case, gender, number, animacy, &mut buf)?.to_owned())
+    }
+    /// Returns the full form of the adjective, as a [`Display`] value, that inflects the adjective
+    /// when it's formatted. Formatting it returns [`core::fmt::Error`], if the adjective can't be
+    /// inflected.
+    pub fn inflect_fmt(
+        &self,
+        case: CaseEx,
+        gender: Gender,
+        number: Number,
+        animacy: Animacy,
+    ) -> impl Display + '_ {
+        core::fmt::from_fn(move |f| self.inflect(case, gender, number, animacy, f))
+    }
+    /// Inflects the adjective into the buffer, and returns the full form as a string slice.
+    pub fn inflect_to<'b>(
+        &self,
+        case: CaseEx,
+        gender: Gender,
+        number: Number,
+        animacy: Animacy,
+        buf: &'b mut InflectionBuffer,
+    ) -> Result<&'b str, InflectError> {
         // TODO: check exceptions
 
+        // The full forms are returned without the stem's stress mark
+        buf.reset_to_stem(&split_stress_mark(self.stem).0)?;
+
         if let Some(decl) = self.info.declension {
-            let mut buf = InflectionBuffer::from_stem_unchecked(self.stem);
-
-            match decl {
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Pronoun(decl) => decl.inflect(info, &mut buf),
-                Declension::Noun(_) => {
-                    unimplemented!("Adjectives don't decline by noun declension")
-                },
-            };
-
-            if self.info.is_reflexive {
-                buf.append_to_ending("ся");
+            let (case, number) = case.normalize_with(number);
+            let info = DeclInfo { case, number, gender, animacy };
+
+            let capitalization = buf.to_lowercase();
+            if decl.is_noun() {
+                return Err(InflectError::IncompatibleDeclension);
+            }
+            decl.inflect(info, buf)?;
+            buf.apply_capitalization(capitalization);
+        }
+        Ok(buf.as_str())
+    }
+
+    /// Returns the adjective's full forms in the specified gender and animacy, in the 6 main
+    /// cases, in the order of [`CaseAndNumber::VALUES`]: all singular forms, and then all plural
+    /// forms. The animacy only affects the accusative.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = AdjectiveInfo {
+    ///     declension: Some("п 3b".parse().unwrap()),
+    ///     short_forms: ShortFormAvailability::Available,
+    /// };
+    /// let forms = Adjective { stem: "дорог", info }.forms(Gender::Feminine, Animacy::Inanimate);
+    /// assert_eq!(forms.unwrap()[..4], ["дорогая", "дорогой", "дорогой", "дорогую"]);
+    /// ```
+    pub fn forms(&self, gender: Gender, animacy: Animacy) -> Result<[String; 12], InflectError> {
+        let mut buf = InflectionBuffer::default();
+        let mut forms = [const { String::new() }; 12];
+
+        for (form, key) in forms.iter_mut().zip(CaseAndNumber::VALUES) {
+            let (case, number) = key.parts();
+            *form = self.inflect_to(case.into(), gender, number, animacy, &mut buf)?.to_owned();
+        }
+        Ok(forms)
+    }
+}
+
+impl<'a> Adjective<'a> {
+    /// Returns the short forms of the adjective in the specified gender and number. Usually
+    /// there's only one form, but adjectives in '-енный'/'-енний' have two masculine short forms:
+    /// '-енен' and '-ен' (искренен and искрен, своевременен and своевремен).
+    ///
+    /// Returns an empty list, if the adjective doesn't have such a short form. Reflexive adjectives
+    /// never have short forms. Returns an error, if the fleeting vowel can't be inserted into the
+    /// masculine form (a stem without a consonant cluster at the end).
+    pub fn short_forms(&self, gender: Gender, number: Number) -> Result<Vec<String>, InflectError> {
+        let is_masculine = number == Number::Singular && gender == Gender::Masculine;
+
+        let Some(Declension::Adjective(decl)) = self.info.declension else { return Ok(vec![]) };
+        if self.info.is_reflexive()
+            || match self.info.short_forms {
+                ShortFormAvailability::Available | ShortFormAvailability::Difficult => false,
+                ShortFormAvailability::NoMasculine => is_masculine,
+                ShortFormAvailability::Unavailable => true,
             }
+        {
+            return Ok(vec![]);
+        }
+
+        // Inflect the lowercase stem, and restore the capitalization, like in the full forms
+        let mut buf = self.stem_buf()?;
+        let capitalization = buf.to_lowercase();
+        decl.inflect_short(gender, number, &mut buf)?;
+        buf.apply_capitalization(capitalization);
+        let mut forms = vec![to_stressed_string(&buf)];
+
+        // -енен/-ен variants of adjectives in -енный/-енний
+        if is_masculine && decl.flags.has_star() {
+            let mut buf = self.stem_buf()?;
+            let capitalization = buf.to_lowercase();
+            if let [.., letters::е, letters::н, letters::н] = buf.stem() {
+                buf.shrink_stem_by(2);
+                buf.apply_capitalization(capitalization);
+                forms.push(to_stressed_string(&buf));
+            }
+        }
 
-            buf.as_str().fmt(f)
+        Ok(forms)
+    }
+
+    // Returns a buffer with the stem, and the stress position of its stress mark, if it has one
+    fn stem_buf(&self) -> Result<InflectionBuffer, StemError> {
+        let (stem, stress) = split_stress_mark(self.stem);
+        let mut buf = InflectionBuffer::from_stem(&stem)?;
+        buf.set_stress(stress);
+        Ok(buf)
+    }
+}
+
+// Returns the form with the stress mark, if the stress is known from the stem's stress mark
+fn to_stressed_string(buf: &InflectionBuffer) -> String {
+    let mut form = String::new();
+    push_stressed(&mut form, buf, buf.stress(), false);
+    form
+}
+
+impl<'a> Adjective<'a> {
+    /// Returns the synthetic comparative of the adjective: -ее for most adjectives (новый - новее,
+    /// синий - синее, свежий - свежее), and -е with the mutation of the final 'к'/'г'/'х' into
+    /// 'ч'/'ж'/'ш' for stem type 3 (громкий - громче, дорогой - дороже, тихий - тише). Irregular
+    /// comparatives (хороший - лучше, тонкий - тоньше, близкий - ближе) are looked up in a table.
+    ///
+    /// The stress of the -ее ending follows the feminine short form (нова́ - нове́е, краси́ва -
+    /// краси́вее), see [`AdjectiveDeclension::is_comparative_ending_stressed`]. The -е ending is
+    /// never stressed.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::declension::*;
+    ///
+    /// let adj = |stem, decl: &str| Adjective {
+    ///     stem,
+    ///     info: AdjectiveInfo {
+    ///         declension: Some(decl.parse().unwrap()),
+    ///         short_forms: ShortFormAvailability::Available,
+    ///     },
+    /// };
+    /// assert_eq!(adj("нов", "п 1a/b").comparative().unwrap(), "новее");
+    /// assert_eq!(adj("громк", "п 3*a/c′").comparative().unwrap(), "громче");
+    /// assert_eq!(adj("хорош", "п 4a/b").comparative().unwrap(), "лучше");
+    /// ```
+    pub fn comparative(&self) -> Result<String, ComparativeError> {
+        let Some(Declension::Adjective(decl)) = self.info.declension else {
+            return Err(ComparativeError::IncompatibleDeclension);
+        };
+        if self.info.is_reflexive()
+            || self.info.short_forms == ShortFormAvailability::Unavailable
+            || matches!(decl.stem_type, AdjectiveStemType::Type6 | AdjectiveStemType::Type7)
+        {
+            return Err(ComparativeError::Unavailable);
+        }
+
+        // Look up and inflect the lowercase stem, and restore the capitalization afterwards
+        let mut buf = InflectionBuffer::from_stem(&split_stress_mark(self.stem).0)?;
+        let capitalization = buf.to_lowercase();
+
+        if let Some((_, form)) = IRREGULAR_COMPARATIVES.iter().find(|(x, _)| *x == buf.as_str()) {
+            let mut buf = InflectionBuffer::from_stem(form)?;
+            buf.apply_capitalization(capitalization);
+            return Ok(buf.as_str().to_owned());
+        }
+
+        let mutated = match buf.stem().last().copied() {
+            Some(letters::к) => Some(letters::ч),
+            Some(letters::г) => Some(letters::ж),
+            Some(letters::х) => Some(letters::ш),
+            _ => None,
+        };
+
+        if let Some(mutated) = mutated {
+            *buf.stem_mut().last_mut().unwrap() = mutated;
+            buf.append_to_ending_unchecked("е");
         } else {
-            self.stem.fmt(f)
+            buf.append_to_ending_unchecked("ее");
+
+            // The stress moves off the stem's 'ё' onto the ending (тёплый - теплее)
+            if decl.is_comparative_ending_stressed() {
+                for x in buf.stem_mut() {
+                    if *x == letters::ё {
+                        *x = letters::е;
+                    }
+                }
+            }
         }
+        buf.apply_capitalization(capitalization);
+        Ok(buf.as_str().to_owned())
     }
 }
 
 impl AdjectiveDeclension {
+    /// Returns `true`, if the -ее ending of the comparative is stressed. The comparative is
+    /// stressed like the feminine short form: нова́ - нове́е, but краси́ва - краси́вее.
+    pub const fn is_comparative_ending_stressed(self) -> bool {
+        self.stress.short.is_ending_stressed(GenderOrPlural::Feminine)
+    }
+
+    /// Returns the dictionary form of an adjective with the specified stem: the masculine
+    /// nominative singular full form (новый, молодой, синий, трудящийся). Returns an error, if the
+    /// stem contains anything other than Cyrillic letters.
+    pub fn lemma(self, stem: &str) -> Result<String, StemError> {
+        let info = DeclInfo {
+            case: Case::Nominative,
+            number: Number::Singular,
+            gender: Gender::Masculine,
+            animacy: Animacy::Inanimate,
+        };
+        let mut buf = InflectionBuffer::from_stem(stem)?;
+        self.inflect(info, &mut buf);
+        Ok(buf.as_str().to_owned())
+    }
+
     pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) {
-        buf.append_to_ending(self.get_ending(info));
+        // Full forms don't alter the stem, only the ending is appended
+        buf.append_to_ending_unchecked(self.get_ending(info));
+
+        // Participles keep '-ся' after vowels too (трудящаяся, not трудящаясь)
+        if self.is_reflexive {
+            buf.append_to_ending_unchecked("ся");
+        }
+    }
+
+    /// Inflects the stem into the full form, and writes it into the byte buffer, without
+    /// allocating. See [`NounDeclension::inflect_into`](crate::declension::NounDeclension::inflect_into).
+    pub fn inflect_into<'a>(
+        self,
+        info: DeclInfo,
+        stem: &str,
+        out: &'a mut [u8],
+    ) -> Result<&'a str, InflectError> {
+        let mut buf = InflectionBuffer::from_stem(stem)?;
+        self.inflect(info, &mut buf);
+        Ok(buf.copy_to(out)?)
+    }
+
+    /// Inflects the stem into the short form of the specified gender and number (красив,
+    /// красива, красиво, красивы). With the star flag, a fleeting vowel is inserted into the
+    /// masculine form (нужен, умён, горек), and the 'е'/'ё' choice in the neuter ending and the
+    /// fleeting vowel follows the short form stress.
+    ///
+    /// Reflexive adjectives don't have short forms, and this method doesn't append the reflexive
+    /// '-ся'. See [`Adjective::short_forms`] for a method that takes that into account.
+    pub fn inflect_short(
+        self,
+        gender: Gender,
+        number: Number,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
+        let is_masculine = number == Number::Singular && gender == Gender::Masculine;
+        let is_feminine = number == Number::Singular && gender == Gender::Feminine;
+
+        let ending = match self.stem_type {
+            // With a fleeting vowel, soft stems end in a consonant (искренен, not искренень)
+            AdjectiveStemType::Type2 if is_masculine && self.flags.has_star() => "",
+            // Soft stems in -нн take the hard ending (искренна)
+            AdjectiveStemType::Type2
+                if is_feminine && matches!(buf.stem(), [.., letters::н, letters::н]) =>
+            {
+                "а"
+            },
+            _ => self.get_short_ending(gender, number),
+        };
+        buf.append_to_ending_unchecked(ending);
+
+        if self.flags.has_star() && is_masculine {
+            self.apply_short_vowel_alternation(buf)?;
+        }
+        Ok(())
+    }
+
+    fn apply_short_vowel_alternation(self, buf: &mut InflectionBuffer) -> Result<(), InflectError> {
+        // In masculine short form, the stress falls on the inserted vowel, if it's not on the stem
+        // (горький - горек, спокойный - спокоен, мягкий - мягок, умный - умён, смешной - смешон)
+        let stressed = self.stress.short.is_ending_stressed(GenderOrPlural::Masculine);
+        insert_fleeting_vowel(stressed, buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    fn adj<'a>(stem: &'a str, decl: &str, short_forms: ShortFormAvailability) -> Adjective<'a> {
+        let info = AdjectiveInfo { declension: Some(decl.parse().unwrap()), short_forms };
+        Adjective { stem, info }
+    }
+
+    #[track_caller]
+    fn assert_short(adj: &Adjective, expected: [&[&str]; 4]) {
+        let forms = [
+            adj.short_forms(Gender::Masculine, Number::Singular).unwrap(),
+            adj.short_forms(Gender::Feminine, Number::Singular).unwrap(),
+            adj.short_forms(Gender::Neuter, Number::Singular).unwrap(),
+            adj.short_forms(Gender::Masculine, Number::Plural).unwrap(),
+        ];
+        assert_eq!(forms, expected.map(|x| x.iter().map(|x| x.to_string()).collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn short_forms() {
+        use ShortFormAvailability::*;
+
+        let sincere = adj("искренн", "п 2*a", Available);
+        assert_short(&sincere, [
+            &["искренен", "искрен"],
+            &["искренна"],
+            &["искренне"],
+            &["искренни"],
+        ]);
+
+        let timely = adj("своевременн", "п 1*a", Available);
+        assert_short(&timely, [
+            &["своевременен", "своевремен"],
+            &["своевременна"],
+            &["своевременно"],
+            &["своевременны"],
+        ]);
+
+        let blue = adj("син", "п 2a", Available);
+        assert_short(&blue, [&["синь"], &["синя"], &["сине"], &["сини"]]);
+
+        let autumn = adj("осенн", "п 2a", Unavailable);
+        assert_short(&autumn, [&[], &[], &[], &[]]);
+
+        let soft = adj("мягк", "п 3*a/c′", Available);
+        assert_short(&soft, [&["мягок"], &["мягка"], &["мягко"], &["мягки"]]);
+        let bitter = adj("горьк", "п 3*a/c′", Available);
+        assert_short(&bitter, [&["горек"], &["горька"], &["горько"], &["горьки"]]);
+        let calm = adj("спокойн", "п 1*a", Available);
+        assert_short(&calm, [&["спокоен"], &["спокойна"], &["спокойно"], &[
+            "спокойны",
+        ]]);
+        let clever = adj("умн", "п 1*b", Available);
+        assert_short(&clever, [&["умён"], &["умна"], &["умно"], &["умны"]]);
+        let good = adj("хорош", "п 4a/b", Difficult);
+        assert_short(&good, [&["хорош"], &["хороша"], &["хорошо"], &["хороши"]]);
+
+        let small = adj("мал", "п 1b", NoMasculine);
+        assert_short(&small, [&[], &["мала"], &["мало"], &["малы"]]);
+    }
+
+    #[test]
+    fn short_forms_by_stem_type() {
+        use ShortFormAvailability::*;
+
+        // Stem types 1-7
+        let pretty = adj("красив", "п 1a", Available);
+        assert_short(&pretty, [&["красив"], &["красива"], &["красиво"], &[
+            "красивы",
+        ]]);
+        let needed = adj("нужн", "п 1*a/c′", Available);
+        assert_short(&needed, [&["нужен"], &["нужна"], &["нужно"], &["нужны"]]);
+        let quiet = adj("тих", "п 3a/c′", Available);
+        assert_short(&quiet, [&["тих"], &["тиха"], &["тихо"], &["тихи"]]);
+        let hot = adj("горяч", "п 4a/b′", Available);
+        assert_short(&hot, [&["горяч"], &["горяча"], &["горячо"], &["горячи"]]);
+        let stubby = adj("куц", "п 5a", Available);
+        assert_short(&stubby, [&["куц"], &["куца"], &["куце"], &["куцы"]]);
+        let long_necked = adj("длинноше", "п 6a", Difficult);
+        assert_short(&long_necked, [
+            &["длинношей"],
+            &["длинношея"],
+            &["длинношее"],
+            &["длинношеи"],
+        ]);
+        // There are no common type 7 adjectives with short forms, so only the endings are checked
+        let type7 = adj("змии", "п 7a", Difficult);
+        assert_short(&type7, [&["змиий"], &["змиия"], &["змиие"], &["змиии"]]);
+
+        // Stresses a′ and c″
+        let pale = adj("бледн", "п 1*a/a′", Available);
+        assert_short(&pale, [&["бледен"], &["бледна"], &["бледно"], &["бледны"]]);
+        let dear = adj("мил", "п 1a/c″", Available);
+        assert_short(&dear, [&["мил"], &["мила"], &["мило"], &["милы"]]);
+
+        // The neuter ending of soft stems depends on the short form stress
+        let mut buf = InflectionBuffer::from_stem("син").unwrap();
+        let decl: AdjectiveDeclension = "2a/b".parse().unwrap();
+        decl.inflect_short(Gender::Neuter, Number::Singular, &mut buf).unwrap();
+        assert_eq!(buf.as_str(), "синё");
+    }
+
+    #[test]
+    fn stem_types() {
+        use ShortFormAvailability::*;
+
+        let paradigms = [
+            adj("нов", "п 1a", Available),
+            adj("молод", "п 1b", Available),
+            adj("син", "п 2a", Available),
+            adj("дорог", "п 3b", Available),
+            adj("хорош", "п 4a", Available),
+            adj("больш", "п 4b", Unavailable),
+            adj("куц", "п 5a", Available),
+            adj("длинноше", "п 6a", Unavailable),
+            adj("зми", "п 7a", Available),
+        ];
+        let paradigms = paradigms.map(|adj| adj.format_paradigm().unwrap());
+        crate::util::assert_snapshot("adjective_stem_types", &paradigms.join("\n"));
+    }
+
+    #[test]
+    fn inflect_api() {
+        use crate::categories::{Animacy::*, CaseEx};
+        use core::fmt::Write;
+        use std::{format, string::String};
+
+        let adj = adj("нов", "п 1a", ShortFormAvailability::Available);
+        let mut buf = InflectionBuffer::default();
+
+        for case in CaseEx::VALUES {
+            for gender in Gender::VALUES {
+                for number in Number::VALUES {
+                    for animacy in [Inanimate, Animate] {
+                        let form = adj.inflect_to_string(case, gender, number, animacy).unwrap();
+                        let display = adj.inflect_fmt(case, gender, number, animacy);
+                        assert_eq!(format!("{display}"), form);
+                        let in_buf = adj.inflect_to(case, gender, number, animacy, &mut buf);
+                        assert_eq!(in_buf.unwrap(), form);
+                    }
+                }
+            }
+        }
+
+        // Secondary cases are declined as their primary ones
+        let form =
+            adj.inflect_to_string(CaseEx::Locative, Gender::Masculine, Number::Singular, Inanimate);
+        assert_eq!(form.unwrap(), "новом");
+
+        // Indeclinable adjectives are returned as is
+        let beige = Adjective {
+            stem: "беж",
+            info: AdjectiveInfo {
+                declension: None,
+                short_forms: ShortFormAvailability::Unavailable,
+            },
+        };
+        let form =
+            beige.inflect_to_string(CaseEx::Genitive, Gender::Feminine, Number::Plural, Animate);
+        assert_eq!(form.unwrap(), "беж");
+
+        // Adjectives can't be declined by noun declension
+        let info = AdjectiveInfo {
+            declension: Some("1a".parse().unwrap()),
+            short_forms: ShortFormAvailability::Unavailable,
+        };
+        let wrong = Adjective { stem: "нов", info };
+        let form = wrong.inflect_to_string(
+            CaseEx::Nominative,
+            Gender::Masculine,
+            Number::Singular,
+            Inanimate,
+        );
+        assert_eq!(form, Err(InflectError::IncompatibleDeclension));
+        let display = wrong.inflect_fmt(CaseEx::Dative, Gender::Neuter, Number::Plural, Animate);
+        assert!(write!(String::new(), "{display}").is_err());
+        assert_eq!(
+            wrong.forms(Gender::Masculine, Inanimate),
+            Err(InflectError::IncompatibleDeclension)
+        );
+    }
+
+    #[test]
+    fn forms() {
+        use crate::categories::Animacy::*;
+
+        let young = adj("молод", "п 1b", ShortFormAvailability::Available);
+        #[rustfmt::skip]
+        assert_eq!(young.forms(Gender::Masculine, Animate).unwrap(), [
+            "молодой", "молодого", "молодому", "молодого", "молодым", "молодом",
+            "молодые", "молодых", "молодым", "молодых", "молодыми", "молодых",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(young.forms(Gender::Neuter, Inanimate).unwrap(), [
+            "молодое", "молодого", "молодому", "молодое", "молодым", "молодом",
+            "молодые", "молодых", "молодым", "молодые", "молодыми", "молодых",
+        ]);
+    }
+
+    #[test]
+    fn reflexive() {
+        use crate::categories::{Animacy, Case};
+
+        let adj = adj("трудящ", "п 4a, -ся", ShortFormAvailability::Available);
+        assert!(adj.info.is_reflexive());
+
+        let inflect = |case: Case, number, gender, animacy| {
+            adj.inflect_to_string(case.into(), gender, number, animacy).unwrap()
+        };
+        let paradigm =
+            |number, gender, animacy| Case::VALUES.map(|x| inflect(x, number, gender, animacy));
+
+        use {Animacy::*, Gender::*, Number::*};
+        assert_eq!(paradigm(Singular, Masculine, Inanimate), [
+            "трудящийся",
+            "трудящегося",
+            "трудящемуся",
+            "трудящийся",
+            "трудящимся",
+            "трудящемся",
+        ]);
+        assert_eq!(paradigm(Singular, Neuter, Inanimate), [
+            "трудящееся",
+            "трудящегося",
+            "трудящемуся",
+            "трудящееся",
+            "трудящимся",
+            "трудящемся",
+        ]);
+        assert_eq!(paradigm(Singular, Feminine, Inanimate), [
+            "трудящаяся",
+            "трудящейся",
+            "трудящейся",
+            "трудящуюся",
+            "трудящейся",
+            "трудящейся",
+        ]);
+        assert_eq!(paradigm(Plural, Masculine, Inanimate), [
+            "трудящиеся",
+            "трудящихся",
+            "трудящимся",
+            "трудящиеся",
+            "трудящимися",
+            "трудящихся",
+        ]);
+        // The animate accusative takes the genitive form
+        assert_eq!(inflect(Case::Accusative, Singular, Masculine, Animate), "трудящегося");
+        assert_eq!(inflect(Case::Accusative, Plural, Feminine, Animate), "трудящихся");
+    }
+
+    #[test]
+    fn lemma() {
+        let lemmas = [
+            ("нов", "1a", "новый"),
+            ("молод", "1b", "молодой"),
+            ("син", "2a", "синий"),
+            ("русск", "3a", "русский"),
+            ("больш", "4b", "большой"),
+            ("трудящ", "4a, -ся", "трудящийся"),
+        ];
+        for (stem, decl, expected) in lemmas {
+            let decl: AdjectiveDeclension = decl.parse().unwrap();
+            assert_eq!(decl.lemma(stem).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn reflexive_short_forms() {
+        let adj = adj("красив", "п 1a, -ся", ShortFormAvailability::Available);
+        for (gender, number) in
+            [(Gender::Masculine, Number::Singular), (Gender::Neuter, Number::Plural)]
+        {
+            assert_eq!(adj.short_forms(gender, number), Ok(vec![]));
+        }
+    }
+
+    #[test]
+    fn short_form_errors() {
+        // A fleeting vowel can't be inserted without a consonant cluster at the end of the stem
+        let adj = adj("к", "п 3*a", ShortFormAvailability::Available);
+        let err = InflectError::NoConsonantCluster;
+        assert_eq!(adj.short_forms(Gender::Masculine, Number::Singular), Err(err));
+        assert_eq!(adj.short_forms(Gender::Feminine, Number::Singular), Ok(vec!["ка".to_owned()]));
+    }
+
+    #[test]
+    fn comparative() {
+        use ShortFormAvailability::*;
+
+        let table = [
+            // -ее, stressed like the feminine short form
+            ("нов", "п 1a/b", "новее", true),
+            ("красив", "п 1a", "красивее", false),
+            ("бел", "п 1a/c", "белее", true),
+            ("умн", "п 1*b", "умнее", true),
+            ("сыт", "п 1a/c′", "сытее", true),
+            ("пуст", "п 1b/c′", "пустее", true),
+            ("сед", "п 1a/c′", "седее", true),
+            ("тёпл", "п 1*a/c′", "теплее", true),
+            ("син", "п 2a", "синее", false),
+            ("свеж", "п 4a/c″", "свежее", true),
+            ("горяч", "п 4a/b′", "горячее", true),
+            ("куц", "п 5a", "куцее", false),
+            // -е with the consonant mutation
+            ("громк", "п 3*a/c′", "громче", false),
+            ("мягк", "п 3*a/c′", "мягче", false),
+            ("жарк", "п 3*a/c′", "жарче", false),
+            ("ярк", "п 3*a/c′", "ярче", false),
+            ("дорог", "п 3b/c", "дороже", false),
+            ("строг", "п 3a/c′", "строже", false),
+            ("тих", "п 3a/c′", "тише", false),
+            ("сух", "п 3b/c′", "суше", false),
+            // Irregular comparatives
+            ("хорош", "п 4a/b", "лучше", false),
+            ("плох", "п 3b/c′", "хуже", false),
+            ("тонк", "п 3*a/c′", "тоньше", false),
+            ("мелк", "п 3*a/c′", "мельче", false),
+            ("горьк", "п 3*a/c′", "горче", false),
+            ("долг", "п 3a/c", "дольше", false),
+            ("глубок", "п 3a/c′", "глубже", false),
+            ("широк", "п 3a/c′", "шире", false),
+            ("высок", "п 3a/c′", "выше", false),
+            ("близк", "п 3*a/c′", "ближе", false),
+            ("коротк", "п 3*a/c′", "короче", false),
+            ("лёгк", "п 3*b/b", "легче", false),
+            ("дешёв", "п 1a/c", "дешевле", false),
+            ("молод", "п 1b/c", "моложе", false),
+            ("прост", "п 1b/c″", "проще", false),
+            ("чист", "п 1a/c′", "чище", false),
+        ];
+        for (stem, decl, expected, stressed) in table {
+            let adj = adj(stem, decl, Available);
+            assert_eq!(adj.comparative().as_deref(), Ok(expected), "{stem} {decl}");
+
+            let Some(Declension::Adjective(decl)) = adj.info.declension else { unreachable!() };
+            if expected.ends_with("ее") {
+                assert_eq!(decl.is_comparative_ending_stressed(), stressed, "{expected}");
+            }
+        }
+
+        // Adjectives without a synthetic comparative
+        let autumn = adj("осенн", "п 2a", Unavailable);
+        assert_eq!(autumn.comparative(), Err(ComparativeError::Unavailable));
+        let long_necked = adj("длинноше", "п 6a", Difficult);
+        assert_eq!(long_necked.comparative(), Err(ComparativeError::Unavailable));
+        let pronominal = adj("чь", "мс 6*b", Available);
+        assert_eq!(pronominal.comparative(), Err(ComparativeError::IncompatibleDeclension));
+        let reflexive = adj("трудящ", "п 4a, -ся", Available);
+        assert_eq!(reflexive.comparative(), Err(ComparativeError::Unavailable));
+    }
+
+    #[test]
+    fn capitalized_forms() {
+        use crate::categories::{Animacy::*, CaseEx::*, Gender::*, Number::*};
+        use ShortFormAvailability::*;
+        let lemma =
+            |adj: &Adjective| adj.inflect_to_string(Nominative, Masculine, Singular, Inanimate);
+
+        let loud = adj("Громк", "п 3*a/c′", Available);
+        assert_eq!(lemma(&loud), Ok("Громкий".to_owned()));
+        assert_eq!(loud.comparative().as_deref(), Ok("Громче"));
+        assert_short(&loud, [&["Громок"], &["Громка"], &["Громко"], &["Громки"]]);
+
+        let loud = adj("ГРОМК", "п 3*a/c′", Available);
+        assert_eq!(lemma(&loud), Ok("ГРОМКИЙ".to_owned()));
+        assert_eq!(loud.comparative().as_deref(), Ok("ГРОМЧЕ"));
+        assert_short(&loud, [&["ГРОМОК"], &["ГРОМКА"], &["ГРОМКО"], &["ГРОМКИ"]]);
+
+        // Irregular comparatives are looked up by the lowercase stem
+        assert_eq!(adj("Хорош", "п 4a/b", Difficult).comparative().as_deref(), Ok("Лучше"));
+        assert_eq!(adj("ХОРОШ", "п 4a/b", Difficult).comparative().as_deref(), Ok("ЛУЧШЕ"));
 
-        todo!() // TODO
+        let sincere = adj("ИСКРЕНН", "п 2*a", Available);
+        assert_short(&sincere, [
+            &["ИСКРЕНЕН", "ИСКРЕН"],
+            &["ИСКРЕННА"],
+            &["ИСКРЕННЕ"],
+            &["ИСКРЕННИ"],
+        ]);
     }
 }
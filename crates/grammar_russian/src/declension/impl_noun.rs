@@ -1,90 +1,662 @@
 use crate::{
-    InflectionBuffer, Letter,
+    BufferTooSmallError, InflectionBuffer, Letter, LetterSliceExt, StemError,
     categories::{Animacy, Case, CaseEx, Gender, GenderEx, HasGender, HasNumber, Number},
-    declension::{DeclInfo, Declension, NounDeclension, NounStemType},
+    declension::{
+        DeclInfo, Declension, NounDeclension, NounStemType, PrecomputedEndings, StemExtension,
+        StemMismatchError,
+        fleeting::{insert_fleeting_vowel, remove_fleeting_vowel},
+        push_stressed, split_stress_mark, stressed_vowel,
+        trace::{Alternation, AlternationTracer, UniqueAlternation},
+    },
     letters,
     stress::NounStress,
 };
-use std::fmt::Display;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt::Display;
+use thiserror::Error;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Noun<'a> {
     pub stem: &'a str,
+    /// The index of the stem's stressed letter, if it's known, e.g. from a normalized headword
+    /// (see [`Noun::from_normalized`]). A stress mark in the stem itself takes precedence.
+    pub stress: Option<usize>,
     pub info: NounInfo,
-    // exceptions: &'a [(CaseExAndNumber, &'a str)],
+    /// Irregular forms of the noun, that are used instead of the inflected ones, marked with §1,
+    /// §2, etc. in Zaliznyak's entries: (год) в году, (человек) люди. See [`Noun::exception`].
+    pub exceptions: &'a [(CaseEx, Number, &'a str)],
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct NounInfo {
     pub declension: Option<Declension>,
+    /// The gender, that the noun's endings are determined by. It's the same as the noun's own
+    /// gender, unless it's specified in angle brackets: "мо <жо 1a>" (мужчина). For nouns of
+    /// common gender it's feminine by default ("мо-жо 1d", сирота), since they're declined like
+    /// feminine nouns, regardless of the referent's gender.
     pub declension_gender: Gender,
+    /// The noun's own gender, that adjectives and pronouns agree with (высокого мужчины). The
+    /// common gender (мо-жо) is resolved by the referent, see [`Noun::agreement_info_as`].
     pub gender: GenderEx,
     pub animacy: Animacy,
     pub tantum: Option<Number>,
+    /// Whether the noun has a distinct partitive (second genitive) form: чай - чаю, сахар - сахару.
+    pub has_partitive: bool,
+    /// Whether the noun has a distinct locative (second prepositional) form, that's always
+    /// stressed on the ending: лес - в лесу́.
+    pub has_locative: bool,
+}
+
+impl NounInfo {
+    /// Returns `true`, if the noun is only used in the singular (молоко, золото).
+    pub const fn is_singulare_tantum(&self) -> bool {
+        matches!(self.tantum, Some(Number::Singular))
+    }
+    /// Returns `true`, if the noun is only used in the plural (ножницы, сани). Marked with "мн."
+    /// in Zaliznyak's entries.
+    pub const fn is_plurale_tantum(&self) -> bool {
+        matches!(self.tantum, Some(Number::Plural))
+    }
+}
+
+/// How a noun supports a grammatical case. See [`Noun::supports`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecondaryCaseSupport {
+    /// The noun has its own form in this case (all main cases, translative, and marked partitive
+    /// and locative cases).
+    Native,
+    /// The noun doesn't have its own form in this case, and the corresponding main case is used
+    /// instead: genitive for partitive, prepositional for locative.
+    Fallback,
+    /// The noun is indeclinable, and doesn't have distinct forms in secondary cases.
+    None,
+}
+
+/// How [`Noun::try_inflect`] handles secondary cases that the noun doesn't have.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SecondaryCasePolicy {
+    /// Use the corresponding main case instead: genitive for partitive, prepositional for locative.
+    #[default]
+    Fallback,
+    /// Return an error, if the noun doesn't have its own form in the requested case.
+    RequireNative,
+}
+
+/// An error returned by [`Noun::try_inflect`], when the requested number conflicts with the
+/// noun's tantum.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum TantumError {
+    /// The noun is singulare tantum (молоко), and doesn't have plural forms.
+    #[error("the noun is singulare tantum, and doesn't have plural forms")]
+    SingularOnly,
+    /// The noun is plurale tantum (ножницы), and doesn't have singular forms.
+    #[error("the noun is plurale tantum, and doesn't have singular forms")]
+    PluralOnly,
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("the noun doesn't have its own form in the {case} case")]
+pub struct UnsupportedCaseError {
+    pub case: CaseEx,
+    pub support: SecondaryCaseSupport,
+}
+
+/// An error returned when a word can't be inflected, usually because its stem doesn't match the
+/// alternations of its declension (e.g. a stem without vowels with the star flag).
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum InflectError {
+    /// The stem contains a character that isn't a Cyrillic letter.
+    #[error(transparent)]
+    InvalidStem(#[from] StemError),
+    /// The stem doesn't have a vowel to remove in the vowel alternation (*).
+    #[error("no vowels found in stem for vowel alternation")]
+    NoVowelInStem,
+    /// The stem's last vowel can't be a fleeting vowel, since it's not 'о', 'е' or 'ё'.
+    #[error("the last vowel of the stem can't be a fleeting vowel")]
+    UnknownFleetingVowel,
+    /// The stem doesn't have two consonants to insert a fleeting vowel between (*).
+    #[error("no consonant cluster found in stem for vowel alternation")]
+    NoConsonantCluster,
+    /// The stem doesn't have an 'е' or 'ё' for the е/ё alternation (ё). Only returned by
+    /// [`NounDeclension::apply_ye_yo_alternation`], since inflection ignores the flag in this
+    /// case, and reports an [`InflectWarning`] instead.
+    #[error("е/ё not found in stem for е/ё alternation")]
+    NoYeInStem,
+    /// The stem doesn't match any of the unique stem alternations (°).
+    #[error("unknown unique stem alternation")]
+    UnsupportedUniqueAlternation,
+    /// The word is declined by a kind of declension that it can't be declined by: an adjective or
+    /// a pronoun by noun declension.
+    #[error("the word can't be declined by this kind of declension")]
+    IncompatibleDeclension,
+    /// The noun doesn't have its own form in the requested case. See [`Noun::try_inflect`].
+    #[error(transparent)]
+    UnsupportedCase(#[from] UnsupportedCaseError),
+    /// The requested number conflicts with the noun's tantum. See [`Noun::try_inflect`].
+    #[error(transparent)]
+    Tantum(#[from] TantumError),
+    /// The inflected form doesn't fit into the provided buffer. See
+    /// [`NounDeclension::inflect_into`].
+    #[error(transparent)]
+    BufferTooSmall(#[from] BufferTooSmallError),
+}
+
+/// A problem with the word's stem or declension, that didn't prevent it from being inflected.
+/// See [`Noun::inflect_with_warnings`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum InflectWarning {
+    /// The declension has the е/ё alternation flag (ё), but the stem doesn't have an 'е' or 'ё'
+    /// (a data entry error, or a borrowed word). The flag was ignored.
+    #[error("е/ё not found in stem for е/ё alternation, the flag was ignored")]
+    IgnoredYeYoAlternation,
 }
 
 impl<'a> Noun<'a> {
+    /// Creates a noun with the specified stem and info, and without irregular forms.
+    pub const fn new(stem: &'a str, info: NounInfo) -> Self {
+        Self { stem, stress: None, info, exceptions: &[] }
+    }
+    /// Creates a noun with the specified stem and info, checking that the stem's final letter is
+    /// allowed in the declension's stem type. Indeclinable nouns accept any stem.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::declension::*;
+    ///
+    /// assert!(Noun::new_checked("сапог", "м 3c".parse().unwrap()).is_ok());
+    ///
+    /// let err = Noun::new_checked("стол", "м 3c".parse().unwrap()).unwrap_err();
+    /// assert_eq!(err.stem_type, AnyStemType::Type3);
+    /// ```
+    pub fn new_checked(stem: &'a str, info: NounInfo) -> Result<Self, StemMismatchError> {
+        if let Some(decl) = info.declension {
+            decl.validate(stem)?;
+        }
+        Ok(Self::new(stem, info))
+    }
+    /// Creates a noun with a stem that's already split into letters, without converting it back
+    /// into a string.
+    pub const fn from_letters(stem: &'a [Letter], info: NounInfo) -> Self {
+        Self::new(stem.as_str(), info)
+    }
+    /// Returns the noun with the specified stressed letter of the stem.
+    pub const fn with_stress(mut self, stress: Option<usize>) -> Self {
+        self.stress = stress;
+        self
+    }
+    /// Returns the noun with the specified irregular forms.
+    pub const fn with_exceptions(mut self, exceptions: &'a [(CaseEx, Number, &'a str)]) -> Self {
+        self.exceptions = exceptions;
+        self
+    }
+
+    /// Returns the irregular form of the noun in the specified case and number, if it has one.
+    ///
+    /// The case and number are normalized the same way as for inflection, if there isn't a form
+    /// recorded for them exactly. The tantum number is used instead of the requested one. The
+    /// partitive and locative take the genitive and prepositional forms, unless the noun has its
+    /// own forms in those cases, and the translative takes the nominative plural form. The
+    /// accusative takes the nominative or genitive form, depending on the animacy, except in the
+    /// feminine singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let exceptions = [(CaseEx::Nominative, Number::Plural, "люди")];
+    /// let noun = Noun::new("человек", "мо 3a".parse().unwrap()).with_exceptions(&exceptions);
+    /// assert_eq!(noun.exception(CaseEx::Nominative, Number::Plural), Some("люди"));
+    /// assert_eq!(noun.exception(CaseEx::Translative, Number::Plural), Some("люди"));
+    /// assert_eq!(noun.exception(CaseEx::Genitive, Number::Plural), None);
+    /// ```
+    pub fn exception(&self, case: CaseEx, number: Number) -> Option<&'a str> {
+        let find = |case: CaseEx, number: Number| {
+            let form = self.exceptions.iter().find(|x| x.0 == case && x.1 == number);
+            form.map(|x| x.2)
+        };
+
+        let number = self.info.tantum.unwrap_or(number);
+        if let Some(form) = find(case, number) {
+            return Some(form);
+        }
+        // The noun's own partitive and locative forms aren't the same as in the main cases
+        if matches!(case, CaseEx::Partitive | CaseEx::Locative)
+            && self.supports(case) == SecondaryCaseSupport::Native
+        {
+            return None;
+        }
+
+        let (case, number) = case.normalize_with(number);
+        if let Some(form) = find(case.into(), number) {
+            return Some(form);
+        }
+        // Feminine singulars have accusative endings of their own
+        let gender = self.info.declension_gender;
+        if case == Case::Accusative && !(number == Number::Singular && gender == Gender::Feminine) {
+            let acc_case = DeclInfo::new(case, number, gender, self.info.animacy).acc_case();
+            return find(acc_case.into(), number);
+        }
+        None
+    }
+
+    /// Returns whether the noun has its own form in the specified case, or if the corresponding
+    /// main case is used instead.
+    pub const fn supports(&self, case: CaseEx) -> SecondaryCaseSupport {
+        let has_form = match case {
+            CaseEx::Partitive => self.info.has_partitive,
+            CaseEx::Locative => self.info.has_locative,
+            _ => return SecondaryCaseSupport::Native,
+        };
+
+        if has_form {
+            SecondaryCaseSupport::Native
+        } else if self.info.declension.is_some() {
+            SecondaryCaseSupport::Fallback
+        } else {
+            SecondaryCaseSupport::None
+        }
+    }
+
+    /// Writes the form of the noun in the specified case and number into the formatter. If the
+    /// noun can't be inflected, returns [`core::fmt::Error`], so use one of the other methods to
+    /// handle the [`InflectError`].
     pub fn inflect(
         &self,
         case: CaseEx,
         number: Number,
-        f: &mut std::fmt::Formatter,
-    ) -> std::fmt::Result {
-        // TODO: check exceptions
-
-        if let Some(decl) = self.info.declension {
-            let number = self.info.tantum.unwrap_or(number);
-            let (case, number) = case.normalize_with(number);
-
-            let info = DeclInfo {
-                case,
-                number,
-                gender: self.info.declension_gender,
-                animacy: self.info.animacy,
-            };
+        f: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        match self.inflect_to(case, number, &mut InflectionBuffer::default()) {
+            Ok(form) => form.fmt(f),
+            Err(_) => Err(core::fmt::Error),
+        }
+    }
+    /// Returns the form of the noun in the specified case and number, or an error if the noun's
+    /// stem doesn't match its declension. The stem's stress mark, if it has one, is removed (see
+    /// [`inflect_stressed`][Self::inflect_stressed] for the form with the stress marked).
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = NounInfo {
+    ///     declension: Some("1b".parse().unwrap()),
+    ///     declension_gender: Gender::Masculine,
+    ///     gender: GenderEx::Masculine,
+    ///     animacy: Animacy::Inanimate,
+    ///     tantum: None,
+    ///     has_partitive: false,
+    ///     has_locative: false,
+    /// };
+    /// let noun = Noun::new("стол", info);
+    /// assert_eq!(noun.inflect_to_string(CaseEx::Dative, Number::Plural).unwrap(), "столам");
+    ///
+    /// // The star flag requires a vowel in the stem
+    /// let noun = Noun::new("штр", "м 1*a".parse().unwrap());
+    /// let err = noun.inflect_to_string(CaseEx::Genitive, Number::Singular).unwrap_err();
+    /// assert_eq!(err, InflectError::NoVowelInStem);
+    /// ```
+    pub fn inflect_to_string(&self, case: CaseEx, number: Number) -> Result<String, InflectError> {
+        Ok(self.inflect_to(case, number, &mut InflectionBuffer::default())?.to_owned())
+    }
+    /// Same as [`inflect_to_string`][Self::inflect_to_string], but also returns the problems with
+    /// the noun's data, that were worked around while inflecting it. The other methods ignore
+    /// them silently.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// // The е/ё alternation flag is ignored, if the stem doesn't have an 'е'
+    /// let noun = Noun::new("стол", "м 1a, ё".parse().unwrap());
+    /// let (form, warnings) = noun.inflect_with_warnings(CaseEx::Genitive, Number::Plural).unwrap();
+    /// assert_eq!(form, "столов");
+    /// assert_eq!(warnings, [InflectWarning::IgnoredYeYoAlternation]);
+    /// ```
+    pub fn inflect_with_warnings(
+        &self,
+        case: CaseEx,
+        number: Number,
+    ) -> Result<(String, Vec<InflectWarning>), InflectError> {
+        let mut warnings = Vec::new();
+        let mut buf = InflectionBuffer::default();
+        self.inflect_into(case, number, &mut buf, &mut |warning| warnings.push(warning))?;
+        Ok((buf.as_str().to_owned(), warnings))
+    }
+    /// Returns the noun's dictionary form: the nominative singular, or the nominative plural for
+    /// plurale tantum nouns. Unique stem alternations (°) are applied, so the stored stem may
+    /// differ from the lemma's stem (ребёнок, время).
+    pub fn lemma(&self) -> Result<String, InflectError> {
+        self.inflect_to_string(CaseEx::Nominative, Number::Singular)
+    }
+    /// Returns the form of the noun in the specified case and number, as a [`Display`] value,
+    /// that inflects the noun when it's formatted. Formatting it returns [`core::fmt::Error`], if
+    /// the noun can't be inflected.
+    pub fn inflect_fmt(&self, case: CaseEx, number: Number) -> impl Display + '_ {
+        core::fmt::from_fn(move |f| self.inflect(case, number, f))
+    }
+    /// Inflects the noun in the specified case and number into the buffer, and returns the form.
+    /// The buffer's contents are replaced, and its allocation is reused.
+    pub fn inflect_to<'b>(
+        &self,
+        case: CaseEx,
+        number: Number,
+        buf: &'b mut InflectionBuffer,
+    ) -> Result<&'b str, InflectError> {
+        self.inflect_into(case, number, buf, &mut |_| {})?;
+        Ok(buf.as_str())
+    }
 
-            let mut buf = InflectionBuffer::from_stem_unchecked(self.stem);
+    /// Inflects the noun in the specified case and number into the buffer, and returns the form's
+    /// letters. The buffer's contents are replaced, and its allocation is reused.
+    pub fn inflect_to_letters<'b>(
+        &self,
+        case: CaseEx,
+        number: Number,
+        buf: &'b mut InflectionBuffer,
+    ) -> Result<&'b [Letter], InflectError> {
+        self.inflect_into(case, number, buf, &mut |_| {})?;
+        Ok(buf.as_letters())
+    }
 
-            match decl {
-                Declension::Noun(decl) => decl.inflect(info, &mut buf),
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Pronoun(_) => {
-                    unimplemented!("Nouns don't decline by pronoun declension")
-                },
-            };
+    /// Inflects the noun in the specified case and number, handling secondary cases that the
+    /// noun doesn't have according to the specified policy.
+    ///
+    /// Unlike the other methods, that silently use the tantum number instead of the requested one,
+    /// returns a [`TantumError`], if the requested number conflicts with the noun's tantum. The
+    /// translative case is always plural, so it conflicts with singulare tantum.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::new("ножниц", "мн. ж 5a".parse().unwrap());
+    /// let policy = SecondaryCasePolicy::Fallback;
+    ///
+    /// let err = noun.try_inflect(CaseEx::Dative, Number::Singular, policy).unwrap_err();
+    /// assert_eq!(err, InflectError::Tantum(TantumError::PluralOnly));
+    /// // The lenient methods use the plural instead
+    /// assert_eq!(noun.inflect_to_string(CaseEx::Dative, Number::Singular).unwrap(), "ножницам");
+    /// ```
+    pub fn try_inflect(
+        &self,
+        case: CaseEx,
+        number: Number,
+        policy: SecondaryCasePolicy,
+    ) -> Result<String, InflectError> {
+        if let Some(tantum) = self.info.tantum
+            && case.normalize_with(number).1 != tantum
+        {
+            return Err(match tantum {
+                Number::Singular => TantumError::SingularOnly,
+                Number::Plural => TantumError::PluralOnly,
+            }
+            .into());
+        }
 
-            buf.as_str().fmt(f)
-        } else {
-            self.stem.fmt(f)
+        let support = self.supports(case);
+
+        if policy == SecondaryCasePolicy::RequireNative && support != SecondaryCaseSupport::Native {
+            return Err(UnsupportedCaseError { case, support }.into());
+        }
+        self.inflect_to_string(case, number)
+    }
+
+    /// Returns the form of the noun in the specified case and number, with the stressed vowel
+    /// marked with U+0301 (combining acute accent): "столы́", "о́кна", "жена́м".
+    ///
+    /// The stress of ending-stressed forms is determined by the declension's stress schema. In
+    /// stem-stressed forms, the stress is taken from the stem, which may have a stress mark after
+    /// the stressed vowel ("мо́лот"), or from the noun's [`stress`][Self::stress]; without them, it's
+    /// known only if the stem has 'ё' or a single vowel. The letter 'ё' is inherently stressed, and monosyllabic forms don't need a stress
+    /// mark, so neither of them are marked. Indeclinable nouns and irregular forms (see
+    /// [`Noun::exception`]) are returned as is, so the irregular forms may have stress marks too.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::new("окн", "с 1*d".parse().unwrap());
+    /// assert_eq!(noun.inflect_stressed(CaseEx::Nominative, Number::Singular).unwrap(), "окно́");
+    /// assert_eq!(noun.inflect_stressed(CaseEx::Nominative, Number::Plural).unwrap(), "о́кна");
+    ///
+    /// let noun = Noun::new("мо́лот", "м 1a".parse().unwrap());
+    /// assert_eq!(noun.inflect_stressed(CaseEx::Dative, Number::Plural).unwrap(), "мо́лотам");
+    /// ```
+    pub fn inflect_stressed(&self, case: CaseEx, number: Number) -> Result<String, InflectError> {
+        let Some((buf, decl, info)) = self.inflect_buf(case, number)? else {
+            return Ok(self.exception(case, number).unwrap_or(self.stem).to_owned());
+        };
+        let stressed = stressed_vowel(decl, info, &buf);
+
+        let mut dst = String::new();
+        push_stressed(&mut dst, &buf, stressed, false);
+        Ok(dst)
+    }
+
+    /// Inflects the noun into a new buffer, and returns it along with the resolved declension and
+    /// info. The declension differs from the noun's only in the stress of the locative forms.
+    /// Returns `None`, if the noun is indeclinable.
+    pub(crate) fn inflect_buf(
+        &self,
+        case: CaseEx,
+        number: Number,
+    ) -> Result<Option<(InflectionBuffer, Declension, DeclInfo)>, InflectError> {
+        let mut buf = InflectionBuffer::default();
+        let resolved = self.inflect_into(case, number, &mut buf, &mut |_| {})?;
+        Ok(resolved.map(|(decl, info)| (buf, decl, info)))
+    }
+
+    // Resets the buffer to the stem, inflects it, and returns the resolved declension and info.
+    // Returns `None` and leaves only the stem in the buffer, if the noun is indeclinable, or the
+    // irregular form, if the noun has one. All the inflection methods go through here: the stem's
+    // stress mark is removed, and its position (or the noun's stress) is tracked in the buffer (see
+    // InflectionBuffer::stress), so that it doesn't end up in the middle of the form.
+    fn inflect_into(
+        &self,
+        case: CaseEx,
+        number: Number,
+        buf: &mut InflectionBuffer,
+        on_warning: &mut dyn FnMut(InflectWarning),
+    ) -> Result<Option<(Declension, DeclInfo)>, InflectError> {
+        let (stem, stress) = split_stress_mark(self.stem);
+        buf.reset_to_stem(&stem)?;
+        buf.set_stress(stress.or(self.stress));
+        self.inflect_in_place(case, number, buf, on_warning)
+    }
+    // Inflects the stem that's already in the buffer, and returns the resolved declension and info.
+    // The declension differs from the noun's only in the stress of the locative forms.
+    fn inflect_in_place(
+        &self,
+        case: CaseEx,
+        number: Number,
+        buf: &mut InflectionBuffer,
+        on_warning: &mut dyn FnMut(InflectWarning),
+    ) -> Result<Option<(Declension, DeclInfo)>, InflectError> {
+        // Irregular forms are used as is, without the stress mark
+        if let Some(form) = self.exception(case, number) {
+            buf.reset_to_stem(&split_stress_mark(form).0)?;
+            return Ok(None);
+        }
+
+        let Some(mut decl) = self.info.declension else { return Ok(None) };
+        let number = self.info.tantum.unwrap_or(number);
+        let is_native_secondary = matches!(case, CaseEx::Partitive | CaseEx::Locative)
+            && self.supports(case) == SecondaryCaseSupport::Native;
+        let is_locative = case == CaseEx::Locative;
+        let (mut case, number) = case.normalize_with(number);
+
+        // Partitive and locative singular forms end like the dative (чаю, в лесу, в тени), and
+        // the locative ones are always stressed on the ending (в лесу́, на мосту́, в тени́)
+        if is_native_secondary && number == Number::Singular {
+            case = Case::Dative;
+            if is_locative && let Declension::Noun(decl) = &mut decl {
+                decl.stress = NounStress::B;
+            }
         }
+
+        let info = DeclInfo {
+            case,
+            number,
+            gender: self.info.declension_gender,
+            animacy: self.info.animacy,
+        };
+
+        // Inflect the lowercase stem, and restore the capitalization of proper nouns (Москве)
+        let capitalization = buf.to_lowercase();
+        decl.inflect_with_warnings(info, buf, on_warning)?;
+        buf.apply_capitalization(capitalization);
+
+        Ok(Some((decl, info)))
     }
 }
 
 impl NounDeclension {
-    pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) {
-        buf.append_to_ending(self.get_ending(info));
+    /// Appends the ending to the stem in the buffer, and applies the stem alternations. Returns an
+    /// error, if the stem doesn't match the alternations (e.g. a stem without vowels with the
+    /// star flag). The buffer's contents are unspecified after an error.
+    pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) -> Result<(), InflectError> {
+        self.inflect_with_warnings(info, buf, |_| {})
+    }
+    /// Same as [`inflect`][Self::inflect], but calls `on_warning` with the problems that were
+    /// worked around, instead of ignoring them silently.
+    pub fn inflect_with_warnings(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+        mut on_warning: impl FnMut(InflectWarning),
+    ) -> Result<(), InflectError> {
+        buf.append_to_ending_unchecked(self.get_ending(info));
+        self.apply_alternations(info, buf, &mut on_warning)
+    }
+    /// Inflects the stem, and writes the form into the byte buffer, without allocating (for stems
+    /// of up to [`STEM_MAX_LEN`](crate::STEM_MAX_LEN) bytes). A buffer of
+    /// [`FORM_MAX_LEN`](crate::FORM_MAX_LEN) bytes fits any such form. Returns an error, if the
+    /// stem contains anything other than Cyrillic letters (see [`InflectionBuffer::from_stem`]).
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{BufferTooSmallError, FORM_MAX_LEN, categories::*, declension::*};
+    ///
+    /// let decl: NounDeclension = "3*a".parse().unwrap();
+    /// let info = DeclInfo {
+    ///     case: Case::Genitive,
+    ///     number: Number::Plural,
+    ///     gender: Gender::Feminine,
+    ///     animacy: Animacy::Animate,
+    /// };
+    /// let mut out = [0; FORM_MAX_LEN];
+    /// assert_eq!(decl.inflect_into(info, "кошк", &mut out).unwrap(), "кошек");
+    ///
+    /// let err = decl.inflect_into(info, "кошк", &mut [0; 8]).unwrap_err();
+    /// assert_eq!(err, InflectError::BufferTooSmall(BufferTooSmallError { required: 10 }));
+    /// ```
+    pub fn inflect_into<'a>(
+        self,
+        info: DeclInfo,
+        stem: &str,
+        out: &'a mut [u8],
+    ) -> Result<&'a str, InflectError> {
+        let mut buf = InflectionBuffer::from_stem(stem)?;
+        self.inflect(info, &mut buf)?;
+        Ok(buf.copy_to(out)?)
+    }
+    /// Same as [`inflect`][Self::inflect], but panics if the stem doesn't match the alternations.
+    pub fn inflect_unchecked(self, info: DeclInfo, buf: &mut InflectionBuffer) {
+        if let Err(err) = self.inflect(info, buf) {
+            panic!("{err}");
+        }
+    }
+    /// Same as [`inflect`][Self::inflect], but takes the ending from the precomputed endings.
+    /// The endings must have been precomputed for the same gender and animacy as in `info`.
+    pub fn inflect_precomputed(
+        self,
+        endings: &PrecomputedEndings,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
+        buf.append_to_ending_unchecked(endings.get(info));
+        self.apply_alternations(info, buf, &mut |_| {})
+    }
 
+    fn apply_alternations(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+        on_warning: &mut dyn FnMut(InflectWarning),
+    ) -> Result<(), InflectError> {
+        self.apply_alternations_traced(info, buf, on_warning, &mut ())
+    }
+    // Applies the alternations through the tracer, which doesn't do anything in the normal
+    // inflection, and records the ones that changed the form in the traced one
+    pub(super) fn apply_alternations_traced(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+        on_warning: &mut dyn FnMut(InflectWarning),
+        tracer: &mut impl AlternationTracer,
+    ) -> Result<(), InflectError> {
         if self.flags.has_circle() {
-            self.apply_unique_alternation(info, buf);
+            tracer.apply(buf, |buf| {
+                Ok(Alternation::Circle(self.apply_unique_alternation_kind(info, buf)?))
+            })?;
         }
 
         // Special case for stem type 8: replace 'я' with 'а' after hissing consonant in stem
-        if self.stem_type == NounStemType::Type8
-            && buf.stem().last().is_some_and(|x| x.is_hissing())
-            && let [ya @ letters::я, ..] = buf.ending_mut()
-        {
-            *ya = letters::а;
+        if self.stem_type == NounStemType::Type8 {
+            tracer.apply(buf, |buf| {
+                if buf.stem().last().is_some_and(|x| x.is_hissing())
+                    && let [ya @ letters::я, ..] = buf.ending_mut()
+                {
+                    *ya = letters::а;
+                }
+                Ok(Alternation::Type8YaToA)
+            })?;
         }
 
         if self.flags.has_star() {
-            self.apply_vowel_alternation(info, buf);
+            tracer.apply(buf, |buf| {
+                self.apply_vowel_alternation(info, buf)?;
+                Ok(Alternation::Star)
+            })?;
         }
         if self.flags.has_alternating_yo() {
-            self.apply_ye_yo_alternation(info, buf);
+            tracer.apply(buf, |buf| {
+                // Without an 'е' or 'ё' in the stem, the flag is ignored
+                match self.apply_ye_yo_alternation(info, buf) {
+                    Err(InflectError::NoYeInStem) => {
+                        on_warning(InflectWarning::IgnoredYeYoAlternation)
+                    },
+                    result => result?,
+                }
+                Ok(Alternation::YeYo)
+            })?;
         }
+        Ok(())
     }
 
-    pub fn apply_unique_alternation(self, info: DeclInfo, buf: &mut InflectionBuffer) {
+    pub fn apply_unique_alternation(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
+        self.apply_unique_alternation_kind(info, buf)?;
+        Ok(())
+    }
+    // Same as apply_unique_alternation, but also returns the kind of the alternation
+    fn apply_unique_alternation_kind(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<UniqueAlternation, InflectError> {
         use letters as lt;
 
-        match buf.stem_mut() {
+        // -мя (время, знамя, пламя, имя), мать, дочь
+        if let Some(extension) = StemExtension::detect(buf.stem(), info.gender) {
+            extension.apply(self.flags, info, buf);
+            return Ok(UniqueAlternation::Extension(extension));
+        }
+
+        let kind = match buf.stem_mut() {
             // -ин (боярин, крестьянин, землянин, господин)
             [.., lt::и, lt::н] => {
                 if info.is_plural() {
@@ -93,14 +665,15 @@ impl NounDeclension {
 
                     // Nominative - ending 'е', genitive - ending '', other - no changes
                     if let Some(is_nominative) = info.case.acc_is_nom(info) {
-                        buf.replace_ending(match is_nominative {
+                        buf.replace_ending_unchecked(match is_nominative {
                             // Don't override if (1) flag already did (господин - господа)
                             true if !self.flags.has_circled_one() => "е",
                             false => "",
-                            _ => return,
+                            _ => return Ok(UniqueAlternation::In),
                         });
                     }
                 }
+                UniqueAlternation::In
             },
             // -[оё]нок (утёнок, ребёнок, опёнок, мышонок, зайчонок)
             [.., yo @ (lt::о | lt::ё), n @ lt::н, lt::о, lt::к] => {
@@ -116,14 +689,15 @@ impl NounDeclension {
 
                     // Nominative - ending 'а', genitive - ending '', other - no changes
                     if let Some(is_nominative) = info.case.acc_is_nom(info) {
-                        buf.replace_ending(if is_nominative { "а" } else { "" });
+                        buf.replace_ending_unchecked(if is_nominative { "а" } else { "" });
                     }
                 } else {
                     // Remove the last vowel for non-nominative cases ('о', pre-last char)
                     if !info.case.is_nom_or_acc_inan(info) {
-                        buf.remove_from_stem((buf.stem_len - 4)..(buf.stem_len - 2));
+                        buf.remove_from_stem((buf.stem_len() - 4)..(buf.stem_len() - 2));
                     }
                 }
+                UniqueAlternation::Onok
             },
             // -ок (щенок, внучок)
             [.., preceding, o @ lt::о, k @ lt::к] => {
@@ -137,14 +711,15 @@ impl NounDeclension {
 
                     // Nominative - ending 'а', genitive - ending '', other - no changes
                     if let Some(is_nominative) = info.case.acc_is_nom(info) {
-                        buf.replace_ending(if is_nominative { "а" } else { "" });
+                        buf.replace_ending_unchecked(if is_nominative { "а" } else { "" });
                     }
                 } else {
                     // Remove the last vowel for non-nominative cases ('о', pre-last char)
                     if !info.case.is_nom_or_acc_inan(info) {
-                        buf.remove_from_stem((buf.stem_len - 4)..(buf.stem_len - 2));
+                        buf.remove_from_stem((buf.stem_len() - 4)..(buf.stem_len() - 2));
                     }
                 }
+                UniqueAlternation::Ok
             },
             // -[оё]ночек (телёночек, котёночек, мышоночек)
             [.., yo @ (lt::о | lt::ё), n @ lt::н, o @ lt::о, lt::ч, lt::е, lt::к] => {
@@ -160,9 +735,10 @@ impl NounDeclension {
                 } else {
                     // Remove the last vowel for non-nominative cases ('е', pre-last char)
                     if !info.case.is_nom_or_acc_inan(info) {
-                        buf.remove_from_stem((buf.stem_len - 4)..(buf.stem_len - 2));
+                        buf.remove_from_stem((buf.stem_len() - 4)..(buf.stem_len() - 2));
                     }
                 }
+                UniqueAlternation::Onochek
             },
             // -очек (щеночек, внучочек)
             [.., preceding, o @ lt::о, ch @ lt::ч, ye @ lt::е, lt::к] => {
@@ -178,147 +754,104 @@ impl NounDeclension {
                 } else {
                     // Remove the last vowel for non-nominative cases ('е', pre-last char)
                     if !info.case.is_nom_or_acc_inan(info) {
-                        buf.remove_from_stem((buf.stem_len - 4)..(buf.stem_len - 2));
+                        buf.remove_from_stem((buf.stem_len() - 4)..(buf.stem_len() - 2));
                     }
                 }
+                UniqueAlternation::Ochek
             },
-            // -м(я) (время, знамя, пламя, имя)
-            [.., lt::м] if matches!(info.gender, Gender::Neuter) => {
-                if info.is_plural() && !info.case.is_nom_or_acc_inan(info) {
-                    // The е/ё alternation is handled here, instead of in apply_ye_yo_alternation()
-                    let use_yo = info.is_plural()
-                        && self.flags.has_alternating_yo()
-                        && info.case.is_gen_or_acc_an(info);
-
-                    // Append '[её]н' to the stem
-                    buf.append_to_stem(if use_yo { "ён" } else { "ен" });
-                }
-                // Replace nominative ending 'ь' with 'я'
-                if info.is_singular()
-                    && let [ending @ letters::ь] = buf.ending_mut()
-                {
-                    *ending = letters::я;
-                }
-            },
-            _ => {
-                unimplemented!("Unknown unique stem alternation")
-            },
+            _ => return Err(InflectError::UnsupportedUniqueAlternation),
         };
+        Ok(kind)
     }
 
-    pub fn apply_vowel_alternation(self, info: DeclInfo, buf: &mut InflectionBuffer) {
+    pub fn apply_vowel_alternation(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
         let gender = info.gender();
 
         if gender == Gender::Masculine
             || gender == Gender::Feminine && self.stem_type == NounStemType::Type8
         {
-            let Some(last_vowel_index) = buf.stem().iter().rposition(|x| x.is_vowel()) else {
-                unimplemented!("No vowels found in stem for vowel alternation")
-            };
-
-            if info.is_singular() && info.case.is_nom_or_acc_inan(info)
-                || gender == Gender::Feminine && info.case == Case::Instrumental
-            {
-                return;
-            }
+            self.apply_fleeting_vowel_loss(info, buf)
+        } else {
+            self.apply_fleeting_vowel_insertion(info, buf)
+        }
+    }
 
-            let last_vowel = buf.stem()[last_vowel_index];
-            match last_vowel {
-                letters::о => {
-                    buf.remove_from_stem((last_vowel_index * 2)..((last_vowel_index + 1) * 2));
-                },
-                letters::е | letters::ё => {
-                    let preceding = buf.stem().get(last_vowel_index - 1);
-
-                    if let Some(preceding) = preceding {
-                        if preceding.is_vowel() {
-                            buf.stem_mut()[last_vowel_index] = letters::й;
-                        } else if self.stem_type == NounStemType::Type6
-                            || self.stem_type == NounStemType::Type3
-                                && preceding.is_non_sibilant_consonant()
-                            || *preceding == letters::л
-                        {
-                            buf.stem_mut()[last_vowel_index] = letters::ь;
-                        }
-                    } else {
-                        buf.remove_from_stem((last_vowel_index * 2)..((last_vowel_index + 1) * 2));
-                    }
-                },
-                _ => {
-                    unimplemented!("Unknown vowel alternation in stem")
-                },
-            }
-        } else if matches!(gender, Gender::Neuter | Gender::Feminine)
+    /// Removes the fleeting vowel of masculine and feminine stem type 8 nouns in all forms, except
+    /// for the nominative (and inanimate accusative) singular, and feminine instrumental singular
+    /// (сон - сна, вошь - вши - вошью). The irregular genitive plural with the zero ending (②)
+    /// keeps it too (чулок - чулки - чулок).
+    pub fn apply_fleeting_vowel_loss(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
+        if info.is_singular() && info.case.is_nom_or_acc_inan(info)
+            || info.gender == Gender::Feminine && info.case == Case::Instrumental
+        {
+            return Ok(());
+        }
+        if self.flags.has_circled_two()
             && info.is_plural()
             && info.case.acc_is_nom(info) == Some(false)
+            && buf.ending().is_empty()
         {
-            if self.stem_type == NounStemType::Type2
-                && matches!(self.stress, NounStress::B | NounStress::F)
-                || self.flags.has_circled_two()
-            {
-                return;
-            }
-
-            if self.stem_type == NounStemType::Type6
-                && let [.., last @ letters::ь] = buf.stem_mut()
-            {
-                *last = match self.stress.is_ending_stressed(info) {
-                    true => letters::е,
-                    false => letters::и,
-                };
-                return;
-            }
-
-            if gender == Gender::Feminine && matches!(buf.ending(), [letters::ь]) {
-                buf.replace_ending("");
-            }
-
-            let Some(last_cons_index) = buf.stem().iter().rposition(|x| x.is_consonant()) else {
-                unimplemented!("No consonants found in stem for vowel alternation")
-            };
+            return Ok(());
+        }
+        remove_fleeting_vowel(self.stem_type.into(), buf)
+    }
 
-            let last = buf.stem()[last_cons_index];
-            let pre_last = buf.stem_mut().get_mut(last_cons_index - 1);
+    /// Inserts the fleeting vowel of neuter and feminine nouns in genitive (and animate
+    /// accusative) plural (окно - окон, статья - статей, кошка - кошек).
+    pub fn apply_fleeting_vowel_insertion(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
+        if !(info.is_plural() && info.case.acc_is_nom(info) == Some(false)) {
+            return Ok(());
+        }
+        if self.stem_type == NounStemType::Type2
+            && matches!(self.stress, NounStress::B | NounStress::F)
+            || self.flags.has_circled_two()
+        {
+            return Ok(());
+        }
 
-            if let Some(pre_last @ &mut (letters::ь | letters::й)) = pre_last {
-                *pre_last = if last != letters::ц && self.stress.is_ending_stressed(info) {
-                    letters::ё
-                } else {
-                    letters::е
-                };
-                return;
+        if self.stem_type == NounStemType::Type6
+            && let [.., last @ letters::ь] = buf.stem_mut()
+        {
+            *last = match self.stress.is_ending_stressed(info) {
+                true => letters::е,
+                false => letters::и,
             };
+            return Ok(());
+        }
 
-            let pre_last = pre_last.copied();
-
-            if matches!(pre_last, Some(letters::к | letters::г | letters::х))
-                || matches!(last, letters::к | letters::г | letters::х)
-                    && pre_last.is_some_and(|x| x.is_sibilant())
-            {
-                buf.insert_between_last_two_stem_letters(letters::о);
-                return;
-            }
-
-            buf.insert_between_last_two_stem_letters(
-                if last != letters::ц && self.stress.is_ending_stressed(info) {
-                    if pre_last.is_some_and(|x| x.is_hissing()) { letters::о } else { letters::ё }
-                } else {
-                    letters::е
-                },
-            );
+        if info.gender == Gender::Feminine && matches!(buf.ending(), [letters::ь]) {
+            buf.replace_ending_unchecked("");
         }
+
+        insert_fleeting_vowel(self.stress.is_ending_stressed(info), buf)
     }
 
-    pub fn apply_ye_yo_alternation(self, info: DeclInfo, buf: &mut InflectionBuffer) {
+    pub fn apply_ye_yo_alternation(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<(), InflectError> {
         // The е/ё alternation is handled in apply_unique_alternation()
         if self.flags.has_circle() {
-            return;
+            return Ok(());
         }
 
         // If there's a 'ё' in the stem, check if it keeps its stress
         if let Some(yo) = buf.stem_mut().iter_mut().find(|x| matches!(**x, letters::ё)) {
             // SAFETY: yo is not modified until right before return
-            let yo: &mut Letter = unsafe { std::mem::transmute(yo) };
+            let yo: &mut Letter = unsafe { core::mem::transmute(yo) };
 
             // If stress falls on the ending, unstress the 'ё' in stem to just 'е'
             if self.stress.is_ending_stressed(info) && buf.ending().iter().any(|x| x.is_vowel()) {
@@ -336,10 +869,10 @@ impl NounDeclension {
 
             // Find the LAST unstressed 'е' in stem
             let Some(ye) = search_stem.iter_mut().rfind(|x| matches!(**x, letters::е)) else {
-                unimplemented!("е/ё not found in е/ё alternation")
+                return Err(InflectError::NoYeInStem);
             };
             // SAFETY: ye is not modified until right before return
-            let ye: &mut Letter = unsafe { std::mem::transmute(ye) };
+            let ye: &mut Letter = unsafe { core::mem::transmute(ye) };
 
             // If the ending doesn't have any vowels (can't receive stress), stress 'е' in stem into 'ё'
             if !buf.ending().iter().any(|x| x.is_vowel()) {
@@ -348,7 +881,11 @@ impl NounDeclension {
                 // Special case for f/f′/f″: 'е' in stem can only receive stress in first vowel position
                 // E.g. exceptions: железа (1f, ё) - же́лезы, середа (1f′, ё) - се́реды
                 if matches!(self.stress, NounStress::F | NounStress::Fp | NounStress::Fpp) {
-                    if buf.stem().iter().find(|x| x.is_vowel()).is_some_and(|x| std::ptr::eq(ye, x))
+                    if buf
+                        .stem()
+                        .iter()
+                        .find(|x| x.is_vowel())
+                        .is_some_and(|x| core::ptr::eq(ye, x))
                     {
                         *ye = letters::ё;
                     }
@@ -358,5 +895,967 @@ impl NounDeclension {
                 }
             }
         }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        FORM_MAX_LEN, STEM_MAX_LEN, categories::CaseAndNumber, declension::AnyStemType,
+        util::assert_snapshot,
+    };
+    use std::{format, string::ToString, vec};
+    use test::{Bencher, black_box};
+
+    fn inflect_paradigm(decl: NounDeclension, endings: Option<&PrecomputedEndings>) {
+        for key in CaseAndNumber::VALUES {
+            let (case, number) = key.parts();
+            let info =
+                DeclInfo { case, number, gender: Gender::Feminine, animacy: Animacy::Inanimate };
+
+            let mut buf = InflectionBuffer::from_stem("стать").unwrap();
+            match endings {
+                Some(endings) => decl.inflect_precomputed(endings, info, &mut buf),
+                None => decl.inflect(info, &mut buf),
+            }
+            .unwrap();
+            black_box(buf.as_str());
+        }
+    }
+
+    fn noun<'a>(stem: &'a str, decl: &str, gender: Gender) -> Noun<'a> {
+        let info = NounInfo {
+            declension: Some(decl.parse().unwrap()),
+            declension_gender: gender,
+            gender: gender.into(),
+            animacy: Animacy::Inanimate,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+
+    fn paradigm(stem: &str, decl: &str, gender: Gender) -> [String; 12] {
+        let decl: NounDeclension = decl.parse().unwrap();
+
+        CaseAndNumber::VALUES.map(|key| {
+            let (case, number) = key.parts();
+            let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+
+            let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+            decl.inflect(info, &mut buf).unwrap();
+            buf.as_str().to_owned()
+        })
+    }
+
+    #[test]
+    fn ye_yo_in_endings() {
+        // Stressed endings of neuter stem types 6 and 7 with 'ё'
+        #[rustfmt::skip]
+        assert_eq!(paradigm("копь", "6*d", Gender::Neuter), [
+            "копьё", "копья", "копью", "копьё", "копьём", "копье",
+            "копья", "копий", "копьям", "копья", "копьями", "копьях",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(paradigm("бель", "6*b", Gender::Neuter), [
+            "бельё", "белья", "белью", "бельё", "бельём", "белье",
+            "белья", "белей", "бельям", "белья", "бельями", "бельях",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(paradigm("жить", "6*b", Gender::Neuter), [
+            "житьё", "житья", "житью", "житьё", "житьём", "житье",
+            "житья", "житей", "житьям", "житья", "житьями", "житьях",
+        ]);
+        // The genitive plural остриёв is irregular (②)
+        #[rustfmt::skip]
+        assert_eq!(paradigm("остри", "7b②", Gender::Neuter), [
+            "остриё", "острия", "острию", "остриё", "остриём", "острие",
+            "острия", "остриёв", "остриям", "острия", "остриями", "остриях",
+        ]);
+    }
+
+    #[test]
+    fn circled_digits() {
+        let plural = |stem: &str, decl: &str, gender: Gender| {
+            let forms = paradigm(stem, decl, gender);
+            [forms[6].clone(), forms[7].clone()]
+        };
+
+        // ①: masculine nouns take the neuter nominative plural ending, and vice versa
+        assert_eq!(plural("доктор", "1c①", Gender::Masculine), ["доктора", "докторов"]);
+        assert_eq!(plural("берег", "3c①", Gender::Masculine), ["берега", "берегов"]);
+        assert_eq!(plural("учител", "2c①", Gender::Masculine), ["учителя", "учителей"]);
+        assert_eq!(plural("яблок", "3a①", Gender::Neuter), ["яблоки", "яблок"]);
+
+        // ②: masculine nouns take the neuter genitive plural ending, and vice versa
+        assert_eq!(plural("облак", "3c", Gender::Neuter), ["облака", "облак"]);
+        assert_eq!(plural("облак", "3c②", Gender::Neuter), ["облака", "облаков"]);
+        assert_eq!(plural("солдат", "1a②", Gender::Masculine), ["солдаты", "солдат"]);
+        assert_eq!(plural("пол", "2c②", Gender::Feminine), ["поли", "полей"]);
+        // The fleeting vowel is kept with the zero ending, and not inserted otherwise
+        assert_eq!(plural("чулок", "3*b②", Gender::Masculine), ["чулки", "чулок"]);
+        assert_eq!(plural("облак", "3*c②", Gender::Neuter), ["облака", "облаков"]);
+
+        // ①②: both endings are irregular
+        assert_eq!(plural("глаз", "1c①②", Gender::Masculine), ["глаза", "глаз"]);
+
+        // The animate accusative plural follows the genitive one
+        let noun = Noun::new("солдат", "мо 1a②".parse().unwrap());
+        assert_eq!(noun.inflect_to_string(CaseEx::Accusative, Number::Plural).unwrap(), "солдат");
+        let noun = Noun::new("доктор", "мо 1c①".parse().unwrap());
+        assert_eq!(noun.inflect_to_string(CaseEx::Accusative, Number::Plural).unwrap(), "докторов");
+        let noun = Noun::new("берег", "м 3c①".parse().unwrap());
+        assert_eq!(noun.inflect_to_string(CaseEx::Accusative, Number::Plural).unwrap(), "берега");
+
+        // The precomputed endings include the irregular ones
+        let decl: NounDeclension = "1c①②".parse().unwrap();
+        let endings = decl.precompute(Gender::Masculine, Animacy::Inanimate);
+        assert_eq!(endings.get_by(CaseAndNumber::NominativePlural), "а");
+        assert_eq!(endings.get_by(CaseAndNumber::GenitivePlural), "");
+    }
+
+    #[test]
+    fn vowel_alternation_gen_pl() {
+        let assert = |stem: &str, decl: &str, gender: Gender, expected: &str| {
+            let decl: NounDeclension = decl.parse().unwrap();
+            let (case, number) = (Case::Genitive, Number::Plural);
+            let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+
+            let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+            decl.inflect(info, &mut buf).unwrap();
+            assert_eq!(buf.as_str(), expected);
+        };
+
+        // 'о' after and before к/г/х, unless preceded by a hissing consonant
+        assert("сказк", "3*a", Gender::Feminine, "сказок");
+        assert("кукл", "1*a", Gender::Feminine, "кукол");
+        assert("окн", "1*d", Gender::Neuter, "окон");
+        assert("кошк", "3*a", Gender::Feminine, "кошек");
+        assert("ложк", "3*a", Gender::Feminine, "ложек");
+        assert("кишк", "3*b", Gender::Feminine, "кишок");
+        // 'е'/'ё' otherwise
+        assert("сосн", "1*d", Gender::Feminine, "сосен");
+        assert("чашк", "3*a", Gender::Feminine, "чашек");
+    }
+
+    #[test]
+    fn vowel_alternation_paradigms() {
+        use Gender::*;
+
+        #[rustfmt::skip]
+        let nouns = [
+            // Fleeting vowel loss in masculine nouns
+            ("сон", "1*b", Masculine),
+            ("лев", "1*b", Masculine),
+            ("боец", "5*b", Masculine),
+            ("кусок", "3*b", Masculine),
+            ("зверёк", "3*b", Masculine),
+            ("ручеёк", "3*b", Masculine),
+            // ...and in feminine stem type 8, except for instrumental singular
+            ("вош", "8*b", Feminine),
+            // Fleeting vowel insertion in neuter and feminine genitive plural
+            ("окн", "1*d", Neuter),
+            ("письм", "1*d", Neuter),
+            ("кольц", "5*d", Neuter),
+            ("полотенц", "5*a", Neuter),
+            ("ущель", "6*a", Neuter),
+            ("кошк", "3*a", Feminine),
+            ("чайк", "3*a", Feminine),
+            ("серьг", "3*f", Feminine),
+            ("весн", "1*d", Feminine),
+            ("песн", "2*a", Feminine),
+            // Stem type 2 with stress b/f keeps the ending 'ей', and 6 replaces 'ь'
+            ("ступн", "2*b", Feminine),
+            ("стать", "6*b", Feminine),
+        ];
+
+        let paradigms =
+            nouns.map(|(stem, decl, gender)| noun(stem, decl, gender).format_paradigm().unwrap());
+        assert_snapshot("noun_vowel_alternation", &paradigms.join("\n"));
+    }
+
+    fn apply(
+        method: fn(NounDeclension, DeclInfo, &mut InflectionBuffer) -> Result<(), InflectError>,
+        (stem, ending): (&str, &str),
+        decl: &str,
+        info: DeclInfo,
+    ) -> String {
+        let decl: NounDeclension = decl.parse().unwrap();
+        let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+        buf.append_to_ending(ending);
+        method(decl, info, &mut buf).unwrap();
+        buf.as_str().to_owned()
+    }
+
+    #[test]
+    fn fleeting_vowel_loss() {
+        let loss = NounDeclension::apply_fleeting_vowel_loss;
+        let info = |case, number, gender, animacy| DeclInfo { case, number, gender, animacy };
+        use {Animacy::*, Case::*, Gender::*, Number::*};
+
+        assert_eq!(
+            apply(loss, ("сон", "а"), "1*b", info(Genitive, Singular, Masculine, Inanimate)),
+            "сна"
+        );
+        assert_eq!(
+            apply(loss, ("сон", "ы"), "1*b", info(Nominative, Plural, Masculine, Inanimate)),
+            "сны"
+        );
+        assert_eq!(
+            apply(loss, ("лев", "а"), "1*b", info(Accusative, Singular, Masculine, Animate)),
+            "льва"
+        );
+        assert_eq!(
+            apply(loss, ("вош", "и"), "8*b", info(Dative, Singular, Feminine, Inanimate)),
+            "вши"
+        );
+
+        // Nominative and inanimate accusative singular keep the vowel
+        assert_eq!(
+            apply(loss, ("сон", ""), "1*b", info(Nominative, Singular, Masculine, Inanimate)),
+            "сон"
+        );
+        assert_eq!(
+            apply(loss, ("сон", ""), "1*b", info(Accusative, Singular, Masculine, Inanimate)),
+            "сон"
+        );
+        assert_eq!(
+            apply(loss, ("вош", "ь"), "8*b", info(Accusative, Singular, Feminine, Inanimate)),
+            "вошь"
+        );
+        // Feminine instrumental singular keeps the vowel
+        assert_eq!(
+            apply(loss, ("вош", "ью"), "8*b", info(Instrumental, Singular, Feminine, Inanimate)),
+            "вошью"
+        );
+    }
+
+    #[test]
+    fn fleeting_vowel_insertion() {
+        let insertion = NounDeclension::apply_fleeting_vowel_insertion;
+        let info = |case, number, gender, animacy| DeclInfo { case, number, gender, animacy };
+        use {Animacy::*, Case::*, Gender::*, Number::*};
+
+        assert_eq!(
+            apply(insertion, ("окн", ""), "1*d", info(Genitive, Plural, Neuter, Inanimate)),
+            "окон"
+        );
+        assert_eq!(
+            apply(insertion, ("кукл", ""), "1*a", info(Accusative, Plural, Feminine, Animate)),
+            "кукол"
+        );
+        assert_eq!(
+            apply(insertion, ("песн", "ь"), "2*a", info(Genitive, Plural, Feminine, Inanimate)),
+            "песен"
+        );
+        assert_eq!(
+            apply(insertion, ("стать", "й"), "6*b", info(Genitive, Plural, Feminine, Inanimate)),
+            "статей"
+        );
+        assert_eq!(
+            apply(insertion, ("ущель", "й"), "6*a", info(Genitive, Plural, Neuter, Inanimate)),
+            "ущелий"
+        );
+
+        // Only genitive and animate accusative plural get the inserted vowel
+        assert_eq!(
+            apply(insertion, ("окн", "а"), "1*d", info(Genitive, Singular, Neuter, Inanimate)),
+            "окна"
+        );
+        assert_eq!(
+            apply(insertion, ("окн", "а"), "1*d", info(Accusative, Plural, Neuter, Inanimate)),
+            "окна"
+        );
+        assert_eq!(
+            apply(insertion, ("окн", "ах"), "1*d", info(Prepositional, Plural, Neuter, Inanimate)),
+            "окнах"
+        );
+        // Stem type 2 with stress b/f keeps the ending 'ей' instead
+        assert_eq!(
+            apply(insertion, ("ступн", "ей"), "2*b", info(Genitive, Plural, Feminine, Inanimate)),
+            "ступней"
+        );
+        assert_eq!(
+            apply(insertion, ("ступн", "ей"), "2*f", info(Genitive, Plural, Feminine, Inanimate)),
+            "ступней"
+        );
+        // Irregular genitive plural (②) doesn't get the inserted vowel
+        assert_eq!(
+            apply(insertion, ("окн", ""), "1*d②", info(Genitive, Plural, Neuter, Inanimate)),
+            "окн"
+        );
+    }
+
+    #[test]
+    fn secondary_cases() {
+        use {SecondaryCasePolicy::*, SecondaryCaseSupport as Support};
+
+        let noun = |stem, decl: &str, has_partitive, has_locative| {
+            let info = NounInfo {
+                declension: (decl != "0").then(|| decl.parse().unwrap()),
+                declension_gender: Gender::Masculine,
+                gender: GenderEx::Masculine,
+                animacy: Animacy::Inanimate,
+                tantum: None,
+                has_partitive,
+                has_locative,
+            };
+            Noun::new(stem, info)
+        };
+        let prt =
+            |noun: &Noun, policy| noun.try_inflect(CaseEx::Partitive, Number::Singular, policy);
+        let loc =
+            |noun: &Noun, policy| noun.try_inflect(CaseEx::Locative, Number::Singular, policy);
+        let err = |case, support| Err(InflectError::from(UnsupportedCaseError { case, support }));
+
+        // Locative only: сад - в саду, but сада
+        let garden = noun("сад", "1c", false, true);
+        assert_eq!(garden.supports(CaseEx::Partitive), Support::Fallback);
+        assert_eq!(garden.supports(CaseEx::Locative), Support::Native);
+        assert_eq!(prt(&garden, Fallback).as_deref(), Ok("сада"));
+        assert_eq!(prt(&garden, RequireNative), err(CaseEx::Partitive, Support::Fallback));
+        assert_eq!(loc(&garden, RequireNative).as_deref(), Ok("саду"));
+        let plural = garden.try_inflect(CaseEx::Locative, Number::Plural, RequireNative);
+        assert_eq!(plural.as_deref(), Ok("садах"));
+
+        // Partitive only: сахар - сахару, but в сахаре
+        let sugar = noun("сахар", "1a", true, false);
+        assert_eq!(sugar.supports(CaseEx::Partitive), Support::Native);
+        assert_eq!(sugar.supports(CaseEx::Locative), Support::Fallback);
+        assert_eq!(prt(&sugar, RequireNative).as_deref(), Ok("сахару"));
+        assert_eq!(loc(&sugar, Fallback).as_deref(), Ok("сахаре"));
+        assert_eq!(loc(&sugar, RequireNative), err(CaseEx::Locative, Support::Fallback));
+
+        // Both: чай - чаю, в чаю
+        let tea = noun("ча", "6c", true, true);
+        assert_eq!(tea.supports(CaseEx::Partitive), Support::Native);
+        assert_eq!(tea.supports(CaseEx::Locative), Support::Native);
+        assert_eq!(prt(&tea, RequireNative).as_deref(), Ok("чаю"));
+        assert_eq!(loc(&tea, RequireNative).as_deref(), Ok("чаю"));
+
+        // Neither: стол - стола, в столе
+        let table = noun("стол", "1b", false, false);
+        assert_eq!(table.supports(CaseEx::Partitive), Support::Fallback);
+        assert_eq!(table.supports(CaseEx::Locative), Support::Fallback);
+        assert_eq!(prt(&table, Fallback).as_deref(), Ok("стола"));
+        assert_eq!(loc(&table, Fallback).as_deref(), Ok("столе"));
+        assert_eq!(prt(&table, RequireNative), err(CaseEx::Partitive, Support::Fallback));
+        assert_eq!(loc(&table, RequireNative), err(CaseEx::Locative, Support::Fallback));
+
+        // Main cases and translative are always supported
+        assert_eq!(table.supports(CaseEx::Genitive), Support::Native);
+        assert_eq!(table.supports(CaseEx::Translative), Support::Native);
+
+        // Indeclinable nouns don't have secondary case forms
+        let coffee = noun("кофе", "0", false, false);
+        assert_eq!(coffee.supports(CaseEx::Locative), Support::None);
+        assert_eq!(loc(&coffee, Fallback).as_deref(), Ok("кофе"));
+        assert_eq!(loc(&coffee, RequireNative), err(CaseEx::Locative, Support::None));
+    }
+
+    #[test]
+    fn secondary_case_stress() {
+        use {CaseEx::*, Number::*};
+
+        let forms = |stem, info: &str| {
+            let noun = Noun::new(stem, info.parse().unwrap());
+            [Dative, Partitive, Locative, Prepositional]
+                .map(|case| noun.inflect_stressed(case, Singular).unwrap())
+        };
+
+        // Locative forms are always stressed on the ending
+        assert_eq!(forms("лес", "м 1c П2"), ["ле́су", "ле́са", "лесу́", "ле́се"]);
+        assert_eq!(forms("мост", "м 1c П2"), ["мо́сту", "мо́ста", "мосту́", "мо́сте"]);
+        assert_eq!(forms("сад", "м 1c П₂"), ["са́ду", "са́да", "саду́", "са́де"]);
+        assert_eq!(forms("тен", "ж 8e П2"), ["те́ни", "те́ни", "тени́", "те́ни"]);
+        // The е/ё alternation follows the stress of the locative (мёд - в меду́)
+        assert_eq!(forms("мёд", "м 1c, ё Р2 П2"), ["мёду", "мёду", "меду́", "мёде"]);
+
+        // Partitive forms are stressed like the dative
+        assert_eq!(forms("ча", "м 6c Р2 П2"), ["ча́ю", "ча́ю", "чаю́", "ча́е"]);
+        assert_eq!(forms("са́хар", "м 1a Р2"), ["са́хару", "са́хару", "са́харе", "са́харе"]);
+
+        // The plain forms are the same, except for the е/ё alternation
+        let honey = Noun::new("мёд", "м 1c, ё Р2 П2".parse().unwrap());
+        assert_eq!(honey.inflect_to_string(Locative, Singular).unwrap(), "меду");
+        assert_eq!(honey.inflect_to_string(Prepositional, Singular).unwrap(), "мёде");
+    }
+
+    #[test]
+    fn indeclinable() {
+        // Zaliznyak's "0" marks indeclinable nouns: пальто, кофе, кенгуру
+        for entry in ["с 0", "м 0", "мо-жо 0", "мн. с 0"] {
+            let info: NounInfo = entry.parse().unwrap();
+            assert_eq!(info.declension, None);
+            assert_eq!(info.to_string(), entry);
+
+            let noun = Noun::new("пальто", info);
+            assert!(noun.forms().all(|x| x.unwrap().2 == "пальто"), "{entry}");
+            for case in CaseEx::VALUES {
+                for number in Number::VALUES {
+                    let form = noun.inflect_stressed(case, number);
+                    assert_eq!(form.as_deref(), Ok("пальто"), "{entry} {case} {number}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tantum() {
+        use {CaseEx::*, Number::*, SecondaryCasePolicy::Fallback};
+
+        // Plurale tantum: ножницы
+        let scissors = Noun::new("ножниц", "мн. ж 5a".parse().unwrap());
+        assert!(scissors.info.is_plurale_tantum() && !scissors.info.is_singulare_tantum());
+        assert_eq!(scissors.inflect_to_string(Genitive, Singular).as_deref(), Ok("ножниц"));
+        assert_eq!(scissors.inflect_to_string(Dative, Plural).as_deref(), Ok("ножницам"));
+        assert_eq!(scissors.try_inflect(Dative, Plural, Fallback).as_deref(), Ok("ножницам"));
+        assert_eq!(scissors.try_inflect(Translative, Singular, Fallback).as_deref(), Ok("ножницы"));
+        let err = Err(InflectError::Tantum(TantumError::PluralOnly));
+        assert_eq!(scissors.try_inflect(Dative, Singular, Fallback), err);
+        assert_eq!(scissors.try_inflect(Nominative, Singular, Fallback), err);
+
+        // Singulare tantum: молоко (not marked in Zaliznyak's entries)
+        let mut milk = Noun::new("молок", "с 1b".parse().unwrap());
+        assert!(!milk.info.is_singulare_tantum() && !milk.info.is_plurale_tantum());
+        milk.info.tantum = Some(Singular);
+        assert!(milk.info.is_singulare_tantum() && !milk.info.is_plurale_tantum());
+        assert_eq!(milk.inflect_to_string(Instrumental, Plural).as_deref(), Ok("молоком"));
+        assert_eq!(milk.try_inflect(Instrumental, Singular, Fallback).as_deref(), Ok("молоком"));
+        let err = Err(InflectError::Tantum(TantumError::SingularOnly));
+        assert_eq!(milk.try_inflect(Instrumental, Plural, Fallback), err);
+        assert_eq!(milk.try_inflect(Translative, Singular, Fallback), err);
+    }
+
+    #[test]
+    fn capitalization() {
+        use {CaseEx::*, Gender::*, Number::*};
+
+        let moscow = noun("Москв", "1d", Feminine);
+        assert_eq!(moscow.inflect_to_string(Prepositional, Singular).unwrap(), "Москве");
+        assert_eq!(moscow.inflect_to_string(Accusative, Singular).unwrap(), "Москву");
+        assert_eq!(moscow.inflect_stressed(Dative, Singular).unwrap(), "Москве́");
+
+        // Alternations match the letters regardless of their case
+        let lev = noun("Лев", "1*b", Masculine);
+        assert_eq!(lev.inflect_to_string(Dative, Singular).unwrap(), "Льву");
+        let lev = noun("ЛЕВ", "1*b", Masculine);
+        assert_eq!(lev.inflect_to_string(Genitive, Singular).unwrap(), "ЛЬВА");
+        let ivan = noun("Иван", "1a", Masculine);
+        assert_eq!(ivan.inflect_to_string(Instrumental, Plural).unwrap(), "Иванами");
+    }
+
+    #[test]
+    fn lemma() {
+        use Gender::*;
+
+        let lemmas = [
+            (noun("стол", "1b", Masculine), "стол"),
+            (noun("ребёнок", "3°a", Masculine), "ребёнок"),
+            (noun("врем", "8°c, ё", Neuter), "время"),
+            (noun("звезд", "1d, ё", Feminine), "звезда"),
+        ];
+        for (noun, expected) in lemmas {
+            assert_eq!(noun.lemma().as_deref(), Ok(expected));
+        }
+
+        // Plurale tantum nouns are lemmatized in the plural
+        let scissors = Noun::new("ножниц", "мн. ж 5a".parse().unwrap());
+        assert_eq!(scissors.lemma().as_deref(), Ok("ножницы"));
+        let pants = Noun::new("брюк", "мн. ж 3a".parse().unwrap());
+        assert_eq!(pants.lemma().as_deref(), Ok("брюки"));
+    }
+
+    #[test]
+    fn inflect_to_string() {
+        use {Animacy::*, CaseEx::*, Gender::*, Number::*};
+
+        let noun = |stem, decl: &str, gender: Gender, animacy| {
+            let info = NounInfo {
+                declension: (decl != "0").then(|| decl.parse().unwrap()),
+                declension_gender: gender,
+                gender: gender.into(),
+                animacy,
+                tantum: None,
+                has_partitive: false,
+                has_locative: false,
+            };
+            Noun::new(stem, info)
+        };
+
+        let table = [
+            (noun("стол", "1b", Masculine, Inanimate), Dative, Plural, "столам"),
+            (noun("кон", "2b", Masculine, Animate), Accusative, Singular, "коня"),
+            (noun("кошк", "3*a", Feminine, Animate), Accusative, Plural, "кошек"),
+            (noun("нож", "4b", Masculine, Inanimate), Instrumental, Singular, "ножом"),
+            (noun("кольц", "5*d", Neuter, Inanimate), Genitive, Plural, "колец"),
+            (noun("стать", "6*b", Feminine, Inanimate), Instrumental, Singular, "статьёй"),
+            (noun("собрани", "7a", Neuter, Inanimate), Prepositional, Singular, "собрании"),
+            (noun("тетрад", "8a", Feminine, Inanimate), Instrumental, Singular, "тетрадью"),
+            (noun("тетрад", "8a", Feminine, Inanimate), Genitive, Plural, "тетрадей"),
+            (noun("врем", "8°c, ё", Neuter, Inanimate), Genitive, Plural, "времён"),
+        ];
+
+        let mut buf = InflectionBuffer::default();
+        for (noun, case, number, expected) in table {
+            assert_eq!(noun.inflect_to_string(case, number).as_deref(), Ok(expected));
+            assert_eq!(noun.inflect_fmt(case, number).to_string(), expected);
+            assert_eq!(noun.inflect_to(case, number, &mut buf), Ok(expected));
+        }
+
+        // The tantum number overrides the requested one
+        let mut scissors = noun("ножниц", "5a", Feminine, Inanimate);
+        scissors.info.tantum = Some(Plural);
+        assert_eq!(scissors.inflect_to_string(Dative, Singular).as_deref(), Ok("ножницам"));
+
+        // Indeclinable nouns are returned as is
+        let coffee = noun("кофе", "0", Masculine, Inanimate);
+        assert_eq!(coffee.inflect_to_string(Instrumental, Plural).as_deref(), Ok("кофе"));
+        assert_eq!(coffee.inflect_to(Dative, Singular, &mut buf), Ok("кофе"));
+    }
+
+    #[test]
+    fn reflexive_adjective_declension() {
+        // Substantivized participles in -ся: трудящийся, учащаяся
+        let noun = Noun::new("трудящ", "мо п 4a, -ся".parse().unwrap());
+        let forms = noun.forms().map(|x| x.unwrap().2).collect::<Vec<_>>();
+        assert_eq!(forms, [
+            "трудящийся",
+            "трудящегося",
+            "трудящемуся",
+            "трудящегося",
+            "трудящимся",
+            "трудящемся",
+            "трудящиеся",
+            "трудящихся",
+            "трудящимся",
+            "трудящихся",
+            "трудящимися",
+            "трудящихся",
+        ]);
+
+        let noun = Noun::new("учащ", "жо п 4a, -ся".parse().unwrap());
+        let forms = noun.forms().map(|x| x.unwrap().2).collect::<Vec<_>>();
+        assert_eq!(forms, [
+            "учащаяся",
+            "учащейся",
+            "учащейся",
+            "учащуюся",
+            "учащейся",
+            "учащейся",
+            "учащиеся",
+            "учащихся",
+            "учащимся",
+            "учащихся",
+            "учащимися",
+            "учащихся",
+        ]);
+    }
+
+    #[test]
+    fn exceptions() {
+        use {CaseEx::*, Number::*};
+
+        // год (м 1c): the locative is irregular, but the prepositional isn't
+        let exceptions = [(Locative, Singular, "году́")];
+        let year = Noun::new("год", "м 1c".parse().unwrap()).with_exceptions(&exceptions);
+        assert_eq!(year.inflect_to_string(Locative, Singular).unwrap(), "году");
+        assert_eq!(year.inflect_stressed(Locative, Singular).unwrap(), "году́");
+        assert_eq!(year.inflect_to_string(Prepositional, Singular).unwrap(), "годе");
+        assert_eq!(year.inflect_to_string(Locative, Plural).unwrap(), "годах");
+
+        // человек (мо 3a): suppletive plural forms
+        let exceptions = [
+            (Nominative, Plural, "люди"),
+            (Genitive, Plural, "людей"),
+            (Dative, Plural, "людям"),
+            (Instrumental, Plural, "людьми"),
+            (Prepositional, Plural, "людях"),
+        ];
+        let person = Noun::new("человек", "мо 3a".parse().unwrap()).with_exceptions(&exceptions);
+        let forms = person.forms().map(|x| x.unwrap().2).collect::<Vec<_>>();
+        assert_eq!(forms, [
+            "человек",
+            "человека",
+            "человеку",
+            "человека",
+            "человеком",
+            "человеке",
+            "люди",
+            "людей",
+            "людям",
+            "людей",
+            "людьми",
+            "людях",
+        ]);
+        // The secondary cases take the forms of the main cases
+        assert_eq!(person.inflect_to_string(Partitive, Plural).unwrap(), "людей");
+        assert_eq!(person.inflect_to_string(Locative, Plural).unwrap(), "людях");
+        assert_eq!(person.inflect_to_string(Translative, Plural).unwrap(), "люди");
+        assert_eq!(person.lemma().unwrap(), "человек");
+
+        // The accusative takes the nominative form of inanimate nouns
+        let exceptions = [(Nominative, Plural, "колёса")];
+        let wheel = Noun::new("колес", "с 1b".parse().unwrap()).with_exceptions(&exceptions);
+        assert_eq!(wheel.inflect_to_string(Accusative, Plural).unwrap(), "колёса");
+        assert_eq!(wheel.inflect_to_string(Genitive, Plural).unwrap(), "колес");
+
+        // The noun's own partitive and locative forms aren't taken from the main cases
+        let exceptions = [(Genitive, Singular, "чая")];
+        let mut tea = Noun::new("ча", "м 6c".parse().unwrap()).with_exceptions(&exceptions);
+        assert_eq!(tea.exception(Partitive, Singular), Some("чая"));
+        tea.info.has_partitive = true;
+        assert_eq!(tea.exception(Partitive, Singular), None);
+        assert_eq!(tea.inflect_to_string(Partitive, Singular).unwrap(), "чаю");
+
+        // Exceptions of the tantum number are used for both numbers
+        let exceptions = [(Genitive, Plural, "саней")];
+        let sleigh = Noun::new("сан", "мн. ж 2e".parse().unwrap()).with_exceptions(&exceptions);
+        assert_eq!(sleigh.inflect_to_string(Genitive, Singular).unwrap(), "саней");
+    }
+
+    #[test]
+    fn warnings() {
+        use {CaseEx::*, Number::*};
+        let warn = InflectWarning::IgnoredYeYoAlternation;
+
+        // The е/ё alternation flag is ignored in all forms, if there's no 'е' or 'ё' in the stem
+        let noun = Noun::new("джинс", "м 1a, ё".parse().unwrap());
+        for (case, number) in CaseAndNumber::VALUES.map(|x| x.parts()) {
+            let expected = noun.inflect_to_string(case.into(), number).unwrap();
+            let result = noun.inflect_with_warnings(case.into(), number);
+            assert_eq!(result, Ok((expected, vec![warn])), "{case:?} {number:?}");
+        }
+        let noun = Noun::new("Лун", "ж 1d, ё".parse().unwrap());
+        assert_eq!(noun.inflect_with_warnings(Dative, Plural), Ok(("Лунам".into(), vec![warn])));
+
+        // No warnings, if the alternation was applied, or the flag isn't set
+        let noun = Noun::new("звезд", "ж 1d, ё".parse().unwrap());
+        assert_eq!(noun.inflect_with_warnings(Genitive, Plural), Ok(("звёзд".into(), vec![])));
+        let noun = Noun::new("стол", "м 1b".parse().unwrap());
+        assert_eq!(noun.inflect_with_warnings(Genitive, Plural), Ok(("столов".into(), vec![])));
+
+        // Errors are still returned as errors
+        let noun = Noun::new("штр", "м 1*a, ё".parse().unwrap());
+        assert_eq!(
+            noun.inflect_with_warnings(Genitive, Singular),
+            Err(InflectError::NoVowelInStem)
+        );
+
+        // The declension reports the warnings through the callback
+        let decl: NounDeclension = "1a, ё".parse().unwrap();
+        let info = DeclInfo::new(Case::Genitive, Plural, Gender::Masculine, Animacy::Inanimate);
+        let mut buf = InflectionBuffer::from_stem("стол").unwrap();
+        let mut warnings = vec![];
+        decl.inflect_with_warnings(info, &mut buf, |x| warnings.push(x)).unwrap();
+        assert_eq!((buf.as_str(), warnings.as_slice()), ("столов", [warn].as_slice()));
+    }
+
+    #[test]
+    fn malformed_stems() {
+        use {CaseEx::*, Number::*};
+
+        let inflect = |stem, info: &str, case, number| {
+            let noun = Noun::new(stem, info.parse().unwrap());
+            noun.inflect_to_string(case, number)
+        };
+
+        // Fleeting vowel loss and insertion (*)
+        assert_eq!(inflect("штр", "м 1*a", Genitive, Singular), Err(InflectError::NoVowelInStem));
+        assert_eq!(inflect("штр", "м 1*a", Nominative, Singular).as_deref(), Ok("штр"));
+        let err = InflectError::UnknownFleetingVowel;
+        assert_eq!(inflect("слан", "м 1*a", Genitive, Singular), Err(err));
+        let err = InflectError::NoConsonantCluster;
+        assert_eq!(inflect("к", "с 3*a", Genitive, Plural), Err(err));
+        // Alternating ё is ignored without an 'е' in the stem, and unique alternations (ё, °)
+        assert_eq!(inflect("стол", "м 1a, ё", Genitive, Plural).as_deref(), Ok("столов"));
+        let err = InflectError::UnsupportedUniqueAlternation;
+        assert_eq!(inflect("стол", "м 1°a", Genitive, Plural), Err(err));
+
+        // Pronoun declensions with the star flag require a vowel in the stem too
+        let err = InflectError::NoVowelInStem;
+        assert_eq!(inflect("чр", "м мс 6*b", Genitive, Singular), Err(err));
+
+        // Stems with anything other than Cyrillic letters aren't inflected at all, since the
+        // alternations could split the other characters into invalid UTF-8
+        let err = InflectError::InvalidStem(StemError { ch: '€', index: 4 });
+        assert_eq!(inflect("нй€", "с 1*d", Genitive, Plural), Err(err));
+        let err = InflectError::InvalidStem(StemError { ch: 'o', index: 2 });
+        assert_eq!(inflect("кoт", "мо 1a", Genitive, Singular), Err(err));
+        let mut out = [0; FORM_MAX_LEN];
+        let decl: NounDeclension = "1*d".parse().unwrap();
+        let info = DeclInfo {
+            case: Case::Genitive,
+            number: Plural,
+            gender: Gender::Neuter,
+            animacy: Animacy::Inanimate,
+        };
+        assert!(decl.inflect_into(info, "нй€", &mut out).is_err());
+    }
+
+    #[test]
+    fn pronoun_declension() {
+        // Surnames in -ов and -ин are declined like possessive pronouns
+        let noun = Noun::new("Пушкин", "жо мс 1a".parse().unwrap());
+        let forms: Vec<_> = noun.forms().map(|x| x.unwrap().2).collect();
+        #[rustfmt::skip]
+        assert_eq!(forms, [
+            "Пушкина", "Пушкиной", "Пушкиной", "Пушкину", "Пушкиной", "Пушкиной",
+            "Пушкины", "Пушкиных", "Пушкиным", "Пушкиных", "Пушкиными", "Пушкиных",
+        ]);
+
+        let noun = Noun::new("Иванов", "мо мс 1a".parse().unwrap());
+        assert_eq!(noun.inflect_to_string(CaseEx::Genitive, Number::Singular).unwrap(), "Иванова");
+        assert_eq!(
+            noun.inflect_to_string(CaseEx::Instrumental, Number::Singular).unwrap(),
+            "Ивановым"
+        );
+        assert_eq!(noun.inflect_to_string(CaseEx::Accusative, Number::Plural).unwrap(), "Ивановых");
+    }
+
+    #[test]
+    fn declension_gender() {
+        // The declension gender overrides the gender in the endings, but not in the agreement
+        let inflect = |info: &str, case, number| {
+            let noun = Noun::new("ноч", info.parse().unwrap());
+            noun.inflect_to_string(case, number).unwrap()
+        };
+        use {CaseEx::*, Number::*};
+        assert_eq!(inflect("м 8b", Instrumental, Singular), "ночём");
+        assert_eq!(inflect("м <ж 8b>", Instrumental, Singular), "ночью");
+        assert_eq!(inflect("м <ж 8b>", Nominative, Singular), "ночь");
+        assert_eq!(inflect("м <ж 8b>", Dative, Plural), "ночам");
+
+        let info: NounInfo = "мо <жо 1a>".parse().unwrap();
+        assert_eq!((info.gender, info.declension_gender), (GenderEx::Masculine, Gender::Feminine));
+        let noun = Noun::new("мужчин", info);
+        assert_eq!(noun.inflect_to_string(Accusative, Singular).unwrap(), "мужчину");
+        assert_eq!(noun.inflect_to_string(Accusative, Plural).unwrap(), "мужчин");
+        assert_eq!(noun.agreement_info(Accusative, Singular).gender, Gender::Masculine);
+    }
+
+    #[test]
+    fn stress_marks() {
+        let inflect = |stem, info: &str, case, number| {
+            let noun = Noun::new(stem, info.parse().unwrap());
+            noun.inflect_stressed(case, number).unwrap()
+        };
+        use {CaseEx::*, Number::*};
+
+        // Ending-stressed forms are marked on the ending
+        assert_eq!(inflect("стол", "м 1b", Nominative, Plural), "столы́");
+        assert_eq!(inflect("слов", "с 1c", Dative, Plural), "слова́м");
+        assert_eq!(inflect("жен", "жо 1d, ё", Nominative, Singular), "жена́");
+        assert_eq!(inflect("окн", "с 1*d", Nominative, Singular), "окно́");
+        // Stem-stressed forms are marked, if the stem has only one vowel
+        assert_eq!(inflect("окн", "с 1*d", Nominative, Plural), "о́кна");
+        // The letter 'ё' is inherently stressed, and isn't marked
+        assert_eq!(inflect("жен", "жо 1d, ё", Dative, Plural), "жёнам");
+        // or if the stem is marked, and the mark is tracked through the alternations
+        assert_eq!(inflect("ко́мнат", "ж 1a", Instrumental, Plural), "ко́мнатами");
+        assert_eq!(inflect("па́лец", "м 5*a", Genitive, Singular), "па́льца");
+        assert_eq!(inflect("о́кн", "с 1*d", Genitive, Plural), "о́кон");
+        assert_eq!(inflect("комнат", "ж 1a", Instrumental, Plural), "комнатами");
+
+        // Monosyllabic forms and indeclinable nouns aren't marked
+        assert_eq!(inflect("стол", "м 1b", Nominative, Singular), "стол");
+        assert_eq!(inflect("кофе", "м 0", Genitive, Singular), "кофе");
+    }
+
+    #[test]
+    fn marked_stems() {
+        use {CaseEx::*, Number::*};
+        let noun = Noun::new("кусо́к", "м 3*b".parse().unwrap());
+        let mut buf = InflectionBuffer::default();
+
+        // Only inflect_stressed keeps the mark, the other methods remove it
+        assert_eq!(noun.inflect_stressed(Genitive, Singular).unwrap(), "куска́");
+        assert_eq!(noun.inflect_to_string(Genitive, Singular).unwrap(), "куска");
+        assert_eq!(noun.inflect_to(Genitive, Singular, &mut buf).unwrap(), "куска");
+        let letters = noun.inflect_to_letters(Genitive, Singular, &mut buf).unwrap();
+        assert_eq!(letters.as_str(), "куска");
+        assert_eq!(
+            noun.inflect_with_warnings(Genitive, Singular).unwrap(),
+            ("куска".into(), vec![])
+        );
+        assert_eq!(format!("{}", noun.inflect_fmt(Genitive, Singular)), "куска");
+        let policy = SecondaryCasePolicy::Fallback;
+        assert_eq!(noun.try_inflect(Genitive, Singular, policy).unwrap(), "куска");
+        assert_eq!(noun.lemma().unwrap(), "кусок");
+
+        // The mark is tracked through the alternations
+        let noun = Noun::new("па́лец", "м 5*a".parse().unwrap());
+        assert_eq!(noun.inflect_to_string(Genitive, Singular).unwrap(), "пальца");
+        assert_eq!(noun.inflect_stressed(Genitive, Singular).unwrap(), "па́льца");
+    }
+
+    #[test]
+    fn from_letters() {
+        let noun = |stem, decl: &str, gender: Gender| {
+            let mut info: NounInfo =
+                format!("{} {decl}", gender.with_an(Animacy::Inanimate).abbr_zaliznyak())
+                    .parse()
+                    .unwrap();
+            (info.has_partitive, info.has_locative) = (true, true);
+            Noun::new(stem, info)
+        };
+
+        // Nouns with all kinds of alternations, stem extensions and secondary cases
+        let nouns = [
+            noun("стол", "1b", Gender::Masculine),
+            noun("сон", "1*b", Gender::Masculine),
+            noun("кошк", "3*a", Gender::Feminine),
+            noun("сестр", "1*d, ё", Gender::Feminine),
+            noun("боец", "5*b", Gender::Masculine),
+            noun("ребёнок", "3°a", Gender::Masculine),
+            noun("врем", "8°c, ё", Gender::Neuter),
+            noun("мат", "8°e", Gender::Feminine),
+            noun("ча", "6c", Gender::Masculine),
+            noun("кофе", "0", Gender::Masculine),
+        ];
+
+        let (mut str_buf, mut letters_buf) =
+            (InflectionBuffer::default(), InflectionBuffer::default());
+        for noun in nouns {
+            let letters = Letter::iter(noun.stem).collect::<Vec<_>>();
+            let from_letters = Noun::from_letters(&letters, noun.info);
+            assert_eq!(from_letters, noun);
+
+            for case in CaseEx::VALUES {
+                for number in Number::VALUES {
+                    let expected = noun.inflect_to(case, number, &mut str_buf).unwrap();
+                    let actual =
+                        from_letters.inflect_to_letters(case, number, &mut letters_buf).unwrap();
+                    assert_eq!(actual.as_bytes(), expected.as_bytes(), "{expected}");
+                    assert_eq!(InflectionBuffer::from_letters(actual).as_str(), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn inflect_into() {
+        let info = |case, number| DeclInfo {
+            case,
+            number,
+            gender: Gender::Feminine,
+            animacy: Animacy::Inanimate,
+        };
+        let decl: NounDeclension = "6*b".parse().unwrap();
+        let mut out = [0; FORM_MAX_LEN];
+
+        // The forms are the same as the ones inflected into an InflectionBuffer
+        for key in CaseAndNumber::VALUES {
+            let (case, number) = key.parts();
+            let mut buf = InflectionBuffer::from_stem("стать").unwrap();
+            decl.inflect(info(case, number), &mut buf).unwrap();
+            let form = decl.inflect_into(info(case, number), "стать", &mut out).unwrap();
+            assert_eq!(form, buf.as_str());
+        }
+
+        // The buffer must fit the whole form
+        let info = info(Case::Instrumental, Number::Plural);
+        assert_eq!(decl.inflect_into(info, "стать", &mut out[..16]), Ok("статьями"));
+        let err = BufferTooSmallError { required: 16 };
+        assert_eq!(decl.inflect_into(info, "стать", &mut out[..15]), Err(err.into()));
+        // Errors of the alternations are returned as well
+        let star: NounDeclension = "1*a".parse().unwrap();
+        let info_masc = DeclInfo { gender: Gender::Masculine, ..info };
+        assert_eq!(star.inflect_into(info_masc, "штр", &mut out), Err(InflectError::NoVowelInStem));
+
+        // Stems of the maximum length fit
+        let stem = "я".repeat(STEM_MAX_LEN / 2);
+        assert!(decl.inflect_into(info, &stem, &mut out).unwrap().ends_with("ями"));
+    }
+
+    #[test]
+    fn new_checked() {
+        let check = |stem: &'static str, info: &str| Noun::new_checked(stem, info.parse().unwrap());
+
+        // Stems that match their declension, including pronominal and adjectival ones
+        assert!(check("сапо́г", "м 3c").is_ok());
+        assert!(check("Москв", "ж 1a").is_ok());
+        assert!(check("музе", "м 6a").is_ok());
+        assert!(check("гербари", "м 7a").is_ok());
+        assert!(check("тен", "ж 8e").is_ok());
+        assert!(check("рабоч", "мо п 4a").is_ok());
+        assert!(check("кенгуру", "мо 0").is_ok());
+        assert!(check("сам", "мо мс 1b").is_ok());
+
+        // Stems that don't
+        let err = check("стол", "м 3c").unwrap_err();
+        assert_eq!(err, StemMismatchError {
+            stem_type: AnyStemType::Type3,
+            last: Some(letters::л)
+        });
+        assert_eq!(err.to_string(), "the stem can't be of stem type 3");
+        let err = check("", "м 1a").unwrap_err();
+        assert_eq!(err, StemMismatchError { stem_type: AnyStemType::Type1, last: None });
+        assert!(check("нож", "м 1a").is_err());
+        assert!(check("край", "м 2a").is_err());
+        assert!(check("отец", "м 4a").is_err());
+        assert!(check("птиц", "ж 6a").is_err());
+        assert!(check("Мари", "ж 6a").is_err());
+        assert!(check("музе", "м 8a").is_err());
+        assert!(check("стол", "мо п 4a").is_err());
+        assert!(check("сам", "мо мс 4a").is_err());
+
+        // Inflection doesn't validate the stem
+        let noun = Noun::new("стол", "м 3c".parse().unwrap());
+        assert!(noun.inflect_to_string(CaseEx::Genitive, Number::Singular).is_ok());
+    }
+
+    #[bench]
+    fn bench_inflect_single(b: &mut Bencher) {
+        let noun = noun("стать", "6*b", Gender::Feminine);
+        let mut buf = InflectionBuffer::default();
+        b.iter(|| {
+            let form = black_box(&noun).inflect_to(CaseEx::Genitive, Number::Plural, &mut buf);
+            black_box(form.unwrap());
+        });
+    }
+    #[bench]
+    fn bench_inflect_paradigm(b: &mut Bencher) {
+        let decl: NounDeclension = "6*b".parse().unwrap();
+        b.iter(|| inflect_paradigm(black_box(decl), None));
+    }
+    #[bench]
+    fn bench_inflect_paradigm_precomputed(b: &mut Bencher) {
+        let decl: NounDeclension = "6*b".parse().unwrap();
+        let endings = decl.precompute(Gender::Feminine, Animacy::Inanimate);
+        b.iter(|| inflect_paradigm(black_box(decl), Some(black_box(&endings))));
+    }
+
+    #[bench]
+    fn bench_inflect_into(b: &mut Bencher) {
+        let noun = noun("стать", "6*b", Gender::Feminine);
+        let Some(Declension::Noun(decl)) = noun.info.declension else { unreachable!() };
+        let mut out = [0; FORM_MAX_LEN];
+        b.iter(|| {
+            for key in CaseAndNumber::VALUES {
+                let (case, number) = key.parts();
+                let info =
+                    DeclInfo { case, number, gender: Gender::Feminine, animacy: noun.info.animacy };
+                black_box(decl.inflect_into(info, black_box(noun.stem), &mut out).unwrap());
+            }
+        });
+    }
+    #[bench]
+    fn bench_inflect_display(b: &mut Bencher) {
+        use core::fmt::Write;
+
+        let noun = noun("стать", "6*b", Gender::Feminine);
+        let mut out = String::with_capacity(FORM_MAX_LEN);
+        b.iter(|| {
+            for key in CaseAndNumber::VALUES {
+                let (case, number) = key.parts();
+                out.clear();
+                write!(out, "{}", black_box(&noun).inflect_fmt(case.into(), number)).unwrap();
+                black_box(out.as_str());
+            }
+        });
     }
 }
@@ -1,42 +1,330 @@
 use crate::{
     InflectionBuffer,
-    declension::{DeclInfo, Declension, PronounDeclension},
+    categories::{Animacy, CaseAndNumber, CaseEx, Gender, HasNumber, Number},
+    declension::{
+        DeclInfo, Declension, InflectError, PronounDeclension, fleeting::remove_fleeting_vowel,
+    },
 };
-use std::fmt::Display;
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt::Display;
 
+/// A pronoun, or a word declined like one (наш, чей, мамин).
+///
+/// Pronouns with their own unique declensions aren't supported: pronoun declensions don't accept
+/// the circle flag (°), and the stem alternations of весь and тот/этот (всем, все, всех; тем, те,
+/// тех) aren't implemented. Declined by "мс 6b" with the stem "вс", весь only gets its feminine
+/// and some of its masculine and neuter singular forms right (вся, всю, всего, всему, всё, всём).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Pronoun<'a> {
     pub stem: &'a str,
     pub info: PronounInfo,
     // exceptions: &'a [(CaseAndNumber, &'a str)],
 }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PronounInfo {
     pub declension: Option<Declension>,
+    /// The gender that the pronoun agrees with, or its own gender, if it's a substantive pronoun.
+    pub gender: Gender,
+    /// The animacy that the pronoun agrees with, used in the accusative.
+    pub animacy: Animacy,
 }
 
 impl<'a> Pronoun<'a> {
-    pub fn inflect(&self, info: DeclInfo, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    /// Writes the form of the pronoun in the specified case and number into the formatter. If the
+    /// pronoun can't be inflected, returns [`core::fmt::Error`], so use one of the other methods
+    /// to handle the [`InflectError`].
+    pub fn inflect(
+        &self,
+        case: CaseEx,
+        number: Number,
+        f: &mut core::fmt::Formatter,
+    ) -> core::fmt::Result {
+        match self.inflect_to(case, number, &mut InflectionBuffer::default()) {
+            Ok(form) => form.fmt(f),
+            Err(_) => Err(core::fmt::Error),
+        }
+    }
+    /// Returns the form of the pronoun in the specified case and number, agreeing with the
+    /// pronoun's gender and animacy. Indeclinable pronouns (without a declension) are returned
+    /// as is.
+    ///
+    /// Returns an error, if the pronoun is declined by noun declension, or if its stem doesn't
+    /// match the declension's alternations.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = PronounInfo {
+    ///     declension: Some("мс 4a".parse().unwrap()),
+    ///     gender: Gender::Feminine,
+    ///     animacy: Animacy::Inanimate,
+    /// };
+    /// let pronoun = Pronoun { stem: "наш", info };
+    /// assert_eq!(pronoun.inflect_to_string(CaseEx::Accusative, Number::Singular).unwrap(), "нашу");
+    /// assert_eq!(pronoun.inflect_to_string(CaseEx::Dative, Number::Plural).unwrap(), "нашим");
+    /// ```
+    pub fn inflect_to_string(&self, case: CaseEx, number: Number) -> Result<String, InflectError> {
+        Ok(self.inflect_to(case, number, &mut InflectionBuffer::default())?.to_owned())
+    }
+    /// Returns the form of the pronoun in the specified case and number, as a [`Display`] value,
+    /// that inflects the pronoun when it's formatted. Formatting it returns [`core::fmt::Error`],
+    /// if the pronoun can't be inflected.
+    pub fn inflect_fmt(&self, case: CaseEx, number: Number) -> impl Display + '_ {
+        core::fmt::from_fn(move |f| self.inflect(case, number, f))
+    }
+    /// Inflects the pronoun in the specified case and number into the buffer, and returns the
+    /// form. The buffer's contents are replaced, and its allocation is reused.
+    pub fn inflect_to<'b>(
+        &self,
+        case: CaseEx,
+        number: Number,
+        buf: &'b mut InflectionBuffer,
+    ) -> Result<&'b str, InflectError> {
         // TODO: check exceptions
 
+        buf.reset_to_stem(self.stem)?;
+
         if let Some(decl) = self.info.declension {
-            let mut buf = InflectionBuffer::from_stem_unchecked(self.stem);
+            let (case, number) = case.normalize_with(number);
+            let info =
+                DeclInfo { case, number, gender: self.info.gender, animacy: self.info.animacy };
 
-            match decl {
-                Declension::Pronoun(decl) => decl.inflect(info, &mut buf),
-                Declension::Adjective(decl) => decl.inflect(info, &mut buf),
-                Declension::Noun(_) => unimplemented!("Pronouns don't decline by noun declension"),
-            };
+            let capitalization = buf.to_lowercase();
+            if decl.is_noun() {
+                return Err(InflectError::IncompatibleDeclension);
+            }
+            decl.inflect(info, buf)?;
+            buf.apply_capitalization(capitalization);
+        }
+        Ok(buf.as_str())
+    }
+
+    /// Returns the pronoun's forms in the 6 main cases, in the order of [`CaseAndNumber::VALUES`]:
+    /// all singular forms, and then all plural forms.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let info = PronounInfo {
+    ///     declension: Some("мс 4a".parse().unwrap()),
+    ///     gender: Gender::Masculine,
+    ///     animacy: Animacy::Animate,
+    /// };
+    /// let forms = Pronoun { stem: "наш", info }.forms().unwrap();
+    /// assert_eq!(forms[..4], ["наш", "нашего", "нашему", "нашего"]);
+    /// assert_eq!(forms[6..8], ["наши", "наших"]);
+    /// ```
+    pub fn forms(&self) -> Result<[String; 12], InflectError> {
+        let mut buf = InflectionBuffer::default();
+        let mut forms = [const { String::new() }; 12];
 
-            buf.as_str().fmt(f)
-        } else {
-            self.stem.fmt(f)
+        for (form, key) in forms.iter_mut().zip(CaseAndNumber::VALUES) {
+            let (case, number) = key.parts();
+            *form = self.inflect_to(case.into(), number, &mut buf)?.to_owned();
         }
+        Ok(forms)
     }
 }
 
 impl PronounDeclension {
-    pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) {
-        buf.append_to_ending(self.get_ending(info));
+    /// Appends the ending to the stem in the buffer, and applies the stem alternations. Returns an
+    /// error, if the stem doesn't match the alternations (e.g. a stem without vowels with the
+    /// star flag). The buffer's contents are unspecified after an error.
+    pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) -> Result<(), InflectError> {
+        buf.append_to_ending_unchecked(self.get_ending(info));
+
+        // The unique stem alternations (°) aren't supported, see Pronoun
+
+        // The fleeting vowel is only kept in masculine nominative singular (чей - чьего, чья)
+        if self.flags.has_star()
+            && !(info.is_singular()
+                && info.gender == Gender::Masculine
+                && info.case.is_nom_or_acc_inan(info))
+        {
+            remove_fleeting_vowel(self.stem_type.into(), buf)?;
+        }
+        Ok(())
+    }
+    /// Inflects the stem, and writes the form into the byte buffer, without allocating. See
+    /// [`NounDeclension::inflect_into`](crate::declension::NounDeclension::inflect_into).
+    pub fn inflect_into<'a>(
+        self,
+        info: DeclInfo,
+        stem: &str,
+        out: &'a mut [u8],
+    ) -> Result<&'a str, InflectError> {
+        let mut buf = InflectionBuffer::from_stem(stem)?;
+        self.inflect(info, &mut buf)?;
+        Ok(buf.copy_to(out)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Case, Number},
+        declension::ParseDeclensionError,
+    };
+    use std::format;
+
+    #[test]
+    fn forms() {
+        let pronoun = |stem, decl: &str, gender, animacy| {
+            let info = PronounInfo { declension: Some(decl.parse().unwrap()), gender, animacy };
+            Pronoun { stem, info }
+        };
+        use {Animacy::*, Gender::*};
+
+        #[rustfmt::skip]
+        assert_eq!(pronoun("наш", "мс 4a", Masculine, Animate).forms().unwrap(), [
+            "наш", "нашего", "нашему", "нашего", "нашим", "нашем",
+            "наши", "наших", "нашим", "наших", "нашими", "наших",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(pronoun("наш", "мс 4a", Neuter, Inanimate).forms().unwrap(), [
+            "наше", "нашего", "нашему", "наше", "нашим", "нашем",
+            "наши", "наших", "нашим", "наши", "нашими", "наших",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(pronoun("че", "мс 6*b", Feminine, Inanimate).forms().unwrap(), [
+            "чья", "чьей", "чьей", "чью", "чьей", "чьей",
+            "чьи", "чьих", "чьим", "чьи", "чьими", "чьих",
+        ]);
+
+        // Secondary cases are declined as their primary ones
+        let our = pronoun("наш", "мс 4a", Masculine, Inanimate);
+        assert_eq!(our.inflect_to_string(CaseEx::Locative, Number::Singular).unwrap(), "нашем");
+        assert_eq!(format!("{}", our.inflect_fmt(CaseEx::Dative, Number::Plural)), "нашим");
+
+        // Malformed stems and noun declensions are reported as errors
+        let err = pronoun("чр", "мс 6*b", Masculine, Inanimate).forms().unwrap_err();
+        assert_eq!(err, InflectError::NoVowelInStem);
+        let err = pronoun("наш", "4a", Masculine, Inanimate).forms().unwrap_err();
+        assert_eq!(err, InflectError::IncompatibleDeclension);
+
+        // Indeclinable pronouns are returned as is
+        let info = PronounInfo { declension: None, gender: Masculine, animacy: Inanimate };
+        let forms = Pronoun { stem: "ихний", info }.forms().unwrap();
+        assert!(forms.iter().all(|x| x == "ихний"));
+    }
+
+    #[test]
+    fn unique_pronouns() {
+        let pronoun = |stem, decl: &str, gender| {
+            let info = PronounInfo {
+                declension: Some(decl.parse().unwrap()),
+                gender,
+                animacy: Animacy::Inanimate,
+            };
+            Pronoun { stem, info }.forms().unwrap()
+        };
+        use Gender::*;
+
+        // весь is declined like чей in the feminine, and in most of the masculine and neuter
+        // singular forms, but not in the instrumental and the plural (всем, все, всех), and its
+        // masculine nominative has a fleeting vowel (весь), so those forms aren't supported
+        assert_eq!(pronoun("вс", "мс 6b", Feminine)[..6], [
+            "вся", "всей", "всей", "всю", "всей", "всей"
+        ]);
+        let neuter = pronoun("вс", "мс 6b", Neuter);
+        assert_eq!([&*neuter[0], &neuter[1], &neuter[2], &neuter[3], &neuter[5]], [
+            "всё",
+            "всего",
+            "всему",
+            "всё",
+            "всём",
+        ]);
+
+        // The unique alternations are rejected for pronouns, instead of being ignored
+        let err = ParseDeclensionError::IncompatibleFlags;
+        assert_eq!("мс 6°*b".parse::<Declension>(), Err(err));
+        assert_eq!("мс 1°b".parse::<Declension>(), Err(err));
+    }
+
+    #[test]
+    fn accusative() {
+        let pronoun = |stem, decl: &str, gender, animacy| {
+            let info = PronounInfo { declension: Some(decl.parse().unwrap()), gender, animacy };
+            Pronoun { stem, info }.forms().unwrap()
+        };
+        use {Animacy::*, Gender::*};
+
+        // Only the masculine singular and the plural accusative depend on the animacy
+        #[rustfmt::skip]
+        assert_eq!(pronoun("че", "мс 6*b", Masculine, Inanimate), [
+            "чей", "чьего", "чьему", "чей", "чьим", "чьём",
+            "чьи", "чьих", "чьим", "чьи", "чьими", "чьих",
+        ]);
+        #[rustfmt::skip]
+        assert_eq!(pronoun("че", "мс 6*b", Masculine, Animate), [
+            "чей", "чьего", "чьему", "чьего", "чьим", "чьём",
+            "чьи", "чьих", "чьим", "чьих", "чьими", "чьих",
+        ]);
+        // The neuter singular accusative is always the nominative (вижу наше животное)
+        #[rustfmt::skip]
+        assert_eq!(pronoun("наш", "мс 4a", Neuter, Animate), [
+            "наше", "нашего", "нашему", "наше", "нашим", "нашем",
+            "наши", "наших", "нашим", "наших", "нашими", "наших",
+        ]);
+        // and the feminine singular accusative has its own ending
+        #[rustfmt::skip]
+        assert_eq!(pronoun("наш", "мс 4a", Feminine, Animate), [
+            "наша", "нашей", "нашей", "нашу", "нашей", "нашей",
+            "наши", "наших", "нашим", "наших", "нашими", "наших",
+        ]);
+
+        // The same holds for all stem types, and for the adjectives too
+        for decl in ["мс 1a", "мс 2a", "мс 4a", "мс 6a", "п 1a", "п 2a", "п 3a", "п 5a", "п 7a"]
+        {
+            let decl: Declension = decl.parse().unwrap();
+            let ending = |case, number, gender, animacy| {
+                decl.get_ending(DeclInfo { case, number, gender, animacy })
+            };
+            use {Case::*, Number::*};
+
+            for animacy in [Inanimate, Animate] {
+                let acc = |number, gender| ending(Accusative, number, gender, animacy);
+                let by_animacy = |number, gender| match animacy {
+                    Inanimate => ending(Nominative, number, gender, animacy),
+                    Animate => ending(Genitive, number, gender, animacy),
+                };
+                assert_eq!(acc(Singular, Neuter), ending(Nominative, Singular, Neuter, animacy));
+                assert_eq!(acc(Singular, Masculine), by_animacy(Singular, Masculine));
+                assert_eq!(
+                    acc(Singular, Feminine),
+                    ending(Accusative, Singular, Feminine, Inanimate)
+                );
+                for gender in [Masculine, Neuter, Feminine] {
+                    assert_eq!(acc(Plural, gender), by_animacy(Plural, gender));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fleeting_vowel() {
+        let decl = match "мс 6*b".parse::<Declension>().unwrap() {
+            Declension::Pronoun(decl) => decl,
+            _ => unreachable!(),
+        };
+        let inflect = |case, number, gender| {
+            let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+            let mut buf = InflectionBuffer::from_stem("че").unwrap();
+            decl.inflect(info, &mut buf).unwrap();
+            buf.as_str().to_owned()
+        };
+        use {Case::*, Gender::*, Number::*};
 
-        todo!() // TODO
+        assert_eq!(inflect(Nominative, Singular, Masculine), "чей");
+        assert_eq!(inflect(Accusative, Singular, Masculine), "чей");
+        assert_eq!(inflect(Genitive, Singular, Masculine), "чьего");
+        assert_eq!(inflect(Nominative, Singular, Neuter), "чьё");
+        assert_eq!(inflect(Nominative, Singular, Feminine), "чья");
+        assert_eq!(inflect(Accusative, Singular, Feminine), "чью");
+        assert_eq!(inflect(Nominative, Plural, Masculine), "чьи");
+        assert_eq!(inflect(Instrumental, Singular, Masculine), "чьим");
     }
 }
@@ -1,4 +1,7 @@
-use crate::categories::{Animacy, Case, Gender, HasAnimacy, HasCase, HasGender, HasNumber, Number};
+use crate::categories::{
+    Animacy, Case, CaseAndNumber, Gender, GenderAnimacy, GenderOrPlural, HasAnimacy, HasCase,
+    HasGender, HasNumber, Number,
+};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct DeclInfo {
@@ -8,6 +11,92 @@ pub struct DeclInfo {
     pub animacy: Animacy,
 }
 
+impl DeclInfo {
+    /// Creates a declension info with the specified case, number, gender and animacy.
+    pub const fn new(case: Case, number: Number, gender: Gender, animacy: Animacy) -> Self {
+        Self { case, number, gender, animacy }
+    }
+    /// Creates a declension info from a case and number combination, and a gender and animacy
+    /// combination.
+    pub const fn from_parts(case_number: CaseAndNumber, gender_animacy: GenderAnimacy) -> Self {
+        let (case, number) = case_number.parts();
+        let (gender, animacy) = gender_animacy.parts();
+        Self { case, number, gender, animacy }
+    }
+
+    /// Returns the info with the specified case.
+    pub const fn with_case(mut self, case: Case) -> Self {
+        self.case = case;
+        self
+    }
+    /// Returns the info with the specified number.
+    pub const fn with_number(mut self, number: Number) -> Self {
+        self.number = number;
+        self
+    }
+    /// Returns the info with the specified gender.
+    pub const fn with_gender(mut self, gender: Gender) -> Self {
+        self.gender = gender;
+        self
+    }
+    /// Returns the info with the specified animacy.
+    pub const fn with_animacy(mut self, animacy: Animacy) -> Self {
+        self.animacy = animacy;
+        self
+    }
+
+    /// Returns the case and number of the info.
+    pub const fn case_and_number(self) -> CaseAndNumber {
+        CaseAndNumber::new(self.case, self.number)
+    }
+    /// Returns the gender and animacy of the info.
+    pub const fn gender_animacy(self) -> GenderAnimacy {
+        GenderAnimacy::new(self.gender, self.animacy)
+    }
+
+    /// Returns an iterator over all 72 combinations of case, number, gender and animacy, in the
+    /// order of [`CaseAndNumber::VALUES`], and then [`GenderAnimacy::VALUES`] within each one.
+    pub fn iter_all() -> impl Iterator<Item = Self> + Clone {
+        (CaseAndNumber::VALUES.into_iter())
+            .flat_map(|cn| GenderAnimacy::VALUES.map(|ga| Self::from_parts(cn, ga)))
+    }
+
+    /// Returns the case that the accusative takes the ending of: nominative or genitive.
+    ///
+    /// Animate plurals and masculine singulars take the genitive ending. Neuter singulars always
+    /// take the nominative ending, even if animate (вижу большое животное). Feminine singulars
+    /// have endings of their own, and aren't resolved by this.
+    pub const fn acc_case(self) -> Case {
+        match (self.number, self.gender) {
+            (Number::Singular, Gender::Neuter) => Case::Nominative,
+            _ => self.animacy.acc_case(),
+        }
+    }
+}
+
+/// A full or short form of an adjective. See [`AdjectiveStress::is_stem_stressed`].
+///
+/// [`AdjectiveStress::is_stem_stressed`]: crate::stress::AdjectiveStress::is_stem_stressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdjectiveForm {
+    /// A full form in the specified case, number, gender and animacy (новый, новому).
+    Full(DeclInfo),
+    /// A short form in the specified gender, or the plural (нов, нова, новы).
+    Short(GenderOrPlural),
+}
+
+impl const From<(CaseAndNumber, GenderAnimacy)> for DeclInfo {
+    fn from(value: (CaseAndNumber, GenderAnimacy)) -> Self {
+        Self::from_parts(value.0, value.1)
+    }
+}
+
+impl const From<DeclInfo> for GenderOrPlural {
+    fn from(value: DeclInfo) -> Self {
+        Self::new(value.gender, value.number)
+    }
+}
+
 impl const HasCase for DeclInfo {
     fn case(&self) -> Case {
         self.case
@@ -28,3 +117,62 @@ impl const HasAnimacy for DeclInfo {
         self.animacy
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn construct() {
+        const INFO: DeclInfo =
+            DeclInfo::new(Case::Dative, Number::Plural, Gender::Neuter, Animacy::Animate);
+        assert_eq!(INFO, DeclInfo {
+            case: Case::Dative,
+            number: Number::Plural,
+            gender: Gender::Neuter,
+            animacy: Animacy::Animate
+        },);
+        assert_eq!(DeclInfo::from_parts(CaseAndNumber::DativePlural, GenderAnimacy::NEUT_AN), INFO);
+        assert_eq!(DeclInfo::from((INFO.case_and_number(), INFO.gender_animacy())), INFO);
+
+        const CHANGED: DeclInfo = DeclInfo::new(
+            Case::Nominative,
+            Number::Singular,
+            Gender::Masculine,
+            Animacy::Inanimate,
+        )
+        .with_case(Case::Dative)
+        .with_number(Number::Plural)
+        .with_gender(Gender::Neuter)
+        .with_animacy(Animacy::Animate);
+        assert_eq!(CHANGED, INFO);
+        assert_eq!(INFO.with_case(Case::Genitive).case, Case::Genitive);
+    }
+
+    #[test]
+    fn iter_all() {
+        let all: Vec<_> = DeclInfo::iter_all().collect();
+        assert_eq!(all.len(), 6 * 2 * 3 * 2);
+        assert_eq!(all[0], DeclInfo::default());
+        assert_eq!(all[1], DeclInfo::default().with_animacy(Animacy::Animate));
+        assert_eq!(all[6], DeclInfo::default().with_case(Case::Genitive));
+
+        // Every combination is yielded exactly once
+        for (i, info) in all.iter().enumerate() {
+            assert_eq!(all.iter().position(|x| x == info), Some(i), "{info:?}");
+        }
+    }
+
+    #[test]
+    fn gender_or_plural() {
+        for info in DeclInfo::iter_all() {
+            let expected = match info.number {
+                Number::Singular => GenderOrPlural::from(info.gender),
+                Number::Plural => GenderOrPlural::Plural,
+            };
+            assert_eq!(GenderOrPlural::from(info), expected, "{info:?}");
+            assert_eq!(GenderOrPlural::from(info).number(), info.number);
+        }
+    }
+}
@@ -1,15 +1,34 @@
+mod agreement;
+mod compound;
 mod declensions;
+mod derive;
 mod endings;
+mod extract;
+mod fit;
 mod flags;
+mod fleeting;
 mod fmt;
 mod from_str;
 mod impl_adjective;
 mod impl_noun;
 mod impl_pronoun;
 mod info;
+mod noun_info;
+mod paradigm;
+mod relaxed;
+mod stem_extension;
 mod stem_types;
+mod stress_marks;
+mod trace;
+mod word;
 
+pub use agreement::*;
+pub use compound::*;
 pub use declensions::*;
+pub use derive::*;
+pub use endings::{Endings, PrecomputedEndings};
+pub use extract::*;
+pub use fit::*;
 pub use flags::*;
 pub use fmt::*;
 pub use from_str::*;
@@ -17,4 +36,11 @@ pub use impl_adjective::*;
 pub use impl_noun::*;
 pub use impl_pronoun::*;
 pub use info::*;
+pub use noun_info::*;
+pub use paradigm::*;
+pub use relaxed::*;
+pub use stem_extension::*;
 pub use stem_types::*;
+pub(crate) use stress_marks::*;
+pub use trace::{Alternation, InflectTrace, UniqueAlternation};
+pub use word::*;
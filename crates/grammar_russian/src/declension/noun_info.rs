@@ -0,0 +1,309 @@
+use crate::{
+    FmtBuf,
+    categories::{GenderAnimacy, GenderExAnimacy, HasAnimacy, HasGender, Number, ParseGenderError},
+    declension::{
+        DECLENSION_MAX_LEN, Declension, MaybeZeroDeclension, NounInfo, ParseDeclensionError,
+    },
+    entry::{ENTRY_GENDER_MAX_LEN, EntryOverflowError, EntryWriter},
+};
+use thiserror::Error;
+
+/// The maximum length of a formatted [`NounInfo`], in bytes.
+// Longest form: мн. мо-жо <жо п 7°*f″/f″①②③④⑤, ё, -ся> Р2 П2
+pub const NOUN_INFO_MAX_LEN: usize = "мн. ".len()
+    + ENTRY_GENDER_MAX_LEN
+    + " <".len()
+    + "жо ".len()
+    + DECLENSION_MAX_LEN
+    + ">".len()
+    + " Р2 П2".len();
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseNounInfoError {
+    /// The gender and animacy marker is invalid: "п 1a", "мо-ж 1a", "мх 1a".
+    #[error("invalid gender marker: {0}")]
+    InvalidGender(ParseGenderError),
+    /// The declension is invalid: "м 9a", "ж 3*".
+    #[error("invalid declension: {0:?}")]
+    InvalidDeclension(ParseDeclensionError),
+}
+
+type Error = ParseNounInfoError;
+
+impl NounInfo {
+    /// Formats the noun's gender, animacy and declension as in a Zaliznyak dictionary entry:
+    /// "мо 1a", "ж 8°e, ё", "мн. ж 5a" or "м 0". Distinct partitive and locative forms are marked
+    /// with "Р2" and "П2" at the end: "м 6c Р2 П2".
+    ///
+    /// Singulare tantum isn't marked in Zaliznyak's entries, so it isn't written. The declension
+    /// gender is implied by the gender (common gender nouns are declined as feminine), and is
+    /// written only if it differs, along with the animacy, in angle brackets before the
+    /// declension: "мо <жо 1a>". The first marker is the gender that the noun agrees in, and the
+    /// bracketed one is the gender that it's declined as. Indeclinable nouns don't have a
+    /// declension gender, so it's never written for them.
+    pub fn fmt_to<'a>(&self, dst: &'a mut [u8; NOUN_INFO_MAX_LEN]) -> &'a str {
+        // The buffer always fits the longest noun info
+        self.write_to(EntryWriter::new(dst)).unwrap()
+    }
+    /// Formats the noun info into an owned buffer, that dereferences to a string slice.
+    /// See [`fmt_to`][Self::fmt_to] for the format.
+    pub fn fmt_to_buf(&self) -> FmtBuf<NOUN_INFO_MAX_LEN> {
+        let mut buf = [0; NOUN_INFO_MAX_LEN];
+        let len = self.fmt_to(&mut buf).len();
+        FmtBuf::from_parts(buf, len)
+    }
+
+    fn write_to<'a>(&self, mut dst: EntryWriter<'a>) -> Result<&'a str, EntryOverflowError> {
+        if self.tantum == Some(Number::Plural) {
+            dst.push_component("мн.")?;
+        }
+        dst.push_gender(GenderExAnimacy::from((self.gender, self.animacy)))?;
+
+        match self.declension {
+            Some(decl) if self.declension_gender != self.gender.normalize() => {
+                let decl_gender = GenderAnimacy::from((self.declension_gender, self.animacy));
+                dst.push_component("<")?;
+                dst.push_str(decl_gender.abbr_zaliznyak())?;
+                dst.push_declension(MaybeZeroDeclension::new(Some(decl)))?;
+                dst.push_str(">")?;
+            },
+            decl => dst.push_declension(MaybeZeroDeclension::new(decl))?,
+        }
+
+        if self.has_partitive {
+            dst.push_component("Р2")?;
+        }
+        if self.has_locative {
+            dst.push_component("П2")?;
+        }
+        Ok(dst.finish())
+    }
+}
+
+impl core::fmt::Display for NounInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.fmt_to(&mut [0; NOUN_INFO_MAX_LEN]).fmt(f)
+    }
+}
+
+impl core::str::FromStr for NounInfo {
+    type Err = ParseNounInfoError;
+
+    /// Parses the noun's gender, animacy and declension from a Zaliznyak dictionary entry:
+    /// "мо 1a", "ж 8°e, ё", "мн. ж 5a", "мо <жо 1a>" or "м 0". See [`NounInfo::fmt_to`] for the
+    /// format. The partitive and locative markers may also be written with subscript digits, as in the
+    /// dictionary: "м 1c П₂".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (tantum, s) = match s.strip_prefix("мн. ") {
+            Some(rest) => (Some(Number::Plural), rest),
+            None => (None, s),
+        };
+
+        let (gender, len) = GenderExAnimacy::from_str_partial(s).map_err(Error::InvalidGender)?;
+        let (gender, animacy) = gender.parts();
+
+        // The gender marker must be followed by a space, or the end of the string
+        let mut rest = &s[len..];
+        if !(rest.is_empty() || rest.starts_with(' ')) {
+            return Err(Error::InvalidGender(ParseGenderError::Invalid));
+        }
+
+        let has_locative = strip_marker(&mut rest, " П2") || strip_marker(&mut rest, " П₂");
+        let has_partitive = strip_marker(&mut rest, " Р2") || strip_marker(&mut rest, " Р₂");
+
+        let (declension, declension_gender) = match rest.strip_prefix(" <") {
+            // The declension gender is specified before the declension: "мо <жо 1a>"
+            Some(inner) => {
+                let inner = inner
+                    .strip_suffix('>')
+                    .ok_or(Error::InvalidGender(ParseGenderError::Invalid))?;
+                let (decl_gender, len) =
+                    GenderAnimacy::from_str_partial(inner).map_err(Error::InvalidGender)?;
+                if decl_gender.animacy() != animacy || !inner[len..].starts_with(' ') {
+                    return Err(Error::InvalidGender(ParseGenderError::Invalid));
+                }
+                // Indeclinable nouns don't have a declension gender, so "0" isn't allowed here
+                let decl =
+                    inner[len + 1..].parse::<Declension>().map_err(Error::InvalidDeclension)?;
+                (Some(decl), decl_gender.gender())
+            },
+            // A missing declension is the same as the indeclinable marker "0"
+            None if rest.is_empty() => (None, gender.normalize()),
+            None => {
+                let decl =
+                    rest[1..].parse::<MaybeZeroDeclension>().map_err(Error::InvalidDeclension)?;
+                (decl.into(), gender.normalize())
+            },
+        };
+
+        Ok(Self {
+            declension,
+            declension_gender,
+            gender,
+            animacy,
+            tantum,
+            has_partitive,
+            has_locative,
+        })
+    }
+}
+
+fn strip_marker(s: &mut &str, marker: &str) -> bool {
+    s.strip_suffix(marker).map(|rest| *s = rest).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Animacy, Gender, GenderEx};
+    use std::string::ToString;
+    use test::{Bencher, black_box};
+
+    #[test]
+    fn from_str() {
+        let info: NounInfo = "мо 1a".parse().unwrap();
+        assert_eq!((info.gender, info.animacy), (GenderEx::Masculine, Animacy::Animate));
+        assert_eq!(info.declension, Some("1a".parse().unwrap()));
+        assert_eq!(info.declension_gender, Gender::Masculine);
+        assert_eq!(info.tantum, None);
+
+        let info: NounInfo = "ж 8°e, ё".parse().unwrap();
+        assert_eq!((info.gender, info.animacy), (GenderEx::Feminine, Animacy::Inanimate));
+        assert_eq!(info.declension, Some("8°e, ё".parse().unwrap()));
+
+        let info: NounInfo = "мо-жо 1d".parse().unwrap();
+        assert_eq!((info.gender, info.declension_gender), (GenderEx::Common, Gender::Feminine));
+
+        // Plurale tantum, indeclinable nouns, and secondary cases
+        let info: NounInfo = "мн. ж 5a".parse().unwrap();
+        assert_eq!((info.tantum, info.gender), (Some(Number::Plural), GenderEx::Feminine));
+        let info: NounInfo = "м 0".parse().unwrap();
+        assert_eq!(info.declension, None);
+        assert_eq!("м".parse::<NounInfo>(), Ok(info));
+        let info: NounInfo = "м 6c Р2 П2".parse().unwrap();
+        assert_eq!((info.has_partitive, info.has_locative), (true, true));
+        assert_eq!(info.declension, Some("6c".parse().unwrap()));
+        assert_eq!("м 6c Р₂ П₂".parse(), Ok(info));
+
+        // Substantivized adjectives and pronominal nouns are declined as adjectives and pronouns
+        let info: NounInfo = "мо п 4a".parse().unwrap();
+        assert_eq!(info.declension, Some("п 4a".parse().unwrap()));
+        let info: NounInfo = "жо мс 1a".parse().unwrap();
+        assert_eq!(info.declension, Some("мс 1a".parse().unwrap()));
+
+        // The bracketed gender is the declension gender, the first one is the agreement gender
+        let info: NounInfo = "мо <жо 1a>".parse().unwrap();
+        assert_eq!((info.gender, info.declension_gender), (GenderEx::Masculine, Gender::Feminine));
+        assert_eq!(info.declension, Some("1a".parse().unwrap()));
+        let info: NounInfo = "мо-жо <мо 1a> Р2".parse().unwrap();
+        assert_eq!((info.gender, info.declension_gender), (GenderEx::Common, Gender::Masculine));
+        assert!(info.has_partitive);
+    }
+
+    #[test]
+    fn from_str_errors() {
+        let assert = |s: &str, expected: Error| {
+            assert_eq!(s.parse::<NounInfo>(), Err(expected), "{s}");
+        };
+
+        // Invalid gender markers
+        assert("", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("п 1a", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мх 1a", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мн.ж 5a", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мо-ж 1a", Error::InvalidGender(ParseGenderError::InvalidCompound));
+
+        // Invalid declension genders
+        assert("мо <ж 1a>", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("м <жо 1a>", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мо <жо 1a", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мо <жо1a>", Error::InvalidGender(ParseGenderError::Invalid));
+        assert("мо <мо-жо 1a>", Error::InvalidGender(ParseGenderError::InvalidCompound));
+        assert("мо <жо 0>", Error::InvalidDeclension(ParseDeclensionError::InvalidStemType));
+
+        // Invalid declensions
+        assert("м 9a", Error::InvalidDeclension(ParseDeclensionError::InvalidStemType));
+        assert("м  1a", Error::InvalidDeclension(ParseDeclensionError::InvalidStemType));
+        assert("м 1a ", Error::InvalidDeclension(ParseDeclensionError::Invalid));
+        assert("м 0 Р2 1a", Error::InvalidDeclension(ParseDeclensionError::Invalid));
+        assert("м 0a", Error::InvalidDeclension(ParseDeclensionError::Invalid));
+    }
+
+    #[test]
+    fn round_trip() {
+        let entries = [
+            "м 1a",
+            "мо 1a",
+            "с 7*b′①",
+            "жо 7*b′①",
+            "ж 8°e, ё",
+            "мо-жо 1d",
+            "мн. ж 5a",
+            "мн. мо 3*a",
+            "м 0",
+            "с 0",
+            "м 6c Р2 П2",
+            "м 1c П2",
+            "м 1a Р2",
+            "мо п 4a",
+            "мо п 4a, -ся",
+            "жо мс 1a",
+            "мн. мо-жо 7°*f″①②③, ё Р2 П2",
+            "м 1b",
+            "м 1*b",
+            "м 1c①",
+            "м 1e",
+            "м 2a",
+            "мо 2*b",
+            "м 3*d",
+            "ж 3a",
+            "м 4b",
+            "ж 4a",
+            "с 4a",
+            "м 5*b",
+            "ж 5f",
+            "с 5a",
+            "м 6*b",
+            "ж 7a",
+            "ж 8b, ё",
+            "с 8°c",
+            "м 8e",
+            "с 1a, ё",
+            "с 1*d, ё",
+            "с 3*a",
+            "мн. с 1c",
+            "мн. м 0",
+            "жо 0",
+            "мо-жо 0",
+            "ж 1a П2",
+            "жо п 1a",
+            "с п 2*a",
+            "с мс 6*a",
+            "мо мс 2a",
+            "мо <жо 1a>",
+            "мо <жо 1d>",
+            "мо <жо 3*a>",
+            "жо 3*a",
+            "м <ж 8b>",
+            "м <с 3*a>",
+            "мо-жо <мо 1a>",
+            "мо-жо <мо 4a> Р2",
+            "мн. мо <жо 1a>",
+        ];
+        for s in entries {
+            let info: NounInfo = s.parse().unwrap();
+            assert_eq!(info.to_string(), s);
+            assert_eq!(info.to_string().parse(), Ok(info), "{s}");
+        }
+    }
+
+    #[bench]
+    fn bench_parse_noun_info(b: &mut Bencher) {
+        let inputs = ["м 1a", "жо 6*b", "мн. мо-жо 3*d, ё", "м 6c Р2 П2", "мо <жо 1a>", "с 0"];
+        b.iter(|| {
+            for s in black_box(inputs) {
+                black_box(s.parse::<NounInfo>().unwrap());
+            }
+        });
+    }
+}
@@ -0,0 +1,398 @@
+use crate::{
+    InflectionBuffer,
+    categories::{Animacy, Case, CaseAndNumber, CaseEx, Gender, GenderExAnimacy, Number},
+    declension::{
+        Adjective, DeclInfo, InflectError, MaybeZeroDeclension, Noun, Pronoun, PronounInfo,
+        SecondaryCaseSupport,
+    },
+    entry::Entry,
+};
+use alloc::{
+    borrow::ToOwned,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+type Row = (&'static str, Vec<String>);
+
+/// An iterator over the forms of a noun, returned by [`Noun::forms`].
+#[derive(Debug)]
+pub struct NounForms<'n, 'a> {
+    noun: &'n Noun<'a>,
+    buf: InflectionBuffer,
+    index: usize,
+    extended: bool,
+}
+
+const EXTENDED_FORMS: [(CaseEx, Number); 4] = [
+    (CaseEx::Partitive, Number::Singular),
+    (CaseEx::Partitive, Number::Plural),
+    (CaseEx::Locative, Number::Singular),
+    (CaseEx::Locative, Number::Plural),
+];
+
+impl<'a> Noun<'a> {
+    /// Returns an iterator over the noun's forms in the 6 main cases, in the order of
+    /// [`CaseAndNumber::VALUES`]: all singular forms, and then all plural forms. The forms are
+    /// inflected one by one into the same buffer.
+    ///
+    /// All 12 forms are produced even for singularia and pluralia tantum, so the tantum nouns
+    /// yield duplicates: the singular and plural forms of ножницы are both "ножницы". If a form
+    /// can't be inflected, an [`InflectError`] is yielded in its place.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*};
+    ///
+    /// let noun = Noun::new("стол", "м 1b".parse().unwrap());
+    /// let forms: Vec<_> = noun.forms().map(|x| x.unwrap().2).collect();
+    /// assert_eq!(forms[..3], ["стол", "стола", "столу"]);
+    /// assert_eq!(forms[6..9], ["столы", "столов", "столам"]);
+    /// ```
+    pub fn forms(&self) -> NounForms<'_, 'a> {
+        NounForms { noun: self, buf: InflectionBuffer::default(), index: 0, extended: false }
+    }
+}
+
+impl NounForms<'_, '_> {
+    /// Includes the partitive and locative forms after the main ones, if the noun has its own
+    /// forms in those cases (see [`Noun::supports`]).
+    pub fn include_extended_cases(mut self) -> Self {
+        self.extended = true;
+        self
+    }
+}
+
+impl Iterator for NounForms<'_, '_> {
+    type Item = Result<(CaseEx, Number, String), InflectError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (case, number) = match self.index {
+                index @ 0..12 => {
+                    let (case, number) = CaseAndNumber::VALUES[index].parts();
+                    (CaseEx::from(case), number)
+                },
+                index @ 12..16 if self.extended => EXTENDED_FORMS[index - 12],
+                _ => return None,
+            };
+            self.index += 1;
+
+            if self.noun.supports(case) == SecondaryCaseSupport::Native {
+                let form = self.noun.inflect_to(case, number, &mut self.buf);
+                return Some(form.map(|form| (case, number, form.to_owned())));
+            }
+        }
+    }
+}
+impl core::iter::FusedIterator for NounForms<'_, '_> {}
+
+impl Noun<'_> {
+    /// Formats the noun's full paradigm as a plain-text table: the entry on the first line, and
+    /// then a row with the singular and plural forms for each case. The partitive and locative
+    /// rows are included only if the noun has its own forms in those cases.
+    ///
+    /// The format is stable, and is used for the snapshots of paradigms in tests:
+    /// ```text
+    /// стол м 1b
+    /// nom  стол    столы
+    /// gen  стола   столов
+    /// dat  столу   столам
+    /// acc  стол    столы
+    /// ins  столом  столами
+    /// prp  столе   столах
+    /// ```
+    ///
+    /// Returns an error, if any of the forms can't be inflected.
+    pub fn format_paradigm(&self) -> Result<String, InflectError> {
+        // Group the forms into rows by case, with the singular form first
+        let mut rows: Vec<Row> = vec![];
+        for form in self.forms().include_extended_cases() {
+            let (case, _, form) = form?;
+            match rows.iter_mut().find(|(label, _)| *label == case.abbr_lower()) {
+                Some((_, cells)) => cells.push(form),
+                None => rows.push((case.abbr_lower(), vec![form])),
+            }
+        }
+
+        let entry = Entry {
+            lemma: &rows[0].1[0],
+            gender: Some(GenderExAnimacy::from((self.info.gender, self.info.animacy))),
+            declension: self.info.declension.into(),
+        };
+        Ok(format_table(&format!("{entry}"), None, &rows))
+    }
+}
+
+impl Adjective<'_> {
+    /// Formats the adjective's full paradigm as a plain-text table: the entry on the first line,
+    /// a header row with the genders, and then a row for each case (with separate inanimate and
+    /// animate accusative rows), followed by a row of short forms, if the adjective has any.
+    ///
+    /// The format is stable, and is used for the snapshots of paradigms in tests:
+    /// ```text
+    /// новый п 1a
+    ///           masc    neut    fem    pl
+    /// nom       новый   новое   новая  новые
+    /// gen       нового  нового  новой  новых
+    /// dat       новому  новому  новой  новым
+    /// acc inan  новый   новое   новую  новые
+    /// acc an    нового  новое   новую  новых
+    /// ins       новым   новым   новой  новыми
+    /// prp       новом   новом   новой  новых
+    /// short     нов     ново    нова   новы
+    /// ```
+    ///
+    /// Returns an error, if any of the short forms can't be inflected.
+    pub fn format_paradigm(&self) -> Result<String, InflectError> {
+        let (entry, mut rows) = decl_info_rows(self.info.declension.into(), |info, f| {
+            self.inflect(info.case.into(), info.gender, info.number, info.animacy, f)
+        });
+
+        let short = GENDER_COLUMNS.iter().map(|&(gender, number)| self.short_forms(gender, number));
+        let short = short.collect::<Result<Vec<_>, _>>()?;
+        if short.iter().any(|forms| !forms.is_empty()) {
+            let cells = short.iter().map(|forms| match forms.is_empty() {
+                true => "—".to_owned(),
+                false => forms.join(", "),
+            });
+            rows.push(("short", cells.collect()));
+        }
+
+        Ok(format_table(&entry, Some(&GENDER_HEADERS), &rows))
+    }
+}
+
+impl Pronoun<'_> {
+    /// Formats the pronoun's full paradigm as a plain-text table, in the same format as
+    /// [`Adjective::format_paradigm`], but without the short forms.
+    pub fn format_paradigm(&self) -> String {
+        let (entry, rows) = decl_info_rows(self.info.declension.into(), |info, f| {
+            let pronoun_info =
+                PronounInfo { gender: info.gender, animacy: info.animacy, ..self.info };
+            Pronoun { info: pronoun_info, ..*self }.inflect(info.case.into(), info.number, f)
+        });
+
+        format_table(&entry, Some(&GENDER_HEADERS), &rows)
+    }
+}
+
+const GENDER_COLUMNS: [(Gender, Number); 4] = [
+    (Gender::Masculine, Number::Singular),
+    (Gender::Neuter, Number::Singular),
+    (Gender::Feminine, Number::Singular),
+    (Gender::Masculine, Number::Plural),
+];
+const GENDER_HEADERS: [&str; 4] = ["masc", "neut", "fem", "pl"];
+
+// Returns the entry and the case rows of a word that inflects by gender and number
+fn decl_info_rows(
+    declension: MaybeZeroDeclension,
+    inflect: impl Fn(DeclInfo, &mut core::fmt::Formatter) -> core::fmt::Result,
+) -> (String, Vec<Row>) {
+    let form = |case, (gender, number), animacy| {
+        let info = DeclInfo { case, number, gender, animacy };
+        core::fmt::from_fn(|f| inflect(info, f)).to_string()
+    };
+
+    let lemma = form(Case::Nominative, GENDER_COLUMNS[0], Animacy::Inanimate);
+    let entry = Entry { lemma: &lemma, gender: None, declension };
+
+    let mut rows = vec![];
+    for case in Case::VALUES {
+        let animacies: &[(&str, Animacy)] = match case {
+            Case::Accusative => &[("acc inan", Animacy::Inanimate), ("acc an", Animacy::Animate)],
+            _ => &[(case.abbr_lower(), Animacy::Inanimate)],
+        };
+        for &(label, animacy) in animacies {
+            rows.push((label, GENDER_COLUMNS.map(|x| form(case, x, animacy)).to_vec()));
+        }
+    }
+    (format!("{entry}"), rows)
+}
+
+// Formats the table with the title on the first line, and columns aligned with two spaces
+fn format_table(title: &str, headers: Option<&[&str]>, rows: &[Row]) -> String {
+    let header_row = headers.map(|headers| ("", headers.iter().map(|&x| x.to_owned()).collect()));
+    let all_rows = || header_row.iter().chain(rows);
+
+    let columns = all_rows().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    let mut widths = vec![all_rows().map(|(label, _)| label.chars().count()).max().unwrap_or(0)];
+    for i in 0..columns {
+        let cells = all_rows().filter_map(|(_, cells)| cells.get(i));
+        widths.push(cells.map(|cell| cell.chars().count()).max().unwrap_or(0));
+    }
+
+    let mut dst = format!("{title}\n");
+    for (label, cells) in all_rows() {
+        let line_start = dst.len();
+
+        for (text, width) in
+            core::iter::once(*label).chain(cells.iter().map(|x| x.as_str())).zip(&widths)
+        {
+            dst.push_str(text);
+            dst.extend(core::iter::repeat_n(' ', width - text.chars().count() + 2));
+        }
+
+        // Don't leave trailing whitespace in the lines
+        dst.truncate(line_start + dst[line_start..].trim_end().len());
+        dst.push('\n');
+    }
+    dst
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{GenderEx, HasCase, HasNumber},
+        declension::{AdjectiveInfo, NounInfo, PronounInfo, ShortFormAvailability},
+        util::assert_snapshot,
+    };
+    use test::{Bencher, black_box};
+
+    fn noun<'a>(stem: &'a str, decl: &str, gender: GenderEx, animacy: Animacy) -> Noun<'a> {
+        let info = NounInfo {
+            declension: (decl != "0").then(|| decl.parse().unwrap()),
+            declension_gender: gender.normalize(),
+            gender,
+            animacy,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+
+    #[test]
+    fn nouns() {
+        use {Animacy::*, GenderEx::*};
+
+        let mut tea = noun("ча", "6c", Masculine, Inanimate);
+        (tea.info.has_partitive, tea.info.has_locative) = (true, true);
+        let mut scissors = noun("ножниц", "5a", Feminine, Inanimate);
+        scissors.info.tantum = Some(Number::Plural);
+
+        let paradigms = [
+            noun("стол", "1b", Masculine, Inanimate),
+            noun("кошк", "3*a", Feminine, Animate),
+            noun("сирот", "1d", Common, Animate),
+            tea,
+            scissors,
+            noun("кофе", "0", Masculine, Inanimate),
+        ];
+        let paradigms = paradigms.map(|noun| noun.format_paradigm().unwrap());
+        assert_snapshot("paradigm_nouns", &paradigms.join("\n"));
+    }
+
+    #[test]
+    fn adjectives() {
+        let adj = |stem, decl: &str, short_forms| {
+            let info = AdjectiveInfo { declension: Some(decl.parse().unwrap()), short_forms };
+            Adjective { stem, info }
+        };
+        use ShortFormAvailability::*;
+
+        let paradigms = [
+            adj("нов", "п 1a", Available),
+            adj("син", "п 2a", Available),
+            adj("горьк", "п 3*a/c′", Available),
+            adj("искренн", "п 2*a", Available),
+            adj("осенн", "п 2a", Unavailable),
+        ];
+        let paradigms = paradigms.map(|adj| adj.format_paradigm().unwrap());
+        assert_snapshot("paradigm_adjectives", &paradigms.join("\n"));
+    }
+
+    #[test]
+    fn pronouns() {
+        let pronoun = |stem, decl: &str| {
+            let info = PronounInfo {
+                declension: Some(decl.parse().unwrap()),
+                gender: Gender::Masculine,
+                animacy: Animacy::Inanimate,
+            };
+            Pronoun { stem, info }
+        };
+
+        let paradigms = [pronoun("че", "мс 6*b"), pronoun("ваш", "мс 4a")];
+        let paradigms = paradigms.map(|pronoun| pronoun.format_paradigm());
+        assert_snapshot("paradigm_pronouns", &paradigms.join("\n"));
+    }
+
+    #[test]
+    fn forms() {
+        use {Animacy::*, GenderEx::*};
+
+        let mut tea = noun("ча", "6c", Masculine, Inanimate);
+        (tea.info.has_partitive, tea.info.has_locative) = (true, false);
+        let mut scissors = noun("ножниц", "5a", Feminine, Inanimate);
+        scissors.info.tantum = Some(Number::Plural);
+
+        let nouns = [
+            noun("стол", "1b", Masculine, Inanimate),
+            noun("кон", "2b", Masculine, Animate),
+            noun("кошк", "3*a", Feminine, Animate),
+            noun("нож", "4b", Masculine, Inanimate),
+            noun("птиц", "5a", Feminine, Animate),
+            noun("стать", "6*b", Feminine, Inanimate),
+            noun("зда", "7a", Neuter, Inanimate),
+            noun("дверь", "8e", Feminine, Inanimate),
+            tea,
+            scissors,
+            noun("кофе", "0", Masculine, Inanimate),
+        ];
+
+        for noun in nouns {
+            let expected: Vec<_> = (CaseAndNumber::VALUES.iter())
+                .map(|x| (CaseEx::from(x.case()), x.number()))
+                .map(|(case, number)| Ok((case, number, noun.inflect_to_string(case, number)?)))
+                .collect();
+            assert_eq!(noun.forms().collect::<Vec<_>>(), expected, "{}", noun.stem);
+
+            // Extended cases are included only if the noun has its own forms in them
+            let extended: Vec<_> = noun.forms().include_extended_cases().skip(12).collect();
+            let expected: Vec<_> = (EXTENDED_FORMS.into_iter())
+                .filter(|&(case, _)| noun.supports(case) == SecondaryCaseSupport::Native)
+                .map(|(case, number)| Ok((case, number, noun.inflect_to_string(case, number)?)))
+                .collect();
+            assert_eq!(extended, expected, "{}", noun.stem);
+        }
+
+        // Tantum nouns yield the same forms in both numbers
+        let scissors = nouns[9].forms().map(|x| x.unwrap().2).collect::<Vec<_>>();
+        assert_eq!(scissors[..6], scissors[6..]);
+        assert_eq!(scissors[0], "ножницы");
+
+        let tea = nouns[8].forms().include_extended_cases().skip(12).map(|x| x.unwrap().2);
+        assert_eq!(tea.collect::<Vec<_>>(), ["чаю", "чаёв"]);
+    }
+
+    #[test]
+    fn format() {
+        let paradigm =
+            noun("стол", "1b", GenderEx::Masculine, Animacy::Inanimate).format_paradigm().unwrap();
+        let expected = "\
+стол м 1b
+nom  стол    столы
+gen  стола   столов
+dat  столу   столам
+acc  стол    столы
+ins  столом  столами
+prp  столе   столах
+";
+        assert_eq!(paradigm, expected);
+    }
+
+    #[bench]
+    fn bench_noun_forms(b: &mut Bencher) {
+        let noun = Noun::new("стать", "ж 6*b".parse().unwrap());
+        b.iter(|| {
+            for form in black_box(&noun).forms() {
+                black_box(form.unwrap());
+            }
+        });
+    }
+}
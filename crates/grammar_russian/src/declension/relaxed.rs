@@ -0,0 +1,396 @@
+use crate::{
+    declension::{
+        AdjectiveDeclension, AdjectivePrefix, DECLENSION_INPUT_MAX_LEN, Declension, DeclensionKind,
+        NounDeclension, ParseDeclensionError, PronounDeclension,
+    },
+    stress::{StressRelaxations, normalize_separators},
+};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use bitflags::bitflags;
+
+bitflags! {
+    /// Deviations from the strict declension notation tolerated by [`Declension::parse_relaxed`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct RelaxationsApplied: u16 {
+        /// The kind prefix wasn't followed by a space: "мс1a", "п1a".
+        const MISSING_SPACE = 1 << 0;
+        /// An English kind prefix was used: "noun 1a", "pro 1a", "adj 1a".
+        const ENGLISH_PREFIX = 1 << 1;
+        /// The kind prefix contained Latin look-alike letters: "mc 1a", "мc 1a". A Latin "n" isn't
+        /// read as "п", since it could just as well be an abbreviation of "noun".
+        const LATIN_HOMOGLYPHS = 1 << 2;
+        /// The declension was followed by a period: "1a.".
+        const TRAILING_PERIOD = 1 << 3;
+        /// The stresses were separated by ':', '⁄' (U+2044) or '∕' (U+2215), instead of '/'.
+        const ALTERNATIVE_SLASH = 1 << 4;
+        /// The stress separator was surrounded by spaces: "4a / c′".
+        const SPACED_SLASH = 1 << 5;
+        /// The markers weren't in the canonical order (stress, circled digits, ё, -ся), or the
+        /// leading ° and * were swapped: "7*b′, ё ①", "7b′*".
+        const REORDERED_MARKERS = 1 << 6;
+        /// The markers were separated by single spaces or commas, or the commas before ё and -ся
+        /// were missing: "7 *b′ ①", "7*b′①,ё", "7*b′① ё".
+        const LOOSE_SEPARATORS = 1 << 7;
+        /// The markers were written in ASCII: 'o' or '^' for °, "yo" for ё, and "-sya" for -ся,
+        /// as in "7o*b'(1), yo". See [`Declension::fmt_ascii_to`].
+        const ASCII_MARKERS = 1 << 8;
+    }
+}
+
+impl Declension {
+    /// Parses a declension, tolerating some common deviations from the notation, that are often
+    /// found in scraped data. Returns the declension and the relaxations that had to be applied.
+    ///
+    /// See [`RelaxationsApplied`] for a list of the tolerated deviations. Otherwise, the notation
+    /// is parsed just as strictly as with [`FromStr`](std::str::FromStr), and inputs longer than
+    /// [`DECLENSION_INPUT_MAX_LEN`] are rejected before being scanned.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::declension::*;
+    ///
+    /// let (decl, relaxations) = Declension::parse_relaxed("мс1a.").unwrap();
+    /// assert_eq!(decl, "мс 1a".parse().unwrap());
+    /// assert_eq!(
+    ///     relaxations,
+    ///     RelaxationsApplied::MISSING_SPACE | RelaxationsApplied::TRAILING_PERIOD,
+    /// );
+    /// ```
+    pub fn parse_relaxed(s: &str) -> Result<(Self, RelaxationsApplied), ParseDeclensionError> {
+        if s.len() > DECLENSION_INPUT_MAX_LEN {
+            return Err(ParseDeclensionError::InputTooLong);
+        }
+        let mut relaxations = RelaxationsApplied::empty();
+
+        let (kind, prefix, rest) = strip_kind_prefix(s, &mut relaxations)?;
+
+        let rest = match rest.strip_suffix('.') {
+            Some(rest) => {
+                relaxations |= RelaxationsApplied::TRAILING_PERIOD;
+                rest
+            },
+            None => rest,
+        };
+
+        let mut stress_relaxations = StressRelaxations::empty();
+        let rest = normalize_separators(rest, &mut stress_relaxations);
+        if stress_relaxations.contains(StressRelaxations::ALTERNATIVE_SLASH) {
+            relaxations |= RelaxationsApplied::ALTERNATIVE_SLASH;
+        }
+        if stress_relaxations.contains(StressRelaxations::SPACED_SLASH) {
+            relaxations |= RelaxationsApplied::SPACED_SLASH;
+        }
+        let rest = normalize_markers(&rest, &mut relaxations)?;
+
+        let decl = match kind {
+            DeclensionKind::Noun => Self::Noun(rest.parse::<NounDeclension>()?),
+            DeclensionKind::Pronoun => Self::Pronoun(rest.parse::<PronounDeclension>()?),
+            DeclensionKind::Adjective => {
+                Self::Adjective(AdjectiveDeclension { prefix, ..rest.parse()? })
+            },
+        };
+        Ok((decl, relaxations))
+    }
+}
+
+fn strip_kind_prefix<'a>(
+    s: &'a str,
+    relaxations: &mut RelaxationsApplied,
+) -> Result<(DeclensionKind, AdjectivePrefix, &'a str), ParseDeclensionError> {
+    use {AdjectivePrefix as P, DeclensionKind as K, RelaxationsApplied as R};
+
+    // The compound prefixes go before the ones they start with
+    const PREFIXES: [(&str, DeclensionKind, AdjectivePrefix, RelaxationsApplied); 12] = [
+        ("мс-п", K::Adjective, P::Pronominal, R::empty()),
+        ("числ.-п", K::Adjective, P::Numeral, R::empty()),
+        ("мс", K::Pronoun, P::Adjective, R::empty()),
+        ("п", K::Adjective, P::Adjective, R::empty()),
+        ("noun", K::Noun, P::Adjective, R::ENGLISH_PREFIX),
+        ("pro-adj", K::Adjective, P::Pronominal, R::ENGLISH_PREFIX),
+        ("num-adj", K::Adjective, P::Numeral, R::ENGLISH_PREFIX),
+        ("pro", K::Pronoun, P::Adjective, R::ENGLISH_PREFIX),
+        ("adj", K::Adjective, P::Adjective, R::ENGLISH_PREFIX),
+        ("mc", K::Pronoun, P::Adjective, R::LATIN_HOMOGLYPHS),
+        ("mс", K::Pronoun, P::Adjective, R::LATIN_HOMOGLYPHS),
+        ("мc", K::Pronoun, P::Adjective, R::LATIN_HOMOGLYPHS),
+    ];
+
+    for (prefix, kind, adj_prefix, relaxation) in PREFIXES {
+        let Some(rest) = s.strip_prefix(prefix) else { continue };
+
+        *relaxations |= relaxation;
+        return match rest.strip_prefix(' ') {
+            Some(rest) => Ok((kind, adj_prefix, rest)),
+            None if rest.starts_with(|ch: char| ch.is_ascii_digit()) => {
+                *relaxations |= RelaxationsApplied::MISSING_SPACE;
+                Ok((kind, adj_prefix, rest))
+            },
+            None => Err(ParseDeclensionError::Invalid),
+        };
+    }
+    Ok((DeclensionKind::Noun, AdjectivePrefix::Adjective, s))
+}
+
+// The markers following the stem type, in their canonical order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Marker {
+    StemType,
+    Leading(u8),
+    Stress,
+    Circled(char),
+    Yo,
+    Reflexive,
+}
+
+// Rearranges the declension's markers into the canonical order and spacing, so that the strict
+// parser can parse it: "7 *b′, ё ①" into "7*b′①, ё". Anything that isn't a marker is rejected.
+fn normalize_markers<'a>(
+    s: &'a str,
+    relaxations: &mut RelaxationsApplied,
+) -> Result<Cow<'a, str>, ParseDeclensionError> {
+    const fn is_stress_char(ch: char) -> bool {
+        matches!(ch, 'a'..='f' | '′' | '″' | '\'' | '"' | '/')
+    }
+
+    // The markers with their original and canonical notation
+    let mut markers: Vec<(Marker, &str, &str)> = Vec::new();
+    let mut rest = s;
+    let mut after_separator = false;
+
+    while !rest.is_empty() {
+        if let Some(r) = [", ", ",", " "].into_iter().find_map(|sep| rest.strip_prefix(sep)) {
+            // Separators can't be doubled, or precede the stem type
+            if after_separator || markers.is_empty() {
+                return Err(ParseDeclensionError::Invalid);
+            }
+            (rest, after_separator) = (r, true);
+            continue;
+        }
+        after_separator = false;
+
+        let ch = rest.chars().next().unwrap();
+        let (marker, len) = match ch {
+            '0'..='9' => (Marker::StemType, rest.find(|ch: char| !ch.is_ascii_digit())),
+            '°' | 'o' | '^' => (Marker::Leading(0), Some(ch.len_utf8())),
+            '*' => (Marker::Leading(1), Some(1)),
+            '①'..='⑤' => (Marker::Circled(ch), Some(ch.len_utf8())),
+            'ё' => (Marker::Yo, Some(ch.len_utf8())),
+            _ if is_stress_char(ch) => (Marker::Stress, rest.find(|ch| !is_stress_char(ch))),
+            _ => match rest.as_bytes() {
+                [b'(', digit @ b'1'..=b'5', b')', ..] => {
+                    let circled = char::from_u32('①' as u32 + (digit - b'1') as u32).unwrap();
+                    (Marker::Circled(circled), Some(3))
+                },
+                _ if rest.starts_with("-ся") => (Marker::Reflexive, Some("-ся".len())),
+                _ if rest.starts_with("yo") => (Marker::Yo, Some("yo".len())),
+                _ if rest.starts_with("-sya") => (Marker::Reflexive, Some("-sya".len())),
+                _ => return Err(ParseDeclensionError::Invalid),
+            },
+        };
+        let (marker_str, r) = rest.split_at(len.unwrap_or(rest.len()));
+        let canonical = match (marker, marker_str) {
+            (Marker::Leading(0), "o" | "^") => "°",
+            (Marker::Yo, "yo") => "ё",
+            (Marker::Reflexive, "-sya") => "-ся",
+            _ => marker_str,
+        };
+        markers.push((marker, marker_str, canonical));
+        rest = r;
+    }
+    if after_separator || markers.first().is_some_and(|x| x.0 != Marker::StemType) {
+        return Err(ParseDeclensionError::Invalid);
+    }
+
+    let join = |markers: &[(Marker, &str, &str)], canonical: bool| {
+        let mut dst = String::with_capacity(s.len() + 4);
+        for &(marker, original_str, canonical_str) in markers {
+            if matches!(marker, Marker::Yo | Marker::Reflexive) {
+                dst.push_str(", ");
+            }
+            dst.push_str(if canonical { canonical_str } else { original_str });
+        }
+        dst
+    };
+
+    let loose = join(&markers, false) != s;
+    let reordered = !markers.is_sorted_by_key(|x| x.0);
+    let ascii = markers.iter().any(|x| x.1 != x.2);
+    if !(loose || reordered || ascii) {
+        return Ok(Cow::Borrowed(s));
+    }
+    if loose {
+        *relaxations |= RelaxationsApplied::LOOSE_SEPARATORS;
+    }
+    if reordered {
+        *relaxations |= RelaxationsApplied::REORDERED_MARKERS;
+    }
+    if ascii {
+        *relaxations |= RelaxationsApplied::ASCII_MARKERS;
+    }
+
+    // Each marker can only be specified once
+    markers.sort_by_key(|x| x.0);
+    if markers.windows(2).any(|x| x[0].0 == x[1].0) {
+        return Err(ParseDeclensionError::Invalid);
+    }
+    Ok(Cow::Owned(join(&markers, true)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn relaxations() {
+        type R = RelaxationsApplied;
+
+        let assert = |s: &str, expected: &str, relaxations: R| {
+            let expected: Declension = expected.parse().unwrap();
+            assert_eq!(Declension::parse_relaxed(s), Ok((expected, relaxations)), "{s}");
+        };
+
+        // Strict notation
+        assert("1a", "1a", R::empty());
+        assert("мс 6*b", "мс 6*b", R::empty());
+        assert("п 4a/c′", "п 4a/c′", R::empty());
+
+        // Individual relaxations
+        assert("мс1a", "мс 1a", R::MISSING_SPACE);
+        assert("п3*a/c", "п 3*a/c", R::MISSING_SPACE);
+        assert("noun 1a", "1a", R::ENGLISH_PREFIX);
+        assert("pro 6*b", "мс 6*b", R::ENGLISH_PREFIX);
+        assert("adj 4a/c′", "п 4a/c′", R::ENGLISH_PREFIX);
+        assert("mc 1a", "мс 1a", R::LATIN_HOMOGLYPHS);
+        assert("мc 1a", "мс 1a", R::LATIN_HOMOGLYPHS);
+        assert("3*d.", "3*d", R::TRAILING_PERIOD);
+
+        // Combined relaxations
+        assert("adj1a", "п 1a", R::ENGLISH_PREFIX | R::MISSING_SPACE);
+        assert("mc1a.", "мс 1a", R::LATIN_HOMOGLYPHS | R::MISSING_SPACE | R::TRAILING_PERIOD);
+        assert("noun 8°c, ё.", "8°c, ё", R::ENGLISH_PREFIX | R::TRAILING_PERIOD);
+        assert("п 1a:c′", "п 1a/c′", R::ALTERNATIVE_SLASH);
+        assert("п 1a⁄c′", "п 1a/c′", R::ALTERNATIVE_SLASH);
+        assert("п 1a∕c′", "п 1a/c′", R::ALTERNATIVE_SLASH);
+        assert("п 1*a / c″, ё", "п 1*a/c″, ё", R::SPACED_SLASH);
+        let all = R::MISSING_SPACE
+            | R::ENGLISH_PREFIX
+            | R::TRAILING_PERIOD
+            | R::ALTERNATIVE_SLASH
+            | R::SPACED_SLASH;
+        assert("adj4a ∕ c′.", "п 4a/c′", all);
+
+        // The strict parser still rejects them
+        for s in ["мс1a", "noun 1a", "mc 1a", "3*d.", "adj1a", "п 1a:c′", "п 1a / c′"] {
+            assert!(s.parse::<Declension>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn still_invalid() {
+        let assert = |s: &str| {
+            assert!(Declension::parse_relaxed(s).is_err(), "{s}");
+        };
+
+        assert("");
+        assert(".");
+        assert("1a..");
+        assert("мс");
+        assert("мс  1a");
+        assert("мсx1a");
+        assert("adjective 1a");
+        assert("noun");
+        // A Latin "n" is ambiguous between "п" and "noun", and isn't guessed
+        assert("n 1a");
+        assert("n1a");
+        assert("n 4a/c′");
+        assert("mc 8a");
+        assert("adj 8a");
+        assert("1z");
+        assert(". 1a");
+        assert("п 1a  /  c′");
+        assert("п 1a:/c′");
+    }
+
+    #[test]
+    fn markers() {
+        type R = RelaxationsApplied;
+        let (loose, reordered) = (R::LOOSE_SEPARATORS, R::REORDERED_MARKERS);
+
+        let assert = |s: &str, relaxations: R| {
+            let (decl, applied) = Declension::parse_relaxed(s).unwrap();
+            assert_eq!((decl.to_string().as_str(), applied), ("7°*b′①②, ё", relaxations), "{s}");
+        };
+
+        assert("7°*b′①②, ё", R::empty());
+        assert("7°*b′(1)(2), ё", R::empty());
+        assert("7°*b′ ①②, ё", loose);
+        assert("7 °*b′ ① ②, ё", loose);
+        assert("7°*b′①②,ё", loose);
+        assert("7°*b′①② ё", loose);
+        assert("7°*b′①②ё.", loose | R::TRAILING_PERIOD);
+        assert("7*°b′①②, ё", reordered);
+        assert("7°*b′②①, ё", reordered);
+        assert("7°*①②b′, ё", reordered);
+        assert("7°*b′, ё①②", reordered);
+        assert("7°*b′, ё ②①", loose | reordered);
+        assert("7 * ° b′, ё, ①, ②", loose | reordered);
+        assert("noun 7°*b′ ё (1)(2)", R::ENGLISH_PREFIX | loose | reordered);
+
+        // The other relaxations still apply
+        let (decl, applied) = Declension::parse_relaxed("adj1*a / c′ ④, -ся.").unwrap();
+        assert_eq!(decl, "п 1*a/c′④, -ся".parse().unwrap());
+        assert_eq!(
+            applied,
+            R::ENGLISH_PREFIX | R::MISSING_SPACE | R::TRAILING_PERIOD | R::SPACED_SLASH | loose,
+        );
+
+        // Duplicate, unknown and misplaced markers, and doubled separators aren't tolerated
+        for s in [
+            "7*b′①①",
+            "7*b′①(1)",
+            "7*b′, ё, ё",
+            "7*b′ c",
+            "7**b′",
+            "7*b′ x",
+            "*7b′",
+            "b′7*",
+            "7*b′,, ё",
+            "7*b′  ①",
+            "7*b′ ①,",
+            "7*b′ , ё",
+            "7*b′ ①, ё ",
+            ", 7*b′",
+            "7*b′ ся",
+        ] {
+            assert!(Declension::parse_relaxed(s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn ascii() {
+        type R = RelaxationsApplied;
+
+        let assert = |s: &str, expected: &str, relaxations: R| {
+            let expected: Declension = expected.parse().unwrap();
+            assert_eq!(Declension::parse_relaxed(s), Ok((expected, relaxations)), "{s}");
+        };
+
+        // Primes and parenthesized digits are accepted by the strict parser too
+        assert("п 1a/c''(1)(2)", "п 1a/c″①②", R::empty());
+        assert("8o*f''(1)(2)(3), yo", "8°*f″①②③, ё", R::ASCII_MARKERS);
+        assert("8^*f''", "8°*f″", R::ASCII_MARKERS);
+        assert("7*b', yo", "7*b′, ё", R::ASCII_MARKERS);
+        assert("adj 4a/c', -sya", "п 4a/c′, -ся", R::ENGLISH_PREFIX | R::ASCII_MARKERS);
+        assert("pro 6*b", "мс 6*b", R::ENGLISH_PREFIX);
+        assert("8o*f''(1),yo", "8°*f″①, ё", R::ASCII_MARKERS | R::LOOSE_SEPARATORS);
+        let all =
+            R::TRAILING_PERIOD | R::REORDERED_MARKERS | R::LOOSE_SEPARATORS | R::ASCII_MARKERS;
+        assert("3*o b yo.", "3°*b, ё", all);
+
+        // Duplicate and unknown ASCII markers are rejected
+        for s in ["8o°b", "8^ob", "7b, yo, ё", "7b y", "7b, -sy", "п 1a, -sya, -ся"] {
+            assert!(Declension::parse_relaxed(s).is_err(), "{s}");
+        }
+    }
+}
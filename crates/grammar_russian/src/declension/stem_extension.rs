@@ -0,0 +1,111 @@
+use crate::{
+    InflectionBuffer, Letter,
+    categories::{Case, Gender, HasNumber},
+    declension::{DeclInfo, DeclensionFlags},
+    letters,
+};
+
+/// A stem extension of a noun with unique stem alternation (°), that is inserted between the stem
+/// and the ending in all forms, except for the nominative and accusative singular.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StemExtension {
+    /// -ен-/-ён- of neuter nouns in -мя: время - времени - времена, знамя - знамён.
+    En,
+    /// -ер- of feminine nouns мать and дочь: мать - матери - матерью, дочь - дочерей.
+    Er,
+}
+
+impl StemExtension {
+    /// Determines the stem extension of a noun with unique stem alternation (°) by its stem.
+    pub const fn detect(stem: &[Letter], gender: Gender) -> Option<Self> {
+        match (stem, gender) {
+            ([.., letters::м], Gender::Neuter) => Some(Self::En),
+            ([.., letters::т | letters::ч], Gender::Feminine) => Some(Self::Er),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the form is extended. Only the nominative and accusative singular aren't.
+    pub const fn is_extended(self, info: DeclInfo) -> bool {
+        info.is_plural() || !matches!(info.case, Case::Nominative | Case::Accusative)
+    }
+
+    /// Returns the extension inserted into the form, or an empty string if it isn't extended.
+    pub const fn infix(self, flags: DeclensionFlags, info: DeclInfo) -> &'static str {
+        if !self.is_extended(info) {
+            return "";
+        }
+        match self {
+            Self::En => {
+                // The е/ё alternation is only in genitive plural (знамён, времён)
+                let use_yo = flags.has_alternating_yo()
+                    && info.is_plural()
+                    && info.case.is_gen_or_acc_an(info);
+                if use_yo { "ён" } else { "ен" }
+            },
+            Self::Er => "ер",
+        }
+    }
+
+    /// Returns the ending that replaces the one from the declension table, if any.
+    pub const fn ending(self, info: DeclInfo) -> Option<&'static str> {
+        match self {
+            // Neuter endings of stem type 8 are only used in plural: времена, времён, временам
+            Self::En if info.is_singular() => Some(match info.case {
+                Case::Nominative | Case::Accusative => "я",
+                Case::Instrumental => "ем",
+                _ => "и",
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn apply(self, flags: DeclensionFlags, info: DeclInfo, buf: &mut InflectionBuffer) {
+        if let Some(ending) = self.ending(info) {
+            buf.replace_ending_unchecked(ending);
+        }
+        buf.append_to_stem_unchecked(self.infix(flags, info));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::Animacy,
+        declension::{Noun, NounInfo},
+        util::assert_snapshot,
+    };
+
+    fn noun<'a>(stem: &'a str, decl: &str, gender: Gender, animacy: Animacy) -> Noun<'a> {
+        let info = NounInfo {
+            declension: Some(decl.parse().unwrap()),
+            declension_gender: gender,
+            gender: gender.into(),
+            animacy,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+
+    #[test]
+    fn er() {
+        // The more common instrumental plural дочерьми is an irregular form (-ьми)
+        let paradigms = [
+            noun("мат", "8°e", Gender::Feminine, Animacy::Animate).format_paradigm().unwrap(),
+            noun("доч", "8°e", Gender::Feminine, Animacy::Animate).format_paradigm().unwrap(),
+        ];
+        assert_snapshot("noun_stem_extension_er", &paradigms.join("\n"));
+    }
+
+    #[test]
+    fn en() {
+        let paradigms = [
+            noun("врем", "8°c, ё", Gender::Neuter, Animacy::Inanimate).format_paradigm().unwrap(),
+            noun("им", "8°c, ё", Gender::Neuter, Animacy::Inanimate).format_paradigm().unwrap(),
+        ];
+        assert_snapshot("noun_stem_extension_en", &paradigms.join("\n"));
+    }
+}
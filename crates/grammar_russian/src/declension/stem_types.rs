@@ -1,4 +1,4 @@
-use crate::util::enum_conversion;
+use crate::{Letter, letters, util::enum_conversion};
 use thiserror::Error;
 
 macro_rules! impl_stem_type {
@@ -11,9 +11,9 @@ macro_rules! impl_stem_type {
         $vis_e:vis struct $E:ident($error:expr);
     ) => (
         $(#[$outer])*
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
         $vis enum $T {
-            $($(#[$inner])* $variant,)+
+            $($(#[$inner])* $variant = $value,)+
         }
         $(#[$outer_e])*
         #[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
@@ -21,36 +21,57 @@ macro_rules! impl_stem_type {
         $vis_e struct $E;
 
         impl $T {
+            pub const VALUES: [Self; [$($value),*].len()] = [$(<$T>::$variant),*];
+
+            /// Returns the stem type with the specified number (`1` for stem type 1), or `None`
+            /// if there's no such stem type.
             pub const fn from_digit(num: u8) -> Option<Self> {
                 Some(match num {
                     $($value => <$T>::$variant,)*
                     _ => return None,
                 })
             }
-            pub const fn from_ascii_digit(num: u8) -> Option<Self> {
-                Self::from_digit(num - b'0')
+            /// Returns the stem type with the specified ASCII digit (`b'1'` for stem type 1), or
+            /// `None` if the byte isn't a digit of an existing stem type.
+            pub const fn from_ascii_digit(ch: u8) -> Option<Self> {
+                Self::from_digit(ch.wrapping_sub(b'0'))
             }
+            /// Returns the stem type's number (`1` for stem type 1).
             pub const fn to_digit(&self) -> u8 {
                 match self {
                     $(<$T>::$variant => $value,)*
                 }
             }
+            /// Returns the stem type's ASCII digit (`b'1'` for stem type 1).
             pub const fn to_ascii_digit(&self) -> u8 {
                 b'0' + self.to_digit()
             }
         }
-        impl std::fmt::Display for $T {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                self.to_ascii_digit().fmt(f)
+        impl const TryFrom<u8> for $T {
+            type Error = $E;
+            fn try_from(value: u8) -> Result<Self, Self::Error> {
+                match Self::from_digit(value) {
+                    Some(x) => Ok(x),
+                    None => Err($E),
+                }
+            }
+        }
+        impl const From<$T> for u8 {
+            fn from(value: $T) -> u8 {
+                value.to_digit()
             }
         }
-        impl std::str::FromStr for $T {
+        impl core::fmt::Display for $T {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                self.to_digit().fmt(f)
+            }
+        }
+        impl core::str::FromStr for $T {
             type Err = $E;
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                if let [ch] = s.as_bytes() {
-                    Self::from_ascii_digit(*ch).ok_or(Self::Err {})
-                } else {
-                    Err(Self::Err {})
+                match s.as_bytes() {
+                    [ch] => Self::from_ascii_digit(*ch).ok_or($E),
+                    _ => Err($E),
                 }
             }
         }
@@ -94,3 +115,225 @@ enum_conversion!(PronounStemType => AnyStemType [<= PronounStemTypeError] {
 enum_conversion!(AdjectiveStemType => AnyStemType [<= AdjectiveStemTypeError] {
     Type1, Type2, Type3, Type4, Type5, Type6, Type7,
 });
+
+// Conversions between the stem types of different kinds of words
+enum_conversion!(AdjectiveStemType => NounStemType [<= AdjectiveStemTypeError] {
+    Type1, Type2, Type3, Type4, Type5, Type6, Type7,
+});
+enum_conversion!(PronounStemType => NounStemType [<= PronounStemTypeError] {
+    Type1, Type2, Type4, Type6,
+});
+enum_conversion!(PronounStemType => AdjectiveStemType [<= PronounStemTypeError] {
+    Type1, Type2, Type4, Type6,
+});
+
+impl AnyStemType {
+    /// Determines whether a stem with the specified final letter can be of this stem type.
+    ///
+    /// Note that stems ending in 'и' are always of stem type 7 (Мария, гербарий, собрание),
+    /// and stems ending in any other vowel or 'ь' - of stem type 6 (Марья, музей, воскресенье).
+    pub const fn allows_stem_final(self, last: Letter) -> bool {
+        use letters as lt;
+
+        match self {
+            Self::Type1 | Self::Type2 => {
+                last.is_non_sibilant_consonant() && !matches!(last, lt::к | lt::г | lt::х | lt::й)
+            },
+            Self::Type3 => matches!(last, lt::к | lt::г | lt::х),
+            Self::Type4 => last.is_hissing(),
+            Self::Type5 => matches!(last, lt::ц),
+            Self::Type6 => last.is_vowel() && !matches!(last, lt::и) || matches!(last, lt::ь),
+            Self::Type7 => matches!(last, lt::и),
+            Self::Type8 => last.is_consonant() && !matches!(last, lt::й),
+        }
+    }
+    /// Determines whether the stem can be of this stem type, by its final letter (in any case).
+    /// Empty stems don't match any stem type.
+    ///
+    /// Type 1 stems end in a hard consonant (стол), type 2 - in a soft one (конь → кон), type 3 -
+    /// in 'к', 'г' or 'х', type 4 - in a hissing consonant, type 5 - in 'ц', type 6 - in a vowel
+    /// or 'ь' (музей → музе, статья → стать), type 7 - in 'и', and type 8 - in any consonant
+    /// except 'й' (тень → тен).
+    pub const fn matches_stem(self, stem: &[Letter]) -> bool {
+        match stem {
+            [.., last] => self.allows_stem_final(last.to_lowercase()),
+            [] => false,
+        }
+    }
+}
+
+macro_rules! impl_stem_final_methods {
+    ($($T:ty),+ $(,)?) => ($(
+        impl $T {
+            /// Determines whether a stem with the specified final letter can be of this stem
+            /// type. See [`AnyStemType::allows_stem_final`].
+            pub const fn allows_stem_final(self, last: Letter) -> bool {
+                AnyStemType::from(self).allows_stem_final(last)
+            }
+            /// Determines whether the stem can be of this stem type, by its final letter.
+            /// See [`AnyStemType::matches_stem`].
+            pub const fn matches_stem(self, stem: &[Letter]) -> bool {
+                AnyStemType::from(self).matches_stem(stem)
+            }
+        }
+    )+);
+}
+impl_stem_final_methods!(NounStemType, AdjectiveStemType, PronounStemType);
+
+/// An error returned when a stem's final letter isn't allowed in the declension's stem type, for
+/// example, a stem of type 3 that doesn't end in 'к', 'г' or 'х'.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("the stem can't be of stem type {stem_type}")]
+pub struct StemMismatchError {
+    /// The stem type of the declension.
+    pub stem_type: AnyStemType,
+    /// The final letter of the stem, or `None` if the stem is empty.
+    pub last: Option<Letter>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{string::ToString, vec::Vec};
+
+    #[test]
+    fn stem_finals() {
+        // Letters that can be the final letter of a stem of each stem type
+        let table = [
+            (AnyStemType::Type1, "бвдзлмнпрстф"),
+            (AnyStemType::Type2, "бвдзлмнпрстф"),
+            (AnyStemType::Type3, "гкх"),
+            (AnyStemType::Type4, "жчшщ"),
+            (AnyStemType::Type5, "ц"),
+            (AnyStemType::Type6, "аеёоуыэюяь"),
+            (AnyStemType::Type7, "и"),
+            (AnyStemType::Type8, "бвгджзклмнпрстфхцчшщ"),
+        ];
+
+        for (stem_type, allowed) in table {
+            for ch in "абвгдеёжзийклмнопрстуфхцчшщъыьэюя".chars() {
+                let expected = allowed.contains(ch);
+                assert_eq!(
+                    stem_type.allows_stem_final(Letter::from(ch)),
+                    expected,
+                    "{stem_type} {ch}"
+                );
+            }
+        }
+
+        // The narrower stem types agree with the general one
+        for stem_type in AnyStemType::VALUES {
+            for ch in "абвгдеёжзийклмнопрстуфхцчшщъыьэюя".chars() {
+                let (letter, expected) =
+                    (Letter::from(ch), stem_type.allows_stem_final(Letter::from(ch)));
+                assert_eq!(NounStemType::from(stem_type).allows_stem_final(letter), expected);
+                if let Ok(adj) = AdjectiveStemType::try_from(stem_type) {
+                    assert_eq!(adj.allows_stem_final(letter), expected);
+                }
+                if let Ok(pro) = PronounStemType::try_from(stem_type) {
+                    assert_eq!(pro.allows_stem_final(letter), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_stem() {
+        let letters = |s: &str| Letter::iter(s).collect::<Vec<_>>();
+
+        // Stems that match each stem type, and ones that don't
+        let table = [
+            (AnyStemType::Type1, ["стол", "ЗАВОД", "мам"], ["", "конь", "сапог"]),
+            (AnyStemType::Type2, ["кон", "ДЯД", "пол"], ["", "нож", "край"]),
+            (AnyStemType::Type3, ["сапог", "Книг", "пастух"], ["", "стол", "нож"]),
+            (AnyStemType::Type4, ["нож", "туч", "плащ"], ["", "отец", "сапог"]),
+            (AnyStemType::Type5, ["отец", "птиц", "ЛИЦ"], ["", "нож", "кон"]),
+            (AnyStemType::Type6, ["музе", "стать", "Марь"], ["", "гербари", "стол"]),
+            (AnyStemType::Type7, ["гербари", "Мари", "собрани"], [
+                "", "музе", "край",
+            ]),
+            (AnyStemType::Type8, ["тен", "врем", "ноч"], ["", "музе", "край"]),
+        ];
+        for (stem_type, matching, other) in table {
+            for stem in matching {
+                assert!(stem_type.matches_stem(&letters(stem)), "{stem_type} {stem}");
+            }
+            for stem in other {
+                assert!(!stem_type.matches_stem(&letters(stem)), "{stem_type} {stem}");
+            }
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        // Conversions between the stem types keep the number, and fail if there's no such type
+        for any in AnyStemType::VALUES {
+            let noun = NounStemType::from(any);
+            assert_eq!(noun.to_digit(), any.to_digit());
+            assert_eq!(AnyStemType::from(noun), any);
+
+            let adj = AdjectiveStemType::try_from(noun);
+            assert_eq!(adj, AdjectiveStemType::try_from(any), "{any}");
+            let pro = PronounStemType::try_from(noun);
+            assert_eq!(pro, PronounStemType::try_from(any), "{any}");
+
+            if let Ok(adj) = adj {
+                assert_eq!(NounStemType::from(adj), noun);
+                assert_eq!(PronounStemType::try_from(adj), pro, "{any}");
+            }
+            if let Ok(pro) = pro {
+                assert_eq!(NounStemType::from(pro), noun);
+                assert_eq!(AdjectiveStemType::from(pro), adj.unwrap());
+            }
+        }
+
+        // The errors explain which stem types are valid for the kind of word
+        let err = AdjectiveStemType::try_from(NounStemType::Type8).unwrap_err();
+        assert_eq!(err.to_string(), "adjectives can only have stem types 1 through 7");
+        let err = PronounStemType::try_from(AdjectiveStemType::Type3).unwrap_err();
+        assert_eq!(err.to_string(), "pronouns can only have stem types 1, 2, 4 and 6");
+
+        // Stem types can be converted in const contexts
+        const ADJ: AdjectiveStemType = AdjectiveStemType::from(PronounStemType::Type4);
+        assert_eq!(ADJ, AdjectiveStemType::Type4);
+    }
+
+    #[test]
+    fn digits() {
+        macro_rules! assert_digits {
+            ($T:ty, $E:expr, [$($digit:literal),*]) => {{
+                let digits: &[u8] = &[$($digit),*];
+                assert_eq!(<$T>::VALUES.map(|x| x.to_digit()), digits);
+
+                for num in 0..=9_u8 {
+                    let ch = b'0' + num;
+                    let expected = <$T>::VALUES.into_iter().find(|x| x.to_digit() == num);
+
+                    assert_eq!(<$T>::from_digit(num), expected, "{num}");
+                    assert_eq!(<$T>::from_ascii_digit(ch), expected, "{num}");
+                    assert_eq!(<$T>::try_from(num), expected.ok_or($E), "{num}");
+                    assert_eq!((ch as char).to_string().parse::<$T>(), expected.ok_or($E), "{num}");
+
+                    // The ASCII digits are not numeric values, and vice versa
+                    assert_eq!(<$T>::try_from(ch), Err($E), "{num}");
+                    assert_eq!(<$T>::from_ascii_digit(num), None, "{num}");
+
+                    if let Some(x) = expected {
+                        assert_eq!(u8::from(x), num);
+                        assert_eq!(x.to_ascii_digit(), ch);
+                        assert_eq!(x.to_string(), (ch as char).to_string());
+                    }
+                }
+                assert_eq!("".parse::<$T>(), Err($E));
+                assert_eq!("11".parse::<$T>(), Err($E));
+                assert_eq!(<$T>::from_ascii_digit(0), None);
+                assert_eq!(<$T>::from_ascii_digit(u8::MAX), None);
+            }};
+        }
+
+        assert_digits!(AnyStemType, AnyStemTypeError, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_digits!(NounStemType, NounStemTypeError, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_digits!(PronounStemType, PronounStemTypeError, [1, 2, 4, 6]);
+        assert_digits!(AdjectiveStemType, AdjectiveStemTypeError, [1, 2, 3, 4, 5, 6, 7]);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    InflectionBuffer,
+    declension::{DeclInfo, Declension},
+    letters,
+};
+use alloc::{borrow::Cow, string::String};
+
+/// The combining acute accent (U+0301), that marks the stressed vowel.
+pub(crate) const STRESS_MARK: char = '\u{0301}';
+
+/// Removes the stress mark from the stem, and returns the stem along with the index of the
+/// stressed letter (the one preceding the mark), if the stem was marked: "мо́лот" → ("молот", 1).
+pub(crate) fn split_stress_mark(stem: &str) -> (Cow<'_, str>, Option<usize>) {
+    let Some(byte_index) = stem.find(STRESS_MARK) else { return (Cow::Borrowed(stem), None) };
+
+    let stressed = stem[..byte_index].chars().count().checked_sub(1);
+    (Cow::Owned(stem.chars().filter(|&ch| ch != STRESS_MARK).collect()), stressed)
+}
+
+/// Returns the index of the stressed vowel in the inflected form, if it's known and the form
+/// isn't monosyllabic.
+///
+/// In ending-stressed forms, the stress falls onto the first vowel of the ending, or onto the
+/// last vowel of the stem if the ending has no vowels. In stem-stressed forms, the stress is
+/// known only if it was set in the buffer (see [`split_stress_mark`]), if the stem has 'ё', or if
+/// the stem has only one vowel.
+pub(crate) fn stressed_vowel(
+    decl: Declension,
+    info: DeclInfo,
+    buf: &InflectionBuffer,
+) -> Option<usize> {
+    let is_stem_stressed = match decl {
+        Declension::Noun(decl) => decl.stress.is_stem_stressed(info),
+        Declension::Adjective(decl) => decl.stress.full.is_stem_stressed(),
+        Declension::Pronoun(decl) => decl.stress.is_stem_stressed(info),
+    };
+    let (stem, ending) = (buf.stem(), buf.ending());
+
+    let vowels = stem.iter().chain(ending).filter(|x| x.is_vowel()).count();
+    if vowels < 2 {
+        return None;
+    }
+
+    if !is_stem_stressed {
+        // If the ending has no vowels, the stress falls onto the last vowel of the stem
+        return match ending.iter().position(|x| x.is_vowel()) {
+            Some(i) => Some(stem.len() + i),
+            None => stem.iter().rposition(|x| x.is_vowel()),
+        };
+    }
+    // The stress of the original stem is tracked through the stem alternations
+    if let Some(i) = buf.stress() {
+        return Some(i);
+    }
+    // The letter 'ё' is always stressed, otherwise the stress is known only in one-vowel stems
+    if let Some(i) = stem.iter().position(|&x| x == letters::ё) {
+        return Some(i);
+    }
+    let mut stem_vowels = stem.iter().enumerate().filter(|(_, x)| x.is_vowel());
+    match (stem_vowels.next(), stem_vowels.next()) {
+        (Some((i, _)), None) => Some(i),
+        _ => None,
+    }
+}
+
+/// Appends the form in the buffer to the string, with the stress mark after the stressed letter.
+/// The letter 'ё' is inherently stressed, and is marked only if it's folded into 'е'.
+pub(crate) fn push_stressed(
+    dst: &mut String,
+    buf: &InflectionBuffer,
+    stressed: Option<usize>,
+    fold_yo: bool,
+) {
+    let letters = buf.stem().iter().chain(buf.ending());
+
+    for (i, &letter) in letters.enumerate() {
+        let is_yo = letter == letters::ё;
+        dst.push_str(if is_yo && fold_yo { "е" } else { letter.as_str() });
+
+        if stressed == Some(i) && (!is_yo || fold_yo) {
+            dst.push(STRESS_MARK);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        assert_eq!(split_stress_mark("стол"), (Cow::Borrowed("стол"), None));
+        assert_eq!(split_stress_mark("мо́лот"), (Cow::Owned("молот".into()), Some(1)));
+        assert_eq!(split_stress_mark("молото́к"), (Cow::Owned("молоток".into()), Some(5)));
+        // A mark without a preceding letter is dropped
+        assert_eq!(split_stress_mark("\u{0301}окн"), (Cow::Owned("окн".into()), None));
+    }
+}
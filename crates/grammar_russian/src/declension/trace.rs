@@ -0,0 +1,233 @@
+use crate::{
+    InflectionBuffer, Letter,
+    declension::{DeclInfo, InflectError, NounDeclension, StemExtension},
+};
+use alloc::vec::Vec;
+
+/// A record of how a noun's form was inflected: the ending, that was taken from the tables, the
+/// stress, and the alternations, that changed the form. See [`NounDeclension::inflect_traced`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InflectTrace {
+    /// The unstressed and the stressed variants of the ending, see
+    /// [`NounDeclension::ending_pair`]. The alternations may change the ending afterwards.
+    pub ending_pair: (&'static str, &'static str),
+    /// Whether the stressed variant of the ending was used. It's also `true`, if both variants
+    /// are the same.
+    pub used_stressed: bool,
+    /// Whether the stress schema puts the stress on the ending in this form, see
+    /// [`NounStress::is_ending_stressed`][crate::stress::NounStress::is_ending_stressed].
+    pub is_ending_stressed: bool,
+    /// The alternations, that changed the form, in the order they were applied.
+    pub alternations: Vec<Alternation>,
+}
+
+impl InflectTrace {
+    /// Returns the ending, that was taken from the tables.
+    pub const fn ending(&self) -> &'static str {
+        if self.used_stressed { self.ending_pair.1 } else { self.ending_pair.0 }
+    }
+}
+
+/// A stem or ending alternation, applied to a noun's form. See [`InflectTrace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alternation {
+    /// The fleeting vowel was removed or inserted (*): сон - сна, окно - окон.
+    Star,
+    /// The unique stem alternation (°) was applied: время - времени, ребёнок - ребята.
+    Circle(UniqueAlternation),
+    /// The 'е' in the stem was stressed into 'ё', or the 'ё' was unstressed into 'е' (ё):
+    /// жена - жёны, ёж - ежа.
+    YeYo,
+    /// The ending's 'я' was replaced with 'а' after a hissing consonant in stem type 8: ночь -
+    /// ночам.
+    Type8YaToA,
+}
+
+/// A kind of the unique stem alternation (°), determined by the noun's stem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniqueAlternation {
+    /// A stem extension is inserted before the ending: время - времени, мать - матери.
+    Extension(StemExtension),
+    /// -ин is removed in the plural: крестьянин - крестьяне.
+    In,
+    /// -\[оё\]нок becomes -\[ая\]т in the plural: ребёнок - ребята, мышонок - мышата.
+    Onok,
+    /// -ок becomes -\[ая\]т in the plural: щенок - щенята.
+    Ok,
+    /// -\[оё\]ночек becomes -\[ая\]тк in the plural: телёночек - телятки.
+    Onochek,
+    /// -очек becomes -\[ая\]тк in the plural: щеночек - щенятки.
+    Ochek,
+}
+
+// Applies the alternations, and observes the changes they make to the form
+pub(super) trait AlternationTracer {
+    fn apply(
+        &mut self,
+        buf: &mut InflectionBuffer,
+        f: impl FnOnce(&mut InflectionBuffer) -> Result<Alternation, InflectError>,
+    ) -> Result<(), InflectError>;
+}
+// The normal inflection only applies the alternations
+impl AlternationTracer for () {
+    fn apply(
+        &mut self,
+        buf: &mut InflectionBuffer,
+        f: impl FnOnce(&mut InflectionBuffer) -> Result<Alternation, InflectError>,
+    ) -> Result<(), InflectError> {
+        f(buf)?;
+        Ok(())
+    }
+}
+// The traced inflection records the alternations, that changed the stem or the ending
+impl AlternationTracer for Vec<Alternation> {
+    fn apply(
+        &mut self,
+        buf: &mut InflectionBuffer,
+        f: impl FnOnce(&mut InflectionBuffer) -> Result<Alternation, InflectError>,
+    ) -> Result<(), InflectError> {
+        let before: Vec<Letter> = buf.as_letters().to_vec();
+        let stem_len = buf.stem_len();
+
+        let alternation = f(buf)?;
+        if buf.as_letters() != before || buf.stem_len() != stem_len {
+            self.push(alternation);
+        }
+        Ok(())
+    }
+}
+
+impl NounDeclension {
+    /// Same as [`inflect`][Self::inflect], but also returns the trace of the inflection: the
+    /// chosen ending, the stress, and the alternations, that changed the form. Meant for showing
+    /// why a form looks the way it does, and slower than the normal inflection.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{InflectionBuffer, categories::*, declension::*};
+    ///
+    /// let decl: NounDeclension = "1d, ё".parse().unwrap();
+    /// let info = DeclInfo::new(Case::Nominative, Number::Plural, Gender::Feminine, Animacy::Animate);
+    ///
+    /// let mut buf = InflectionBuffer::from_stem("жен").unwrap();
+    /// let trace = decl.inflect_traced(info, &mut buf).unwrap();
+    /// assert_eq!(buf.as_str(), "жёны");
+    /// assert_eq!((trace.ending(), trace.is_ending_stressed), ("ы", false));
+    /// assert_eq!(trace.alternations, [Alternation::YeYo]);
+    /// ```
+    pub fn inflect_traced(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+    ) -> Result<InflectTrace, InflectError> {
+        let (un_str, str) = self.ending_pair(info);
+        let is_ending_stressed = self.stress.is_ending_stressed(info);
+        let mut trace = InflectTrace {
+            ending_pair: (un_str, str),
+            used_stressed: un_str == str || is_ending_stressed,
+            is_ending_stressed,
+            alternations: Vec::new(),
+        };
+
+        buf.append_to_ending_unchecked(trace.ending());
+        self.apply_alternations_traced(info, buf, &mut |_| {}, &mut trace.alternations)?;
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Animacy, Case, Gender, Number};
+    use std::string::String;
+
+    fn trace(stem: &str, decl: &str, info: DeclInfo) -> (String, InflectTrace) {
+        let decl: NounDeclension = decl.parse().unwrap();
+        let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+        let trace = decl.inflect_traced(info, &mut buf).unwrap();
+
+        // The traced inflection produces the same form as the normal one
+        let mut expected = InflectionBuffer::from_stem(stem).unwrap();
+        decl.inflect(info, &mut expected).unwrap();
+        assert_eq!(buf.as_str(), expected.as_str());
+
+        (buf.as_str().into(), trace)
+    }
+
+    #[test]
+    fn rebyonok() {
+        use {Case::*, Number::*};
+        let info = |case, number| DeclInfo::new(case, number, Gender::Masculine, Animacy::Animate);
+
+        // The alternation doesn't change the nominative singular
+        let (form, t) = trace("ребёнок", "3°a", info(Nominative, Singular));
+        assert_eq!((form.as_str(), t.ending()), ("ребёнок", ""));
+        assert_eq!(t.alternations, []);
+
+        // The vowel of -нок is removed in the other singular forms
+        let (form, t) = trace("ребёнок", "3°a", info(Dative, Singular));
+        assert_eq!((form.as_str(), t.ending()), ("ребёнку", "у"));
+        assert_eq!(t.alternations, [Alternation::Circle(UniqueAlternation::Onok)]);
+
+        // and the stem and the ending are replaced in the plural
+        let (form, t) = trace("ребёнок", "3°a", info(Nominative, Plural));
+        assert_eq!((form.as_str(), t.ending_pair), ("ребята", ("и", "и")));
+        assert!(!t.is_ending_stressed && t.used_stressed);
+        assert_eq!(t.alternations, [Alternation::Circle(UniqueAlternation::Onok)]);
+    }
+
+    #[test]
+    fn zhena() {
+        use {Case::*, Number::*};
+        let info = |case, number| DeclInfo::new(case, number, Gender::Feminine, Animacy::Animate);
+
+        // The 'е' is stressed into 'ё' only in the stem-stressed plural
+        let (form, t) = trace("жен", "1d, ё", info(Nominative, Singular));
+        assert_eq!((form.as_str(), t.is_ending_stressed), ("жена", true));
+        assert_eq!(t.alternations, []);
+        let (form, t) = trace("жен", "1d, ё", info(Dative, Plural));
+        assert_eq!((form.as_str(), t.is_ending_stressed), ("жёнам", false));
+        assert_eq!(t.alternations, [Alternation::YeYo]);
+
+        // The stressed and unstressed endings of the instrumental singular (женой, but во́дой)
+        let (form, t) = trace("жен", "1d, ё", info(Instrumental, Singular));
+        assert_eq!((form.as_str(), t.ending()), ("женой", "ой"));
+        assert!(t.used_stressed && t.is_ending_stressed);
+    }
+
+    #[test]
+    fn vremya() {
+        use {Case::*, Number::*};
+        let info = |case, number| DeclInfo::new(case, number, Gender::Neuter, Animacy::Inanimate);
+        let extension = Alternation::Circle(UniqueAlternation::Extension(StemExtension::En));
+
+        // The stem extension replaces the table's ending in the singular
+        let (form, t) = trace("врем", "8°c, ё", info(Nominative, Singular));
+        assert_eq!((form.as_str(), t.ending()), ("время", "о"));
+        assert_eq!(t.alternations, [extension]);
+        let (form, t) = trace("врем", "8°c, ё", info(Genitive, Singular));
+        assert_eq!((form.as_str(), t.is_ending_stressed), ("времени", false));
+        assert_eq!(t.alternations, [extension]);
+
+        // The е/ё alternation is a part of the extension (времён), and isn't traced separately
+        let (form, t) = trace("врем", "8°c, ё", info(Genitive, Plural));
+        assert_eq!((form.as_str(), t.is_ending_stressed), ("времён", true));
+        assert_eq!(t.alternations, [extension]);
+        let (form, t) = trace("врем", "8°c, ё", info(Dative, Plural));
+        assert_eq!((form.as_str(), t.ending()), ("временам", "ам"));
+        assert_eq!(t.alternations, [extension]);
+    }
+
+    #[test]
+    fn star_and_type_8() {
+        let info =
+            DeclInfo::new(Case::Genitive, Number::Plural, Gender::Feminine, Animacy::Animate);
+        let (form, t) = trace("кошк", "3*a", info);
+        assert_eq!((form.as_str(), t.alternations.as_slice()), ("кошек", &[Alternation::Star][..]));
+
+        let info = info.with_case(Case::Dative).with_animacy(Animacy::Inanimate);
+        let (form, t) = trace("ноч", "8e", info);
+        assert_eq!((form.as_str(), t.ending()), ("ночам", "ям"));
+        assert_eq!(t.alternations, [Alternation::Type8YaToA]);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::{
+    InflectionBuffer,
+    categories::{Animacy, CaseEx, GenderEx, Number},
+    declension::{
+        Adjective, AdjectiveInfo, DeclInfo, Declension, InflectError, InflectWarning, Noun,
+        NounInfo, Pronoun, PronounInfo,
+    },
+};
+use alloc::{borrow::ToOwned, string::String};
+
+/// A noun, an adjective or a pronoun, that can be inflected without matching on the kind of word.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*};
+///
+/// let noun = Noun::new("звезд", "ж 1d, ё".parse().unwrap());
+/// let info = AdjectiveInfo {
+///     declension: Some("п 1a".parse().unwrap()),
+///     short_forms: ShortFormAvailability::Available,
+/// };
+/// let adj = Adjective { stem: "нов", info };
+/// let words = [Word::from(noun), Word::from(adj)];
+///
+/// let target = InflectTarget::new(CaseEx::Genitive, Number::Plural).with_gender(GenderEx::Feminine);
+/// let forms = words.map(|word| word.inflect(target).unwrap());
+/// assert_eq!(forms, ["звёзд", "новых"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Word<'a> {
+    Noun(Noun<'a>),
+    Adjective(Adjective<'a>),
+    Pronoun(Pronoun<'a>),
+}
+
+/// The grammatical information of a [`Word`], without its stem. Dictionary lines are parsed into
+/// it by [`parse_entry`](crate::entry::parse_entry).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordInfo {
+    Noun(NounInfo),
+    Adjective(AdjectiveInfo),
+    Pronoun(PronounInfo),
+}
+
+/// The grammatical categories to inflect a [`Word`] in.
+///
+/// Nouns have their own gender and animacy, and pronouns agree with the ones in their info, so
+/// only adjectives use the gender and animacy of the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InflectTarget {
+    pub case: CaseEx,
+    pub number: Number,
+    /// The gender that an adjective agrees with. The common gender is normalized to feminine.
+    /// Ignored in the plural.
+    pub gender: GenderEx,
+    /// The animacy that an adjective agrees with, used in the accusative.
+    pub animacy: Animacy,
+}
+
+impl InflectTarget {
+    /// Creates a target in the specified case and number, agreeing with an inanimate masculine
+    /// noun.
+    pub const fn new(case: CaseEx, number: Number) -> Self {
+        Self { case, number, gender: GenderEx::Masculine, animacy: Animacy::Inanimate }
+    }
+    /// Returns the target with the specified gender.
+    pub const fn with_gender(mut self, gender: GenderEx) -> Self {
+        self.gender = gender;
+        self
+    }
+    /// Returns the target with the specified animacy.
+    pub const fn with_animacy(mut self, animacy: Animacy) -> Self {
+        self.animacy = animacy;
+        self
+    }
+}
+
+impl const From<DeclInfo> for InflectTarget {
+    fn from(value: DeclInfo) -> Self {
+        Self {
+            case: value.case.into(),
+            number: value.number,
+            gender: value.gender.into(),
+            animacy: value.animacy,
+        }
+    }
+}
+
+impl<'a> Word<'a> {
+    /// Returns the word's stem.
+    pub const fn stem(&self) -> &'a str {
+        match self {
+            Self::Noun(x) => x.stem,
+            Self::Adjective(x) => x.stem,
+            Self::Pronoun(x) => x.stem,
+        }
+    }
+    /// Returns the word's declension, or `None`, if it's indeclinable.
+    pub const fn declension(&self) -> Option<Declension> {
+        self.info().declension()
+    }
+    /// Returns the word's grammatical information.
+    pub const fn info(&self) -> WordInfo {
+        match self {
+            Self::Noun(x) => WordInfo::Noun(x.info),
+            Self::Adjective(x) => WordInfo::Adjective(x.info),
+            Self::Pronoun(x) => WordInfo::Pronoun(x.info),
+        }
+    }
+
+    /// Returns the word's dictionary form: the nominative singular (or plural, for plurale
+    /// tantum nouns), in the masculine gender for adjectives, and in the pronoun's own gender for
+    /// pronouns.
+    pub fn lemma(&self) -> Result<String, InflectError> {
+        self.inflect(InflectTarget::new(CaseEx::Nominative, Number::Singular))
+    }
+
+    /// Returns the form of the word in the specified case and number, and in the specified gender
+    /// and animacy, if it's an adjective. See [`Noun::inflect_to_string`],
+    /// [`Adjective::inflect_to_string`] and [`Pronoun::inflect_to_string`].
+    pub fn inflect(&self, target: InflectTarget) -> Result<String, InflectError> {
+        Ok(self.inflect_to(target, &mut InflectionBuffer::default())?.to_owned())
+    }
+    /// Inflects the word into the buffer, and returns the form. The buffer's contents are
+    /// replaced, and its allocation is reused.
+    pub fn inflect_to<'b>(
+        &self,
+        target: InflectTarget,
+        buf: &'b mut InflectionBuffer,
+    ) -> Result<&'b str, InflectError> {
+        let InflectTarget { case, number, gender, animacy } = target;
+
+        match self {
+            Self::Noun(x) => x.inflect_to(case, number, buf),
+            Self::Adjective(x) => x.inflect_to(case, gender.normalize(), number, animacy, buf),
+            Self::Pronoun(x) => x.inflect_to(case, number, buf),
+        }
+    }
+}
+
+impl Declension {
+    /// Appends the ending to the stem in the buffer, and applies the stem alternations of the
+    /// declension's kind. See [`NounDeclension::inflect`], [`PronounDeclension::inflect`] and
+    /// [`AdjectiveDeclension::inflect`].
+    ///
+    /// [`NounDeclension::inflect`]: crate::declension::NounDeclension::inflect
+    /// [`PronounDeclension::inflect`]: crate::declension::PronounDeclension::inflect
+    /// [`AdjectiveDeclension::inflect`]: crate::declension::AdjectiveDeclension::inflect
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{InflectionBuffer, categories::*, declension::*};
+    ///
+    /// let info = DeclInfo::new(Case::Genitive, Number::Plural, Gender::Feminine, Animacy::Animate);
+    /// let decls = ["3*a", "п 3a", "мс 6*b"].map(|x| x.parse::<Declension>().unwrap());
+    ///
+    /// let forms = ["кошк", "русск", "че"].map(|stem| InflectionBuffer::from_stem(stem).unwrap());
+    /// let forms = forms.into_iter().zip(decls).map(|(mut buf, decl)| {
+    ///     decl.inflect(info, &mut buf).unwrap();
+    ///     buf.as_str().to_owned()
+    /// });
+    /// assert_eq!(forms.collect::<Vec<_>>(), ["кошек", "русских", "чьих"]);
+    /// ```
+    pub fn inflect(self, info: DeclInfo, buf: &mut InflectionBuffer) -> Result<(), InflectError> {
+        self.inflect_with_warnings(info, buf, |_| {})
+    }
+    /// Same as [`inflect`][Self::inflect], but calls `on_warning` with the problems that were
+    /// worked around, instead of ignoring them silently.
+    pub fn inflect_with_warnings(
+        self,
+        info: DeclInfo,
+        buf: &mut InflectionBuffer,
+        on_warning: impl FnMut(InflectWarning),
+    ) -> Result<(), InflectError> {
+        match self {
+            Self::Noun(x) => x.inflect_with_warnings(info, buf, on_warning),
+            Self::Pronoun(x) => x.inflect(info, buf),
+            Self::Adjective(x) => {
+                x.inflect(info, buf);
+                Ok(())
+            },
+        }
+    }
+}
+
+impl WordInfo {
+    /// Returns the word's declension, or `None`, if it's indeclinable.
+    pub const fn declension(&self) -> Option<Declension> {
+        match self {
+            Self::Noun(x) => x.declension,
+            Self::Adjective(x) => x.declension,
+            Self::Pronoun(x) => x.declension,
+        }
+    }
+}
+
+impl<'a> const From<Noun<'a>> for Word<'a> {
+    fn from(value: Noun<'a>) -> Self {
+        Self::Noun(value)
+    }
+}
+impl<'a> const From<Adjective<'a>> for Word<'a> {
+    fn from(value: Adjective<'a>) -> Self {
+        Self::Adjective(value)
+    }
+}
+impl<'a> const From<Pronoun<'a>> for Word<'a> {
+    fn from(value: Pronoun<'a>) -> Self {
+        Self::Pronoun(value)
+    }
+}
+impl const From<NounInfo> for WordInfo {
+    fn from(value: NounInfo) -> Self {
+        Self::Noun(value)
+    }
+}
+impl const From<AdjectiveInfo> for WordInfo {
+    fn from(value: AdjectiveInfo) -> Self {
+        Self::Adjective(value)
+    }
+}
+impl const From<PronounInfo> for WordInfo {
+    fn from(value: PronounInfo) -> Self {
+        Self::Pronoun(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Case, Gender},
+        declension::{AdjectiveInfo, PronounInfo, ShortFormAvailability},
+    };
+
+    fn adjective<'a>(stem: &'a str, decl: &str) -> Adjective<'a> {
+        let declension = Some(decl.parse().unwrap());
+        Adjective {
+            stem,
+            info: AdjectiveInfo { declension, short_forms: ShortFormAvailability::Available },
+        }
+    }
+
+    #[test]
+    fn inflect() {
+        let noun = Word::from(Noun::new("кошк", "жо 3*a".parse().unwrap()));
+        let adj = Word::from(adjective("больш", "п 4b"));
+        let pronoun = Word::from(Pronoun {
+            stem: "наш",
+            info: PronounInfo {
+                declension: Some("мс 4a".parse().unwrap()),
+                gender: Gender::Feminine,
+                animacy: Animacy::Animate,
+            },
+        });
+
+        let target = InflectTarget::new(CaseEx::Accusative, Number::Plural)
+            .with_gender(GenderEx::Feminine)
+            .with_animacy(Animacy::Animate);
+        let forms = [pronoun, adj, noun].map(|x| x.inflect(target).unwrap());
+        assert_eq!(forms, ["наших", "больших", "кошек"]);
+
+        // The gender and animacy of the target only apply to adjectives
+        let target = InflectTarget::new(CaseEx::Accusative, Number::Singular);
+        let forms = [pronoun, adj, noun].map(|x| x.inflect(target).unwrap());
+        assert_eq!(forms, ["нашу", "большой", "кошку"]);
+        let target = target.with_gender(GenderEx::Common);
+        assert_eq!(adj.inflect(target).unwrap(), "большую");
+
+        // Secondary cases are normalized the same way as in Noun::inflect
+        let target = InflectTarget::new(CaseEx::Translative, Number::Singular);
+        let forms = [pronoun, adj, noun].map(|x| x.inflect(target).unwrap());
+        assert_eq!(forms, ["наши", "большие", "кошки"]);
+
+        // The target can be created from a DeclInfo
+        let info = DeclInfo {
+            case: Case::Dative,
+            number: Number::Singular,
+            gender: Gender::Neuter,
+            animacy: Animacy::Inanimate,
+        };
+        assert_eq!(adj.inflect(info.into()).unwrap(), "большому");
+
+        // Adjectives can't be declined by noun declension
+        let adj = Word::from(adjective("нов", "1a"));
+        assert_eq!(adj.lemma(), Err(InflectError::IncompatibleDeclension));
+    }
+
+    #[test]
+    fn lemma() {
+        let scissors = Noun::new("ножниц", "мн. ж 1a".parse().unwrap());
+        let pronoun = Pronoun {
+            stem: "че",
+            info: PronounInfo {
+                declension: Some("мс 6*b".parse().unwrap()),
+                gender: Gender::Masculine,
+                animacy: Animacy::Inanimate,
+            },
+        };
+        let words = [
+            Word::from(Noun::new("звезд", "ж 1d, ё".parse().unwrap())),
+            Word::from(scissors),
+            Word::from(Noun::new("кофе", "м 0".parse().unwrap())),
+            Word::from(adjective("син", "п 2a")),
+            Word::from(pronoun),
+        ];
+        let lemmas = words.map(|x| x.lemma().unwrap());
+        assert_eq!(lemmas, ["звезда", "ножницы", "кофе", "синий", "чей"]);
+
+        assert_eq!(words.map(|x| x.stem()), ["звезд", "ножниц", "кофе", "син", "че"]);
+        assert_eq!(words[2].declension(), None);
+        assert_eq!(words[3].declension(), Some("п 2a".parse().unwrap()));
+    }
+
+    #[test]
+    fn declension_inflect() {
+        use {Case::*, Number::*};
+        let inflect = |stem: &str, decl: &str, info: DeclInfo| {
+            let decl: Declension = decl.parse().unwrap();
+            let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+            let mut warnings = 0;
+            decl.inflect_with_warnings(info, &mut buf, |_| warnings += 1).unwrap();
+
+            // The ending comes from the declension's table
+            assert!(buf.as_str().ends_with(decl.get_ending(info)) || decl.flags().has_star());
+            (buf.as_str().to_owned(), warnings)
+        };
+        let info = |case, number, gender| DeclInfo::new(case, number, gender, Animacy::Inanimate);
+
+        // One word per declension kind
+        let form = inflect("звезд", "1d, ё", info(Nominative, Plural, Gender::Feminine));
+        assert_eq!(form, ("звёзды".into(), 0));
+        let form = inflect("син", "п 2a", info(Dative, Singular, Gender::Neuter));
+        assert_eq!(form, ("синему".into(), 0));
+        let form = inflect("че", "мс 6*b", info(Instrumental, Singular, Gender::Masculine));
+        assert_eq!(form, ("чьим".into(), 0));
+
+        // Only the noun declensions report the warnings
+        let form = inflect("стол", "1b, ё", info(Genitive, Plural, Gender::Masculine));
+        assert_eq!(form, ("столов".into(), 1));
+
+        let decl: Declension = "мс 6*b".parse().unwrap();
+        let info = info(Genitive, Singular, Gender::Feminine);
+        assert_eq!((decl.get_ending(info), decl.ending_pair(info)), ("ей", ("ей", "ей")));
+    }
+}
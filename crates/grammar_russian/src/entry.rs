@@ -0,0 +1,571 @@
+use crate::{
+    categories::{Animacy, Gender, GenderExAnimacy},
+    declension::{
+        AdjectiveInfo, DECLENSION_MAX_LEN, Declension, MaybeZeroDeclension, ParseDeclensionError,
+        ParseNounInfoError, PronounInfo, ShortFormAvailability, WordInfo,
+    },
+};
+use alloc::{vec, vec::Vec};
+use thiserror::Error;
+
+/// The default bound on the length of a lemma in an entry, in bytes (32 Cyrillic letters).
+pub const ENTRY_LEMMA_MAX_LEN: usize = 64;
+// Longest form: мо-жо (9 bytes, 5 chars)
+pub const ENTRY_GENDER_MAX_LEN: usize = "мо-жо".len();
+
+/// Returns the maximum length of an entry with a lemma of up to the specified length, in bytes.
+pub const fn entry_max_len(lemma_max_len: usize) -> usize {
+    lemma_max_len + 1 + ENTRY_GENDER_MAX_LEN + 1 + DECLENSION_MAX_LEN
+}
+// Longest parsable form: {lemma} числ.-п 7*a/c″①②③④⑤, ё, -ся (64 + 48 bytes). The bound also fits
+// constructed entries that don't parse, like {lemma} мо-жо числ.-п 7°*f″/f″①②③④⑤, ё, -ся.
+pub const ENTRY_MAX_LEN: usize = entry_max_len(ENTRY_LEMMA_MAX_LEN);
+
+#[derive(Debug, Default, Error, Clone, Copy, PartialEq, Eq)]
+#[error("entry doesn't fit into the buffer")]
+pub struct EntryOverflowError;
+
+/// A dictionary entry: a lemma, its gender and animacy (for nouns), and its declension.
+/// Formatted as "стол м 1b", "кошка жо 3*a", "новый п 1a" or "кофе м 0".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry<'a> {
+    pub lemma: &'a str,
+    pub gender: Option<GenderExAnimacy>,
+    pub declension: MaybeZeroDeclension,
+}
+
+/// A writer of entries into a byte buffer, that returns an error if the buffer is too small.
+///
+/// Components are separated with spaces. If a component doesn't fit, the writer is left as it
+/// was before the call.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*, entry::*};
+///
+/// let mut buf = [0; ENTRY_MAX_LEN];
+/// let mut dst = EntryWriter::new(&mut buf);
+///
+/// dst.push_lemma("кошка").unwrap();
+/// dst.push_gender(GenderExAnimacy::FeminineAnimate).unwrap();
+/// dst.push_declension("3*a".parse::<Declension>().unwrap().into()).unwrap();
+/// assert_eq!(dst.finish(), "кошка жо 3*a");
+/// ```
+#[derive(Debug)]
+pub struct EntryWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> EntryWriter<'a> {
+    pub const fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    pub fn push_lemma(&mut self, lemma: &str) -> Result<(), EntryOverflowError> {
+        self.push_component(lemma)
+    }
+    pub fn push_gender(&mut self, gender: GenderExAnimacy) -> Result<(), EntryOverflowError> {
+        self.push_component(gender.abbr_zaliznyak())
+    }
+    pub fn push_declension(&mut self, decl: MaybeZeroDeclension) -> Result<(), EntryOverflowError> {
+        self.push_component(decl.fmt_to(&mut [0; DECLENSION_MAX_LEN]))
+    }
+
+    pub(crate) fn push_component(&mut self, s: &str) -> Result<(), EntryOverflowError> {
+        let start = if self.len > 0 { self.len + 1 } else { 0 };
+        let end = start + s.len();
+
+        let dst = self.buf.get_mut(self.len..end).ok_or(EntryOverflowError)?;
+        if start > self.len {
+            dst[0] = b' ';
+        }
+        dst[(start - self.len)..].copy_from_slice(s.as_bytes());
+
+        self.len = end;
+        Ok(())
+    }
+
+    // Appends the string to the last component, without a space
+    pub(crate) fn push_str(&mut self, s: &str) -> Result<(), EntryOverflowError> {
+        let end = self.len + s.len();
+        let dst = self.buf.get_mut(self.len..end).ok_or(EntryOverflowError)?;
+        dst.copy_from_slice(s.as_bytes());
+
+        self.len = end;
+        Ok(())
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only complete UTF-8 strings are written into the buffer
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+    pub fn finish(self) -> &'a str {
+        // SAFETY: only complete UTF-8 strings are written into the buffer
+        unsafe { str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl<'a> Entry<'a> {
+    pub fn fmt_to<'b>(&self, dst: &'b mut [u8]) -> Result<&'b str, EntryOverflowError> {
+        let mut dst = EntryWriter::new(dst);
+
+        dst.push_lemma(self.lemma)?;
+        if let Some(gender) = self.gender {
+            dst.push_gender(gender)?;
+        }
+        dst.push_declension(self.declension)?;
+
+        Ok(dst.finish())
+    }
+}
+
+impl core::fmt::Display for Entry<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        // Format on the stack, unless the lemma is unusually long
+        if self.lemma.len() <= ENTRY_LEMMA_MAX_LEN {
+            self.fmt_to(&mut [0; ENTRY_MAX_LEN]).unwrap().fmt(f)
+        } else {
+            self.fmt_to(&mut vec![0; entry_max_len(self.lemma.len())]).unwrap().fmt(f)
+        }
+    }
+}
+
+/// The kind of a [`ParseEntryError`].
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ParseEntryErrorKind {
+    /// The line is empty, or contains only a comment: "// существительные".
+    #[error("the line doesn't contain an entry")]
+    Empty,
+    /// The line contains only the headword: "стол".
+    #[error("missing grammatical description after the headword")]
+    MissingDescription,
+    /// A bracketed note isn't closed, or its brackets don't match: "стол м 1b [устар.".
+    #[error("unclosed note")]
+    UnclosedNote,
+    /// The noun's gender, animacy or declension is invalid: "стол м 9a", "мужчина мо <ж 1a>".
+    #[error("invalid noun description: {0}")]
+    InvalidNoun(ParseNounInfoError),
+    /// The adjective's or the pronoun's declension is invalid: "новый п 9a", "наш мс 4a?".
+    #[error("invalid declension: {0:?}")]
+    InvalidDeclension(ParseDeclensionError),
+    /// A noun declension isn't preceded by the gender and animacy marker: "стол 1b".
+    #[error("missing gender before the noun declension")]
+    MissingGender,
+}
+
+/// An error returned by [`parse_entry`], along with the byte offset of the invalid part of the
+/// line.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("{kind} (at byte {offset})")]
+pub struct ParseEntryError {
+    pub kind: ParseEntryErrorKind,
+    /// The byte offset of the invalid part: the start of the noun's description, the invalid
+    /// part of the declension, or the bracket of the unclosed note.
+    pub offset: usize,
+}
+
+impl ParseEntryError {
+    const fn new(kind: ParseEntryErrorKind, offset: usize) -> Self {
+        Self { kind, offset }
+    }
+}
+
+/// Parses a dictionary line into the headword and its grammatical description: "заяц мо 5*a",
+/// "хороший п 4a/c′", "наш мс 4a". The headword may consist of several words ("железная дорога
+/// ж 3a"), so the boundary is the leftmost one, after which the rest of the line is a valid
+/// description.
+///
+/// Nouns are described by their [`NounInfo`](crate::declension::NounInfo): "мо <жо 1a>",
+/// "мн. ж 5a", "м 6c Р2 П2". Adjectives and pronouns are described by their declension, and
+/// adjectives may be followed by a short form marker: "осенний п 2a✕", "п 1a⌧" or "п 1a—".
+/// The pronouns agree with the masculine inanimate gender, as in their dictionary form.
+///
+/// A comment after "//" and the notes in square brackets or parentheses at the end of the line
+/// are ignored: "дом м 1c [мн. дома́] // проверить".
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{declension::*, entry::*};
+///
+/// let (headword, info) = parse_entry("железная дорога ж 3a // составное").unwrap();
+/// assert_eq!(headword, "железная дорога");
+/// assert_eq!(info, WordInfo::Noun("ж 3a".parse().unwrap()));
+///
+/// let err = parse_entry("новый п 9a").unwrap_err();
+/// let kind = ParseEntryErrorKind::InvalidDeclension(ParseDeclensionError::InvalidStemType);
+/// assert_eq!((err.kind, &"новый п 9a"[err.offset..]), (kind, "9a"));
+/// ```
+pub fn parse_entry(line: &str) -> Result<(&str, WordInfo), ParseEntryError> {
+    use ParseEntryErrorKind as Kind;
+
+    let content = strip_notes(line)?;
+    let headword_start = content.len() - content.trim_start().len();
+    if headword_start == content.len() {
+        return Err(ParseEntryError::new(Kind::Empty, 0));
+    }
+
+    // Try the boundaries from left to right, so that the longest description is found: both
+    // "мн. ж 5a" and "ж 5a" are valid descriptions, as well as "мо п 4a" and "п 4a"
+    let mut error: Option<(ParseEntryError, bool)> = None;
+    let mut pos = headword_start;
+    while let Some(len) = content[pos..].find(char::is_whitespace) {
+        let headword_end = pos + len;
+        let desc = content[headword_end..].trim_start();
+        let desc_start = content.len() - desc.len();
+
+        match parse_description(desc) {
+            Ok(info) => return Ok((&content[headword_start..headword_end], info)),
+            Err((kind, offset)) => {
+                // An invalid stem type at the start means that it's still the headword. Report
+                // the first error in a description, or in the word right after the headword.
+                let is_desc = offset > 0
+                    || kind != Kind::InvalidDeclension(ParseDeclensionError::InvalidStemType);
+                if error.is_none_or(|(_, was_desc)| is_desc && !was_desc) {
+                    error = Some((ParseEntryError::new(kind, desc_start + offset), is_desc));
+                }
+            },
+        }
+        pos = desc_start;
+    }
+    Err(match error {
+        Some((err, _)) => err,
+        None => ParseEntryError::new(Kind::MissingDescription, content.len()),
+    })
+}
+
+/// Parses the entries of a dictionary, one per line, skipping the empty lines and the lines with
+/// only a comment. Yields the lines' indices along with the results of [`parse_entry`].
+///
+/// # Examples
+/// ```
+/// use grammar_russian::entry::*;
+///
+/// let text = "// существительные\nстол м 1b\n\nкошка 3*a\n";
+/// let results: Vec<_> = parse_entries(text).collect();
+/// assert_eq!(results.len(), 2);
+/// assert_eq!(results[0].0, 1);
+/// assert_eq!(results[1].1.unwrap_err().kind, ParseEntryErrorKind::MissingGender);
+/// ```
+pub fn parse_entries(
+    text: &str,
+) -> impl Iterator<Item = (usize, Result<(&str, WordInfo), ParseEntryError>)> {
+    (text.lines().enumerate())
+        .map(|(i, line)| (i, parse_entry(line)))
+        .filter(|(_, result)| !matches!(result, Err(err) if err.kind == ParseEntryErrorKind::Empty))
+}
+
+// Removes the comment and the notes at the end of the line, and any whitespace before them
+fn strip_notes(line: &str) -> Result<&str, ParseEntryError> {
+    let line = match line.find("//") {
+        Some(start) => &line[..start],
+        None => line,
+    };
+
+    // The notes start at the first bracket after the headword, and must all be closed
+    let trimmed = line.trim_start();
+    let headword_end =
+        line.len() - trimmed.len() + trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+    let Some(notes_start) = line[headword_end..].find(['[', '(']).map(|x| headword_end + x) else {
+        return Ok(line.trim_end());
+    };
+
+    let mut unclosed = Vec::new();
+    for (i, ch) in line[notes_start..].char_indices() {
+        match ch {
+            '[' | '(' => unclosed.push((notes_start + i, ch)),
+            ']' | ')' => {
+                let expected = if ch == ']' { '[' } else { '(' };
+                match unclosed.pop() {
+                    Some((_, open)) if open == expected => {},
+                    // The bracket closes a note of the other kind, or doesn't have a pair at all
+                    Some((start, _)) => return Err(unclosed_note(start)),
+                    None => return Err(unclosed_note(notes_start + i)),
+                }
+            },
+            _ => {},
+        }
+    }
+    match unclosed.first() {
+        Some(&(start, _)) => Err(unclosed_note(start)),
+        None => Ok(line[..notes_start].trim_end()),
+    }
+}
+
+const fn unclosed_note(offset: usize) -> ParseEntryError {
+    ParseEntryError::new(ParseEntryErrorKind::UnclosedNote, offset)
+}
+
+// Parses a noun's, an adjective's or a pronoun's description, that spans the entire string.
+// Returns the error along with its offset in the string.
+fn parse_description(desc: &str) -> Result<WordInfo, (ParseEntryErrorKind, usize)> {
+    use ParseEntryErrorKind as Kind;
+
+    // Nouns start with a gender marker, followed by a space: "мо 1a", "мн. ж 5a", "м"
+    let is_noun = desc.starts_with("мн. ")
+        || GenderExAnimacy::from_str_partial(desc)
+            .is_ok_and(|(_, len)| desc[len..].is_empty() || desc[len..].starts_with(' '));
+    if is_noun {
+        return desc.parse().map(WordInfo::Noun).map_err(|err| (Kind::InvalidNoun(err), 0));
+    }
+
+    // Indeclinable adjectives and pronouns are marked with "0": "беж п 0"
+    let (declension, is_pronoun, rest) = match desc.split_once(' ') {
+        Some((prefix @ ("п" | "мс"), "0")) => (None, prefix == "мс", ""),
+        _ => {
+            let (decl, len) = Declension::from_str_partial(desc)
+                .map_err(|err| (Kind::InvalidDeclension(err.kind), err.offset))?;
+            if decl.is_noun() {
+                return Err((Kind::MissingGender, 0));
+            }
+            (Some(decl), decl.is_pronoun(), &desc[len..])
+        },
+    };
+
+    if is_pronoun {
+        if !rest.is_empty() {
+            let offset = desc.len() - rest.len();
+            return Err((Kind::InvalidDeclension(ParseDeclensionError::Invalid), offset));
+        }
+        let (gender, animacy) = (Gender::Masculine, Animacy::Inanimate);
+        return Ok(WordInfo::Pronoun(PronounInfo { declension, gender, animacy }));
+    }
+
+    // Adjectives may be followed by the short form marker
+    let short_forms = match rest.trim_start() {
+        "" => ShortFormAvailability::Available,
+        "✕" => ShortFormAvailability::Unavailable,
+        "⌧" => ShortFormAvailability::Difficult,
+        "—" => ShortFormAvailability::NoMasculine,
+        _ => {
+            let offset = desc.len() - rest.len();
+            return Err((Kind::InvalidDeclension(ParseDeclensionError::Invalid), offset));
+        },
+    };
+    Ok(WordInfo::Adjective(AdjectiveInfo { declension, short_forms }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        declension::{AdjectiveDeclension, AdjectivePrefix, AdjectiveStemType, DeclensionFlags},
+        stress::AdjectiveStress,
+    };
+    use std::{format, string::ToString};
+
+    fn entry<'a>(lemma: &'a str, gender: Option<GenderExAnimacy>, decl: &str) -> Entry<'a> {
+        Entry { lemma, gender, declension: decl.parse().unwrap() }
+    }
+
+    #[test]
+    fn fmt() {
+        use GenderExAnimacy::*;
+
+        assert_eq!(entry("стол", Some(MasculineInanimate), "1b").to_string(), "стол м 1b");
+        assert_eq!(entry("кошка", Some(FeminineAnimate), "3*a").to_string(), "кошка жо 3*a");
+        assert_eq!(entry("сирота", Some(CommonAnimate), "1d").to_string(), "сирота мо-жо 1d");
+        assert_eq!(entry("новый", None, "п 1a").to_string(), "новый п 1a");
+        assert_eq!(entry("кофе", Some(MasculineInanimate), "0").to_string(), "кофе м 0");
+
+        // Lemmas longer than the default bound are still formatted by Display
+        let long = "я".repeat(ENTRY_LEMMA_MAX_LEN);
+        let expected = format!("{long} м 1a");
+        assert_eq!(entry(&long, Some(MasculineInanimate), "1a").to_string(), expected);
+    }
+
+    #[test]
+    fn longest() {
+        let lemma = "я".repeat(ENTRY_LEMMA_MAX_LEN / 2);
+        assert_eq!(lemma.len(), ENTRY_LEMMA_MAX_LEN);
+
+        // Adjectives don't accept ° when parsed, but can still be constructed with it
+        let decl = AdjectiveDeclension {
+            stem_type: AdjectiveStemType::Type7,
+            flags: DeclensionFlags::all(),
+            stress: AdjectiveStress::A_Cpp,
+            is_reflexive: true,
+            prefix: AdjectivePrefix::Numeral,
+        };
+        let longest = Entry {
+            lemma: &lemma,
+            gender: Some(GenderExAnimacy::CommonAnimate),
+            declension: Declension::Adjective(decl).into(),
+        };
+        let mut buf = [0; ENTRY_MAX_LEN];
+        let formatted = longest.fmt_to(&mut buf).unwrap();
+        assert_eq!(formatted, format!("{lemma} мо-жо числ.-п 7°*a/c″①②③④⑤, ё, -ся"));
+
+        // The declension's own longest form: числ.-п 7°*f″/f″①②③④⑤, ё, -ся
+        let mut buf = [0; ENTRY_MAX_LEN];
+        let mut dst = EntryWriter::new(&mut buf);
+        dst.push_lemma(&lemma).unwrap();
+        dst.push_gender(GenderExAnimacy::CommonAnimate).unwrap();
+        dst.push_component("числ.-п 7°*f″/f″①②③④⑤, ё, -ся").unwrap();
+        assert_eq!(dst.finish().len(), ENTRY_MAX_LEN);
+
+        // The longest entries that can actually be parsed fit as well
+        for desc in ["числ.-п 7*a/c″①②③④⑤, ё, -ся", "мо-жо 7°*f″①②③④⑤, ё"]
+        {
+            let line = format!("{lemma} {desc}");
+            assert_eq!(parse_entry(&line).unwrap().0, lemma);
+            assert!(line.len() < ENTRY_MAX_LEN);
+        }
+    }
+
+    // A mixed sample of dictionary lines, with the expected headwords and descriptions
+    const SAMPLE: &str = "\
+// Существительные
+заяц мо 5*a
+стол м 1b
+кошка жо 3*a
+железная дорога ж 3a
+мужчина мо <жо 1a>
+сирота мо-жо 1d
+ножницы мн. ж 1*a
+кофе м 0
+кенгуру мо
+чай м 6c Р2
+лес м 1c П2 [в лесу́]
+рабочий мо п 4a
+звезда ж 1d, ё // мн. звёзды
+Новый год м 1c
+
+// Прилагательные и местоимения
+хороший п 4a/c'
+большой п 4b
+трудящийся п 4a, -ся
+осенний п 2a✕
+беж п 0
+наш мс 4a
+весь мс 6*b (определительное)
+озеро\tс 1a, ё
+  день м 2*b
+стол 1b
+новый п 9a
+железная дорога ж 9a
+кошка жо 3*a [устар.
+стол
+время с 8°c, ё
+";
+
+    #[test]
+    fn parse() {
+        use ParseDeclensionError as Decl;
+        use ParseEntryErrorKind::*;
+
+        let noun = |s: &str| Ok(WordInfo::Noun(s.parse().unwrap()));
+        let adj = |s: &str, short_forms| {
+            let declension = Some(s.parse().unwrap());
+            Ok(WordInfo::Adjective(AdjectiveInfo { declension, short_forms }))
+        };
+        let pronoun = |declension: Option<&str>| {
+            let declension = declension.map(|x| x.parse().unwrap());
+            let (gender, animacy) = (Gender::Masculine, Animacy::Inanimate);
+            Ok(WordInfo::Pronoun(PronounInfo { declension, gender, animacy }))
+        };
+        let err = |kind, offset| Err(ParseEntryError { kind, offset });
+        let available = ShortFormAvailability::Available;
+
+        let expected = [
+            ("заяц", noun("мо 5*a")),
+            ("стол", noun("м 1b")),
+            ("кошка", noun("жо 3*a")),
+            ("железная дорога", noun("ж 3a")),
+            ("мужчина", noun("мо <жо 1a>")),
+            ("сирота", noun("мо-жо 1d")),
+            ("ножницы", noun("мн. ж 1*a")),
+            ("кофе", noun("м 0")),
+            ("кенгуру", noun("мо")),
+            ("чай", noun("м 6c Р2")),
+            ("лес", noun("м 1c П2")),
+            ("рабочий", noun("мо п 4a")),
+            ("звезда", noun("ж 1d, ё")),
+            ("Новый год", noun("м 1c")),
+            ("хороший", adj("п 4a/c′", available)),
+            ("большой", adj("п 4b", available)),
+            ("трудящийся", adj("п 4a, -ся", available)),
+            ("осенний", adj("п 2a", ShortFormAvailability::Unavailable)),
+            (
+                "беж",
+                Ok(WordInfo::Adjective(AdjectiveInfo { declension: None, short_forms: available })),
+            ),
+            ("наш", pronoun(Some("мс 4a"))),
+            ("весь", pronoun(Some("мс 6*b"))),
+            ("озеро", noun("с 1a, ё")),
+            ("день", noun("м 2*b")),
+            ("стол 1b", err(MissingGender, "стол ".len())),
+            ("новый п 9a", err(InvalidDeclension(Decl::InvalidStemType), "новый п ".len())),
+            ("железная дорога ж 9a", {
+                let kind =
+                    InvalidNoun(ParseNounInfoError::InvalidDeclension(Decl::InvalidStemType));
+                err(kind, "железная дорога ".len())
+            }),
+            ("кошка жо 3*a [устар.", err(UnclosedNote, "кошка жо 3*a ".len())),
+            ("стол", err(MissingDescription, "стол".len())),
+            ("время", noun("с 8°c, ё")),
+        ];
+
+        let results: Vec<_> = parse_entries(SAMPLE).collect();
+        assert_eq!(results.len(), 30 - 1 - 1 - 1 + 2);
+        for ((i, result), (headword, expected)) in results.into_iter().zip(expected) {
+            let line = SAMPLE.lines().nth(i).unwrap();
+            match expected {
+                Ok(info) => assert_eq!(result, Ok((headword, info)), "{line}"),
+                // The errors are listed with the whole lines instead of the headwords
+                Err(err) => assert_eq!((line, result), (headword, Err(err))),
+            }
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        use ParseEntryErrorKind::*;
+        let err = |kind, offset| Err(ParseEntryError { kind, offset });
+
+        assert_eq!(parse_entry(""), err(Empty, 0));
+        assert_eq!(parse_entry("   "), err(Empty, 0));
+        assert_eq!(parse_entry("// стол м 1b"), err(Empty, 0));
+        assert_eq!(parse_entry("стол // м 1b"), err(MissingDescription, "стол".len()));
+        assert_eq!(parse_entry("стол [м 1b]"), err(MissingDescription, "стол".len()));
+
+        // Mismatched and stray brackets
+        assert_eq!(parse_entry("стол м 1b [устар.)"), err(UnclosedNote, "стол м 1b ".len()));
+        assert_eq!(parse_entry("стол м 1b (a [b)]"), err(UnclosedNote, "стол м 1b (a ".len()));
+        assert_eq!(parse_entry("стол м 1b [a]]"), err(UnclosedNote, "стол м 1b [a]".len()));
+
+        // Anything after the declension, other than a short form marker for adjectives
+        let invalid = InvalidDeclension(ParseDeclensionError::Invalid);
+        assert_eq!(parse_entry("новый п 1a?"), err(invalid, "новый п 1a".len()));
+        assert_eq!(parse_entry("наш мс 4a✕"), err(invalid, "наш мс 4a".len()));
+
+        // The gender marker must be separated from the declension
+        let invalid = InvalidDeclension(ParseDeclensionError::InvalidStemType);
+        assert_eq!(parse_entry("заяц мо5*a"), err(invalid, "заяц ".len()));
+
+        // Multi-word headwords, and notes in parentheses within the headword
+        assert_eq!(parse_entry("(по)любить п 1a").map(|x| x.0), Ok("(по)любить"));
+        assert_eq!(parse_entry("\tмн ч м 1a").map(|x| x.0), Ok("мн ч"));
+        let (headword, info) = parse_entry("мужчина  мо п 4a").unwrap();
+        assert_eq!((headword, info.declension()), ("мужчина", Some("п 4a".parse().unwrap())));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut buf = [0; 16];
+        let mut dst = EntryWriter::new(&mut buf);
+
+        dst.push_lemma("кошка").unwrap();
+        assert_eq!(dst.push_gender(GenderExAnimacy::CommonAnimate), Err(EntryOverflowError));
+        assert_eq!(dst.as_str(), "кошка");
+
+        dst.push_gender(GenderExAnimacy::FeminineAnimate).unwrap();
+        let decl = "3*a".parse::<Declension>().unwrap();
+        assert_eq!(dst.push_declension(decl.into()), Err(EntryOverflowError));
+        assert_eq!(dst.finish(), "кошка жо");
+
+        let mut buf = [0; 8];
+        let long = entry("кошка", Some(GenderExAnimacy::FeminineAnimate), "3*a");
+        assert_eq!(long.fmt_to(&mut buf), Err(EntryOverflowError));
+    }
+}
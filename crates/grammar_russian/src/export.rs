@@ -0,0 +1,398 @@
+use crate::{
+    categories::{CaseAndNumber, CaseEx, Number},
+    declension::{Noun, STRESS_MARK, push_stressed, stressed_vowel},
+};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Options of the CSV/TSV export of paradigms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// The field delimiter: `,` for CSV, or `\t` for TSV.
+    pub delimiter: char,
+    /// Whether to write 'ё' as 'е' in the forms.
+    pub fold_yo: bool,
+    /// Whether to mark the stressed vowels in the forms with U+0301. The stress is only marked
+    /// where it's known: on the endings, on the stems with a stress mark (see
+    /// [`Noun::inflect_stressed`]), and on the stems with only one vowel. Monosyllabic forms and
+    /// stressed 'ё' aren't marked.
+    pub stress_marks: bool,
+    /// Whether to include the secondary cases: partitive, locative and translative.
+    pub secondary_cases: bool,
+}
+
+impl CsvOptions {
+    /// Comma-separated values, without any other options.
+    pub const CSV: Self =
+        Self { delimiter: ',', fold_yo: false, stress_marks: false, secondary_cases: false };
+    /// Tab-separated values, without any other options.
+    pub const TSV: Self = Self { delimiter: '\t', ..Self::CSV };
+}
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::CSV
+    }
+}
+
+// The secondary cases follow the 12 main cells, in this order
+const SECONDARY_CELLS: [(CaseEx, Number); 3] = [
+    (CaseEx::Partitive, Number::Singular),
+    (CaseEx::Locative, Number::Singular),
+    (CaseEx::Translative, Number::Plural),
+];
+
+/// Writes the header row of the paradigm table.
+///
+/// The columns are: `lemma`, `index`, and then the cells in the order of
+/// [`CaseAndNumber::VALUES`] (`nom_sg` … `prp_sg`, `nom_pl` … `prp_pl`), followed by `prt_sg`,
+/// `loc_sg` and `transl_pl`, if the secondary cases are included. This order is stable.
+pub fn write_csv_header<W: Write>(dst: &mut W, opts: CsvOptions) -> core::fmt::Result {
+    dst.write_str("lemma")?;
+    dst.write_char(opts.delimiter)?;
+    dst.write_str("index")?;
+
+    let main = CaseAndNumber::VALUES.map(|x| {
+        let (case, number) = x.parts();
+        (case.into(), number)
+    });
+    let secondary = if opts.secondary_cases { &SECONDARY_CELLS[..] } else { &[] };
+
+    for (case, number) in main.iter().chain(secondary) {
+        dst.write_char(opts.delimiter)?;
+        write!(dst, "{}_{}", case.abbr_lower(), number.abbr_lower())?;
+    }
+    dst.write_char('\n')
+}
+
+/// Writes a row with the noun's lemma, index and forms. See [`write_csv_header`] for the order of
+/// the columns. Indeclinable nouns have the index `0`, and the same form in all the cells.
+///
+/// Returns [`core::fmt::Error`], if the noun can't be inflected (see
+/// [`InflectError`](crate::declension::InflectError)). The row may be partially written then.
+pub fn write_csv_row<W: Write>(dst: &mut W, noun: &Noun, opts: CsvOptions) -> core::fmt::Result {
+    let mut field = String::new();
+
+    // The lemma is written without stress marks, so that it can be used as a key
+    let lemma_opts = CsvOptions { stress_marks: false, ..opts };
+    write_form(&mut field, noun, CaseEx::Nominative, Number::Singular, lemma_opts)?;
+    write_field(dst, &field, opts)?;
+
+    field.clear();
+    match noun.info.declension {
+        Some(decl) => write!(field, "{decl}")?,
+        None => field.push('0'),
+    };
+    dst.write_char(opts.delimiter)?;
+    write_field(dst, &field, opts)?;
+
+    let main = CaseAndNumber::VALUES.map(|x| {
+        let (case, number) = x.parts();
+        (case.into(), number)
+    });
+    let secondary = if opts.secondary_cases { &SECONDARY_CELLS[..] } else { &[] };
+
+    for &(case, number) in main.iter().chain(secondary) {
+        field.clear();
+        write_form(&mut field, noun, case, number, opts)?;
+        dst.write_char(opts.delimiter)?;
+        write_field(dst, &field, opts)?;
+    }
+    dst.write_char('\n')
+}
+
+/// Writes the header row, and then a row for each of the nouns, as they're iterated.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::*, export::*};
+///
+/// let info = NounInfo {
+///     declension: Some("1b".parse().unwrap()),
+///     declension_gender: Gender::Masculine,
+///     gender: GenderEx::Masculine,
+///     animacy: Animacy::Inanimate,
+///     tantum: None,
+///     has_partitive: false,
+///     has_locative: false,
+/// };
+/// let nouns = [Noun::new("стол", info)];
+///
+/// let mut csv = String::new();
+/// write_paradigms_csv(&mut csv, &nouns, CsvOptions::CSV).unwrap();
+///
+/// let mut lines = csv.lines();
+/// assert!(lines.next().unwrap().starts_with("lemma,index,nom_sg,gen_sg,"));
+/// assert!(lines.next().unwrap().starts_with("стол,1b,стол,стола,"));
+/// ```
+pub fn write_paradigms_csv<'n, 'a: 'n, W: Write>(
+    dst: &mut W,
+    nouns: impl IntoIterator<Item = &'n Noun<'a>>,
+    opts: CsvOptions,
+) -> core::fmt::Result {
+    write_csv_header(dst, opts)?;
+    for noun in nouns {
+        write_csv_row(dst, noun, opts)?;
+    }
+    Ok(())
+}
+
+/// Returns the noun's paradigm as a table with a header row and a single data row, or an error
+/// if the noun can't be inflected.
+pub fn paradigm_csv(noun: &Noun, opts: CsvOptions) -> Result<String, core::fmt::Error> {
+    let mut csv = String::new();
+    write_paradigms_csv(&mut csv, [noun], opts)?;
+    Ok(csv)
+}
+
+fn write_field<W: Write>(dst: &mut W, field: &str, opts: CsvOptions) -> core::fmt::Result {
+    let needs_quotes =
+        field.chars().any(|ch| matches!(ch, '"' | '\n' | '\r') || ch == opts.delimiter);
+    if !needs_quotes {
+        return dst.write_str(field);
+    }
+
+    dst.write_char('"')?;
+    for (i, part) in field.split('"').enumerate() {
+        if i > 0 {
+            dst.write_str("\"\"")?;
+        }
+        dst.write_str(part)?;
+    }
+    dst.write_char('"')
+}
+
+// Returns an error, if the noun can't be inflected
+fn write_form(
+    dst: &mut String,
+    noun: &Noun,
+    case: CaseEx,
+    number: Number,
+    opts: CsvOptions,
+) -> core::fmt::Result {
+    let resolved = noun.inflect_buf(case, number).map_err(|_| core::fmt::Error)?;
+    let Some((buf, decl, info)) = resolved else {
+        push_verbatim(dst, noun.exception(case, number).unwrap_or(noun.stem), opts);
+        return Ok(());
+    };
+
+    let stressed = if opts.stress_marks { stressed_vowel(decl, info, &buf) } else { None };
+    push_stressed(dst, &buf, stressed, opts.fold_yo);
+    Ok(())
+}
+
+// Appends an irregular or indeclinable form, with the same 'ё' folding and stress marks as in the
+// regular forms: the form's own stress marks are kept only with stress_marks, and a folded 'ё'
+// is marked in polysyllabic forms.
+fn push_verbatim(dst: &mut String, form: &str, opts: CsvOptions) {
+    let is_vowel = |ch: char| "аеёиоуыэюяАЕЁИОУЫЭЮЯ".contains(ch);
+    let is_polysyllabic = form.chars().filter(|&ch| is_vowel(ch)).count() > 1;
+
+    for ch in form.chars() {
+        match ch {
+            STRESS_MARK if !opts.stress_marks => {},
+            'ё' | 'Ё' if opts.fold_yo => {
+                dst.push(if ch == 'ё' { 'е' } else { 'Е' });
+                if opts.stress_marks && is_polysyllabic && !form.contains(STRESS_MARK) {
+                    dst.push(STRESS_MARK);
+                }
+            },
+            _ => dst.push(ch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Gender, GenderEx},
+        declension::NounInfo,
+    };
+    use alloc::{string::ToString, vec::Vec};
+
+    fn noun<'a>(stem: &'a str, decl: &str, gender: Gender, animacy: Animacy) -> Noun<'a> {
+        let info = NounInfo {
+            declension: (decl != "0").then(|| decl.parse().unwrap()),
+            declension_gender: gender,
+            gender: GenderEx::from(gender),
+            animacy,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+
+    // A naive CSV parser, that only handles the quoting rules
+    fn parse(csv: &str, delimiter: char) -> Vec<Vec<String>> {
+        let mut rows = vec![];
+        let (mut row, mut field) = (vec![], String::new());
+        let mut chars = csv.chars().peekable();
+        let mut quoted = false;
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                },
+                '"' => quoted = !quoted,
+                '\n' if !quoted => {
+                    row.push(core::mem::take(&mut field));
+                    rows.push(core::mem::take(&mut row));
+                },
+                _ if ch == delimiter && !quoted => row.push(core::mem::take(&mut field)),
+                _ => field.push(ch),
+            }
+        }
+        assert!(!quoted && row.is_empty() && field.is_empty());
+        rows
+    }
+
+    #[test]
+    fn header() {
+        let rows = parse(
+            &paradigm_csv(&noun("стол", "1b", Gender::MASC, Animacy::INAN), CsvOptions::CSV)
+                .unwrap(),
+            ',',
+        );
+        #[rustfmt::skip]
+        assert_eq!(rows[0], [
+            "lemma", "index",
+            "nom_sg", "gen_sg", "dat_sg", "acc_sg", "ins_sg", "prp_sg",
+            "nom_pl", "gen_pl", "dat_pl", "acc_pl", "ins_pl", "prp_pl",
+        ]);
+
+        let opts = CsvOptions { secondary_cases: true, ..CsvOptions::TSV };
+        let rows = parse(
+            &paradigm_csv(&noun("стол", "1b", Gender::MASC, Animacy::INAN), opts).unwrap(),
+            '\t',
+        );
+        assert_eq!(rows[0].len(), 17);
+        assert_eq!(rows[0][14..], ["prt_sg", "loc_sg", "transl_pl"]);
+        assert_eq!(rows[1][14..], ["стола", "столе", "столы"]);
+    }
+
+    #[test]
+    fn rows() {
+        let nouns = [
+            noun("стол", "1b", Gender::MASC, Animacy::INAN),
+            noun("звезд", "1d, ё", Gender::FEM, Animacy::INAN),
+            noun("кофе", "0", Gender::MASC, Animacy::INAN),
+        ];
+        let mut csv = String::new();
+        write_paradigms_csv(&mut csv, &nouns, CsvOptions::CSV).unwrap();
+
+        let rows = parse(&csv, ',');
+        assert_eq!(rows.len(), 4);
+        assert!(rows.iter().all(|row| row.len() == 14));
+
+        #[rustfmt::skip]
+        assert_eq!(rows[1], [
+            "стол", "1b",
+            "стол", "стола", "столу", "стол", "столом", "столе",
+            "столы", "столов", "столам", "столы", "столами", "столах",
+        ]);
+        // The index with a delimiter in it is quoted
+        assert!(csv.contains("звезда,\"1d, ё\","));
+        #[rustfmt::skip]
+        assert_eq!(rows[2], [
+            "звезда", "1d, ё",
+            "звезда", "звезды", "звезде", "звезду", "звездой", "звезде",
+            "звёзды", "звёзд", "звёздам", "звёзды", "звёздами", "звёздах",
+        ]);
+        assert_eq!(rows[3][..3], ["кофе", "0", "кофе"]);
+        assert!(rows[3][2..].iter().all(|x| x == "кофе"));
+
+        // With a tab delimiter, the index doesn't need quotes
+        let mut tsv = String::new();
+        write_paradigms_csv(&mut tsv, &nouns, CsvOptions::TSV).unwrap();
+        assert!(!tsv.contains('"'));
+        assert_eq!(parse(&tsv, '\t'), rows);
+    }
+
+    #[test]
+    fn yo_and_stress() {
+        let star = noun("звезд", "1d, ё", Gender::FEM, Animacy::INAN);
+
+        let opts = CsvOptions { fold_yo: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&star, opts).unwrap(), ',');
+        assert_eq!(rows[1][0], "звезда");
+        assert_eq!(rows[1][8..10], ["звезды", "звезд"]);
+        // The index isn't folded
+        assert_eq!(rows[1][1], "1d, ё");
+
+        let opts = CsvOptions { stress_marks: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&star, opts).unwrap(), ',');
+        #[rustfmt::skip]
+        assert_eq!(rows[1][2..], [
+            "звезда́", "звезды́", "звезде́", "звезду́", "звездо́й", "звезде́",
+            "звёзды", "звёзд", "звёздам", "звёзды", "звёздами", "звёздах",
+        ]);
+        // The lemma isn't marked
+        assert_eq!(rows[1][0], "звезда");
+
+        let opts = CsvOptions { stress_marks: true, fold_yo: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&star, opts).unwrap(), ',');
+        assert_eq!(rows[1][8..10], ["зве́зды", "звезд"]);
+
+        // Monosyllabic forms aren't marked, and polysyllabic stems only when it's known
+        let rows = parse(
+            &paradigm_csv(&noun("стол", "1b", Gender::MASC, Animacy::INAN), CsvOptions {
+                stress_marks: true,
+                ..CsvOptions::CSV
+            })
+            .unwrap(),
+            ',',
+        );
+        assert_eq!(rows[1][2..4], ["стол", "стола́"]);
+        let rows = parse(
+            &paradigm_csv(&noun("комнат", "1a", Gender::FEM, Animacy::INAN), CsvOptions {
+                stress_marks: true,
+                ..CsvOptions::CSV
+            })
+            .unwrap(),
+            ',',
+        );
+        assert_eq!(rows[1][2..4], ["комната", "комнаты"]);
+    }
+
+    #[test]
+    fn verbatim_forms() {
+        let star = noun("звезд", "1d, ё", Gender::FEM, Animacy::INAN);
+        let exceptions = [
+            (CaseEx::Genitive, Number::Plural, "звёзд"),
+            (CaseEx::Dative, Number::Plural, "звёздам"),
+        ];
+        let star = star.with_exceptions(&exceptions);
+        let bureau = noun("бюро́", "0", Gender::NEUT, Animacy::INAN);
+
+        // Irregular and indeclinable forms are normalized like the regular ones
+        let opts = CsvOptions { fold_yo: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&star, opts).unwrap(), ',');
+        assert_eq!(rows[1][8..11], ["звезды", "звезд", "звездам"]);
+        let rows = parse(&paradigm_csv(&bureau, opts).unwrap(), ',');
+        assert_eq!(rows[1][..3], ["бюро", "0", "бюро"]);
+
+        let opts = CsvOptions { stress_marks: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&bureau, opts).unwrap(), ',');
+        assert_eq!(rows[1][..3], ["бюро", "0", "бюро́"]);
+
+        let opts = CsvOptions { stress_marks: true, fold_yo: true, ..CsvOptions::CSV };
+        let rows = parse(&paradigm_csv(&star, opts).unwrap(), ',');
+        assert_eq!(rows[1][8..11], ["зве́зды", "звезд", "зве́здам"]);
+    }
+
+    #[test]
+    fn quoting() {
+        let opts = CsvOptions { delimiter: 'а', ..CsvOptions::CSV };
+        let csv = paradigm_csv(&noun("стол", "1b", Gender::MASC, Animacy::INAN), opts).unwrap();
+        assert!(csv.contains("\"стола\""));
+        assert_eq!(parse(&csv, 'а')[1][3], "стола");
+
+        let mut dst = String::new();
+        write_field(&mut dst, "a \"b\", c", CsvOptions::CSV).unwrap();
+        assert_eq!(dst, "\"a \"\"b\"\", c\"");
+        assert_eq!(parse(&(dst + "\n"), ','), [["a \"b\", c".to_string()]]);
+    }
+}
@@ -0,0 +1,129 @@
+/// A formatted value in a fixed-size buffer, returned by the `fmt_to_buf` methods of declensions,
+/// stresses and flags. Unlike the `fmt_to` methods, that write into a borrowed buffer, it owns its
+/// buffer, and can be returned from functions and stored without allocating.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{FmtBuf, declension::*, stress::*};
+///
+/// let decl: Declension = "мс 6*b".parse().unwrap();
+/// let formatted: FmtBuf<DECLENSION_MAX_LEN> = decl.fmt_to_buf();
+/// assert_eq!(formatted, "мс 6*b");
+///
+/// // The buffer dereferences to a string slice
+/// let stress = AnyDualStress::new(AnyStress::A, Some(AnyStress::Cpp));
+/// assert_eq!(stress.fmt_to_buf().chars().count(), 4);
+/// ```
+#[derive(Clone, Copy)]
+pub struct FmtBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FmtBuf<N> {
+    /// Creates a buffer from the bytes and the length of the formatted string in them.
+    /// The first `len` bytes must be valid UTF-8.
+    pub(crate) const fn from_parts(buf: [u8; N], len: usize) -> Self {
+        debug_assert!(len <= N);
+        Self { buf, len }
+    }
+
+    /// Returns the formatted string.
+    pub const fn as_str(&self) -> &str {
+        let (bytes, _) = self.buf.split_at(self.len);
+        unsafe { str::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<const N: usize> core::ops::Deref for FmtBuf<N> {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl<const N: usize> AsRef<str> for FmtBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FmtBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+impl<const N: usize> core::fmt::Debug for FmtBuf<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+// The bytes after the formatted string are ignored in comparisons
+impl<const N: usize> PartialEq for FmtBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<const N: usize> Eq for FmtBuf<N> {}
+impl<const N: usize> core::hash::Hash for FmtBuf<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+impl<const N: usize> PartialEq<str> for FmtBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+impl<const N: usize> PartialEq<&str> for FmtBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        declension::{
+            DECLENSION_FLAGS_MAX_LEN, DECLENSION_MAX_LEN, Declension, DeclensionFlags,
+            MaybeZeroDeclension, NOUN_INFO_MAX_LEN, NounInfo,
+        },
+        stress::{AnyDualStress, AnyStress, DUAL_STRESS_MAX_LEN, STRESS_MAX_LEN},
+    };
+
+    #[test]
+    fn fmt_to_buf() {
+        // The owned buffers contain the same strings as the borrowed ones
+        let stress = AnyStress::Fpp;
+        assert_eq!(stress.fmt_to_buf(), &*stress.fmt_to(&mut [0; STRESS_MAX_LEN]));
+        let stress = AnyDualStress::new(AnyStress::Fpp, Some(AnyStress::Fpp));
+        assert_eq!(stress.fmt_to_buf(), &*stress.fmt_to(&mut [0; DUAL_STRESS_MAX_LEN]));
+        let flags = DeclensionFlags::all();
+        assert_eq!(flags.fmt_to_buf(), &*flags.fmt_to(&mut [0; DECLENSION_FLAGS_MAX_LEN]));
+
+        let decl: Declension = "п 1*a/c″①②, ё, -ся".parse().unwrap();
+        assert_eq!(decl.fmt_to_buf(), &*decl.fmt_to(&mut [0; DECLENSION_MAX_LEN]));
+        assert_eq!(decl.fmt_to_buf(), "п 1*a/c″①②, ё, -ся");
+        let decl = decl.as_adjective().unwrap();
+        assert_eq!(decl.fmt_to_buf(), "1*a/c″①②, ё, -ся");
+        assert_eq!(MaybeZeroDeclension::ZERO.fmt_to_buf(), "0");
+
+        let info: NounInfo = "мн. мо-жо 7°*f″①②③, ё Р2 П2".parse().unwrap();
+        assert_eq!(info.fmt_to_buf(), info.fmt_to(&mut [0; NOUN_INFO_MAX_LEN]));
+
+        // The buffers can be created in const contexts
+        const STRESS: FmtBuf<STRESS_MAX_LEN> = AnyStress::Bp.fmt_to_buf();
+        assert_eq!(STRESS.as_str(), "b′");
+    }
+
+    #[test]
+    fn traits() {
+        let a = FmtBuf::from_parts(*b"ab\0", 2);
+        let b = FmtBuf::from_parts(*b"abc", 2);
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 2);
+        assert_eq!(std::format!("{a} {b:?}"), "ab \"ab\"");
+        assert_ne!(a, FmtBuf::from_parts(*b"abc", 3));
+    }
+}
@@ -1,19 +1,146 @@
-use crate::Letter;
+use crate::{Capitalization, Letter, LetterSliceExt};
+use alloc::vec::Vec;
+use thiserror::Error;
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct InflectionBuffer {
-    dst: Vec<u8>,
-    pub stem_len: usize,
+    // Always a valid UTF-8 string of Cyrillic letters, with the stem ending at a letter boundary
+    dst: Storage,
+    stem_len: usize,
+    stress: Option<StressPos>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StressPos {
+    /// The index of the stressed letter in the form.
+    Letter(usize),
+    /// The stressed letter was removed from the stem, and the stress moved onto the ending.
+    Ending,
+}
+
+/// An error returned by [`InflectionBuffer::from_stem`], when the stem contains a character that
+/// isn't a Cyrillic letter.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("stem must consist only of cyrillic letters, found {ch:?} at byte {index}")]
+pub struct StemError {
+    /// The first invalid character in the stem.
+    pub ch: char,
+    /// The byte index of the invalid character in the stem.
+    pub index: usize,
+}
+
+/// An error returned when an inflected form doesn't fit into the provided byte buffer.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+#[error("buffer too small for the inflected form, {required} bytes required")]
+pub struct BufferTooSmallError {
+    /// The length of the inflected form, in bytes.
+    pub required: usize,
+}
+
+// Spare room for the ending and stem extensions (-ами, -ен-, -ят-), in bytes
+const SPARE_CAPACITY: usize = 16;
+
+/// The maximum length of a stem, in bytes, that is inflected without allocating: 48 letters,
+/// more than in any Russian word. Longer stems are still supported, but the buffer spills onto
+/// the heap.
+pub const STEM_MAX_LEN: usize = 96;
+/// The maximum length of a form inflected from a stem of up to [`STEM_MAX_LEN`] bytes. A buffer
+/// of this length fits any form returned by the `inflect_into` methods
+/// ([`NounDeclension::inflect_into`](crate::declension::NounDeclension::inflect_into)).
+pub const FORM_MAX_LEN: usize = STEM_MAX_LEN + SPARE_CAPACITY;
+
+// Make sure that the buffer can be sent to and shared between threads
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<InflectionBuffer>();
+};
+
 impl InflectionBuffer {
-    // TODO: document that stem must contain only cyrillic alphabetic characters
-    pub fn from_stem_unchecked(stem: &str) -> Self {
-        let mut dst = Vec::with_capacity(stem.len() + 16);
-        dst.extend_from_slice(stem.as_bytes());
-        Self { dst, stem_len: stem.len() }
+    /// Creates a buffer with the specified stem, making sure that it consists only of Cyrillic
+    /// letters (lowercase or uppercase). The stem may be empty.
+    ///
+    /// The buffer has room for the stem and 8 more letters, which fits any ending along with
+    /// stem extensions (-ен-, -ят-), so inflecting the stem usually doesn't reallocate.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::InflectionBuffer;
+    ///
+    /// let mut buf = InflectionBuffer::from_stem("стол").unwrap();
+    /// buf.append_to_ending("ами");
+    /// assert_eq!(buf.as_str(), "столами");
+    ///
+    /// let err = InflectionBuffer::from_stem("st0l").unwrap_err();
+    /// assert_eq!((err.ch, err.index), ('s', 0));
+    /// ```
+    pub fn from_stem(stem: &str) -> Result<Self, StemError> {
+        check_stem(stem)?;
+        // SAFETY: The stem was just checked
+        Ok(unsafe { Self::from_stem_unchecked(stem) })
+    }
+    /// Creates a buffer with the specified stem, without checking it. See
+    /// [`from_stem`][Self::from_stem] for the checked version.
+    ///
+    /// # Safety
+    ///
+    /// The stem must consist only of Cyrillic letters (lowercase or uppercase), as checked by
+    /// [`from_stem`][Self::from_stem]. The letters are 2 bytes long in UTF-8, and the buffer splits
+    /// and rearranges the bytes in pairs, so any other character may leave the buffer with invalid
+    /// UTF-8, that [`as_str`][Self::as_str] and [`copy_to`][Self::copy_to] return as a string.
+    pub unsafe fn from_stem_unchecked(stem: &str) -> Self {
+        let mut dst = Storage::default();
+        dst.replace_range(0..0, stem.as_bytes());
+        Self { dst, stem_len: stem.len(), stress: None }
+    }
+    /// Creates a buffer with the specified stem. Unlike [`from_stem`][Self::from_stem], the stem is
+    /// already known to consist only of letters.
+    pub fn from_letters(stem: &[Letter]) -> Self {
+        // SAFETY: Letters are always valid
+        unsafe { Self::from_stem_unchecked(stem.as_str()) }
+    }
+    /// Replaces the contents of the buffer with the specified stem, reusing the allocation.
+    /// Returns an error and leaves the buffer unchanged, if the stem isn't valid.
+    pub fn reset_to_stem(&mut self, stem: &str) -> Result<(), StemError> {
+        check_stem(stem)?;
+        // SAFETY: The stem was just checked
+        unsafe { self.reset_to_stem_unchecked(stem) };
+        Ok(())
+    }
+    /// Replaces the contents of the buffer with the specified stem, reusing the allocation.
+    ///
+    /// # Safety
+    ///
+    /// The stem must consist only of Cyrillic letters, see
+    /// [`from_stem_unchecked`][Self::from_stem_unchecked].
+    pub unsafe fn reset_to_stem_unchecked(&mut self, stem: &str) {
+        self.dst.replace_range(0..self.dst.len(), stem.as_bytes());
+        self.stem_len = stem.len();
+        self.stress = None;
+    }
+
+    /// Returns the index of the stressed letter in the form, if it's known.
+    ///
+    /// The stress position is kept up to date by all the methods that insert or remove letters.
+    /// If the stressed letter itself is removed (сон - сна), the stress moves onto the first vowel
+    /// of the ending, and if the ending doesn't have any vowels, the stress is unknown.
+    pub fn stress(&self) -> Option<usize> {
+        match self.stress? {
+            StressPos::Letter(index) => Some(index),
+            StressPos::Ending => {
+                let index = self.ending().iter().position(|x| x.is_vowel())?;
+                Some(self.stem_len / 2 + index)
+            },
+        }
+    }
+    /// Sets the index of the stressed letter in the form.
+    pub fn set_stress(&mut self, index: Option<usize>) {
+        self.stress = index.map(StressPos::Letter);
     }
 
+    /// Returns the length of the stem, in bytes (2 bytes per letter).
+    pub const fn stem_len(&self) -> usize {
+        self.stem_len
+    }
     pub const fn stem(&self) -> &[Letter] {
         // FIXME(const-hack): Remove `as_slice()` when Deref for Vec is constified.
         Letter::from_bytes(self.dst.as_slice().get(..self.stem_len).unwrap())
@@ -31,35 +158,434 @@ impl InflectionBuffer {
         Letter::from_bytes_mut(self.dst.as_mut_slice().get_mut(self.stem_len..).unwrap())
     }
 
+    /// Appends the letters to the ending.
+    ///
+    /// The buffer grows as needed, like a [`Vec`], so appending never overflows: the only limit
+    /// is the allocator's, and it panics only if the capacity exceeds `isize::MAX` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the appended string contains anything other than Cyrillic letters.
     pub fn append_to_ending(&mut self, append: &str) {
-        self.dst.extend_from_slice(append.as_bytes());
+        assert_letters(append);
+        self.append_to_ending_unchecked(append);
     }
+    /// Replaces the ending with the letters. Panics like
+    /// [`append_to_ending`][Self::append_to_ending].
     pub fn replace_ending(&mut self, new_ending: &str) {
-        self.dst.splice(self.stem_len.., new_ending.bytes());
+        assert_letters(new_ending);
+        self.replace_ending_unchecked(new_ending);
     }
-
+    /// Appends the letters to the stem, shifting the ending. Grows the buffer and panics like
+    /// [`append_to_ending`][Self::append_to_ending].
     pub fn append_to_stem(&mut self, append: &str) {
+        assert_letters(append);
+        self.append_to_stem_unchecked(append);
+    }
+
+    // The same methods for the declensions' own endings and infixes, that are always letters, so
+    // that inflecting doesn't scan them every time. They're only checked in debug builds.
+    pub(crate) fn append_to_ending_unchecked(&mut self, append: &str) {
+        debug_assert_letters(append);
+        self.dst.replace_range(self.dst.len()..self.dst.len(), append.as_bytes());
+    }
+    pub(crate) fn replace_ending_unchecked(&mut self, new_ending: &str) {
+        debug_assert_letters(new_ending);
+        // If the stress was on the ending, it stays on the new ending
+        if let Some(StressPos::Letter(index)) = self.stress
+            && index >= self.stem_len / 2
+        {
+            self.stress = Some(StressPos::Ending);
+        }
+        self.dst.replace_range(self.stem_len..self.dst.len(), new_ending.as_bytes());
+    }
+    pub(crate) fn append_to_stem_unchecked(&mut self, append: &str) {
+        debug_assert_letters(append);
         self.insert_at(self.stem_len, append);
         self.stem_len += append.len();
     }
+    /// Removes the specified number of bytes from the end of the stem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem is shorter than that, or if it's not a whole number of letters.
     pub fn shrink_stem_by(&mut self, shrink: usize) {
-        self.dst.splice((self.stem_len - shrink)..self.stem_len, []);
+        assert!(
+            shrink <= self.stem_len && shrink.is_multiple_of(2),
+            "invalid stem length: {shrink}"
+        );
+        self.remove_stress_letters((self.stem_len - shrink)..self.stem_len);
+        self.dst.replace_range((self.stem_len - shrink)..self.stem_len, &[]);
         self.stem_len -= shrink;
     }
-    pub fn remove_from_stem<R: std::ops::RangeBounds<usize>>(&mut self, range: R) {
-        let shrink = self.dst.splice(range, []).len();
-        self.stem_len -= shrink;
+    /// Removes the specified byte range from the stem.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the stem's bounds, or doesn't fall on letter boundaries.
+    pub fn remove_from_stem<R: core::ops::RangeBounds<usize>>(&mut self, range: R) {
+        use core::ops::Bound;
+
+        let start = match range.start_bound() {
+            Bound::Included(&x) => x,
+            Bound::Excluded(&x) => x + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&x) => x + 1,
+            Bound::Excluded(&x) => x,
+            Bound::Unbounded => self.stem_len,
+        };
+        assert!(
+            start <= end
+                && end <= self.stem_len
+                && start.is_multiple_of(2)
+                && end.is_multiple_of(2),
+            "invalid stem range: {start}..{end}",
+        );
+        self.remove_stress_letters(start..end);
+
+        self.dst.replace_range(start..end, &[]);
+        self.stem_len -= end - start;
     }
+    /// Inserts the letter before the last letter of the stem (кошк - кошек). Grows the buffer as
+    /// needed, like [`append_to_ending`][Self::append_to_ending].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the stem is empty.
     pub fn insert_between_last_two_stem_letters(&mut self, ch: Letter) {
+        assert!(self.stem_len >= 2, "cannot insert a letter into an empty stem");
         self.insert_at(self.stem_len - 2, ch.as_str());
         self.stem_len += 2;
     }
     fn insert_at(&mut self, index: usize, replace: &str) {
-        self.dst.splice(index..index, replace.bytes());
+        // Shift the stress, if the letters are inserted before the stressed letter
+        if let Some(StressPos::Letter(stressed)) = &mut self.stress
+            && *stressed >= index / 2
+        {
+            *stressed += replace.len() / 2;
+        }
+        self.dst.replace_range(index..index, replace.as_bytes());
+    }
+    // Updates the stress position before the letters in the byte range are removed
+    fn remove_stress_letters(&mut self, range: core::ops::Range<usize>) {
+        if let Some(StressPos::Letter(stressed)) = self.stress {
+            let (start, end) = (range.start / 2, range.end / 2);
+
+            if (start..end).contains(&stressed) {
+                self.stress = Some(StressPos::Ending);
+            } else if stressed >= end {
+                self.stress = Some(StressPos::Letter(stressed - (end - start)));
+            }
+        }
+    }
+
+    /// Converts the whole form to lowercase, and returns its original capitalization, so that
+    /// the alternations can match the stem's letters regardless of their case. The
+    /// capitalization can be restored with [`apply_capitalization`][Self::apply_capitalization].
+    pub fn to_lowercase(&mut self) -> Capitalization {
+        let letters = Letter::from_bytes_mut(self.dst.as_mut_slice());
+        let capitalization = Capitalization::of(letters);
+        if capitalization != Capitalization::Lowercase {
+            letters.iter_mut().for_each(|x| *x = x.to_lowercase());
+        }
+        capitalization
+    }
+    /// Applies the capitalization to the lowercase form. See [`Capitalization::apply`].
+    pub fn apply_capitalization(&mut self, capitalization: Capitalization) {
+        capitalization.apply(Letter::from_bytes_mut(self.dst.as_mut_slice()));
     }
 
     pub const fn as_str(&self) -> &str {
         // FIXME(const-hack): Remove `as_slice()` when Deref for Vec is constified.
         unsafe { str::from_utf8_unchecked(self.dst.as_slice()) }
     }
+    pub const fn as_letters(&self) -> &[Letter] {
+        // FIXME(const-hack): Remove `as_slice()` when Deref for Vec is constified.
+        Letter::from_bytes(self.dst.as_slice())
+    }
+
+    /// Copies the form into the byte buffer, and returns it as a string slice. Returns an error,
+    /// if the form doesn't fit; a buffer of [`FORM_MAX_LEN`] bytes fits the forms of all stems of
+    /// up to [`STEM_MAX_LEN`] bytes.
+    pub fn copy_to<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, BufferTooSmallError> {
+        let bytes = self.dst.as_slice();
+        let Some(out) = out.get_mut(..bytes.len()) else {
+            return Err(BufferTooSmallError { required: bytes.len() });
+        };
+        out.copy_from_slice(bytes);
+        // The bytes were copied from a valid string
+        Ok(unsafe { str::from_utf8_unchecked(out) })
+    }
+}
+
+// The buffer's bytes, stored inline for stems of up to STEM_MAX_LEN bytes, and spilled onto the
+// heap for longer ones, so that inflecting most words doesn't allocate
+#[derive(Debug)]
+enum Storage {
+    Inline { bytes: [u8; FORM_MAX_LEN], len: usize },
+    Heap(Vec<u8>),
+}
+
+impl Default for Storage {
+    fn default() -> Self {
+        Self::Inline { bytes: [0; FORM_MAX_LEN], len: 0 }
+    }
+}
+impl PartialEq for Storage {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl Eq for Storage {}
+
+impl Storage {
+    const fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+    const fn as_slice(&self) -> &[u8] {
+        match self {
+            Self::Inline { bytes, len } => bytes.split_at(*len).0,
+            Self::Heap(vec) => vec.as_slice(),
+        }
+    }
+    const fn as_mut_slice(&mut self) -> &mut [u8] {
+        match self {
+            Self::Inline { bytes, len } => bytes.split_at_mut(*len).0,
+            Self::Heap(vec) => vec.as_mut_slice(),
+        }
+    }
+    #[cfg(test)]
+    fn capacity(&self) -> usize {
+        match self {
+            Self::Inline { .. } => FORM_MAX_LEN,
+            Self::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    // Replaces the bytes in the range, like Vec::splice, moving the bytes onto the heap if they
+    // don't fit inline anymore
+    fn replace_range(&mut self, range: core::ops::Range<usize>, replace: &[u8]) {
+        match self {
+            Self::Inline { bytes, len } if *len - range.len() + replace.len() <= FORM_MAX_LEN => {
+                let new_len = *len - range.len() + replace.len();
+                bytes.copy_within(range.end..*len, range.start + replace.len());
+                bytes[range.start..(range.start + replace.len())].copy_from_slice(replace);
+                *len = new_len;
+            },
+            Self::Inline { bytes, len } => {
+                let mut vec = Vec::with_capacity(*len + replace.len() + SPARE_CAPACITY);
+                vec.extend_from_slice(&bytes[..*len]);
+                vec.splice(range, replace.iter().copied());
+                *self = Self::Heap(vec);
+            },
+            Self::Heap(vec) => {
+                vec.splice(range, replace.iter().copied());
+            },
+        }
+    }
+}
+
+fn check_stem(stem: &str) -> Result<(), StemError> {
+    match stem.char_indices().find(|&(_, ch)| Letter::try_from_char(ch).is_none()) {
+        Some((index, ch)) => Err(StemError { ch, index }),
+        None => Ok(()),
+    }
+}
+#[track_caller]
+fn assert_letters(s: &str) {
+    if let Err(err) = check_stem(s) {
+        panic!("{err}");
+    }
+}
+#[track_caller]
+fn debug_assert_letters(s: &str) {
+    if cfg!(debug_assertions) {
+        assert_letters(s);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, Case, Gender, Number},
+        declension::{DeclInfo, NounDeclension},
+        letters,
+    };
+    use std::{borrow::ToOwned, format, string::String};
+
+    fn inflect(stem: &str, stress: usize, decl: &str, info: DeclInfo) -> (String, Option<usize>) {
+        let decl: NounDeclension = decl.parse().unwrap();
+
+        let mut buf = InflectionBuffer::from_stem(stem).unwrap();
+        buf.set_stress(Some(stress));
+        decl.inflect(info, &mut buf).unwrap();
+        (buf.as_str().to_owned(), buf.stress())
+    }
+
+    #[test]
+    fn stress_fleeting_vowel() {
+        let info = |case, number| DeclInfo {
+            case,
+            number,
+            gender: Gender::Masculine,
+            animacy: Animacy::Inanimate,
+        };
+
+        // The stressed fleeting vowel is removed, and the stress moves onto the ending
+        let sg = |case| inflect("сон", 1, "1*b", info(case, Number::Singular));
+        let pl = |case| inflect("сон", 1, "1*b", info(case, Number::Plural));
+        assert_eq!(sg(Case::Nominative), ("сон".to_owned(), Some(1)));
+        assert_eq!(sg(Case::Genitive), ("сна".to_owned(), Some(2)));
+        assert_eq!(sg(Case::Instrumental), ("сном".to_owned(), Some(2)));
+        assert_eq!(pl(Case::Genitive), ("снов".to_owned(), Some(2)));
+        assert_eq!(pl(Case::Instrumental), ("снами".to_owned(), Some(2)));
+
+        // The stress before the removed vowel isn't affected
+        let (form, stress) = inflect("ручеёк", 2, "3*b", info(Case::Genitive, Number::Singular));
+        assert_eq!((form.as_str(), stress), ("ручейка", Some(2)));
+        let (form, stress) = inflect("кусок", 3, "3*b", info(Case::Genitive, Number::Singular));
+        assert_eq!((form.as_str(), stress), ("куска", Some(4)));
+        // The stress after the removed vowel is shifted
+        let mut buf = InflectionBuffer::from_stem("сон").unwrap();
+        buf.append_to_ending("ы");
+        buf.set_stress(Some(3));
+        buf.remove_from_stem(2..4);
+        assert_eq!((buf.as_str(), buf.stress()), ("сны", Some(2)));
+        // The stress before the inserted vowel isn't affected (сосна - сосен)
+        let info = DeclInfo { gender: Gender::Feminine, ..info(Case::Genitive, Number::Plural) };
+        let (form, stress) = inflect("сосн", 1, "1*a", info);
+        assert_eq!((form.as_str(), stress), ("сосен", Some(1)));
+        // The stress after the inserted vowel is shifted
+        let mut buf = InflectionBuffer::from_stem("сосн").unwrap();
+        buf.append_to_ending("ы");
+        buf.set_stress(Some(4));
+        buf.insert_between_last_two_stem_letters(crate::letters::е);
+        assert_eq!((buf.as_str(), buf.stress()), ("сосены", Some(5)));
+    }
+
+    #[test]
+    fn stress_unique_alternations() {
+        let info = |case, number| DeclInfo {
+            case,
+            number,
+            gender: Gender::Masculine,
+            animacy: Animacy::Animate,
+        };
+
+        // -ёнок - -ят: the stress stays on 'ё' - 'я' (ребёнок - ребята)
+        let (form, stress) = inflect("ребёнок", 3, "3°a", info(Case::Nominative, Number::Plural));
+        assert_eq!((form.as_str(), stress), ("ребята", Some(3)));
+        let (form, stress) = inflect("ребёнок", 3, "3°a", info(Case::Instrumental, Number::Plural));
+        assert_eq!((form.as_str(), stress), ("ребятами", Some(3)));
+        let (form, stress) = inflect("ребёнок", 3, "3°a", info(Case::Genitive, Number::Singular));
+        assert_eq!((form.as_str(), stress), ("ребёнка", Some(3)));
+
+        // -мя - -мени: the stress on the stem isn't affected by the infix (время - времени)
+        let info = DeclInfo { gender: Gender::Neuter, ..info(Case::Genitive, Number::Singular) };
+        let (form, stress) = inflect("врем", 2, "8°c, ё", info);
+        assert_eq!((form.as_str(), stress), ("времени", Some(2)));
+
+        // ...while the stress on the ending is shifted by it (времена)
+        let mut buf = InflectionBuffer::from_stem("врем").unwrap();
+        buf.append_to_ending("а");
+        buf.set_stress(Some(4));
+        buf.append_to_stem("ен");
+        assert_eq!((buf.as_str(), buf.stress()), ("времена", Some(6)));
+
+        // The stress on the replaced ending stays on the new ending
+        buf.replace_ending("ам");
+        assert_eq!((buf.as_str(), buf.stress()), ("временам", Some(6)));
+        buf.replace_ending("");
+        assert_eq!((buf.as_str(), buf.stress()), ("времен", None));
+
+        // Resetting the buffer clears the stress
+        buf.set_stress(Some(1));
+        buf.reset_to_stem("сон").unwrap();
+        assert_eq!(buf.stress(), None);
+    }
+
+    #[test]
+    fn from_stem() {
+        let buf = InflectionBuffer::from_stem("кошк").unwrap();
+        assert_eq!((buf.as_str(), buf.stem().len(), buf.ending()), ("кошк", 4, [].as_slice()));
+        assert!(buf.dst.capacity() >= "кошк".len() + SPARE_CAPACITY);
+
+        // Forms longer than the inline storage are moved onto the heap
+        let stem = "я".repeat(FORM_MAX_LEN / 2 - 1);
+        let mut buf = InflectionBuffer::from_stem(&stem).unwrap();
+        buf.append_to_ending("и");
+        assert!(matches!(buf.dst, Storage::Inline { .. }));
+        buf.insert_between_last_two_stem_letters(letters::е);
+        assert!(matches!(buf.dst, Storage::Heap(_)));
+        assert_eq!(buf.as_str(), format!("{}еяи", "я".repeat(FORM_MAX_LEN / 2 - 2)));
+        buf.reset_to_stem("кошк").unwrap();
+        assert_eq!((buf.as_str(), buf.stem().len()), ("кошк", 4));
+
+        // Uppercase letters and empty stems are allowed
+        assert_eq!(InflectionBuffer::from_stem("Мари").unwrap().as_str(), "Мари");
+        assert_eq!(InflectionBuffer::from_stem("").unwrap().as_str(), "");
+
+        // Latin letters, digits, spaces, hyphens and combining stress marks are not
+        let err = |ch, index| Err(StemError { ch, index });
+        assert_eq!(InflectionBuffer::from_stem("кот").map(|_| ()), Ok(()));
+        assert_eq!(InflectionBuffer::from_stem("кoт"), err('o', 2));
+        assert_eq!(InflectionBuffer::from_stem("к2"), err('2', 2));
+        assert_eq!(InflectionBuffer::from_stem("жар птиц"), err(' ', 6));
+        assert_eq!(InflectionBuffer::from_stem("жар-птиц"), err('-', 6));
+        assert_eq!(InflectionBuffer::from_stem("ко\u{301}т"), err('\u{301}', 4));
+
+        // Resetting to an invalid stem leaves the buffer unchanged
+        let mut buf = InflectionBuffer::from_stem("стол").unwrap();
+        buf.append_to_ending("ами");
+        assert_eq!(buf.reset_to_stem("stol"), Err(StemError { ch: 's', index: 0 }));
+        assert_eq!(buf.as_str(), "столами");
+        assert_eq!(buf.reset_to_stem("окн"), Ok(()));
+        assert_eq!((buf.as_str(), buf.stem_len), ("окн", 6));
+    }
+
+    #[test]
+    fn growth() {
+        // Appending past the initial capacity reallocates instead of overflowing
+        let mut buf = InflectionBuffer::from_stem("к").unwrap();
+        for _ in 0..1000 {
+            buf.append_to_stem("о");
+            buf.append_to_ending("а");
+            buf.insert_between_last_two_stem_letters(letters::т);
+        }
+        assert_eq!(buf.stem().len(), 2001);
+        assert_eq!(buf.ending().len(), 1000);
+        assert!(buf.stem().starts_with(&[letters::к, letters::т, letters::о]));
+        assert!(buf.stem().ends_with(&[letters::т, letters::о]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot insert a letter into an empty stem")]
+    fn insert_into_empty_stem() {
+        let mut buf = InflectionBuffer::from_stem("").unwrap();
+        buf.append_to_ending("а");
+        buf.insert_between_last_two_stem_letters(letters::о);
+    }
+
+    // The buffer must always hold whole letters, since it's returned as a string without checking
+    #[test]
+    #[should_panic(expected = "stem must consist only of cyrillic letters")]
+    fn append_non_letters() {
+        let mut buf = InflectionBuffer::from_stem("кот").unwrap();
+        buf.append_to_ending("€");
+    }
+    #[test]
+    #[should_panic(expected = "invalid stem range: 0..1")]
+    fn remove_part_of_letter() {
+        let mut buf = InflectionBuffer::from_stem("кот").unwrap();
+        buf.remove_from_stem(0..1);
+    }
+    #[test]
+    #[should_panic(expected = "invalid stem length: 3")]
+    fn shrink_by_part_of_letter() {
+        let mut buf = InflectionBuffer::from_stem("кот").unwrap();
+        buf.shrink_stem_by(3);
+    }
 }
@@ -1,3 +1,23 @@
+//! # Thread safety
+//!
+//! All public types of this crate are plain data, and are [`Send`] and [`Sync`]: nouns,
+//! adjectives, pronouns and verbs only borrow their stems, declensions, stresses and categories are
+//! [`Copy`] values, and [`InflectionBuffer`] owns its contents. Inflection doesn't use any
+//! global or cached state, so the same word can be inflected from multiple threads at once.
+//! The only exception is [`Morphology`](morphology::Morphology), that caches the inflected forms
+//! in [`OnceLock`](std::sync::OnceLock)s, and so can still be shared between threads.
+//!
+//! # Features
+//!
+//! - `std` (enabled by default): the [`morphology`] module, and [`std`] support in dependencies.
+//!   Without it, the crate is `no_std`, but still requires [`alloc`]. The buffer APIs
+//!   (`inflect_into`, `fmt_to` and `fmt_to_buf`) don't allocate at all, see `tests/no_alloc.rs`.
+//! - `export`: the `export` module, that writes noun paradigms as CSV/TSV.
+//! - `serde`: `Serialize` and `Deserialize` implementations for declensions, stresses and
+//!   categories. Declensions and stresses are serialized in their compact notation ("жо 7*b′①").
+//! - `arbitrary`: `Arbitrary` implementations for declensions, flags and stresses, for fuzzing.
+//!   Only valid values are generated, that can be formatted and parsed back.
+
 #![feature(const_trait_impl)]
 #![feature(const_destruct)]
 #![feature(const_from)]
@@ -8,16 +28,217 @@
 #![feature(core_intrinsics)]
 #![feature(const_eval_select)]
 #![cfg_attr(test, feature(test))]
+#![cfg_attr(not(feature = "std"), no_std)]
 // Fix issues with alphabet::letters::*
 #![allow(confusable_idents, non_upper_case_globals, internal_features)]
 
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+#[cfg(test)]
+extern crate test;
+
 pub mod categories;
+pub mod conjugation;
 pub mod declension;
+pub mod entry;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(feature = "std")]
+pub mod morphology;
+pub mod numerals;
+pub mod parsing;
+pub mod phrase;
+pub mod stem;
 pub mod stress;
 
 mod alphabet;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+mod fmt_buf;
 mod inflection_buffer;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod util;
 
 pub use alphabet::*;
+pub use fmt_buf::*;
 pub use inflection_buffer::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        categories::*, conjugation::*, declension::*, entry::*, phrase::*, stem::*, stress::*, *,
+    };
+    use std::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn send_sync() {
+        // Alphabet and inflection
+        assert_send_sync::<Letter>();
+        assert_send_sync::<Letters>();
+        assert_send_sync::<Capitalization>();
+        assert_send_sync::<NormalizedStem>();
+        assert_send_sync::<NormalizeStemError>();
+        assert_send_sync::<InflectionBuffer>();
+        assert_send_sync::<StemError>();
+        assert_send_sync::<BufferTooSmallError>();
+        assert_send_sync::<FmtBuf<1>>();
+        assert_send_sync::<parsing::Parser>();
+
+        // Categories
+        assert_send_sync::<CaseEx>();
+        assert_send_sync::<Case>();
+        assert_send_sync::<GenderEx>();
+        assert_send_sync::<Gender>();
+        assert_send_sync::<Animacy>();
+        assert_send_sync::<Number>();
+        assert_send_sync::<GenderExAnimacy>();
+        assert_send_sync::<GenderAnimacy>();
+        assert_send_sync::<Person>();
+        assert_send_sync::<Aspect>();
+        assert_send_sync::<CaseAndNumber>();
+        assert_send_sync::<PersonAndNumber>();
+        assert_send_sync::<GenderOrPlural>();
+        assert_send_sync::<CaseError>();
+        assert_send_sync::<GenderError>();
+        assert_send_sync::<ParseGenderError>();
+        assert_send_sync::<ParsePersonError>();
+        assert_send_sync::<ParseAnimacyError>();
+        assert_send_sync::<ParseNumberError>();
+        assert_send_sync::<ParseGenderOrPluralError>();
+        assert_send_sync::<Grammemes>();
+        assert_send_sync::<InflectTrace>();
+        assert_send_sync::<Alternation>();
+        assert_send_sync::<UniqueAlternation>();
+        assert_send_sync::<ParseGrammemesError>();
+        assert_send_sync::<ParseCaseError>();
+
+        // Stress
+        assert_send_sync::<AnyStress>();
+        assert_send_sync::<NounStress>();
+        assert_send_sync::<PronounStress>();
+        assert_send_sync::<AdjectiveFullStress>();
+        assert_send_sync::<AdjectiveShortStress>();
+        assert_send_sync::<VerbPresentStress>();
+        assert_send_sync::<VerbPastStress>();
+        assert_send_sync::<AnyDualStress>();
+        assert_send_sync::<AdjectiveStress>();
+        assert_send_sync::<VerbStress>();
+        assert_send_sync::<ParseStressError>();
+        assert_send_sync::<AdjectiveStressError>();
+        assert_send_sync::<VerbStressError>();
+
+        // Declension
+        assert_send_sync::<Declension>();
+        assert_send_sync::<DeclensionKind>();
+        assert_send_sync::<AdjectivePrefix>();
+        assert_send_sync::<NounDeclension>();
+        assert_send_sync::<PrecomputedEndings>();
+        assert_send_sync::<Endings>();
+        assert_send_sync::<StemExtension>();
+        #[cfg(feature = "std")]
+        {
+            use crate::morphology::*;
+            assert_send_sync::<Morphology>();
+            assert_send_sync::<NounId>();
+        }
+        assert_send_sync::<NounInfo>();
+        assert_send_sync::<ParseNounInfoError>();
+        assert_send_sync::<NounForms>();
+        assert_send_sync::<PronounDeclension>();
+        assert_send_sync::<AdjectiveDeclension>();
+        assert_send_sync::<MaybeZeroDeclension>();
+        assert_send_sync::<DeclensionFlags>();
+        assert_send_sync::<AnyStemType>();
+        assert_send_sync::<NounStemType>();
+        assert_send_sync::<PronounStemType>();
+        assert_send_sync::<ParseDeclensionFlagsError>();
+        assert_send_sync::<AdjectiveStemType>();
+        assert_send_sync::<ParseDeclensionError>();
+        assert_send_sync::<ParseDeclensionErrorAt>();
+        assert_send_sync::<ExtractStemError>();
+        assert_send_sync::<StemMismatchError>();
+        assert_send_sync::<InvalidPrefixError>();
+        assert_send_sync::<DeclInfo>();
+        assert_send_sync::<AdjectiveForm>();
+        assert_send_sync::<Noun>();
+        assert_send_sync::<NounInfo>();
+        assert_send_sync::<CompoundNoun>();
+        assert_send_sync::<CompoundPart>();
+        assert_send_sync::<SecondaryCaseSupport>();
+        assert_send_sync::<SecondaryCasePolicy>();
+        assert_send_sync::<UnsupportedCaseError>();
+        assert_send_sync::<TantumError>();
+        assert_send_sync::<InflectError>();
+        assert_send_sync::<InflectWarning>();
+        assert_send_sync::<Adjective>();
+        assert_send_sync::<AdjectiveInfo>();
+        assert_send_sync::<ShortFormAvailability>();
+        assert_send_sync::<ComparativeError>();
+        assert_send_sync::<Pronoun>();
+        assert_send_sync::<PronounInfo>();
+        assert_send_sync::<Word>();
+        assert_send_sync::<WordInfo>();
+        assert_send_sync::<InflectTarget>();
+
+        // Conjugation
+        assert_send_sync::<ConjugationType>();
+        assert_send_sync::<ParseConjugationTypeError>();
+        assert_send_sync::<VerbInfo>();
+        assert_send_sync::<Verb>();
+        assert_send_sync::<ConjugateError>();
+
+        // Entries and phrases
+        assert_send_sync::<Entry>();
+        assert_send_sync::<EntryWriter>();
+        assert_send_sync::<EntryOverflowError>();
+        assert_send_sync::<ParseEntryError>();
+        assert_send_sync::<ParseEntryErrorKind>();
+        assert_send_sync::<CountForm>();
+        #[cfg(feature = "export")]
+        assert_send_sync::<crate::export::CsvOptions>();
+    }
+
+    #[test]
+    fn inflect_concurrently() {
+        let info = NounInfo {
+            declension: Some("1b".parse().unwrap()),
+            declension_gender: Gender::Masculine,
+            gender: GenderEx::Masculine,
+            animacy: Animacy::Inanimate,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        let noun = Noun::new("стол", info);
+        let paradigm = || -> Vec<String> {
+            let mut forms = vec![];
+            for number in Number::VALUES {
+                for case in Case::VALUES {
+                    forms.push(
+                        core::fmt::from_fn(|f| noun.inflect(case.into(), number, f)).to_string(),
+                    );
+                }
+            }
+            forms
+        };
+        let expected = paradigm();
+
+        std::thread::scope(|s| {
+            for _ in 0..8 {
+                s.spawn(|| {
+                    for _ in 0..100 {
+                        assert_eq!(paradigm(), expected);
+                    }
+                });
+            }
+        });
+    }
+}
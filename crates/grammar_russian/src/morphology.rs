@@ -0,0 +1,259 @@
+use crate::{
+    categories::{CaseAndNumber, CaseEx, Number},
+    declension::{
+        Declension, ExtractStemError, InflectError, Noun, NounInfo, ParseDeclensionError,
+    },
+};
+use std::{collections::HashMap, ops::Range, sync::OnceLock};
+
+/// An id of a noun, added to a [`Morphology`] session.
+///
+/// Ids are indices into the session's arena, and aren't checked against the session they came
+/// from. An id from another session refers to a different noun there, or makes the methods panic,
+/// if the session doesn't have that many nouns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NounId(u32);
+
+/// A session for bulk workloads, such as inflecting all the words in a dictionary.
+///
+/// Parsed declensions are cached by their notation, stems of nouns are interned in a single
+/// arena, and the inflected forms are computed once and then reused.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, morphology::*};
+///
+/// let mut morph = Morphology::new();
+/// let id = morph.make_noun("кошка", "жо 3*a".parse().unwrap()).unwrap();
+///
+/// assert_eq!(morph.inflect(id, Case::Genitive.with(Number::Plural)), Ok("кошек"));
+/// ```
+#[derive(Debug, Default)]
+pub struct Morphology {
+    decl_cache: HashMap<Box<str>, Declension>,
+    stems: String,
+    stem_cache: HashMap<Box<str>, Range<usize>>,
+    nouns: Vec<NounEntry>,
+}
+
+#[derive(Debug)]
+struct NounEntry {
+    stem: Range<usize>,
+    info: NounInfo,
+    exceptions: Box<[(CaseEx, Number, Box<str>)]>,
+    // Indexed by the case (including the secondary ones) and number, like CaseAndNumber
+    forms: [OnceLock<Result<Box<str>, InflectError>>; 18],
+}
+
+impl Morphology {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the declension, or returns the one parsed earlier from the same string.
+    /// Invalid declensions aren't cached.
+    pub fn parse_declension_cached(&mut self, s: &str) -> Result<Declension, ParseDeclensionError> {
+        if let Some(decl) = self.decl_cache.get(s) {
+            return Ok(*decl);
+        }
+        let decl = s.parse::<Declension>()?;
+        self.decl_cache.insert(s.into(), decl);
+        Ok(decl)
+    }
+
+    /// Adds a noun by its lemma and info. The stem is extracted the same way as in
+    /// [`Noun::from_headword`], so the lemma of a plurale tantum noun is in the plural.
+    pub fn make_noun(&mut self, lemma: &str, info: NounInfo) -> Result<NounId, ExtractStemError> {
+        self.make_noun_with_exceptions(lemma, info, &[])
+    }
+    /// Same as [`make_noun`][Self::make_noun], but also specifies the noun's irregular forms
+    /// (see [`Noun::exceptions`]).
+    pub fn make_noun_with_exceptions(
+        &mut self,
+        lemma: &str,
+        info: NounInfo,
+        exceptions: &[(CaseEx, Number, &str)],
+    ) -> Result<NounId, ExtractStemError> {
+        let noun = Noun::from_headword(lemma, info)?;
+        let stem = self.intern_stem(noun.stem);
+
+        let id = NounId(self.nouns.len() as u32);
+        self.nouns.push(NounEntry {
+            stem,
+            info,
+            exceptions: exceptions
+                .iter()
+                .map(|&(case, number, form)| (case, number, form.into()))
+                .collect(),
+            forms: Default::default(),
+        });
+        Ok(id)
+    }
+
+    fn intern_stem(&mut self, stem: &str) -> Range<usize> {
+        if let Some(range) = self.stem_cache.get(stem) {
+            return range.clone();
+        }
+        let range = self.stems.len()..self.stems.len() + stem.len();
+        self.stems.push_str(stem);
+        self.stem_cache.insert(stem.into(), range.clone());
+        range
+    }
+
+    pub fn stem(&self, id: NounId) -> &str {
+        &self.stems[self.nouns[id.0 as usize].stem.clone()]
+    }
+    pub fn info(&self, id: NounId) -> NounInfo {
+        self.nouns[id.0 as usize].info
+    }
+
+    /// Returns the noun's form. It's inflected on the first call, and reused afterwards.
+    /// Errors are cached the same way as the forms.
+    pub fn inflect(&self, id: NounId, cell: CaseAndNumber) -> Result<&str, InflectError> {
+        let (case, number) = cell.parts();
+        self.inflect_ex(id, case.into(), number)
+    }
+    /// Same as [`inflect`][Self::inflect], but also supports the secondary cases: partitive,
+    /// translative and locative.
+    pub fn inflect_ex(
+        &self,
+        id: NounId,
+        case: CaseEx,
+        number: Number,
+    ) -> Result<&str, InflectError> {
+        let entry = &self.nouns[id.0 as usize];
+
+        let form = entry.forms[((case as usize) << 1) | number as usize].get_or_init(|| {
+            let exceptions: Vec<_> = entry
+                .exceptions
+                .iter()
+                .map(|(case, number, form)| (*case, *number, &**form))
+                .collect();
+            let noun = Noun::new(self.stem(id), entry.info).with_exceptions(&exceptions);
+            Ok(noun.inflect_to_string(case, number)?.into())
+        });
+        form.as_deref().map_err(|err| *err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::Case;
+    use test::{Bencher, black_box};
+
+    const NOUNS: [(&str, &str); 6] = [
+        ("стол", "м 1b"),
+        ("кошка", "жо 3*a"),
+        ("окно", "с 1*d"),
+        ("книга", "ж 3a"),
+        ("сад", "м 1c П2"),
+        ("конь", "мо 2f"),
+    ];
+
+    fn inflect_naive(lemma: &str, info: &str, cell: CaseAndNumber) -> String {
+        let noun = Noun::from_headword(lemma, info.parse().unwrap()).unwrap();
+        let (case, number) = cell.parts();
+        noun.inflect_to_string(case.into(), number).unwrap()
+    }
+
+    #[test]
+    fn cached() {
+        let mut morph = Morphology::new();
+
+        let ids = NOUNS.map(|(lemma, info)| morph.make_noun(lemma, info.parse().unwrap()).unwrap());
+
+        // Forms are identical to the naive ones, both on the first and on the repeated calls
+        for _ in 0..2 {
+            for (id, (lemma, info)) in ids.into_iter().zip(NOUNS) {
+                assert_eq!(morph.stem(id), &lemma[..morph.stem(id).len()]);
+                for cell in CaseAndNumber::VALUES {
+                    assert_eq!(morph.inflect(id, cell), Ok(&*inflect_naive(lemma, info, cell)));
+                }
+            }
+        }
+
+        // The same stem is only stored once
+        let len = morph.stems.len();
+        let id = morph.make_noun("столы", "мн. м 1b".parse().unwrap()).unwrap();
+        assert_eq!(morph.stems.len(), len);
+        assert_eq!(morph.stem(id), "стол");
+        assert_eq!(morph.info(id), "мн. м 1b".parse().unwrap());
+    }
+
+    #[test]
+    fn noun_info() {
+        let mut morph = Morphology::new();
+        let sg = Case::Genitive.with(Number::Singular);
+
+        // The tantum number, secondary cases and irregular forms are applied, like in Noun
+        let id = morph.make_noun("ножницы", "мн. ж 5a".parse().unwrap()).unwrap();
+        assert_eq!(morph.inflect(id, Case::Dative.with(Number::Singular)), Ok("ножницам"));
+
+        let id = morph.make_noun("лес", "м 1c П2".parse().unwrap()).unwrap();
+        assert_eq!(morph.inflect_ex(id, CaseEx::Locative, Number::Singular), Ok("лесу"));
+        assert_eq!(morph.inflect_ex(id, CaseEx::Prepositional, Number::Singular), Ok("лесе"));
+        assert_eq!(morph.inflect(id, sg), Ok("леса"));
+
+        let exceptions = [(CaseEx::Nominative, Number::Plural, "люди")];
+        let info = "мо 3a".parse().unwrap();
+        let id = morph.make_noun_with_exceptions("человек", info, &exceptions).unwrap();
+        assert_eq!(morph.inflect_ex(id, CaseEx::Translative, Number::Plural), Ok("люди"));
+        assert_eq!(morph.inflect(id, sg), Ok("человека"));
+    }
+
+    #[test]
+    fn errors() {
+        let mut morph = Morphology::new();
+
+        assert_eq!(
+            morph.make_noun("stol", "м 1a".parse().unwrap()),
+            Err(ExtractStemError::InvalidLetters),
+        );
+        assert!(morph.nouns.is_empty());
+
+        // Inflection errors are returned, and cached like the forms
+        let id = morph.make_noun("штр", "м 1*a".parse().unwrap()).unwrap();
+        let cell = CaseAndNumber::VALUES[1];
+        for _ in 0..2 {
+            assert_eq!(morph.inflect(id, cell), Err(InflectError::NoVowelInStem));
+        }
+        assert_eq!(morph.inflect(id, CaseAndNumber::VALUES[0]), Ok("штр"));
+    }
+
+    #[test]
+    fn declension_cache() {
+        let mut morph = Morphology::new();
+
+        // The same declension is only parsed once, and invalid ones aren't cached
+        assert_eq!(morph.parse_declension_cached("1a"), Ok("1a".parse().unwrap()));
+        assert_eq!(morph.parse_declension_cached("1a"), Ok("1a".parse().unwrap()));
+        assert_eq!(morph.parse_declension_cached("п 1a"), Ok("п 1a".parse().unwrap()));
+        assert!(morph.parse_declension_cached("1z").is_err());
+        assert_eq!(morph.decl_cache.len(), 2);
+    }
+
+    #[bench]
+    fn bench_inflect_naive(b: &mut Bencher) {
+        b.iter(|| {
+            for (lemma, info) in NOUNS {
+                for cell in CaseAndNumber::VALUES {
+                    black_box(inflect_naive(lemma, info, cell));
+                }
+            }
+        });
+    }
+    #[bench]
+    fn bench_inflect_cached(b: &mut Bencher) {
+        let mut morph = Morphology::new();
+        let ids = NOUNS.map(|(lemma, info)| morph.make_noun(lemma, info.parse().unwrap()).unwrap());
+
+        b.iter(|| {
+            for id in ids {
+                for cell in CaseAndNumber::VALUES {
+                    black_box(morph.inflect(id, cell).unwrap());
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,573 @@
+//! Cardinal and ordinal number words, declined in all cases: "двадцать одного", "в тысяча
+//! девятьсот восемьдесят четвёртом году".
+
+use crate::{
+    InflectionBuffer,
+    categories::{Animacy, Case, Gender, Number},
+    declension::{
+        AdjectiveDeclension, AdjectivePrefix, AdjectiveStemType, DeclInfo, DeclensionFlags,
+        NounDeclension, NounStemType,
+    },
+    phrase::CountForm,
+    stress::{AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, NounStress},
+};
+use alloc::string::String;
+
+// один declines like a pronominal adjective, and agrees in gender. Accusative of the masculine
+// depends on animacy, and is resolved into nominative or genitive before the lookup.
+const ONE: [[&str; 6]; 3] = [
+    ["один", "одного", "одному", "один", "одним", "одном"],
+    ["одно", "одного", "одному", "одно", "одним", "одном"],
+    ["одна", "одной", "одной", "одну", "одной", "одной"],
+];
+// два, три, четыре: nominative (two for два/две), genitive/prepositional, dative, instrumental
+const TWO_TO_FOUR: [[&str; 5]; 3] = [
+    ["два", "две", "двух", "двум", "двумя"],
+    ["три", "три", "трёх", "трём", "тремя"],
+    ["четыре", "четыре", "четырёх", "четырём", "четырьмя"],
+];
+// сорок, девяносто, сто: nominative/accusative, and all other cases
+const FORTY_NINETY_HUNDRED: [(u64, &str, &str); 3] =
+    [(40, "сорок", "сорока"), (90, "девяносто", "девяноста"), (100, "сто", "ста")];
+// двести, триста, четыреста (пятьсот-девятьсот are formed regularly)
+const HUNDREDS_NOMINATIVE: [&str; 3] = ["двести", "триста", "четыреста"];
+
+// Stems of пять-тридцать, that decline like feminine nouns of stem type 8
+const TYPE_8_STEMS: [(u64, &str); 17] = [
+    (5, "пят"),
+    (6, "шест"),
+    (7, "сем"),
+    (8, "восем"),
+    (9, "девят"),
+    (10, "десят"),
+    (11, "одиннадцат"),
+    (12, "двенадцат"),
+    (13, "тринадцат"),
+    (14, "четырнадцат"),
+    (15, "пятнадцат"),
+    (16, "шестнадцат"),
+    (17, "семнадцат"),
+    (18, "восемнадцат"),
+    (19, "девятнадцат"),
+    (20, "двадцат"),
+    (30, "тридцат"),
+];
+
+// Stems of the ordinals, that decline like adjectives of stem type 1 (except третий)
+const ORDINAL_STEMS: [(u64, &str, AdjectiveFullStress); 36] = {
+    use AdjectiveFullStress::{A, B};
+    [
+        (0, "нулев", B),
+        (1, "перв", A),
+        (2, "втор", B),
+        (4, "четвёрт", A),
+        (5, "пят", A),
+        (6, "шест", B),
+        (7, "седьм", B),
+        (8, "восьм", B),
+        (9, "девят", A),
+        (10, "десят", A),
+        (11, "одиннадцат", A),
+        (12, "двенадцат", A),
+        (13, "тринадцат", A),
+        (14, "четырнадцат", A),
+        (15, "пятнадцат", A),
+        (16, "шестнадцат", A),
+        (17, "семнадцат", A),
+        (18, "восемнадцат", A),
+        (19, "девятнадцат", A),
+        (20, "двадцат", A),
+        (30, "тридцат", A),
+        (40, "сороков", B),
+        (50, "пятидесят", A),
+        (60, "шестидесят", A),
+        (70, "семидесят", A),
+        (80, "восьмидесят", A),
+        (90, "девяност", A),
+        (100, "сот", A),
+        (200, "двухсот", A),
+        (300, "трёхсот", A),
+        (400, "четырёхсот", A),
+        (500, "пятисот", A),
+        (600, "шестисот", A),
+        (700, "семисот", A),
+        (800, "восьмисот", A),
+        (900, "девятисот", A),
+    ]
+};
+
+// третий declines like a possessive adjective: третий, третьего, третья, третье, третьи.
+// Accusative of the masculine and plural is resolved into nominative or genitive before the lookup.
+const THIRD: [[&str; 6]; 4] = [
+    ["третий", "третьего", "третьему", "третий", "третьим", "третьем"],
+    ["третье", "третьего", "третьему", "третье", "третьим", "третьем"],
+    ["третья", "третьей", "третьей", "третью", "третьей", "третьей"],
+    ["третьи", "третьих", "третьим", "третьи", "третьими", "третьих"],
+];
+
+// Scale nouns: тысяча declines like a feminine noun of stem type 4, and the others - like
+// masculine nouns of stem type 1. The ordinals are formed with the suffix -н-: тысячный.
+const SCALES: [(&str, Gender, NounStemType); 6] = [
+    ("тысяч", Gender::Feminine, NounStemType::Type4),
+    ("миллион", Gender::Masculine, NounStemType::Type1),
+    ("миллиард", Gender::Masculine, NounStemType::Type1),
+    ("триллион", Gender::Masculine, NounStemType::Type1),
+    ("квадриллион", Gender::Masculine, NounStemType::Type1),
+    ("квинтиллион", Gender::Masculine, NounStemType::Type1),
+];
+
+/// Returns the cardinal number in words, declined in the specified case: "двадцать одна",
+/// "двухсот сорока", "тысяча девятьсот восемьдесят четыре".
+///
+/// The gender is used by один and два (одна, две), and the animacy - by the accusative of один,
+/// and of standalone два, три and четыре (вижу двух студентов, but вижу двадцать два студента).
+/// The round scales are written without один: тысяча, миллион.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, numerals::*};
+///
+/// assert_eq!(cardinal(21, Gender::Feminine, Case::Nominative, Animacy::Inanimate), "двадцать одна");
+/// assert_eq!(cardinal(240, Gender::Masculine, Case::Genitive, Animacy::Inanimate), "двухсот сорока");
+/// assert_eq!(
+///     cardinal(1984, Gender::Masculine, Case::Instrumental, Animacy::Inanimate),
+///     "тысячей девятьюстами восемьюдесятью четырьмя",
+/// );
+/// ```
+pub fn cardinal(n: u64, gender: Gender, case: Case, animacy: Animacy) -> String {
+    let mut dst = String::new();
+
+    if n == 0 {
+        let zero = NounDeclension {
+            stem_type: NounStemType::Type2,
+            flags: DeclensionFlags::empty(),
+            stress: NounStress::B,
+        };
+        push_noun(&mut dst, "нол", zero, Gender::Masculine, case, Number::Singular);
+        return dst;
+    }
+
+    // Two, three and four are animate only when standalone
+    let few_animacy = if matches!(n, 2..=4) { animacy } else { Animacy::Inanimate };
+
+    for (scale, group) in groups(n).rev() {
+        if group == 0 {
+            continue;
+        }
+        if scale == 0 {
+            push_group(&mut dst, group, gender, case, (animacy, few_animacy));
+        } else {
+            push_scale(&mut dst, scale, group, case);
+        }
+    }
+    dst
+}
+
+/// Returns the ordinal number in words, declined in the specified case, gender and number:
+/// "первый", "двадцать третьей", "в тысяча девятьсот восемьдесят четвёртом году". Only the last
+/// word of a compound ordinal is declined, and the preceding ones are cardinals in nominative.
+///
+/// Ordinals of round thousands, millions, etc. are written as one word: двухтысячный, стотысячный.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::{categories::*, declension::DeclInfo, numerals::*};
+///
+/// let info = DeclInfo {
+///     case: Case::Prepositional,
+///     number: Number::Singular,
+///     gender: Gender::Masculine,
+///     animacy: Animacy::Inanimate,
+/// };
+/// assert_eq!(ordinal(1984, info), "тысяча девятьсот восемьдесят четвёртом");
+/// assert_eq!(ordinal(2000, info), "двухтысячном");
+/// ```
+pub fn ordinal(n: u64, info: DeclInfo) -> String {
+    let mut dst = String::new();
+
+    // Find the lowest non-zero group, the one that becomes an ordinal
+    let Some((scale, group)) = groups(n).find(|&(_, group)| group != 0) else {
+        let (_, stem, stress) = ORDINAL_STEMS[0];
+        push_ordinal(&mut dst, stem, stress, info);
+        return dst;
+    };
+
+    // The higher groups are cardinals in nominative
+    let higher = n - group * 1000u64.pow(scale as u32);
+    if higher != 0 {
+        dst.push_str(&cardinal(higher, Gender::Masculine, Case::Nominative, Animacy::Inanimate));
+    }
+
+    if scale > 0 {
+        // Compound ordinals of scales: двухтысячный, двадцатиоднотысячный, стомиллионный
+        let mut stem = String::new();
+        if group != 1 {
+            let count = cardinal(group, Gender::Feminine, Case::Genitive, Animacy::Inanimate);
+            for word in count.split(' ') {
+                // Except for один, сто and девяносто, the count is in genitive
+                stem.push_str(match word {
+                    "одной" => "одно",
+                    "ста" => "сто",
+                    "девяноста" => "девяносто",
+                    _ => word,
+                });
+            }
+        }
+        stem.push_str(SCALES[scale - 1].0);
+        stem.push('н');
+
+        push_space(&mut dst);
+        push_ordinal(&mut dst, &stem, AdjectiveFullStress::A, info);
+        return dst;
+    }
+
+    // Only the last component is ordinal: сто двадцать третий, сто двадцатый, двухсотый
+    let (hundreds, rest) = (group / 100 * 100, group % 100);
+    let last = match rest {
+        0 => hundreds,
+        1..20 => rest,
+        _ if rest % 10 == 0 => rest,
+        _ => rest % 10,
+    };
+    if group != last {
+        push_group(&mut dst, group - last, Gender::Masculine, Case::Nominative, Default::default());
+    }
+
+    push_space(&mut dst);
+    if last == 3 {
+        let row = match info.number {
+            Number::Singular => info.gender as usize,
+            Number::Plural => 3,
+        };
+        let case = match info.case {
+            Case::Accusative if row != Gender::Feminine as usize => info.acc_case(),
+            case => case,
+        };
+        dst.push_str(THIRD[row][case as usize]);
+    } else {
+        let &(_, stem, stress) = ORDINAL_STEMS.iter().find(|x| x.0 == last).unwrap();
+        push_ordinal(&mut dst, stem, stress, info);
+    }
+    dst
+}
+
+// Returns the groups of three digits, from the lowest to the highest, with their scales
+fn groups(n: u64) -> impl DoubleEndedIterator<Item = (usize, u64)> {
+    (0..=SCALES.len()).map(move |scale| (scale, n / 1000u64.pow(scale as u32) % 1000))
+}
+
+// Pushes a separating space, if the buffer isn't empty
+fn push_space(dst: &mut String) {
+    if !dst.is_empty() {
+        dst.push(' ');
+    }
+}
+
+// Pushes a group of 1-999 in the specified case, with the animacies of один and of два-четыре
+fn push_group(
+    dst: &mut String,
+    group: u64,
+    gender: Gender,
+    case: Case,
+    (one_animacy, few_animacy): (Animacy, Animacy),
+) {
+    let (hundreds, tens, units) = (group / 100, group % 100 / 10 * 10, group % 10);
+
+    if hundreds > 0 {
+        push_space(dst);
+        push_hundreds(dst, hundreds, case);
+    }
+    if tens >= 20 || tens == 10 && units == 0 {
+        push_space(dst);
+        push_tens(dst, tens, case);
+    }
+    if tens == 10 && units > 0 {
+        push_space(dst);
+        push_units(dst, 10 + units, gender, case, Animacy::Inanimate);
+    } else if units > 0 {
+        push_space(dst);
+        let animacy = if units == 1 { one_animacy } else { few_animacy };
+        push_units(dst, units, gender, case, animacy);
+    }
+}
+
+// Pushes один-девятнадцать in the specified case
+fn push_units(dst: &mut String, n: u64, gender: Gender, case: Case, animacy: Animacy) {
+    // Accusative of один-четыре is the same as either nominative or genitive,
+    // except for the feminine одну
+    let case = match (case, n) {
+        (Case::Accusative, 1) if gender != Gender::Feminine => {
+            DeclInfo { case, number: Number::Singular, gender, animacy }.acc_case()
+        },
+        (Case::Accusative, 2..=4) => animacy.acc_case(),
+        _ => case,
+    };
+
+    match n {
+        1 => dst.push_str(ONE[gender as usize][case as usize]),
+        2..=4 => {
+            let forms = TWO_TO_FOUR[n as usize - 2];
+            dst.push_str(match case {
+                Case::Nominative | Case::Accusative => {
+                    forms[if gender == Gender::Feminine { 1 } else { 0 }]
+                },
+                Case::Genitive | Case::Prepositional => forms[2],
+                Case::Dative => forms[3],
+                Case::Instrumental => forms[4],
+            });
+        },
+        _ => push_type_8(dst, n, case),
+    }
+}
+
+// Pushes десять-девяносто in the specified case
+fn push_tens(dst: &mut String, n: u64, case: Case) {
+    match n {
+        10 | 20 | 30 => push_type_8(dst, n, case),
+        40 | 90 => push_forty_ninety_hundred(dst, n, case),
+        // пятьдесят-восемьдесят: both parts are declined (пятидесяти, пятьюдесятью)
+        _ => {
+            push_type_8(dst, n / 10, case);
+            match case {
+                Case::Nominative | Case::Accusative => dst.push_str("десят"),
+                _ => push_type_8(dst, 10, case),
+            }
+        },
+    }
+}
+
+// Pushes сто-девятьсот in the specified case
+fn push_hundreds(dst: &mut String, n: u64, case: Case) {
+    match (n, case) {
+        (1, _) => push_forty_ninety_hundred(dst, 100, case),
+        (2..=4, Case::Nominative | Case::Accusative) => {
+            dst.push_str(HUNDREDS_NOMINATIVE[n as usize - 2])
+        },
+        // пятьсот-девятьсот: both parts are declined (пятисот, пятьюстами)
+        _ => {
+            match n {
+                2..=4 => push_units(dst, n, Gender::Masculine, case, Animacy::Inanimate),
+                _ => push_type_8(dst, n, case),
+            }
+            dst.push_str(match case {
+                Case::Nominative | Case::Accusative | Case::Genitive => "сот",
+                Case::Dative => "стам",
+                Case::Instrumental => "стами",
+                Case::Prepositional => "стах",
+            });
+        },
+    }
+}
+
+fn push_forty_ninety_hundred(dst: &mut String, n: u64, case: Case) {
+    let &(_, direct, oblique) = FORTY_NINETY_HUNDRED.iter().find(|x| x.0 == n).unwrap();
+    dst.push_str(if matches!(case, Case::Nominative | Case::Accusative) {
+        direct
+    } else {
+        oblique
+    });
+}
+
+fn push_type_8(dst: &mut String, n: u64, case: Case) {
+    let &(_, mut stem) = TYPE_8_STEMS.iter().find(|x| x.0 == n).unwrap();
+
+    // восемь has an irregular alternation of 'е' with 'ь': восьми, but восемью
+    if n == 8 && !matches!(case, Case::Nominative | Case::Accusative | Case::Instrumental) {
+        stem = "восьм";
+    }
+
+    let decl = NounDeclension {
+        stem_type: NounStemType::Type8,
+        flags: DeclensionFlags::empty(),
+        stress: NounStress::A,
+    };
+    push_noun(dst, stem, decl, Gender::Feminine, case, Number::Singular);
+}
+
+// Pushes a group of a scale, along with the scale noun: две тысячи, пяти миллионов
+fn push_scale(dst: &mut String, scale: usize, group: u64, case: Case) {
+    let (stem, gender, stem_type) = SCALES[scale - 1];
+
+    // Round scales are written without один: тысяча, миллион
+    if group != 1 {
+        push_group(dst, group, gender, case, Default::default());
+    }
+
+    // After один, the noun agrees with it in case (тысячу, двадцать одной тысячей). Otherwise,
+    // in nominative and accusative the noun takes the counted form (две тысячи, пять тысяч),
+    // and in other cases it's plural (двум тысячам, пяти тысячам)
+    let count = CountForm::of(group);
+    let (case, number) = match case {
+        _ if count == CountForm::One => (case, Number::Singular),
+        Case::Nominative | Case::Accusative => count.noun_form(),
+        _ => (case, Number::Plural),
+    };
+
+    let decl = NounDeclension { stem_type, flags: DeclensionFlags::empty(), stress: NounStress::A };
+    push_space(dst);
+    push_noun(dst, stem, decl, gender, case, number);
+}
+
+fn push_noun(
+    dst: &mut String,
+    stem: &str,
+    decl: NounDeclension,
+    gender: Gender,
+    case: Case,
+    number: Number,
+) {
+    let info = DeclInfo { case, number, gender, animacy: Animacy::Inanimate };
+    // Numeral declensions don't have any flags, and never fail
+    // SAFETY: The stems of numerals are all Cyrillic
+    let mut buf = unsafe { InflectionBuffer::from_stem_unchecked(stem) };
+    decl.inflect_unchecked(info, &mut buf);
+    dst.push_str(buf.as_str());
+}
+
+fn push_ordinal(dst: &mut String, stem: &str, stress: AdjectiveFullStress, info: DeclInfo) {
+    let decl = AdjectiveDeclension {
+        stem_type: AdjectiveStemType::Type1,
+        flags: DeclensionFlags::empty(),
+        stress: AdjectiveStress { full: stress, short: AdjectiveShortStress::A },
+        is_reflexive: false,
+        prefix: AdjectivePrefix::Numeral,
+    };
+    // SAFETY: The stems of numerals are all Cyrillic
+    let mut buf = unsafe { InflectionBuffer::from_stem_unchecked(stem) };
+    decl.inflect(info, &mut buf);
+    dst.push_str(buf.as_str());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Animacy::*;
+    use Gender::*;
+
+    fn assert_cardinals(n: u64, gender: Gender, animacy: Animacy, expected: [&str; 6]) {
+        let forms = Case::VALUES.map(|case| cardinal(n, gender, case, animacy));
+        assert_eq!(forms, expected, "{n} {gender:?} {animacy:?}");
+    }
+    fn assert_ordinals(
+        n: u64,
+        number: Number,
+        gender: Gender,
+        animacy: Animacy,
+        expected: [&str; 6],
+    ) {
+        let forms = Case::VALUES.map(|case| ordinal(n, DeclInfo { case, number, gender, animacy }));
+        assert_eq!(forms, expected, "{n} {number:?} {gender:?} {animacy:?}");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn cardinal_units() {
+        assert_cardinals(0, Masculine, Inanimate, ["ноль", "ноля", "нолю", "ноль", "нолём", "ноле"]);
+        assert_cardinals(1, Masculine, Inanimate, ["один", "одного", "одному", "один", "одним", "одном"]);
+        assert_cardinals(1, Masculine, Animate, ["один", "одного", "одному", "одного", "одним", "одном"]);
+        assert_cardinals(1, Neuter, Animate, ["одно", "одного", "одному", "одно", "одним", "одном"]);
+        assert_cardinals(1, Feminine, Animate, ["одна", "одной", "одной", "одну", "одной", "одной"]);
+        assert_cardinals(2, Masculine, Inanimate, ["два", "двух", "двум", "два", "двумя", "двух"]);
+        assert_cardinals(2, Feminine, Animate, ["две", "двух", "двум", "двух", "двумя", "двух"]);
+        assert_cardinals(3, Masculine, Animate, ["три", "трёх", "трём", "трёх", "тремя", "трёх"]);
+        assert_cardinals(4, Neuter, Inanimate, ["четыре", "четырёх", "четырём", "четыре", "четырьмя", "четырёх"]);
+        assert_cardinals(8, Masculine, Animate, ["восемь", "восьми", "восьми", "восемь", "восемью", "восьми"]);
+        assert_cardinals(11, Masculine, Animate, [
+            "одиннадцать", "одиннадцати", "одиннадцати", "одиннадцать", "одиннадцатью", "одиннадцати",
+        ]);
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn cardinal_compound() {
+        assert_cardinals(40, Masculine, Inanimate, ["сорок", "сорока", "сорока", "сорок", "сорока", "сорока"]);
+        assert_cardinals(90, Masculine, Inanimate, [
+            "девяносто", "девяноста", "девяноста", "девяносто", "девяноста", "девяноста",
+        ]);
+        assert_cardinals(100, Masculine, Inanimate, ["сто", "ста", "ста", "сто", "ста", "ста"]);
+        assert_cardinals(50, Masculine, Inanimate, [
+            "пятьдесят", "пятидесяти", "пятидесяти", "пятьдесят", "пятьюдесятью", "пятидесяти",
+        ]);
+        assert_cardinals(80, Masculine, Inanimate, [
+            "восемьдесят", "восьмидесяти", "восьмидесяти", "восемьдесят", "восемьюдесятью", "восьмидесяти",
+        ]);
+        assert_cardinals(200, Masculine, Inanimate, ["двести", "двухсот", "двумстам", "двести", "двумястами", "двухстах"]);
+        assert_cardinals(300, Masculine, Inanimate, ["триста", "трёхсот", "трёмстам", "триста", "тремястами", "трёхстах"]);
+        assert_cardinals(500, Masculine, Inanimate, ["пятьсот", "пятисот", "пятистам", "пятьсот", "пятьюстами", "пятистах"]);
+
+        // Only standalone два-четыре are animate, but один is animate in compounds as well
+        assert_eq!(cardinal(22, Masculine, Case::Accusative, Animate), "двадцать два");
+        assert_eq!(cardinal(21, Masculine, Case::Accusative, Animate), "двадцать одного");
+        assert_eq!(cardinal(21, Feminine, Case::Dative, Animate), "двадцати одной");
+        assert_eq!(cardinal(113, Masculine, Case::Genitive, Inanimate), "ста тринадцати");
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn cardinal_scales() {
+        assert_cardinals(1000, Masculine, Inanimate, ["тысяча", "тысячи", "тысяче", "тысячу", "тысячей", "тысяче"]);
+        assert_cardinals(1984, Masculine, Animate, [
+            "тысяча девятьсот восемьдесят четыре",
+            "тысячи девятисот восьмидесяти четырёх",
+            "тысяче девятистам восьмидесяти четырём",
+            "тысячу девятьсот восемьдесят четыре",
+            "тысячей девятьюстами восемьюдесятью четырьмя",
+            "тысяче девятистах восьмидесяти четырёх",
+        ]);
+        assert_eq!(cardinal(2000, Masculine, Case::Nominative, Inanimate), "две тысячи");
+        assert_eq!(cardinal(2000, Masculine, Case::Dative, Inanimate), "двум тысячам");
+        assert_eq!(cardinal(5000, Masculine, Case::Nominative, Inanimate), "пять тысяч");
+        assert_eq!(cardinal(21000, Masculine, Case::Instrumental, Inanimate), "двадцатью одной тысячей");
+        assert_eq!(cardinal(3_000_001, Feminine, Case::Nominative, Inanimate), "три миллиона одна");
+        assert_eq!(cardinal(1_000_000_000, Masculine, Case::Genitive, Inanimate), "миллиарда");
+        assert_eq!(
+            cardinal(u64::MAX, Masculine, Case::Nominative, Inanimate),
+            "восемнадцать квинтиллионов четыреста сорок шесть квадриллионов семьсот сорок четыре \
+             триллиона семьдесят три миллиарда семьсот девять миллионов пятьсот пятьдесят одна \
+             тысяча шестьсот пятнадцать",
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn ordinals_declined() {
+        use Number::*;
+
+        assert_ordinals(0, Singular, Masculine, Inanimate, ["нулевой", "нулевого", "нулевому", "нулевой", "нулевым", "нулевом"]);
+        assert_ordinals(1, Singular, Masculine, Inanimate, ["первый", "первого", "первому", "первый", "первым", "первом"]);
+        assert_ordinals(2, Singular, Feminine, Inanimate, ["вторая", "второй", "второй", "вторую", "второй", "второй"]);
+        assert_ordinals(3, Singular, Masculine, Animate, ["третий", "третьего", "третьему", "третьего", "третьим", "третьем"]);
+        assert_ordinals(3, Singular, Neuter, Animate, ["третье", "третьего", "третьему", "третье", "третьим", "третьем"]);
+        assert_ordinals(3, Singular, Feminine, Animate, ["третья", "третьей", "третьей", "третью", "третьей", "третьей"]);
+        assert_ordinals(3, Plural, Masculine, Inanimate, ["третьи", "третьих", "третьим", "третьи", "третьими", "третьих"]);
+        assert_ordinals(8, Singular, Masculine, Inanimate, ["восьмой", "восьмого", "восьмому", "восьмой", "восьмым", "восьмом"]);
+        assert_ordinals(40, Singular, Neuter, Inanimate, [
+            "сороковое", "сорокового", "сороковому", "сороковое", "сороковым", "сороковом",
+        ]);
+        assert_ordinals(90, Plural, Masculine, Animate, [
+            "девяностые", "девяностых", "девяностым", "девяностых", "девяностыми", "девяностых",
+        ]);
+    }
+
+    #[test]
+    fn ordinals_compound() {
+        let assert = |n: u64, case: Case, gender: Gender, expected: &str| {
+            let info = DeclInfo { case, number: Number::Singular, gender, animacy: Inanimate };
+            assert_eq!(ordinal(n, info), expected);
+        };
+
+        assert(21, Case::Genitive, Masculine, "двадцать первого");
+        assert(23, Case::Dative, Feminine, "двадцать третьей");
+        assert(100, Case::Nominative, Masculine, "сотый");
+        assert(300, Case::Nominative, Masculine, "трёхсотый");
+        assert(120, Case::Nominative, Masculine, "сто двадцатый");
+        assert(1000, Case::Nominative, Masculine, "тысячный");
+        assert(1984, Case::Prepositional, Masculine, "тысяча девятьсот восемьдесят четвёртом");
+        assert(2000, Case::Genitive, Masculine, "двухтысячного");
+        assert(2015, Case::Nominative, Masculine, "две тысячи пятнадцатый");
+        assert(21000, Case::Nominative, Masculine, "двадцатиоднотысячный");
+        assert(100_000, Case::Nominative, Masculine, "стотысячный");
+        assert(3_000_000, Case::Nominative, Masculine, "трёхмиллионный");
+        assert(1_002_000, Case::Nominative, Masculine, "миллион двухтысячный");
+    }
+}
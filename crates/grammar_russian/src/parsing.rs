@@ -0,0 +1,230 @@
+//! Parsing of declensions, stresses and genders embedded in custom notations.
+//!
+//! The `FromStr` implementations require the entire string to be the notation. [`Parser`] reads
+//! the notations one after another from a longer string, so that they can be combined with the
+//! separators and the fields of other formats, such as a dictionary's record lines.
+//!
+//! # Examples
+//! ```
+//! use grammar_russian::{categories::*, declension::*, parsing::Parser};
+//!
+//! let mut parser = Parser::new("мо 3*c①; ж 8e");
+//! let mut entries = vec![];
+//! loop {
+//!     let gender = GenderExAnimacy::parse_partial(&mut parser).unwrap();
+//!     assert!(parser.skip(' '));
+//!     entries.push((gender, NounDeclension::parse_partial(&mut parser).unwrap()));
+//!
+//!     if !parser.skip_str("; ") {
+//!         break;
+//!     }
+//! }
+//! assert!(parser.is_finished());
+//! assert_eq!(entries[1], (GenderExAnimacy::FeminineInanimate, "8e".parse().unwrap()));
+//! ```
+
+use crate::{
+    categories::{Animacy, Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Number, Person},
+    declension::{
+        AdjectiveDeclension, Declension, DeclensionFlags, MaybeZeroDeclension, NounDeclension,
+        ParseDeclensionError, PronounDeclension,
+    },
+    stress::{AnyDualStress, AnyStress},
+    util::{PartialParse, UnsafeParser},
+};
+
+/// A parser over a string, that reads the crate's notations from its start, and advances past
+/// them. See the [module documentation](self).
+///
+/// The parser's position is always at a char boundary of the string. The `parse_partial` methods
+/// of the parsed types leave the parser right after the notation, if it was parsed successfully,
+/// and don't advance it at all on an error.
+#[derive(Clone, Copy)]
+pub struct Parser<'a> {
+    source: &'a str,
+    inner: UnsafeParser<'a>,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a parser at the start of the string.
+    pub const fn new(s: &'a str) -> Self {
+        Self { source: s, inner: UnsafeParser::new(s) }
+    }
+
+    /// Returns the number of bytes read from the start of the string.
+    pub const fn position(&self) -> usize {
+        self.source.len() - self.inner.remaining_len()
+    }
+    /// Returns the part of the string, that hasn't been read yet.
+    pub fn remaining(&self) -> &'a str {
+        &self.source[self.position()..]
+    }
+    /// Returns `true`, if the entire string has been read.
+    pub const fn is_finished(&self) -> bool {
+        self.inner.finished()
+    }
+
+    /// Returns the next character, without advancing the parser.
+    pub fn peek(&self) -> Option<char> {
+        self.remaining().chars().next()
+    }
+    /// Reads the next character, and advances the parser past it.
+    pub fn read_one(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.inner.forward(ch.len_utf8());
+        Some(ch)
+    }
+    /// Advances the parser past the character, if the remaining string starts with it.
+    pub const fn skip(&mut self, ch: char) -> bool {
+        self.inner.skip(ch)
+    }
+    /// Advances the parser past the string, if the remaining string starts with it.
+    pub const fn skip_str(&mut self, s: &str) -> bool {
+        self.inner.skip_str(s)
+    }
+    /// Advances the parser by the specified number of bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position after advancing is out of bounds, or isn't at a char boundary.
+    pub fn forward(&mut self, dist: usize) {
+        assert!(
+            self.remaining().is_char_boundary(dist),
+            "can't advance the parser by {dist} bytes: not a char boundary",
+        );
+        self.inner.forward(dist);
+    }
+
+    // Runs the internal parser, and restores the position on an error
+    fn run<T, E>(
+        &mut self,
+        parse: impl FnOnce(&mut UnsafeParser<'a>) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let start = self.inner;
+        let result = parse(&mut self.inner);
+        if result.is_err() {
+            self.inner = start;
+        }
+        debug_assert!(self.source.is_char_boundary(self.position()));
+        result
+    }
+}
+
+impl core::fmt::Debug for Parser<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("Parser")
+            .field("position", &self.position())
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
+
+macro_rules! impl_parse_partial {
+    ($($t:ty),+ $(,)?) => ($(
+        impl $t {
+            /// Parses the notation at the parser's position, and advances the parser past it.
+            /// Unlike `FromStr`, the notation may be followed by anything. On an error, the
+            /// parser isn't advanced.
+            pub fn parse_partial(
+                parser: &mut Parser,
+            ) -> Result<Self, <Self as core::str::FromStr>::Err> {
+                parser.run(<Self as PartialParse>::partial_parse)
+            }
+        }
+    )+);
+}
+impl_parse_partial! {
+    AnyStress, AnyDualStress,
+    NounDeclension, PronounDeclension, AdjectiveDeclension, Declension, MaybeZeroDeclension,
+    GenderEx, Gender, GenderExAnimacy, GenderAnimacy, Animacy, Number, Person,
+}
+
+impl DeclensionFlags {
+    /// Parses the leading flags (° and *) at the parser's position, and advances the parser past
+    /// them. Returns empty flags, if there are none.
+    pub fn parse_leading_partial(parser: &mut Parser) -> Self {
+        let mut flags = Self::empty();
+        Self::partial_parse_leading(&mut flags, &mut parser.inner);
+        flags
+    }
+    /// Parses the trailing flags (①-⑤ and ", ё") at the parser's position, and advances the parser
+    /// past them. Returns empty flags, if there are none, and an error on a repeated flag.
+    pub fn parse_trailing_partial(parser: &mut Parser) -> Result<Self, ParseDeclensionError> {
+        let mut flags = Self::empty();
+        parser.run(|inner| Self::partial_parse_trailing(&mut flags, inner))?;
+        Ok(flags)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{categories::GenderExAnimacy, stress::ParseStressError};
+    use std::string::ToString;
+
+    #[test]
+    fn concatenated() {
+        let mut parser = Parser::new("1a;2b;жо 3*c①");
+        assert_eq!(Declension::parse_partial(&mut parser), Ok("1a".parse().unwrap()));
+        assert!(parser.skip(';'));
+        assert_eq!(Declension::parse_partial(&mut parser), Ok("2b".parse().unwrap()));
+        assert!(parser.skip(';'));
+        assert_eq!(parser.remaining(), "жо 3*c①");
+
+        assert_eq!(
+            GenderExAnimacy::parse_partial(&mut parser),
+            Ok(GenderExAnimacy::FeminineAnimate)
+        );
+        assert!(parser.skip(' '));
+        assert_eq!(NounDeclension::parse_partial(&mut parser), Ok("3*c①".parse().unwrap()));
+        assert!(parser.is_finished());
+        assert_eq!(parser.position(), "1a;2b;жо 3*c①".len());
+    }
+
+    #[test]
+    fn components() {
+        // The declension's components can be parsed separately
+        let mut parser = Parser::new("°*a/c″①②, ё|");
+        let leading = DeclensionFlags::parse_leading_partial(&mut parser);
+        assert_eq!(leading, DeclensionFlags::CIRCLE | DeclensionFlags::STAR);
+        assert_eq!(AnyDualStress::parse_partial(&mut parser), Ok("a/c″".parse().unwrap()));
+        let trailing = DeclensionFlags::parse_trailing_partial(&mut parser).unwrap();
+        assert_eq!(trailing.to_string(), "①②, ё");
+        assert_eq!(parser.read_one(), Some('|'));
+        assert_eq!(parser.read_one(), None);
+
+        // Missing flags are parsed as empty
+        let mut parser = Parser::new("b");
+        assert_eq!(DeclensionFlags::parse_leading_partial(&mut parser), DeclensionFlags::empty());
+        assert_eq!(parser.peek(), Some('b'));
+    }
+
+    #[test]
+    fn errors() {
+        // The parser isn't advanced on an error
+        let mut parser = Parser::new("мс 9a");
+        assert_eq!(
+            Declension::parse_partial(&mut parser),
+            Err(ParseDeclensionError::InvalidStemType),
+        );
+        assert_eq!(parser.position(), 0);
+
+        let mut parser = Parser::new("a/x");
+        assert_eq!(AnyDualStress::parse_partial(&mut parser), Err(ParseStressError::InvalidLetter));
+        assert_eq!(parser.remaining(), "a/x");
+
+        let mut parser = Parser::new("①②①");
+        assert_eq!(
+            DeclensionFlags::parse_trailing_partial(&mut parser),
+            Err(ParseDeclensionError::InvalidFlags),
+        );
+        assert_eq!(parser.position(), 0);
+
+        // Advancing checks the bounds and the char boundaries
+        let mut parser = Parser::new("жо");
+        parser.forward(2);
+        assert_eq!(parser.remaining(), "о");
+        assert!(std::panic::catch_unwind(move || parser.forward(1)).is_err());
+        assert!(std::panic::catch_unwind(move || Parser::new("a").forward(2)).is_err());
+    }
+}
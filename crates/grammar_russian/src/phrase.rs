@@ -0,0 +1,353 @@
+use crate::{
+    Letter,
+    categories::{Case, CaseEx, Gender, Number},
+    declension::{Adjective, InflectError, Noun},
+    letters,
+};
+use alloc::{format, string::String};
+
+/// The form a noun takes after a cardinal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountForm {
+    /// Nominative singular: 1, 21, 31, …, 101 (but not 11). E.g. один стол, двадцать один стол.
+    One,
+    /// Genitive singular: 2-4, 22-24, … (but not 12-14). E.g. два стола, двадцать две книги.
+    Few,
+    /// Genitive plural: 0, 5-20, 25-30, … E.g. пять столов, одиннадцать книг.
+    Many,
+}
+
+impl CountForm {
+    pub const fn of(n: u64) -> Self {
+        // 11-14 are always Many, regardless of their last digit
+        if matches!(n % 100, 11..=14) {
+            return Self::Many;
+        }
+        match n % 10 {
+            1 => Self::One,
+            2..=4 => Self::Few,
+            _ => Self::Many,
+        }
+    }
+
+    /// Returns the case and number of a noun counted by this form.
+    pub const fn noun_form(self) -> (Case, Number) {
+        match self {
+            Self::One => (Case::Nominative, Number::Singular),
+            Self::Few => (Case::Genitive, Number::Singular),
+            Self::Many => (Case::Genitive, Number::Plural),
+        }
+    }
+    /// Returns the case and number of an adjective agreeing with a counted noun of the specified
+    /// gender. After 2-4, adjectives are in genitive plural with masculine and neuter nouns
+    /// (два больших стола), but in nominative plural with feminine ones (две большие книги).
+    pub const fn adjective_form(self, gender: Gender) -> (Case, Number) {
+        match self {
+            Self::One => (Case::Nominative, Number::Singular),
+            Self::Few if matches!(gender, Gender::Feminine) => (Case::Nominative, Number::Plural),
+            Self::Few | Self::Many => (Case::Genitive, Number::Plural),
+        }
+    }
+}
+
+/// Formats a noun phrase quantified by a cardinal number, e.g. "2 больших стола", "22 новые
+/// книги" or "5 больших окон", with both the adjective and the noun in the right form.
+///
+/// Common gender nouns (сирота) are treated as feminine, the same way as in noun inflection.
+/// The adjective takes the number of singulare and plurale tantum nouns, like the noun itself
+/// (1 новые ножницы).
+///
+/// Returns an error, if the adjective or the noun can't be inflected.
+pub fn quantified_phrase(n: u64, adj: &Adjective, noun: &Noun) -> Result<String, InflectError> {
+    let count = CountForm::of(n);
+    let gender = noun.info.gender.normalize();
+
+    let (adj_case, adj_number) = count.adjective_form(gender);
+    let adj_number = noun.info.tantum.unwrap_or(adj_number);
+    let animacy = noun.info.animacy;
+    let (noun_case, noun_number) = count.noun_form();
+
+    let adj = adj.inflect_to_string(CaseEx::from(adj_case), gender, adj_number, animacy)?;
+    let noun = noun.inflect_to_string(CaseEx::from(noun_case), noun_number)?;
+    Ok(format!("{n} {adj} {noun}"))
+}
+
+/// A preposition that has different forms depending on the following word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasePreposition {
+    /// о, об, обо: о доме, об этом, обо всём.
+    O,
+    /// в, во: в доме, во дворе, во мне.
+    V,
+    /// с, со: с окна, со стола, со мной.
+    S,
+    /// к, ко: к дому, ко дну, ко мне.
+    K,
+}
+
+impl BasePreposition {
+    /// Returns the base form of the preposition.
+    pub const fn base(self) -> &'static str {
+        match self {
+            Self::O => "о",
+            Self::V => "в",
+            Self::S => "с",
+            Self::K => "к",
+        }
+    }
+    /// Returns the extended form of the preposition, used before certain consonant clusters.
+    pub const fn extended(self) -> &'static str {
+        match self {
+            Self::O => "обо",
+            Self::V => "во",
+            Self::S => "со",
+            Self::K => "ко",
+        }
+    }
+}
+
+// Beginnings of words that require the extended form (во многом, со всеми, ко второму)
+const VO_PREFIXES: [&str; 2] = ["мн", "двор"];
+const SO_PREFIXES: [&str; 4] = ["мн", "вс", "вт", "двор"];
+const KO_PREFIXES: [&str; 4] = ["мн", "вс", "вт", "двор"];
+
+// Words with a fleeting vowel in the root, that require the extended form (во рту, ко дну)
+const OBO_WORDS: [&str; 8] = ["мне", "всём", "всем", "всех", "всё", "всю", "всей", "что"];
+const VO_WORDS: [&str; 9] = ["сне", "снах", "рту", "льду", "лбу", "ржи", "мгле", "тьме", "имя"];
+const SO_WORDS: [&str; 8] = ["льда", "льдом", "лба", "лбом", "рта", "ртом", "дна", "ржи"];
+const KO_WORDS: [&str; 5] = ["дну", "льду", "лбу", "рту", "сну"];
+
+/// Returns the form of the preposition that should be used before the specified word.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::phrase::{BasePreposition, preposition_form};
+///
+/// assert_eq!(preposition_form(BasePreposition::O, "доме"), "о");
+/// assert_eq!(preposition_form(BasePreposition::O, "этом"), "об");
+/// assert_eq!(preposition_form(BasePreposition::O, "всех"), "обо");
+/// assert_eq!(preposition_form(BasePreposition::V, "дворе"), "во");
+/// assert_eq!(preposition_form(BasePreposition::S, "стола"), "со");
+/// assert_eq!(preposition_form(BasePreposition::K, "мне"), "ко");
+/// ```
+pub fn preposition_form(prep: BasePreposition, following: &str) -> &'static str {
+    use letters as lt;
+
+    let word = following.to_lowercase();
+    let mut chars = word.chars().map(|ch| matches!(ch, 'а'..='я' | 'ё').then(|| Letter::from(ch)));
+    let (first, second) = (chars.next().flatten(), chars.next().flatten());
+    let before_consonant = second.is_some_and(Letter::is_consonant);
+
+    let extended = match prep {
+        BasePreposition::O => {
+            // Before non-iotated vowels, the form 'об' is used (об этом, but о ёжике)
+            let before_vowel =
+                first.is_some_and(|x| matches!(x, lt::а | lt::и | lt::о | lt::у | lt::ы | lt::э));
+            if before_vowel {
+                return "об";
+            }
+            OBO_WORDS.contains(&word.as_str())
+        },
+        BasePreposition::V => {
+            matches!(first, Some(lt::в | lt::ф)) && before_consonant
+                || VO_PREFIXES.iter().any(|x| word.starts_with(x))
+                || VO_WORDS.contains(&word.as_str())
+        },
+        BasePreposition::S => {
+            matches!(first, Some(lt::с | lt::з | lt::ш | lt::ж | lt::щ)) && before_consonant
+                || SO_PREFIXES.iter().any(|x| word.starts_with(x))
+                || SO_WORDS.contains(&word.as_str())
+        },
+        BasePreposition::K => {
+            KO_PREFIXES.iter().any(|x| word.starts_with(x)) || KO_WORDS.contains(&word.as_str())
+        },
+    };
+
+    if extended { prep.extended() } else { prep.base() }
+}
+
+/// Formats a noun with a preposition in front of it, e.g. "во дворе" or "со стола", choosing
+/// the right form of the preposition for the inflected noun.
+///
+/// Returns an error, if the noun can't be inflected.
+pub fn prepositional_phrase(
+    prep: BasePreposition,
+    noun: &Noun,
+    case: CaseEx,
+    number: Number,
+) -> Result<String, InflectError> {
+    let noun = noun.inflect_to_string(case, number)?;
+    Ok(format!("{} {noun}", preposition_form(prep, &noun)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{Animacy, GenderEx},
+        declension::{AdjectiveInfo, NounInfo, ShortFormAvailability},
+    };
+
+    fn noun<'a>(stem: &'a str, decl: &str, gender: GenderEx) -> Noun<'a> {
+        let info = NounInfo {
+            declension: Some(decl.parse().unwrap()),
+            declension_gender: gender.normalize(),
+            gender,
+            animacy: Animacy::Inanimate,
+            tantum: None,
+            has_partitive: false,
+            has_locative: false,
+        };
+        Noun::new(stem, info)
+    }
+    fn adj<'a>(stem: &'a str, decl: &str) -> Adjective<'a> {
+        let info = AdjectiveInfo {
+            declension: Some(decl.parse().unwrap()),
+            short_forms: ShortFormAvailability::Available,
+        };
+        Adjective { stem, info }
+    }
+
+    #[test]
+    fn count_forms() {
+        use CountForm::*;
+
+        let expected = [Many, One, Few, Few, Few, Many, Many, Many, Many, Many];
+        for (n, form) in expected.into_iter().enumerate() {
+            assert_eq!(CountForm::of(n as u64), form);
+        }
+        assert_eq!(CountForm::of(11), Many);
+        assert_eq!(CountForm::of(12), Many);
+        assert_eq!(CountForm::of(14), Many);
+        assert_eq!(CountForm::of(21), One);
+        assert_eq!(CountForm::of(22), Few);
+        assert_eq!(CountForm::of(111), Many);
+        assert_eq!(CountForm::of(112), Many);
+        assert_eq!(CountForm::of(121), One);
+        assert_eq!(CountForm::of(1004), Few);
+    }
+
+    #[test]
+    fn quantified() {
+        let table = noun("стол", "1b", GenderEx::Masculine);
+        let book = noun("книг", "3a", GenderEx::Feminine);
+        let window = noun("окн", "1*d", GenderEx::Neuter);
+        let big = adj("больш", "п 4b");
+        let new = adj("нов", "п 1a");
+
+        let assert = |n: u64, adj: &Adjective, noun: &Noun, expected: &str| {
+            assert_eq!(quantified_phrase(n, adj, noun).unwrap(), expected);
+        };
+
+        assert(1, &big, &table, "1 большой стол");
+        assert(2, &big, &table, "2 больших стола");
+        assert(3, &big, &table, "3 больших стола");
+        assert(5, &big, &table, "5 больших столов");
+        assert(21, &big, &table, "21 большой стол");
+        assert(22, &big, &table, "22 больших стола");
+        assert(12, &big, &table, "12 больших столов");
+
+        assert(1, &new, &book, "1 новая книга");
+        assert(2, &new, &book, "2 новые книги");
+        assert(3, &new, &book, "3 новые книги");
+        assert(5, &new, &book, "5 новых книг");
+        assert(21, &new, &book, "21 новая книга");
+        assert(22, &new, &book, "22 новые книги");
+        assert(13, &new, &book, "13 новых книг");
+
+        assert(1, &big, &window, "1 большое окно");
+        assert(2, &big, &window, "2 больших окна");
+        assert(3, &big, &window, "3 больших окна");
+        assert(5, &big, &window, "5 больших окон");
+        assert(21, &big, &window, "21 большое окно");
+        assert(22, &big, &window, "22 больших окна");
+        assert(11, &big, &window, "11 больших окон");
+
+        // The adjective agrees with the tantum number of the noun
+        let mut scissors = noun("ножниц", "5a", GenderEx::Feminine);
+        scissors.info.tantum = Some(Number::Plural);
+        assert(1, &new, &scissors, "1 новые ножницы");
+        assert(5, &new, &scissors, "5 новых ножниц");
+
+        // Nouns that can't be inflected return an error instead of panicking
+        let err = Err(InflectError::NoVowelInStem);
+        assert_eq!(quantified_phrase(5, &new, &noun("штр", "1*a", GenderEx::Masculine)), err);
+    }
+
+    #[test]
+    fn preposition_forms() {
+        use BasePreposition::*;
+
+        let assert = |prep: BasePreposition, word: &str, expected: &str| {
+            assert_eq!(format!("{} {word}", preposition_form(prep, word)), expected);
+        };
+
+        assert(O, "доме", "о доме");
+        assert(O, "этом", "об этом");
+        assert(O, "игре", "об игре");
+        assert(O, "окне", "об окне");
+        assert(O, "ёжике", "о ёжике");
+        assert(O, "ежах", "о ежах");
+        assert(O, "юге", "о юге");
+        assert(O, "всех", "обо всех");
+        assert(O, "всём", "обо всём");
+        assert(O, "мне", "обо мне");
+        assert(O, "Этом", "об Этом");
+
+        assert(V, "доме", "в доме");
+        assert(V, "дворе", "во дворе");
+        assert(V, "двух", "в двух");
+        assert(V, "вторник", "во вторник");
+        assert(V, "всём", "во всём");
+        assert(V, "Франции", "во Франции");
+        assert(V, "воде", "в воде");
+        assert(V, "мне", "во мне");
+        assert(V, "многом", "во многом");
+        assert(V, "сне", "во сне");
+        assert(V, "снегу", "в снегу");
+        assert(V, "рту", "во рту");
+        assert(V, "окне", "в окне");
+
+        assert(S, "стола", "со стола");
+        assert(S, "окна", "с окна");
+        assert(S, "сыном", "с сыном");
+        assert(S, "звоном", "со звоном");
+        assert(S, "шкафа", "со шкафа");
+        assert(S, "мной", "со мной");
+        assert(S, "всеми", "со всеми");
+        assert(S, "вторника", "со вторника");
+        assert(S, "водой", "с водой");
+        assert(S, "льда", "со льда");
+
+        assert(K, "дому", "к дому");
+        assert(K, "дну", "ко дну");
+        assert(K, "мне", "ко мне");
+        assert(K, "всему", "ко всему");
+        assert(K, "второму", "ко второму");
+        assert(K, "двору", "ко двору");
+        assert(K, "окну", "к окну");
+        assert(K, "воде", "к воде");
+    }
+
+    #[test]
+    fn prepositional() {
+        let table = noun("стол", "1b", GenderEx::Masculine);
+        let window = noun("окн", "1*d", GenderEx::Neuter);
+        let assert = |prep: BasePreposition, noun: &Noun, case: Case, expected: &str| {
+            let phrase = prepositional_phrase(prep, noun, case.into(), Number::Singular);
+            assert_eq!(phrase.unwrap(), expected);
+        };
+
+        assert(BasePreposition::S, &table, Case::Genitive, "со стола");
+        assert(BasePreposition::S, &window, Case::Genitive, "с окна");
+        assert(BasePreposition::O, &window, Case::Prepositional, "об окне");
+        assert(BasePreposition::K, &table, Case::Dative, "к столу");
+        assert(BasePreposition::V, &window, Case::Accusative, "в окно");
+
+        // Nouns that can't be inflected return an error instead of panicking
+        let noun = noun("штр", "1*a", GenderEx::Masculine);
+        let phrase =
+            prepositional_phrase(BasePreposition::O, &noun, CaseEx::Prepositional, Number::Plural);
+        assert_eq!(phrase, Err(InflectError::NoVowelInStem));
+    }
+}
@@ -0,0 +1,214 @@
+//! [`Serialize`] and [`Deserialize`] implementations, enabled by the `serde` feature.
+//!
+//! Declensions, flags, stresses and noun infos are serialized as strings in the same compact
+//! notation, that they're formatted in ("жо 7*b′①", "п 1*a/c′", "b′/c″"), and are deserialized
+//! by their [`FromStr`] implementations, accepting the same strings. Grammemes are serialized as
+//! comma-separated abbreviations ("nom,sg,masc,inan"). Genders are serialized as Zaliznyak's
+//! abbreviations (м, с, ж, мо-жо). Cases, animacy and numbers derive the impls, and
+//! are serialized as the variants' names.
+
+use crate::{
+    categories::{Gender, GenderAnimacy, GenderEx, GenderExAnimacy, Grammemes},
+    declension::{
+        AdjectiveDeclension, Declension, DeclensionFlags, MaybeZeroDeclension, NounDeclension,
+        NounInfo, PronounDeclension,
+    },
+    stress::{
+        AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, AnyDualStress, AnyStress,
+        NounStress, PronounStress, VerbPastStress, VerbPresentStress, VerbStress,
+    },
+};
+use core::{fmt, marker::PhantomData, str::FromStr};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Unexpected, Visitor},
+};
+
+// Deserializes a value from a string, using its FromStr implementation
+struct FromStrVisitor<T> {
+    expecting: &'static str,
+    _marker: PhantomData<T>,
+}
+
+impl<T: FromStr> Visitor<'_> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+    }
+}
+
+macro_rules! impl_deserialize_from_str {
+    ($($t:ty => $expecting:literal),+ $(,)?) => ($(
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let expecting = $expecting;
+                deserializer.deserialize_str(FromStrVisitor { expecting, _marker: PhantomData })
+            }
+        }
+    )+);
+}
+macro_rules! impl_serialize_display {
+    ($($t:ty),+ $(,)?) => ($(
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+    )+);
+}
+macro_rules! impl_serialize_zaliznyak {
+    ($($t:ty),+ $(,)?) => ($(
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.abbr_zaliznyak())
+            }
+        }
+    )+);
+}
+
+impl_serialize_display! {
+    Declension, MaybeZeroDeclension, NounDeclension, PronounDeclension, AdjectiveDeclension,
+    DeclensionFlags, NounInfo, AnyStress, AnyDualStress, NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress,
+    AdjectiveStress, VerbPresentStress, VerbPastStress, VerbStress, Grammemes,
+}
+impl_serialize_zaliznyak!(Gender, GenderEx, GenderAnimacy, GenderExAnimacy);
+
+impl_deserialize_from_str! {
+    Declension => "a declension, like \"1*a\", \"п 1a/c′\" or \"мс 6*a\"",
+    MaybeZeroDeclension => "a declension, like \"1*a\" or \"п 1a/c′\", or \"0\"",
+    NounDeclension => "a noun declension, like \"1*a\"",
+    PronounDeclension => "a pronoun declension, like \"6*a\"",
+    AdjectiveDeclension => "an adjective declension, like \"1a/c′\"",
+    DeclensionFlags => "declension flags, like \"°*①\" or \", ё\"",
+    NounInfo => "a noun's gender and declension, like \"жо 7*b′①\"",
+    AnyStress => "a stress schema, like \"b′\"",
+    AnyDualStress => "a stress schema, like \"a/c′\"",
+    NounStress => "a noun stress schema, like \"b′\"",
+    PronounStress => "a pronoun stress schema, like \"b\"",
+    AdjectiveFullStress => "a full adjective stress schema, like \"b\"",
+    AdjectiveShortStress => "a short adjective stress schema, like \"c″\"",
+    AdjectiveStress => "an adjective stress schema, like \"a/c′\"",
+    VerbPresentStress => "a present tense verb stress schema, like \"c′\"",
+    VerbPastStress => "a past tense verb stress schema, like \"c″\"",
+    VerbStress => "a verb stress schema, like \"c/c′\"",
+    Gender => "a gender abbreviation: м, с or ж",
+    GenderEx => "a gender abbreviation: м, с, ж or м-ж",
+    GenderAnimacy => "a gender and animacy abbreviation, like \"мо\"",
+    GenderExAnimacy => "a gender and animacy abbreviation, like \"мо-жо\"",
+    Grammemes => "comma-separated grammemes, like \"nom,sg,masc,inan\"",
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Animacy, Case, CaseEx, Number};
+    use core::fmt::Debug;
+    use std::{format, string::String};
+
+    #[track_caller]
+    fn round_trip<T>(value: T, json: &str)
+    where T: Serialize + for<'de> Deserialize<'de> + PartialEq + Debug {
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+    }
+    #[track_caller]
+    fn round_trip_str<T>(s: &str)
+    where T: Serialize + for<'de> Deserialize<'de> + PartialEq + Debug + FromStr<Err: Debug> {
+        round_trip(s.parse::<T>().unwrap(), &format!("{s:?}"));
+    }
+
+    #[test]
+    fn declensions() {
+        for s in ["1a", "3*b", "8°*f″①②③④⑤, ё", "п 1*a/c′④", "мс 6*a", "п 4a, -ся"]
+        {
+            round_trip_str::<Declension>(s);
+        }
+        for s in ["0", "1a", "п 1*a/c′④", "мс 6*a"] {
+            round_trip_str::<MaybeZeroDeclension>(s);
+        }
+        for s in ["1a", "7*b′①", "5*d, ё"] {
+            round_trip_str::<NounDeclension>(s);
+        }
+        for s in ["1a", "6*a", "2*b"] {
+            round_trip_str::<PronounDeclension>(s);
+        }
+        for s in ["1a", "1*a/c′", "4b"] {
+            round_trip_str::<AdjectiveDeclension>(s);
+        }
+        // Any string accepted by FromStr is deserialized, not only the formatted ones
+        let decl: AdjectiveDeclension = serde_json::from_str("\"4b/b(1)\"").unwrap();
+        assert_eq!(serde_json::to_string(&decl).unwrap(), "\"4b①\"");
+        for s in ["", "°", "*①", "°*①②③④⑤, ё"] {
+            round_trip_str::<DeclensionFlags>(s);
+        }
+        for s in ["жо 7*b′①", "мн. ж 5a", "м 0", "м 6c Р2 П2", "мо п 4a, -ся"] {
+            round_trip_str::<NounInfo>(s);
+        }
+    }
+
+    #[test]
+    fn stresses() {
+        for s in ["a", "b′", "c″", "f′"] {
+            round_trip_str::<AnyStress>(s);
+        }
+        for s in ["a", "a/c′", "b/c″"] {
+            round_trip_str::<AnyDualStress>(s);
+        }
+        round_trip_str::<NounStress>("b′");
+        round_trip_str::<PronounStress>("f");
+        round_trip_str::<AdjectiveFullStress>("b");
+        round_trip_str::<AdjectiveShortStress>("c″");
+        round_trip_str::<AdjectiveStress>("a/c′");
+        round_trip_str::<VerbPresentStress>("c′");
+        round_trip_str::<VerbPastStress>("c″");
+        round_trip_str::<VerbStress>("c/c′");
+    }
+
+    #[test]
+    fn categories() {
+        for x in Gender::VALUES {
+            round_trip(x, &format!("{:?}", x.abbr_zaliznyak()));
+        }
+        for x in GenderEx::VALUES {
+            round_trip(x, &format!("{:?}", x.abbr_zaliznyak()));
+        }
+        for x in GenderAnimacy::VALUES {
+            round_trip(x, &format!("{:?}", x.abbr_zaliznyak()));
+        }
+        for x in GenderExAnimacy::VALUES {
+            round_trip(x, &format!("{:?}", x.abbr_zaliznyak()));
+        }
+        round_trip_str::<GenderExAnimacy>("мо-жо");
+
+        for x in CaseEx::VALUES {
+            round_trip(x, &format!("{:?}", format!("{x:?}")));
+        }
+        for x in Case::VALUES {
+            round_trip(x, &format!("{:?}", format!("{x:?}")));
+        }
+        round_trip(Animacy::Animate, "\"Animate\"");
+        round_trip(Number::Plural, "\"Plural\"");
+
+        for s in ["", "nom,sg,masc,inan", "pl,3"] {
+            round_trip_str::<Grammemes>(s);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = |json: &str| -> String {
+            serde_json::from_str::<NounDeclension>(json).unwrap_err().to_string()
+        };
+        assert!(err("\"9a\"").contains("expected a noun declension"));
+        assert!(err("\"п 1a\"").starts_with("invalid value: string \"п 1a\""));
+        assert!(err("12").starts_with("invalid type: integer"));
+
+        assert!(serde_json::from_str::<Gender>("\"м-ж\"").is_err());
+        assert!(serde_json::from_str::<NounInfo>("\"м п 9a\"").is_err());
+        assert!(serde_json::from_str::<DeclensionFlags>("\"①*\"").is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! Preprocessing of stems and headwords from real-world sources.
+//!
+//! The inflection expects a clean stem of Cyrillic letters, while dictionaries and user input
+//! often have stress marks (серда́), decomposed letters (и + U+0306 instead of й), uppercase
+//! letters and stray whitespace. [`normalize`] cleans up such input, and keeps the stress
+//! position, so that it can be passed back to the inflection (see [`NormalizedStem::marked`]).
+//!
+//! # Examples
+//! ```
+//! use grammar_russian::{categories::*, declension::*, stem};
+//!
+//! let word = stem::normalize(" Сестра́ ").unwrap();
+//! assert_eq!((word.as_str(), word.stress), ("сестра", Some(5)));
+//!
+//! // Normalized words can be used anywhere a string is expected, or to create nouns
+//! let noun = Noun::from_normalized(&word, "жо 1d, ё".parse().unwrap()).unwrap();
+//! assert_eq!(noun.inflect_to_string(CaseEx::Nominative, Number::Plural).unwrap(), "сёстры");
+//! ```
+
+use crate::{Letter, letters};
+use alloc::string::String;
+use thiserror::Error;
+
+/// The combining acute accent (U+0301), that marks the stressed vowel.
+const ACUTE: char = '\u{0301}';
+/// The combining breve (U+0306): и + U+0306 is the decomposed й.
+const BREVE: char = '\u{0306}';
+/// The combining diaeresis (U+0308): е + U+0308 is the decomposed ё.
+const DIAERESIS: char = '\u{0308}';
+
+/// A stem or a word, cleaned up by [`normalize`]: lowercase, without the stress mark and the
+/// surrounding whitespace, and with the decomposed letters composed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedStem {
+    stem: String,
+    /// The index of the stressed letter, if the input had a stress mark.
+    pub stress: Option<usize>,
+    /// Whether the input had 'ё', either precomposed or decomposed (е + U+0308).
+    pub had_yo: bool,
+}
+
+impl NormalizedStem {
+    /// Returns the normalized stem.
+    pub fn as_str(&self) -> &str {
+        &self.stem
+    }
+    /// Returns the number of letters in the stem.
+    pub fn len(&self) -> usize {
+        self.stem.len() / 2
+    }
+    /// Returns `true`, if the stem has no letters. Normalized stems are never empty.
+    pub fn is_empty(&self) -> bool {
+        self.stem.is_empty()
+    }
+
+    /// Returns the stem with the stress mark after the stressed letter, if it's known. Such stems
+    /// can be used with [`Noun::new`][crate::declension::Noun::new], and the stress is then kept
+    /// in the stressed forms. Headwords can be used without the mark, see
+    /// [`Noun::from_normalized`][crate::declension::Noun::from_normalized].
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::stem;
+    ///
+    /// assert_eq!(stem::normalize("МОЛО́Т").unwrap().marked(), "моло́т");
+    /// assert_eq!(stem::normalize("молот").unwrap().marked(), "молот");
+    /// ```
+    pub fn marked(&self) -> String {
+        let Some(stress) = self.stress else { return self.stem.clone() };
+
+        let mut marked = String::with_capacity(self.stem.len() + ACUTE.len_utf8());
+        marked.push_str(&self.stem[..((stress + 1) * 2)]);
+        marked.push(ACUTE);
+        marked.push_str(&self.stem[((stress + 1) * 2)..]);
+        marked
+    }
+}
+
+impl core::ops::Deref for NormalizedStem {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl AsRef<str> for NormalizedStem {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+impl core::fmt::Display for NormalizedStem {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.stem.fmt(f)
+    }
+}
+
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizeStemError {
+    #[error("stem is empty")]
+    Empty,
+    #[error("stem contains an invalid character {0:?}")]
+    InvalidChar(char),
+    #[error("stress mark must follow a vowel")]
+    MisplacedStressMark,
+    #[error("stem has more than one stress mark")]
+    MultipleStressMarks,
+    #[error("letter at index {0} isn't 'е' or 'ё'")]
+    InvalidYoIndex(usize),
+}
+
+/// Normalizes the stem or word: trims the surrounding whitespace, removes the stress mark
+/// (U+0301) and records the index of the stressed letter, composes the decomposed 'й' and 'ё'
+/// (и + U+0306, е + U+0308), and converts the letters to lowercase.
+///
+/// Returns an error, if the trimmed input is empty, has characters other than Cyrillic letters
+/// and the combining marks above, or has a stress mark, that doesn't follow a vowel.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::stem::{self, NormalizeStemError};
+///
+/// let stem = stem::normalize("серда́").unwrap();
+/// assert_eq!((stem.as_str(), stem.stress, stem.had_yo), ("серда", Some(4), false));
+///
+/// // The decomposed letters are composed
+/// let stem = stem::normalize("сине\u{0308}в").unwrap();
+/// assert_eq!((stem.as_str(), stem.had_yo), ("синёв", true));
+///
+/// assert_eq!(stem::normalize("ма́́ма"), Err(NormalizeStemError::MultipleStressMarks));
+/// assert_eq!(stem::normalize("мама1"), Err(NormalizeStemError::InvalidChar('1')));
+/// ```
+pub fn normalize(input: &str) -> Result<NormalizedStem, NormalizeStemError> {
+    let input = input.trim();
+    let mut stem = String::with_capacity(input.len());
+    let (mut len, mut stress, mut had_yo) = (0, None, false);
+    let mut last: Option<Letter> = None;
+
+    for ch in input.chars() {
+        let letter = match ch {
+            ACUTE => {
+                if stress.is_some() {
+                    return Err(NormalizeStemError::MultipleStressMarks);
+                }
+                if !last.is_some_and(Letter::is_vowel) {
+                    return Err(NormalizeStemError::MisplacedStressMark);
+                }
+                stress = Some(len - 1);
+                continue;
+            },
+            // The decomposed letters can't follow a stress mark
+            BREVE | DIAERESIS if stress.is_some_and(|i| i + 1 == len) => {
+                return Err(NormalizeStemError::InvalidChar(ch));
+            },
+            BREVE if last == Some(letters::и) => letters::й,
+            DIAERESIS if last == Some(letters::е) => letters::ё,
+            _ => match Letter::try_from_char(ch) {
+                Some(letter) => {
+                    let letter = letter.to_lowercase();
+                    stem.push_str(letter.as_str());
+                    len += 1;
+                    last = Some(letter);
+                    had_yo |= letter == letters::ё;
+                    continue;
+                },
+                None => return Err(NormalizeStemError::InvalidChar(ch)),
+            },
+        };
+        // Replace the last letter with the composed one
+        stem.truncate(stem.len() - 2);
+        stem.push_str(letter.as_str());
+        last = Some(letter);
+        had_yo |= letter == letters::ё;
+    }
+
+    if len == 0 {
+        return Err(NormalizeStemError::Empty);
+    }
+    Ok(NormalizedStem { stem, stress, had_yo })
+}
+
+/// Same as [`normalize`], but also replaces the letter 'е' at the specified index with 'ё', for
+/// sources that don't distinguish them. The letter at the index must be 'е' or 'ё'. If the stem
+/// has no stress mark, the 'ё' becomes its stressed letter.
+///
+/// # Examples
+/// ```
+/// use grammar_russian::stem::{self, NormalizeStemError};
+///
+/// let stem = stem::normalize_with_yo("Береза", 3).unwrap();
+/// assert_eq!((stem.as_str(), stem.stress, stem.had_yo), ("берёза", Some(3), false));
+///
+/// assert_eq!(stem::normalize_with_yo("береза", 2), Err(NormalizeStemError::InvalidYoIndex(2)));
+/// ```
+pub fn normalize_with_yo(
+    input: &str,
+    yo_index: usize,
+) -> Result<NormalizedStem, NormalizeStemError> {
+    let mut stem = normalize(input)?;
+
+    let range = (yo_index * 2)..(yo_index * 2 + 2);
+    match stem.stem.get(range.clone()) {
+        Some("е" | "ё") => stem.stem.replace_range(range, "ё"),
+        _ => return Err(NormalizeStemError::InvalidYoIndex(yo_index)),
+    }
+    stem.stress = stem.stress.or(Some(yo_index));
+    Ok(stem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        categories::{CaseEx, Number},
+        declension::{ExtractStemError, Noun},
+    };
+
+    #[test]
+    fn nfc_and_nfd() {
+        // Precomposed (NFC) and decomposed (NFD) letters are normalized into the same stem
+        let nfc = normalize("чайник").unwrap();
+        let nfd = normalize("чаи\u{0306}ник").unwrap();
+        assert_eq!(nfc, nfd);
+        assert_eq!((nfd.as_str(), nfd.len(), nfd.had_yo), ("чайник", 6, false));
+
+        let nfc = normalize("ёлка").unwrap();
+        let nfd = normalize("е\u{0308}лка").unwrap();
+        assert_eq!(nfc, nfd);
+        assert_eq!((nfd.as_str(), nfd.had_yo), ("ёлка", true));
+        assert_eq!(normalize("Е\u{0308}ЛКА").unwrap(), nfc);
+        assert_eq!(normalize("И\u{0306}ОД").unwrap().as_str(), "йод");
+
+        // The combining marks apply only to their letters
+        assert_eq!(normalize("а\u{0306}"), Err(NormalizeStemError::InvalidChar('\u{0306}')));
+        assert_eq!(normalize("\u{0308}е"), Err(NormalizeStemError::InvalidChar('\u{0308}')));
+    }
+
+    #[test]
+    fn stress_position() {
+        // The stress index counts the composed letters
+        let stem = normalize("каи\u{0306}ма́").unwrap();
+        assert_eq!((stem.as_str(), stem.stress), ("кайма", Some(4)));
+        let stem = normalize("е\u{0308}\u{0301}ж").unwrap();
+        assert_eq!((stem.as_str(), stem.stress, stem.had_yo), ("ёж", Some(0), true));
+        let stem = normalize("  СЕРДА́\t").unwrap();
+        assert_eq!((stem.as_str(), stem.stress), ("серда", Some(4)));
+        assert_eq!(stem.marked(), "серда́");
+
+        assert_eq!(normalize("\u{0301}мама"), Err(NormalizeStemError::MisplacedStressMark));
+        assert_eq!(normalize("м\u{0301}ама"), Err(NormalizeStemError::MisplacedStressMark));
+        assert_eq!(normalize("ма\u{0301}ма\u{0301}"), Err(NormalizeStemError::MultipleStressMarks));
+        assert_eq!(
+            normalize("е\u{0301}\u{0308}ж"),
+            Err(NormalizeStemError::InvalidChar('\u{0308}'))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(normalize(""), Err(NormalizeStemError::Empty));
+        assert_eq!(normalize(" \n"), Err(NormalizeStemError::Empty));
+        assert_eq!(normalize("мама папа"), Err(NormalizeStemError::InvalidChar(' ')));
+        assert_eq!(normalize("mama"), Err(NormalizeStemError::InvalidChar('m')));
+
+        assert_eq!(normalize_with_yo("елка", 4), Err(NormalizeStemError::InvalidYoIndex(4)));
+        let stem = normalize_with_yo("е́лка", 0).unwrap();
+        assert_eq!((stem.as_str(), stem.stress), ("ёлка", Some(0)));
+    }
+
+    #[test]
+    fn nouns() {
+        // Both the raw and the normalized stems can be used with the nouns
+        let word = normalize("сто́л").unwrap();
+        let noun = Noun::from_headword(&word, "м 1b".parse().unwrap()).unwrap();
+        assert_eq!(noun.inflect_to_string(CaseEx::Genitive, Number::Singular).unwrap(), "стола");
+
+        // The stress position is kept separately from the stem, and used in the stressed forms
+        let word = normalize("Мо́лот").unwrap();
+        let noun = Noun::from_normalized(&word, "м 1a".parse().unwrap()).unwrap();
+        assert_eq!((noun.stem, noun.stress), ("молот", Some(1)));
+        let form = noun.inflect_stressed(CaseEx::Dative, Number::Plural).unwrap();
+        assert_eq!(form, "мо́лотам");
+        assert_eq!(noun.inflect_to_string(CaseEx::Dative, Number::Plural).unwrap(), "молотам");
+
+        // unless it's in the ending
+        let word = normalize("сестра́").unwrap();
+        let noun = Noun::from_normalized(&word, "жо 1d, ё".parse().unwrap()).unwrap();
+        assert_eq!((noun.stem, noun.stress), ("сестр", None));
+        let form = noun.inflect_stressed(CaseEx::Nominative, Number::Plural).unwrap();
+        assert_eq!(form, "сёстры");
+
+        // The marked stems aren't accepted as headwords
+        let err = Noun::from_headword("доро́га", "ж 3a".parse().unwrap()).unwrap_err();
+        assert_eq!(err, ExtractStemError::InvalidLetters);
+    }
+}
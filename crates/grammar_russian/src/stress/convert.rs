@@ -45,6 +45,7 @@ pub enum VerbStressError {
 }
 
 //                         TABLE OF STRESS TYPE CONVERSIONS
+// (Verified by the conversion_table test below. Update the table when changing conversions.)
 // ┌———————┬——————┬——————┬——————┬——————┬——————┬——————┬——————╥——————┬——————┬——————┐
 // │From\To│ Any  │ Noun │ Pro  │ AdjF │ AdjS │ VerbF│ VerbP║ ANY  │ ADJ  │ VERB │
 // ├———————┼——————┼——————┼——————┼——————┼——————┼——————┼——————╫——————┼——————┼——————┤
@@ -175,3 +176,156 @@ impl const TryFrom<AnyDualStress> for VerbStress {
         ))
     }
 }
+// Try to convert AnyStress to AdjectiveStress and VerbStress (same as with main-only AnyDualStress)
+impl const TryFrom<AnyStress> for AdjectiveStress {
+    type Error = AdjectiveStressError;
+    fn try_from(value: AnyStress) -> Result<Self, Self::Error> {
+        AnyDualStress::new(value, None).try_into()
+    }
+}
+impl const TryFrom<AnyStress> for VerbStress {
+    type Error = VerbStressError;
+    fn try_from(value: AnyStress) -> Result<Self, Self::Error> {
+        AnyDualStress::new(value, None).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::marker::PhantomData;
+    use std::vec::Vec;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Conversion {
+        From,
+        TryFrom,
+        None,
+    }
+
+    // Probes whether the From or TryFrom conversion is implemented, falling back to trait consts
+    macro_rules! probe {
+        ($from:ty => $to:ty) => {{
+            struct IsFrom<T>(PhantomData<T>);
+            struct IsTryFrom<T>(PhantomData<T>);
+            #[allow(dead_code)]
+            trait Fallback {
+                const IMPLS: bool = false;
+            }
+            impl<T> Fallback for IsFrom<T> {}
+            impl<T> Fallback for IsTryFrom<T> {}
+            #[allow(dead_code)]
+            impl<T: From<$from>> IsFrom<T> {
+                const IMPLS: bool = true;
+            }
+            #[allow(dead_code)]
+            impl<T: TryFrom<$from>> IsTryFrom<T> {
+                const IMPLS: bool = true;
+            }
+
+            match (<IsFrom<$to>>::IMPLS, <IsTryFrom<$to>>::IMPLS) {
+                (true, _) => Conversion::From,
+                (false, true) => Conversion::TryFrom,
+                (false, false) => Conversion::None,
+            }
+        }};
+    }
+    macro_rules! probe_matrix {
+        ($($from:ty),*; $to:tt) => ([$( probe_matrix!(@row $from; $to) ),*]);
+        (@row $from:ty; [$($to:ty),*]) => ([$( probe!($from => $to) ),*]);
+    }
+
+    #[test]
+    fn conversion_table() {
+        #[rustfmt::skip]
+        let actual = probe_matrix!(
+            AnyStress, NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress,
+            VerbPresentStress, VerbPastStress, AnyDualStress, AdjectiveStress, VerbStress;
+            [
+                AnyStress, NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress,
+                VerbPresentStress, VerbPastStress, AnyDualStress, AdjectiveStress, VerbStress
+            ]
+        );
+
+        // Parse the table from the comment above
+        let rows = include_str!("convert.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("// │ "))
+            .map(|row| {
+                let cells = row.split(['│', '║']).skip(1).filter(|x| !x.is_empty());
+                cells
+                    .map(|cell| match cell.trim() {
+                        "██" => Some(Conversion::From),
+                        "[]" => Some(Conversion::TryFrom),
+                        "" => Some(Conversion::None),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), actual.len());
+
+        for (i, (row, actual_row)) in rows.iter().zip(actual).enumerate() {
+            assert_eq!(row.len(), actual_row.len(), "row {i}");
+
+            for (j, (cell, actual)) in row.iter().zip(actual_row).enumerate() {
+                // Skip the diagonal (identity conversions)
+                if let Some(expected) = cell {
+                    assert_eq!(*expected, actual, "row {i}, column {j}");
+                } else {
+                    assert_eq!(i, j);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn conversion_values() {
+        let adj_values = AdjectiveFullStress::VALUES.into_iter().flat_map(|full| {
+            AdjectiveShortStress::VALUES.map(|short| AdjectiveStress::new(full, short))
+        });
+        let verb_values = VerbPresentStress::VALUES
+            .into_iter()
+            .flat_map(|present| VerbPastStress::VALUES.map(|past| VerbStress::new(present, past)));
+
+        // Simple stresses convert to AnyStress and AnyDualStress, and back
+        macro_rules! assert_simple {
+            ($($t:ty),*) => ($(
+                for x in <$t>::VALUES {
+                    let any = AnyStress::from(x);
+                    let dual = AnyDualStress::from(x);
+                    assert_eq!(dual, AnyDualStress::new(any, None));
+                    assert_eq!(<$t>::try_from(any), Ok(x));
+                    assert_eq!(<$t>::try_from(dual), Ok(x));
+                }
+                let valid = AnyStress::VALUES.into_iter().filter(|x| <$t>::try_from(*x).is_ok());
+                assert_eq!(valid.count(), <$t>::VALUES.len());
+            )*);
+        }
+        assert_simple!(
+            NounStress,
+            PronounStress,
+            AdjectiveFullStress,
+            AdjectiveShortStress,
+            VerbPresentStress,
+            VerbPastStress
+        );
+
+        for x in AnyStress::VALUES {
+            let dual = AnyDualStress::from(x);
+            assert_eq!(AnyStress::try_from(dual), Ok(x));
+            assert_eq!(AdjectiveStress::try_from(x), AdjectiveStress::try_from(dual));
+            assert_eq!(VerbStress::try_from(x), VerbStress::try_from(dual));
+        }
+        for x in adj_values {
+            assert_eq!(AdjectiveStress::try_from(AnyDualStress::from(x)), Ok(x));
+        }
+        for x in verb_values {
+            assert_eq!(VerbStress::try_from(AnyDualStress::from(x)), Ok(x));
+        }
+        // Dual stresses can't be converted to simple stresses
+        let dual = AnyDualStress::new(AnyStress::A, Some(AnyStress::C));
+        assert_eq!(AnyStress::try_from(dual), Err(AnyStressError));
+        assert_eq!(NounStress::try_from(dual), Err(NounStressError));
+    }
+}
@@ -3,18 +3,30 @@ use crate::{
         AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, AnyDualStress, AnyStress,
         NounStress, PronounStress, VerbPastStress, VerbPresentStress, VerbStress,
     },
-    util::UnsafeBuf,
+    util::{UnsafeBuf, impl_fmt_to_buf},
 };
 
+/// The maximum length of a formatted [`AnyStress`], in bytes.
 // Longest form: f″ (4 bytes, 2 chars)
-pub const STRESS_MAX_LEN: usize = 4;
+pub const STRESS_MAX_LEN: usize = "f″".len();
 pub const STRESS_MAX_CHARS: usize = 2;
+/// The maximum length of a formatted [`AnyDualStress`], in bytes.
 // Longest form: f″/f″ (9 bytes, 5 chars)
 pub const DUAL_STRESS_MAX_LEN: usize = 2 * STRESS_MAX_LEN + 1;
 pub const DUAL_STRESS_MAX_CHARS: usize = 2 * STRESS_MAX_CHARS + 1;
 
 impl AnyStress {
-    pub const fn fmt_to(self, dst: &mut [u8; 4]) -> &mut str {
+    /// Formats the stress schema into the buffer: "a", "b′" or "f″".
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::stress::*;
+    ///
+    /// let mut buf = [0; STRESS_MAX_LEN];
+    /// assert_eq!(AnyStress::Cpp.fmt_to(&mut buf), "c″");
+    /// assert_eq!(AnyStress::Cpp.fmt_to_buf(), "c″");
+    /// ```
+    pub const fn fmt_to(self, dst: &mut [u8; STRESS_MAX_LEN]) -> &mut str {
         // Write the letter: a, b, c, d, e, f
         dst[0] = match self.unprime() {
             Self::A => b'a',
@@ -26,7 +38,7 @@ impl AnyStress {
             _ => unreachable!(),
         };
 
-        // If the stress has primes, it will occupy the entire 4 byte buffer
+        // If the stress has primes, it will occupy the entire buffer
         if self.has_any_primes() {
             // Write the UTF-8 bytes of ′ or ″
             let ch = if self.has_double_prime() { '″' } else { '′' };
@@ -36,16 +48,27 @@ impl AnyStress {
             unsafe { str::from_utf8_unchecked_mut(dst) }
         } else {
             // Return string slice of length 1, containing only the letter
-            let slice = unsafe { std::slice::from_raw_parts_mut(dst.as_mut_ptr(), 1) };
+            let slice = unsafe { core::slice::from_raw_parts_mut(dst.as_mut_ptr(), 1) };
             unsafe { str::from_utf8_unchecked_mut(slice) }
         }
     }
 }
 impl AnyDualStress {
-    pub const fn fmt_to(self, dst: &mut [u8; 9]) -> &mut str {
+    /// Formats the dual stress schema into the buffer: "a", "b′" or "a/c″".
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::stress::*;
+    ///
+    /// let stress = AnyDualStress::new(AnyStress::A, Some(AnyStress::Cpp));
+    /// let mut buf = [0; DUAL_STRESS_MAX_LEN];
+    /// assert_eq!(stress.fmt_to(&mut buf), "a/c″");
+    /// assert_eq!(stress.fmt_to_buf(), "a/c″");
+    /// ```
+    pub const fn fmt_to(self, dst: &mut [u8; DUAL_STRESS_MAX_LEN]) -> &mut str {
         let mut dst = UnsafeBuf::new(dst);
 
-        // Format main into a 4-byte sub-buffer
+        // Format main into a sub-buffer
         let main_len = self.main.fmt_to(dst.chunk()).len();
         dst.forward(main_len);
 
@@ -53,7 +76,7 @@ impl AnyDualStress {
             // Append '/' as a separator
             dst.push('/');
 
-            // Format alt into a 4-byte sub-buffer
+            // Format alt into a sub-buffer
             let alt_len = alt.fmt_to(dst.chunk()).len();
             dst.forward(alt_len);
         }
@@ -62,21 +85,26 @@ impl AnyDualStress {
     }
 }
 
-impl std::fmt::Display for AnyStress {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.fmt_to(&mut [0; 4]).fmt(f)
+impl core::fmt::Display for AnyStress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.fmt_to(&mut [0; STRESS_MAX_LEN]).fmt(f)
     }
 }
-impl std::fmt::Display for AnyDualStress {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        self.fmt_to(&mut [0; 9]).fmt(f)
+impl core::fmt::Display for AnyDualStress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        self.fmt_to(&mut [0; DUAL_STRESS_MAX_LEN]).fmt(f)
     }
 }
 
+impl_fmt_to_buf! {
+    AnyStress => STRESS_MAX_LEN,
+    AnyDualStress => DUAL_STRESS_MAX_LEN,
+}
+
 macro_rules! derive_stress_impls {
     ($($t:ty),* $(,)?) => ($(
-        impl std::fmt::Display for $t {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        impl core::fmt::Display for $t {
+            fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
                 AnyStress::from(*self).fmt(f)
             }
         }
@@ -86,13 +114,13 @@ derive_stress_impls! {
     NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress, VerbPresentStress, VerbPastStress,
 }
 
-impl std::fmt::Display for AdjectiveStress {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for AdjectiveStress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr().fmt(f)
     }
 }
-impl std::fmt::Display for VerbStress {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for VerbStress {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         self.abbr().fmt(f)
     }
 }
@@ -101,10 +129,11 @@ impl std::fmt::Display for VerbStress {
 mod tests {
     use super::*;
     use crate::stress;
+    use std::string::ToString;
 
     #[test]
     fn fmt_any() {
-        fn assert_fmt<T: std::fmt::Display>(value: T, expected: &str) {
+        fn assert_fmt<T: core::fmt::Display>(value: T, expected: &str) {
             assert_eq!(value.to_string(), expected);
         }
 
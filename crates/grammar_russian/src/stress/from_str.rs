@@ -1,29 +1,39 @@
 use crate::{
     stress::{
         AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, AnyDualStress, AnyStress,
-        NounStress, PronounStress, VerbPastStress, VerbPresentStress, VerbStress,
+        DUAL_STRESS_MAX_LEN, NounStress, PronounStress, VerbPastStress, VerbPresentStress,
+        VerbStress,
     },
     util::{PartialParse, UnsafeParser, const_traits::*},
 };
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use bitflags::bitflags;
+
+/// The maximum length of a stress accepted by the parsers, in bytes. Longer inputs are rejected
+/// with [`ParseStressError::InputTooLong`] before being scanned.
+pub const STRESS_INPUT_MAX_LEN: usize = 16;
+const _: () = assert!(DUAL_STRESS_MAX_LEN <= STRESS_INPUT_MAX_LEN);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ParseStressError {
     InvalidLetter,
     InvalidPrime,
     Incompatible,
+    /// The input is longer than [`STRESS_INPUT_MAX_LEN`].
+    InputTooLong,
     Invalid,
 }
 
 impl const PartialParse for AnyStress {
     fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err> {
-        // First, parse the latin letter
+        // First, parse the latin letter, in either case
         let letter = match parser.read_one() {
-            Some(b'a') => Self::A,
-            Some(b'b') => Self::B,
-            Some(b'c') => Self::C,
-            Some(b'd') => Self::D,
-            Some(b'e') => Self::E,
-            Some(b'f') => Self::F,
+            Some(b'a' | b'A') => Self::A,
+            Some(b'b' | b'B') => Self::B,
+            Some(b'c' | b'C') => Self::C,
+            Some(b'd' | b'D') => Self::D,
+            Some(b'e' | b'E') => Self::E,
+            Some(b'f' | b'F') => Self::F,
             _ => return Err(ParseStressError::InvalidLetter),
         };
 
@@ -62,22 +72,43 @@ impl const PartialParse for AnyDualStress {
     }
 }
 
-impl std::str::FromStr for AnyStress {
+impl AnyStress {
+    /// Parses the stress the same way as its `FromStr` implementation, but can be used in const
+    /// contexts.
+    pub const fn parse_const(s: &str) -> Result<Self, ParseStressError> {
+        if s.len() > STRESS_INPUT_MAX_LEN {
+            return Err(ParseStressError::InputTooLong);
+        }
+        Self::from_str_or(s, ParseStressError::Invalid)
+    }
+}
+impl AnyDualStress {
+    /// Parses the dual stress the same way as its `FromStr` implementation, but can be used in
+    /// const contexts.
+    pub const fn parse_const(s: &str) -> Result<Self, ParseStressError> {
+        if s.len() > STRESS_INPUT_MAX_LEN {
+            return Err(ParseStressError::InputTooLong);
+        }
+        Self::from_str_or(s, ParseStressError::Invalid)
+    }
+}
+
+impl core::str::FromStr for AnyStress {
     type Err = ParseStressError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Self::Err::Invalid)
+        Self::parse_const(s)
     }
 }
-impl std::str::FromStr for AnyDualStress {
+impl core::str::FromStr for AnyDualStress {
     type Err = ParseStressError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_str_or(s, Self::Err::Invalid)
+        Self::parse_const(s)
     }
 }
 
 macro_rules! derive_stress_impls {
     ($($t:ty),* $(,)?) => ($(
-        impl std::str::FromStr for $t {
+        impl core::str::FromStr for $t {
             type Err = ParseStressError;
             fn from_str(s: &str) -> Result<Self, Self::Err> {
                 AnyStress::from_str(s)?.try_into().or(Err(Self::Err::Incompatible))
@@ -89,23 +120,136 @@ derive_stress_impls! {
     NounStress, PronounStress, AdjectiveFullStress, AdjectiveShortStress, VerbPresentStress, VerbPastStress,
 }
 
-impl std::str::FromStr for AdjectiveStress {
+impl core::str::FromStr for AdjectiveStress {
     type Err = ParseStressError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         AnyDualStress::from_str(s)?.try_into().or(Err(Self::Err::Incompatible))
     }
 }
-impl std::str::FromStr for VerbStress {
+impl core::str::FromStr for VerbStress {
     type Err = ParseStressError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         AnyDualStress::from_str(s)?.try_into().or(Err(Self::Err::Incompatible))
     }
 }
 
+bitflags! {
+    /// Deviations from the strict stress notation tolerated by [`AnyDualStress::parse_relaxed`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct StressRelaxations: u8 {
+        /// The stresses were separated by ':', '⁄' (U+2044) or '∕' (U+2215), instead of '/'.
+        const ALTERNATIVE_SLASH = 1 << 0;
+        /// The separator was surrounded by spaces: "a / c′".
+        const SPACED_SLASH = 1 << 1;
+    }
+}
+
+impl AnyDualStress {
+    /// Parses a dual stress, tolerating alternative separators found in some digitized sources.
+    /// Returns the stress and the relaxations that had to be applied.
+    ///
+    /// See [`StressRelaxations`] for a list of the tolerated deviations. Otherwise, the notation
+    /// is parsed just as strictly as with [`FromStr`](std::str::FromStr), and inputs longer than
+    /// [`STRESS_INPUT_MAX_LEN`] are rejected before being scanned.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::stress::*;
+    ///
+    /// let (stress, relaxations) = AnyDualStress::parse_relaxed("a : c′").unwrap();
+    /// assert_eq!(stress.to_string(), "a/c′");
+    /// assert_eq!(
+    ///     relaxations,
+    ///     StressRelaxations::ALTERNATIVE_SLASH | StressRelaxations::SPACED_SLASH,
+    /// );
+    /// ```
+    pub fn parse_relaxed(s: &str) -> Result<(Self, StressRelaxations), ParseStressError> {
+        if s.len() > STRESS_INPUT_MAX_LEN {
+            return Err(ParseStressError::InputTooLong);
+        }
+        let mut relaxations = StressRelaxations::empty();
+        let s = normalize_separators(s, &mut relaxations);
+        Ok((s.parse()?, relaxations))
+    }
+}
+
+/// Replaces alternative separators with '/', and removes single spaces around them.
+pub(crate) fn normalize_separators<'a>(
+    s: &'a str,
+    relaxations: &mut StressRelaxations,
+) -> Cow<'a, str> {
+    const fn is_separator(ch: char) -> bool {
+        matches!(ch, '/' | ':' | '⁄' | '∕')
+    }
+
+    let chars: Vec<char> = s.chars().collect();
+    let mut normalized = String::with_capacity(s.len());
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if is_separator(ch) {
+            if ch != '/' {
+                *relaxations |= StressRelaxations::ALTERNATIVE_SLASH;
+            }
+            normalized.push('/');
+        } else if ch == ' '
+            && (chars.get(i + 1).is_some_and(|&x| is_separator(x))
+                || i > 0 && is_separator(chars[i - 1]))
+        {
+            *relaxations |= StressRelaxations::SPACED_SLASH;
+        } else {
+            normalized.push(ch);
+        }
+    }
+
+    if relaxations.is_empty() { Cow::Borrowed(s) } else { Cow::Owned(normalized) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{ParseStressError as Error, *};
     use crate::stress;
+    use std::{format, string::ToString};
+
+    #[test]
+    fn parse_const() {
+        const STRESS: Result<AnyStress, Error> = AnyStress::parse_const("f″");
+        const DUAL: Result<AnyDualStress, Error> = AnyDualStress::parse_const("a/c''");
+
+        const _: () = assert!(matches!(STRESS, Ok(AnyStress::Fpp)));
+        const _: () = assert!(matches!(
+            DUAL,
+            Ok(AnyDualStress { main: AnyStress::A, alt: Some(AnyStress::Cpp) })
+        ));
+        const _: () = assert!(matches!(AnyStress::parse_const("g"), Err(Error::InvalidLetter)));
+
+        for s in ["a", "b′", "c''", "a/b", "a/", "/b", "x", "", "a/b/c"] {
+            assert_eq!(AnyStress::parse_const(s), s.parse(), "{s}");
+            assert_eq!(AnyDualStress::parse_const(s), s.parse(), "{s}");
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        #[track_caller]
+        fn assert_sorted<T: core::str::FromStr<Err: core::fmt::Debug> + Ord>(values: &[&str]) {
+            let parsed: std::vec::Vec<T> = values.iter().map(|x| x.parse().unwrap()).collect();
+            assert!(parsed.is_sorted_by(|a, b| a < b), "{values:?}");
+        }
+
+        let all = ["a", "b", "c", "d", "e", "f", "a′", "b′", "c′", "d′", "e′", "f′", "c″", "f″"];
+        assert_sorted::<AnyStress>(&all);
+        assert_sorted::<NounStress>(&["a", "b", "c", "d", "e", "f", "b′", "d′", "f′", "f″"]);
+        assert_sorted::<PronounStress>(&["a", "b", "f"]);
+        assert_sorted::<AdjectiveFullStress>(&["a", "b"]);
+        assert_sorted::<AdjectiveShortStress>(&["a", "b", "c", "a′", "b′", "c′", "c″"]);
+        assert_sorted::<VerbPresentStress>(&["a", "b", "c", "c′"]);
+        assert_sorted::<VerbPastStress>(&["a", "b", "c", "c′", "c″"]);
+
+        // Dual stresses are ordered by the main stress, and then by the alternative one
+        assert_sorted::<AnyDualStress>(&["a", "a/b", "a/c′", "b", "b/a", "c″/f"]);
+        assert_sorted::<AdjectiveStress>(&["a", "a/b", "a/c″", "b/a", "b"]);
+        assert_sorted::<VerbStress>(&["a", "a/c", "b/a", "b/c", "c′/c″"]);
+    }
 
     #[test]
     fn parse_any() {
@@ -123,7 +267,7 @@ mod tests {
         assert_eq!("/b".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("a/b".parse::<AnyStress>(), Err(Error::Invalid));
         assert_eq!("z".parse::<AnyStress>(), Err(Error::InvalidLetter));
-        assert_eq!("A".parse::<AnyStress>(), Err(Error::InvalidLetter));
+        assert_eq!("Z".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("ab".parse::<AnyStress>(), Err(Error::Invalid));
         assert_eq!("$a".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("a$".parse::<AnyStress>(), Err(Error::Invalid));
@@ -143,12 +287,30 @@ mod tests {
         assert_eq!("a/".parse::<AnyStress>(), Err(Error::Invalid));
         assert_eq!("/b".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("z".parse::<AnyStress>(), Err(Error::InvalidLetter));
-        assert_eq!("A".parse::<AnyStress>(), Err(Error::InvalidLetter));
+        assert_eq!("Z".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("ab".parse::<AnyStress>(), Err(Error::Invalid));
         assert_eq!("$a/b".parse::<AnyStress>(), Err(Error::InvalidLetter));
         assert_eq!("a/b$".parse::<AnyStress>(), Err(Error::Invalid));
     }
 
+    #[test]
+    fn uppercase() {
+        // The letters are parsed in either case, and are always formatted in lowercase
+        for s in ["a", "b", "c", "d", "e", "f", "b′", "d'", "f″", "c''"] {
+            let upper = s.to_uppercase();
+            assert_eq!(upper.parse::<AnyStress>(), s.parse::<AnyStress>(), "{upper}");
+        }
+        assert_eq!("F″".parse::<AnyStress>().unwrap().to_string(), "f″");
+        assert_eq!("A/c′".parse::<AnyDualStress>(), Ok(stress![a / c1]));
+        assert_eq!("a/C'".parse::<AnyDualStress>(), Ok(stress![a / c1]));
+        assert_eq!("A/C″".parse::<AnyDualStress>().unwrap().to_string(), "a/c″");
+        assert_eq!("B".parse::<NounStress>(), Ok(NounStress::B));
+        assert_eq!(AnyDualStress::parse_relaxed("A : C′").unwrap().0, stress![a / c1]);
+
+        assert_eq!("G".parse::<AnyStress>(), Err(Error::InvalidLetter));
+        assert_eq!("А".parse::<AnyStress>(), Err(Error::InvalidLetter)); // Cyrillic А
+    }
+
     #[test]
     fn parse_typed() {
         assert_eq!("a".parse::<NounStress>(), Ok(stress![a]));
@@ -240,4 +402,97 @@ mod tests {
         assert_eq!("c″/a".parse::<VerbStress>(), Err(Error::Incompatible));
         assert_eq!("f″/a".parse::<VerbStress>(), Err(Error::Incompatible));
     }
+
+    #[test]
+    fn separators() {
+        // The '/' is consumed before the alternative stress, and both stresses are required
+        assert_eq!("a/b".parse::<AnyDualStress>(), Ok(stress![a / b]));
+        assert_eq!("a/b".parse::<AdjectiveStress>(), Ok(stress![a / b]));
+        assert_eq!("a/b".parse::<VerbStress>(), Ok(stress![a / b]));
+        assert_eq!(AnyDualStress::parse_relaxed("a/b"), Ok((stress![a / b], Default::default())));
+
+        for s in ["a/", "/b", "a//b"] {
+            assert_eq!(s.parse::<AnyDualStress>(), Err(Error::InvalidLetter), "{s}");
+            assert_eq!(s.parse::<AdjectiveStress>(), Err(Error::InvalidLetter), "{s}");
+            assert_eq!(s.parse::<VerbStress>(), Err(Error::InvalidLetter), "{s}");
+            assert_eq!(AnyDualStress::parse_relaxed(s), Err(Error::InvalidLetter), "{s}");
+        }
+        assert_eq!("a/b/".parse::<AnyDualStress>(), Err(Error::Invalid));
+
+        // The single stresses don't accept the '/' at all
+        for s in ["a/b", "a/", "/b"] {
+            let expected = if s.starts_with('/') { Error::InvalidLetter } else { Error::Invalid };
+            assert_eq!(s.parse::<AnyStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<NounStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<PronounStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<AdjectiveFullStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<AdjectiveShortStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<VerbPresentStress>().map(|_| ()), Err(expected), "{s}");
+            assert_eq!(s.parse::<VerbPastStress>().map(|_| ()), Err(expected), "{s}");
+        }
+
+        // and neither do the declensions, other than the adjective ones
+        use crate::declension::{
+            AdjectiveDeclension, Declension, NounDeclension, ParseDeclensionError,
+        };
+        let decl = "п 1a/b".parse::<Declension>().unwrap();
+        assert_eq!(decl.stress(), stress![a / b]);
+        assert_eq!("1a/b".parse::<AdjectiveDeclension>().unwrap().stress, stress![a / b]);
+        assert_eq!(
+            "п 1a/".parse::<Declension>(),
+            Err(ParseDeclensionError::InvalidStress(Error::InvalidLetter)),
+        );
+        assert_eq!(
+            "1/b".parse::<AdjectiveDeclension>(),
+            Err(ParseDeclensionError::InvalidStress(Error::InvalidLetter)),
+        );
+        assert!("1a/b".parse::<NounDeclension>().is_err());
+    }
+
+    #[test]
+    fn parse_relaxed() {
+        type R = StressRelaxations;
+
+        let assert = |s: &str, expected: AnyDualStress, relaxations: R| {
+            assert_eq!(AnyDualStress::parse_relaxed(s), Ok((expected, relaxations)), "{s}");
+        };
+
+        assert("a", stress![a], R::empty());
+        assert("a/c′", stress![a / c1], R::empty());
+        assert("a:c′", stress![a / c1], R::ALTERNATIVE_SLASH);
+        assert("a⁄c′", stress![a / c1], R::ALTERNATIVE_SLASH);
+        assert("a∕c′", stress![a / c1], R::ALTERNATIVE_SLASH);
+        assert("a / c′", stress![a / c1], R::SPACED_SLASH);
+        assert("a /c′", stress![a / c1], R::SPACED_SLASH);
+        assert("b′/ c″", stress![b1 / c2], R::SPACED_SLASH);
+        assert("a ⁄ c′", stress![a / c1], R::ALTERNATIVE_SLASH | R::SPACED_SLASH);
+
+        assert_eq!(AnyDualStress::parse_relaxed("a  / c′"), Err(Error::Invalid));
+        assert_eq!(AnyDualStress::parse_relaxed("a::c′"), Err(Error::InvalidLetter));
+        assert_eq!(AnyDualStress::parse_relaxed("a;c′"), Err(Error::Invalid));
+        assert_eq!(AnyDualStress::parse_relaxed(" a"), Err(Error::InvalidLetter));
+
+        // The strict parser still rejects them
+        for s in ["a:c′", "a⁄c′", "a∕c′", "a / c′"] {
+            assert!(s.parse::<AnyDualStress>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn input_too_long() {
+        let huge = "a".repeat(10 << 20);
+        assert_eq!(huge.parse::<AnyStress>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<AnyDualStress>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<NounStress>(), Err(Error::InputTooLong));
+        assert_eq!(huge.parse::<AdjectiveStress>(), Err(Error::InputTooLong));
+        assert_eq!(AnyDualStress::parse_relaxed(&huge), Err(Error::InputTooLong));
+        let huge_slashes = format!("a{}c", " / ".repeat(1 << 20));
+        assert_eq!(AnyDualStress::parse_relaxed(&huge_slashes), Err(Error::InputTooLong));
+
+        // Inputs up to the limit are scanned as usual
+        let padded = "a".repeat(STRESS_INPUT_MAX_LEN);
+        assert_eq!(padded.parse::<AnyStress>(), Err(Error::Invalid));
+        assert_eq!("f″/f″".parse::<AnyDualStress>().map(|_| ()), Ok(()));
+        assert_eq!(AnyDualStress::parse_relaxed("f″ ∕ f″").map(|_| ()), Ok(()));
+    }
 }
@@ -11,6 +11,11 @@ pub mod aliases {
     define_empty_structs! { Unset, a, b, c, d, e, f, a1, b1, c1, d1, e1, f1, c2, f2 }
 }
 
+#[diagnostic::on_unimplemented(
+    message = "the stress schema is not valid for `{T}`",
+    label = "invalid stress schema for `{T}`",
+    note = "`{T}::VALUES` lists the stress schemas that it supports"
+)]
 pub const trait StressConst<T> {
     const STRESS: T;
 }
@@ -33,6 +38,12 @@ define_aliases!(VerbPastStress: A a, B b, C c, Cp c1, Cpp c2);
 
 pub struct Builder<Main, Alt>(Main, Alt);
 
+#[diagnostic::on_unimplemented(
+    message = "`{T}` can't be constructed from this stress schema",
+    label = "invalid stress schema for `{T}`",
+    note = "dual stress schemas (`x/y`) are only valid for `AnyDualStress`, `AdjectiveStress` and `VerbStress`",
+    note = "`AdjectiveStress` can be constructed from a single schema only if it's `a`, `b`, `a1` or `b1`, other short form schemas need the full form schema too: `a/c`, `b/c2`"
+)]
 pub const trait Build<T> {
     const RESULT: T;
 }
@@ -135,7 +146,8 @@ impl<X: StressConst<AdjectiveShortStress> + IsStressAOrB> const Build<AdjectiveS
 /// inflect_adj("word", stress![b / c2]);
 /// ```
 ///
-/// Invalid stress values cannot be constructed, and give a compilation error:
+/// Invalid stress values cannot be constructed, and give a compilation error, explaining which
+/// schemas are valid for the type:
 /// ```compile_fail
 /// # use grammar_russian::{stress, stress::*};
 /// #
@@ -160,7 +172,7 @@ pub use stress;
 mod tests {
     use crate::stress::*;
 
-    fn assert_eq<T: std::fmt::Debug + PartialEq>(left: T, right: T) {
+    fn assert_eq<T: core::fmt::Debug + PartialEq>(left: T, right: T) {
         assert_eq!(left, right);
     }
     #[allow(unused)]
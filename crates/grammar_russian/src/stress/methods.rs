@@ -1,6 +1,6 @@
 use crate::{
-    categories::{Case, Gender, HasNumber, Number},
-    declension::DeclInfo,
+    categories::{Case, GenderOrPlural, HasNumber, Number, Person},
+    declension::{AdjectiveForm, DeclInfo},
     stress::{
         AdjectiveFullStress, AdjectiveShortStress, AdjectiveStress, AnyDualStress, AnyStress,
         NounStress, PronounStress, VerbPastStress, VerbPresentStress, VerbStress,
@@ -49,10 +49,48 @@ impl AnyStress {
     }
 }
 
+/// Trait for stresses that can be abbreviated to their shortest dictionary spelling.
+///
+/// Parsing the abbreviation back into the same type is guaranteed to return the original value.
+pub const trait Abbreviate {
+    fn abbr(&self) -> AnyDualStress;
+}
+
+impl const Abbreviate for AnyStress {
+    fn abbr(&self) -> AnyDualStress {
+        (*self).into()
+    }
+}
+impl const Abbreviate for AnyDualStress {
+    fn abbr(&self) -> AnyDualStress {
+        *self
+    }
+}
+impl const Abbreviate for AdjectiveStress {
+    fn abbr(&self) -> AnyDualStress {
+        AdjectiveStress::abbr(*self)
+    }
+}
+impl const Abbreviate for VerbStress {
+    fn abbr(&self) -> AnyDualStress {
+        VerbStress::abbr(*self)
+    }
+}
+
+// Adjective and verb stresses are abbreviated differently in the dictionary, and so, a stress
+// without an alternative one is normalized differently, depending on the word's kind:
+// - adjectives: `x` means `x/x`, and `x′`/`x″` means `x/x′`/`x/x″` (full form stress is never
+//   primed, so the prime can only belong to the short form stress);
+// - verbs: `x` means `x/a`, and `x′` means `x′/a` (past tense stress `a` is the most common).
+// These are the only implementations of the rules; AdjectiveStress and VerbStress delegate here.
 impl AnyDualStress {
+    /// Abbreviates the stress of an adjective: `a/a` to `a`, `b/b′` to `b′`, and so on.
+    /// Stresses that can't be abbreviated are returned as is.
     pub const fn abbr_adj(self) -> AnyDualStress {
         self.try_abbr_adj().map_or(self, AnyDualStress::from)
     }
+    /// Abbreviates the stress of an adjective: `a/a` to `a`, `b/b′` to `b′`, and so on.
+    /// Returns `None`, if the stress can't be abbreviated.
     pub const fn try_abbr_adj(self) -> Option<AnyStress> {
         if let Some(alt) = self.alt
             && !self.main.has_any_primes()
@@ -62,69 +100,94 @@ impl AnyDualStress {
         }
         None
     }
+    /// Abbreviates the stress of a verb: `a/a` to `a`, `c′/a` to `c′`, and so on.
+    /// Stresses that can't be abbreviated are returned as is.
     pub const fn abbr_verb(self) -> AnyDualStress {
         self.try_abbr_verb().map_or(self, AnyDualStress::from)
     }
+    /// Abbreviates the stress of a verb: `a/a` to `a`, `c′/a` to `c′`, and so on.
+    /// Returns `None`, if the stress can't be abbreviated.
     pub const fn try_abbr_verb(self) -> Option<AnyStress> {
         match self.alt {
             Some(AnyStress::A) => Some(self.main),
             _ => None,
         }
     }
+
+    /// Returns the full and short form stresses of an adjective: `a` as `a/a`, `b′` as `b/b′`.
+    /// The inverse of [`abbr_adj`][Self::abbr_adj].
+    pub const fn normalize_adj(self) -> (AnyStress, AnyStress) {
+        if let Some(alt) = self.alt { (self.main, alt) } else { (self.main.unprime(), self.main) }
+    }
+    /// Returns the present and past tense stresses of a verb: `b` as `b/a`, `c′` as `c′/a`.
+    /// The inverse of [`abbr_verb`][Self::abbr_verb].
+    pub const fn normalize_verb(self) -> (AnyStress, AnyStress) {
+        (self.main, self.alt.unwrap_or(AnyStress::A))
+    }
 }
 impl AdjectiveStress {
     pub const fn abbr(self) -> AnyDualStress {
-        if let Some(abbr) = self.try_abbr() { abbr.into() } else { self.into() }
+        AnyDualStress::from(self).abbr_adj()
     }
     pub const fn try_abbr(self) -> Option<AdjectiveShortStress> {
-        match self {
-            Self::A_A => Some(AdjectiveShortStress::A),
-            Self::B_B => Some(AdjectiveShortStress::B),
-            Self::A_Ap => Some(AdjectiveShortStress::Ap),
-            Self::B_Bp => Some(AdjectiveShortStress::Bp),
-            _ => None,
+        match AnyDualStress::from(self).try_abbr_adj() {
+            // The abbreviation is always the short form stress
+            Some(abbr) => match abbr.try_into() {
+                Ok(abbr) => Some(abbr),
+                Err(_) => None,
+            },
+            None => None,
         }
     }
 }
 impl VerbStress {
     pub const fn abbr(self) -> AnyDualStress {
-        if let Some(abbr) = self.try_abbr() { abbr.into() } else { self.into() }
+        AnyDualStress::from(self).abbr_verb()
     }
     pub const fn try_abbr(self) -> Option<VerbPresentStress> {
-        match self.past {
-            VerbPastStress::A => Some(self.present),
-            _ => None,
+        match AnyDualStress::from(self).try_abbr_verb() {
+            // The abbreviation is always the present tense stress
+            Some(abbr) => match abbr.try_into() {
+                Ok(abbr) => Some(abbr),
+                Err(_) => None,
+            },
+            None => None,
         }
     }
 }
 
-impl AnyDualStress {
-    pub const fn normalize_adj(self) -> (AnyStress, AnyStress) {
-        if let Some(alt) = self.alt { (self.main, alt) } else { (self.main.unprime(), self.main) }
-    }
-    pub const fn normalize_verb(self) -> (AnyStress, AnyStress) {
-        (self.main, self.alt.unwrap_or(AnyStress::A))
-    }
-}
-
 impl NounStress {
     pub const fn is_stem_stressed(self, info: DeclInfo) -> bool {
-        // Note: `is_nom_with` is called only when number is plural, that is, when the
+        // Note: `is_nom_or_acc_inan` is called only when number is plural, that is, when the
         // accusative case maps to either nominative or genitive depending on animacy.
+        //
+        // Schemas with special singular accusative/instrumental (b′, d′, f′, f″) are only found
+        // in feminine nouns, where the singular accusative has its own ending, so the singular
+        // accusative here doesn't need to be resolved into nominative or genitive.
 
         match self {
+            // a: stem everywhere (заво́д, ко́мната)
             Self::A => true,
+            // b: ending everywhere (стола́, ножа́)
             Self::B => false,
+            // c: singular on stem, plural on ending (сад - сады́)
             Self::C => info.is_singular(),
+            // d: singular on ending, plural on stem (вино́ - ви́на)
             Self::D => info.is_plural(),
+            // e: singular and plural nominative on stem, other plural on ending (зуб - зу́бы - зубо́в)
             Self::E => info.is_singular() || info.case.is_nom_or_acc_inan(info),
+            // f: plural nominative on stem, all other on ending (губа́ - гу́бы - губа́м)
             Self::F => info.is_plural() && info.case.is_nom_or_acc_inan(info),
+            // b′: like b, but singular instrumental on stem (любо́вь - любо́вью)
             Self::Bp => info.is_singular() && matches!(info.case, Case::Instrumental),
+            // d′: like d, but singular accusative on stem (вода́ - во́ду - во́ды)
             Self::Dp => info.is_plural() || matches!(info.case, Case::Accusative),
+            // f′: like f, but singular accusative on stem (рука́ - ру́ку - ру́ки - рука́м)
             Self::Fp => match info.number {
                 Number::Singular => matches!(info.case, Case::Accusative),
                 Number::Plural => info.case.is_nom_or_acc_inan(info),
             },
+            // f″: like f, but singular instrumental on stem
             Self::Fpp => match info.number {
                 Number::Singular => matches!(info.case, Case::Instrumental),
                 Number::Plural => info.case.is_nom_or_acc_inan(info),
@@ -158,29 +221,319 @@ impl AdjectiveFullStress {
     }
 }
 impl AdjectiveShortStress {
-    pub const fn is_stem_stressed(self, gender: Gender, number: Number) -> bool {
+    /// Returns whether the short form of the specified gender, or the plural, is stressed on the
+    /// stem.
+    pub const fn is_stem_stressed(self, form: GenderOrPlural) -> bool {
+        use GenderOrPlural::*;
+        match self {
+            Self::A | Self::Ap => true,
+            Self::B => false,
+            // c: only feminine on ending (мо́лод - молода́ - мо́лодо - мо́лоды)
+            Self::C => !matches!(form, Feminine),
+            // b′: only masculine on stem (хоро́ш - хороша́ - хорошо́ - хороши́)
+            Self::Bp => matches!(form, Masculine),
+            // c′, c″: feminine and plural on ending
+            Self::Cp | Self::Cpp => matches!(form, Masculine | Neuter),
+        }
+    }
+    pub const fn is_ending_stressed(self, form: GenderOrPlural) -> bool {
+        !self.is_stem_stressed(form)
+    }
+}
+impl AdjectiveStress {
+    /// Returns whether the full or short form of the adjective is stressed on the stem. Full forms
+    /// follow the full form stress, and short forms follow the short form stress.
+    ///
+    /// # Examples
+    /// ```
+    /// use grammar_russian::{categories::*, declension::*, stress::*};
+    ///
+    /// let stress: AdjectiveStress = "a/c".parse().unwrap();
+    /// let info = DeclInfo::new(Case::Dative, Number::Plural, Gender::Feminine, Animacy::Inanimate);
+    /// assert!(stress.is_stem_stressed(AdjectiveForm::Full(info)));
+    /// assert!(!stress.is_stem_stressed(AdjectiveForm::Short(GenderOrPlural::Feminine)));
+    /// ```
+    pub const fn is_stem_stressed(self, form: AdjectiveForm) -> bool {
+        match form {
+            AdjectiveForm::Full(_) => self.full.is_stem_stressed(),
+            AdjectiveForm::Short(form) => self.short.is_stem_stressed(form),
+        }
+    }
+    pub const fn is_ending_stressed(self, form: AdjectiveForm) -> bool {
+        !self.is_stem_stressed(form)
+    }
+}
+
+impl VerbPresentStress {
+    /// Returns whether the present (or simple future) tense form in the specified person and
+    /// number is stressed on the stem.
+    pub const fn is_stem_stressed(self, person: Person, number: Number) -> bool {
+        let first_singular = matches!(person, Person::First) && matches!(number, Number::Singular);
         match self {
             Self::A => true,
             Self::B => false,
-            Self::C => {
-                matches!(number, Number::Plural)
-                    || matches!(gender, Gender::Masculine | Gender::Neuter)
-            },
-            Self::Ap => true,
-            Self::Bp => matches!(number, Number::Singular) && matches!(gender, Gender::Masculine),
-            Self::Cp => {
-                matches!(number, Number::Singular)
-                    && matches!(gender, Gender::Masculine | Gender::Neuter)
-            },
-            Self::Cpp => {
-                matches!(number, Number::Singular)
-                    && matches!(gender, Gender::Masculine | Gender::Neuter)
-            },
+            // c: only 1st person singular on ending (люблю́ - лю́бишь - лю́бят)
+            Self::C => !first_singular,
+            // c′: 1st person singular and plural on ending (хочу́ - хо́чешь - хоти́м)
+            Self::Cp => !first_singular && matches!(number, Number::Singular),
+        }
+    }
+    pub const fn is_ending_stressed(self, person: Person, number: Number) -> bool {
+        !self.is_stem_stressed(person, number)
+    }
+    /// Returns whether the imperative forms are stressed on the ending. Only `a` has the stress on
+    /// the stem, the same as in all the present tense forms.
+    pub const fn is_imperative_ending_stressed(self) -> bool {
+        !matches!(self, Self::A)
+    }
+}
+impl VerbPastStress {
+    /// Returns whether the past tense form of the specified gender, or the plural, is stressed on
+    /// the stem.
+    pub const fn is_stem_stressed(self, form: GenderOrPlural) -> bool {
+        match self {
+            Self::A => true,
+            Self::B => false,
+            // c, c′: only feminine on ending (бра́л - брала́ - бра́ло - бра́ли)
+            Self::C | Self::Cp => !matches!(form, GenderOrPlural::Feminine),
+            // c″: only masculine on stem (начался - начала́сь - начало́сь - начали́сь)
+            Self::Cpp => matches!(form, GenderOrPlural::Masculine),
         }
     }
-    pub const fn is_ending_stressed(self, gender: Gender, number: Number) -> bool {
-        !self.is_stem_stressed(gender, number)
+    pub const fn is_ending_stressed(self, form: GenderOrPlural) -> bool {
+        !self.is_stem_stressed(form)
     }
 }
 
-// TODO: VerbStress methods
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::categories::{Animacy, Gender};
+    use std::string::ToString;
+
+    #[test]
+    fn noun_stress_table() {
+        // Stress schemas of nouns, as defined in Zaliznyak's dictionary.
+        // Each string lists NOM, GEN, DAT, ACC, INS, PRP: 's' - stem stress, 'e' - ending stress.
+        #[rustfmt::skip]
+        let table = [
+            //                sg inan,  sg an,    pl inan,  pl an
+            (NounStress::A,   "ssssss", "ssssss", "ssssss", "ssssss"),
+            (NounStress::B,   "eeeeee", "eeeeee", "eeeeee", "eeeeee"),
+            (NounStress::C,   "ssssss", "ssssss", "eeeeee", "eeeeee"),
+            (NounStress::D,   "eeeeee", "eeeeee", "ssssss", "ssssss"),
+            (NounStress::E,   "ssssss", "ssssss", "seesee", "seeeee"),
+            (NounStress::F,   "eeeeee", "eeeeee", "seesee", "seeeee"),
+            (NounStress::Bp,  "eeeese", "eeeese", "eeeeee", "eeeeee"),
+            (NounStress::Dp,  "eeesee", "eeesee", "ssssss", "ssssss"),
+            (NounStress::Fp,  "eeesee", "eeesee", "seesee", "seeeee"),
+            (NounStress::Fpp, "eeeese", "eeeese", "seesee", "seeeee"),
+        ];
+
+        for (stress, sg_inan, sg_an, pl_inan, pl_an) in table {
+            let rows = [
+                (Number::Singular, Animacy::Inanimate, sg_inan),
+                (Number::Singular, Animacy::Animate, sg_an),
+                (Number::Plural, Animacy::Inanimate, pl_inan),
+                (Number::Plural, Animacy::Animate, pl_an),
+            ];
+            for (number, animacy, expected) in rows {
+                for (case, expected) in Case::VALUES.into_iter().zip(expected.bytes()) {
+                    let info = DeclInfo { case, number, gender: Gender::Feminine, animacy };
+                    assert_eq!(
+                        stress.is_stem_stressed(info),
+                        expected == b's',
+                        "{stress} {case} {number} {animacy}",
+                    );
+                    assert_eq!(stress.is_ending_stressed(info), expected == b'e');
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn abbr_round_trip() {
+        fn assert<T>(value: T)
+        where T: Abbreviate
+                + core::str::FromStr<Err: core::fmt::Debug>
+                + PartialEq
+                + core::fmt::Debug {
+            let abbr = value.abbr().to_string();
+            assert_eq!(abbr.parse::<T>().unwrap(), value, "{abbr}");
+        }
+
+        for stress in AnyStress::VALUES {
+            assert(stress);
+            assert(AnyDualStress::from(stress));
+            for alt in AnyStress::VALUES {
+                assert(AnyDualStress::new(stress, Some(alt)));
+            }
+        }
+        for full in AdjectiveFullStress::VALUES {
+            for short in AdjectiveShortStress::VALUES {
+                assert(AdjectiveStress::new(full, short));
+            }
+        }
+        for present in VerbPresentStress::VALUES {
+            for past in VerbPastStress::VALUES {
+                assert(VerbStress::new(present, past));
+            }
+        }
+    }
+
+    #[test]
+    fn normalize_abbr() {
+        use AnyStress::*;
+
+        // Stresses without an alternative, as normalized for adjectives and verbs
+        #[rustfmt::skip]
+        let table = [
+            (A,   (A, A),   (A, A)),
+            (B,   (B, B),   (B, A)),
+            (C,   (C, C),   (C, A)),
+            (D,   (D, D),   (D, A)),
+            (E,   (E, E),   (E, A)),
+            (F,   (F, F),   (F, A)),
+            (Ap,  (A, Ap),  (Ap, A)),
+            (Bp,  (B, Bp),  (Bp, A)),
+            (Cp,  (C, Cp),  (Cp, A)),
+            (Dp,  (D, Dp),  (Dp, A)),
+            (Ep,  (E, Ep),  (Ep, A)),
+            (Fp,  (F, Fp),  (Fp, A)),
+            (Cpp, (C, Cpp), (Cpp, A)),
+            (Fpp, (F, Fpp), (Fpp, A)),
+        ];
+        assert_eq!(table.map(|x| x.0), AnyStress::VALUES);
+
+        for (stress, adj, verb) in table {
+            let dual = AnyDualStress::from(stress);
+            assert_eq!(dual.normalize_adj(), adj, "{stress}");
+            assert_eq!(dual.normalize_verb(), verb, "{stress}");
+
+            // The normalized forms are abbreviated back into the original stress
+            assert_eq!(AnyDualStress::new(adj.0, Some(adj.1)).abbr_adj(), dual, "{stress}");
+            assert_eq!(AnyDualStress::new(verb.0, Some(verb.1)).abbr_verb(), dual, "{stress}");
+        }
+
+        // Abbreviations of all the other stresses are normalized back into the same stress
+        for main in AnyStress::VALUES {
+            for alt in AnyStress::VALUES {
+                let dual = AnyDualStress::new(main, Some(alt));
+                assert_eq!(dual.abbr_adj().normalize_adj(), (main, alt), "{dual}");
+                assert_eq!(dual.abbr_verb().normalize_verb(), (main, alt), "{dual}");
+            }
+        }
+
+        // Adjective and verb stresses are abbreviated the same way as AnyDualStress
+        for full in AdjectiveFullStress::VALUES {
+            for short in AdjectiveShortStress::VALUES {
+                let stress = AdjectiveStress::new(full, short);
+                assert_eq!(stress.abbr(), AnyDualStress::from(stress).abbr_adj());
+                assert_eq!(
+                    stress.try_abbr().map(AnyStress::from),
+                    AnyDualStress::from(stress).try_abbr_adj()
+                );
+            }
+        }
+        for present in VerbPresentStress::VALUES {
+            for past in VerbPastStress::VALUES {
+                let stress = VerbStress::new(present, past);
+                assert_eq!(stress.abbr(), AnyDualStress::from(stress).abbr_verb());
+                assert_eq!(
+                    stress.try_abbr().map(AnyStress::from),
+                    AnyDualStress::from(stress).try_abbr_verb()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn adjective_stress_table() {
+        // Each string lists the short forms: masc, n, fem, pl
+        #[rustfmt::skip]
+        let short = [
+            (AdjectiveShortStress::A,   "ssss"),
+            (AdjectiveShortStress::B,   "eeee"),
+            (AdjectiveShortStress::C,   "sses"),
+            (AdjectiveShortStress::Ap,  "ssss"),
+            (AdjectiveShortStress::Bp,  "seee"),
+            (AdjectiveShortStress::Cp,  "ssee"),
+            (AdjectiveShortStress::Cpp, "ssee"),
+        ];
+        assert_eq!(short.map(|x| x.0), AdjectiveShortStress::VALUES);
+        let full = [(AdjectiveFullStress::A, true), (AdjectiveFullStress::B, false)];
+        assert_eq!(full.map(|x| x.0), AdjectiveFullStress::VALUES);
+
+        for (full, full_stem_stressed) in full {
+            for (short, expected) in short {
+                let stress = AdjectiveStress::new(full, short);
+
+                // Full forms are stressed the same way in all cases, numbers and genders
+                for info in DeclInfo::iter_all() {
+                    let form = AdjectiveForm::Full(info);
+                    assert_eq!(stress.is_stem_stressed(form), full_stem_stressed, "{stress}");
+                    assert_eq!(stress.is_ending_stressed(form), !full_stem_stressed);
+                }
+                for (form, expected) in GenderOrPlural::VALUES.into_iter().zip(expected.bytes()) {
+                    assert_eq!(short.is_stem_stressed(form), expected == b's', "{short}");
+                    let form = AdjectiveForm::Short(form);
+                    assert_eq!(stress.is_stem_stressed(form), expected == b's', "{stress}");
+                    assert_eq!(stress.is_ending_stressed(form), expected == b'e');
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn verb_stress_table() {
+        // Each string lists 1sg, 2sg, 3sg, 1pl, 2pl, 3pl: 's' - stem stress, 'e' - ending stress.
+        #[rustfmt::skip]
+        let present = [
+            (VerbPresentStress::A,  "ssssss"),
+            (VerbPresentStress::B,  "eeeeee"),
+            (VerbPresentStress::C,  "esssss"),
+            (VerbPresentStress::Cp, "esseee"),
+        ];
+        for (stress, expected) in present {
+            let forms = Number::VALUES.into_iter().flat_map(|n| Person::VALUES.map(|p| (p, n)));
+            for ((person, number), expected) in forms.zip(expected.bytes()) {
+                assert_eq!(stress.is_stem_stressed(person, number), expected == b's', "{stress}");
+                assert_eq!(stress.is_ending_stressed(person, number), expected == b'e');
+            }
+            assert_eq!(stress.is_imperative_ending_stressed(), stress != VerbPresentStress::A);
+        }
+
+        // Each string lists masc, n, fem, pl
+        #[rustfmt::skip]
+        let past = [
+            (VerbPastStress::A,   "ssss"),
+            (VerbPastStress::B,   "eeee"),
+            (VerbPastStress::C,   "sses"),
+            (VerbPastStress::Cp,  "sses"),
+            (VerbPastStress::Cpp, "seee"),
+        ];
+        for (stress, expected) in past {
+            for (form, expected) in GenderOrPlural::VALUES.into_iter().zip(expected.bytes()) {
+                assert_eq!(stress.is_stem_stressed(form), expected == b's', "{stress}");
+                assert_eq!(stress.is_ending_stressed(form), expected == b'e');
+            }
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        assert_eq!(AnyStress::default(), AnyStress::A);
+        assert_eq!(NounStress::default(), NounStress::A);
+        assert_eq!(PronounStress::default(), PronounStress::A);
+        assert_eq!(AdjectiveStress::default(), AdjectiveStress::A);
+        assert_eq!(VerbStress::default(), VerbStress::A);
+        assert_eq!(AnyDualStress::default(), AnyDualStress::new(AnyStress::A, None));
+
+        // The defaults are abbreviated to the unmarked `a`
+        let a = AnyDualStress::new(AnyStress::A, None);
+        assert_eq!(AnyStress::default().abbr(), a);
+        assert_eq!(AnyDualStress::default().abbr(), a);
+        assert_eq!(AdjectiveStress::default().abbr(), a);
+        assert_eq!(VerbStress::default().abbr(), a);
+    }
+}
@@ -6,15 +6,24 @@ mod methods;
 pub use convert::*;
 pub use fmt::*;
 pub use from_str::*;
+pub use methods::*;
 
 #[doc(hidden)]
 pub mod macro_internals;
 
 pub use macro_internals::stress;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A stress schema of any word.
+///
+/// The schemas are ordered as in the dictionary's index: the plain schemas `a`-`f` first, then
+/// the schemas with a single prime `a′`-`f′`, and then the ones with a double prime `c″` and `f″`.
+/// The word-specific schemas ([`NounStress`], [`AdjectiveShortStress`], etc.) are ordered the same
+/// way, and the dual schemas are ordered by the main schema first, with a missing alternative
+/// schema before any other.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AnyStress {
     /// Stress schema `a`. The stress is always on the stem. Used by all inflectable words.
+    #[default]
     A = 1,
     /// Stress schema `b`. The stress is always on the ending. Used by all inflectable words.
     B,
@@ -63,9 +72,10 @@ pub enum AnyStress {
     Fpp,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum NounStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
@@ -86,25 +96,28 @@ pub enum NounStress {
     /// Stress schema `f″` (`f` with double prime). Singular instrumental, and plural nominative - stress on stem, all other - stress on ending.
     Fpp,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PronounStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
     /// Stress schema `f`. Plural nominative - stress on stem, all other - stress on ending.
     F,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AdjectiveFullStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AdjectiveShortStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
@@ -119,9 +132,10 @@ pub enum AdjectiveShortStress {
     /// Stress schema `c″` (`c` with double prime). Feminine - stress on ending, all other - both??? (resolved as on ending).
     Cpp,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum VerbPresentStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
@@ -130,9 +144,10 @@ pub enum VerbPresentStress {
     /// Stress schema `c′` (`c` with single prime). First person, imperative, and plural - stress on ending, all other - stress on stem.
     Cp,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum VerbPastStress {
     /// Stress schema `a`. Stress is always on the stem.
+    #[default]
     A,
     /// Stress schema `b`. Stress is always on the ending.
     B,
@@ -144,17 +159,21 @@ pub enum VerbPastStress {
     Cpp,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A main stress schema with an optional alternative one, e.g. `a/c′`.
+/// Defaults to `a`, the schema of most words in the dictionary.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AnyDualStress {
     pub main: AnyStress,
     pub alt: Option<AnyStress>,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Stress schemas of the full and short forms of an adjective. Defaults to `a/a`, written as `a`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct AdjectiveStress {
     pub full: AdjectiveFullStress,
     pub short: AdjectiveShortStress,
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Stress schemas of the present and past tense forms of a verb. Defaults to `a/a`, written as `a`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct VerbStress {
     pub present: VerbPresentStress,
     pub past: VerbPastStress,
@@ -176,6 +195,36 @@ impl VerbStress {
     }
 }
 
+impl AnyStress {
+    #[rustfmt::skip]
+    pub const VALUES: [AnyStress; 14] = [
+        Self::A, Self::B, Self::C, Self::D, Self::E, Self::F,
+        Self::Ap, Self::Bp, Self::Cp, Self::Dp, Self::Ep, Self::Fp, Self::Cpp, Self::Fpp,
+    ];
+}
+impl NounStress {
+    #[rustfmt::skip]
+    pub const VALUES: [NounStress; 10] = [
+        Self::A, Self::B, Self::C, Self::D, Self::E, Self::F, Self::Bp, Self::Dp, Self::Fp, Self::Fpp,
+    ];
+}
+impl PronounStress {
+    pub const VALUES: [PronounStress; 3] = [Self::A, Self::B, Self::F];
+}
+impl AdjectiveFullStress {
+    pub const VALUES: [AdjectiveFullStress; 2] = [Self::A, Self::B];
+}
+impl AdjectiveShortStress {
+    pub const VALUES: [AdjectiveShortStress; 7] =
+        [Self::A, Self::B, Self::C, Self::Ap, Self::Bp, Self::Cp, Self::Cpp];
+}
+impl VerbPresentStress {
+    pub const VALUES: [VerbPresentStress; 4] = [Self::A, Self::B, Self::C, Self::Cp];
+}
+impl VerbPastStress {
+    pub const VALUES: [VerbPastStress; 5] = [Self::A, Self::B, Self::C, Self::Cp, Self::Cpp];
+}
+
 #[allow(non_upper_case_globals)]
 impl AdjectiveStress {
     pub const A: Self = Self::new(AdjectiveFullStress::A, AdjectiveShortStress::A);
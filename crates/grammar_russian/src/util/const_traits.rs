@@ -3,8 +3,8 @@
 pub(crate) const trait _Result<T, E> {
     fn _unwrap(self) -> T;
 }
-impl<T, E: std::fmt::Debug> const _Result<T, E> for Result<T, E>
-where Result<T, E>: [const] std::marker::Destruct
+impl<T, E: core::fmt::Debug> const _Result<T, E> for Result<T, E>
+where Result<T, E>: [const] core::marker::Destruct
 {
     fn _unwrap(self) -> T {
         if let Ok(x) = self { x } else { panic!("called `_Result::_unwrap()` on an `Err` value") }
@@ -16,12 +16,12 @@ where Result<T, E>: [const] std::marker::Destruct
 pub(crate) const trait _Tryable<T, E> {
     fn _as_result(self) -> Result<T, E>;
 }
-impl<T: [const] std::marker::Destruct, E> const _Tryable<T, E> for Result<T, E> {
+impl<T: [const] core::marker::Destruct, E> const _Tryable<T, E> for Result<T, E> {
     fn _as_result(self) -> Result<T, E> {
         self
     }
 }
-impl<T: [const] std::marker::Destruct> const _Tryable<T, ()> for Option<T> {
+impl<T: [const] core::marker::Destruct> const _Tryable<T, ()> for Option<T> {
     fn _as_result(self) -> Result<T, ()> {
         self.ok_or(())
     }
@@ -1,4 +1,4 @@
-use std::intrinsics::const_eval_select;
+use core::intrinsics::const_eval_select;
 extern crate memchr;
 
 // FIXME(const-hack): Remove this and replace calls when `haystack.find(needle)` is introduced and constified.
@@ -72,4 +72,26 @@ macro_rules! utf8_bytes {
     }};
 }
 
-pub(crate) use {enum_conversion, utf8_bytes};
+// Implements const fmt_to_buf methods, that format the values into owned buffers
+macro_rules! impl_fmt_to_buf {
+    ($($t:ty => $len:expr),+ $(,)?) => ($(
+        impl $t {
+            /// Formats the value into an owned buffer, that dereferences to a string slice.
+            /// See [`fmt_to`][Self::fmt_to] for the format.
+            pub const fn fmt_to_buf(self) -> $crate::FmtBuf<{ $len }> {
+                let mut buf = [0; $len];
+                let len = self.fmt_to(&mut buf).len();
+                $crate::FmtBuf::from_parts(buf, len)
+            }
+        }
+    )+);
+}
+
+pub(crate) use {enum_conversion, impl_fmt_to_buf, utf8_bytes};
+
+#[cfg(test)]
+pub(crate) mod snapshot;
+#[cfg(test)]
+pub(crate) use snapshot::*;
+#[cfg(test)]
+pub(crate) mod vectors;
@@ -0,0 +1,38 @@
+//! A small snapshot testing harness. The snapshots are stored in `tests/snapshots/{name}.snap`,
+//! relative to the crate's root. Run the tests with the `UPDATE_SNAPSHOTS` env var set to write
+//! the actual outputs into the snapshots, and then review the changes in the diff.
+
+use std::{env, format, fs, path::PathBuf};
+
+/// Asserts that the text is the same as the contents of the specified snapshot.
+#[track_caller]
+pub(crate) fn assert_snapshot(name: &str, actual: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots", &format!("{name}.snap")]
+        .iter()
+        .collect();
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(&path) else {
+        panic!(
+            "snapshot `{name}` doesn't exist, run the tests with UPDATE_SNAPSHOTS=1 to create it"
+        );
+    };
+    // Git may check out the snapshots with CRLF line endings on Windows
+    let expected = expected.replace("\r\n", "\n");
+
+    let mismatch = actual.lines().zip(expected.lines()).position(|(a, e)| a != e);
+    if let Some(i) = mismatch {
+        panic!(
+            "snapshot `{name}` doesn't match on line {}:\n  expected: {}\n    actual: {}\n\n{actual}",
+            i + 1,
+            expected.lines().nth(i).unwrap(),
+            actual.lines().nth(i).unwrap(),
+        );
+    }
+    assert_eq!(actual, expected, "snapshot `{name}` doesn't match");
+}
@@ -10,7 +10,7 @@ impl<'a> UnsafeBuf<'a> {
     }
 
     pub const fn push_bytes(&mut self, bytes: &[u8]) {
-        unsafe { std::ptr::copy_nonoverlapping(bytes.as_ptr(), self.end, bytes.len()) };
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), self.end, bytes.len()) };
         self.forward(bytes.len());
     }
     pub const fn push_byte(&mut self, byte: u8) {
@@ -31,6 +31,7 @@ impl<'a> UnsafeBuf<'a> {
     pub const fn forward(&mut self, distance: usize) {
         self.end = unsafe { &mut *(self.end as *mut u8).add(distance) };
     }
+    #[allow(dead_code)]
     pub const fn push_fmt<const N: usize>(
         &mut self,
         format: impl [const] FnOnce(&'a mut [u8; N]) -> &'a str,
@@ -43,7 +44,7 @@ impl<'a> UnsafeBuf<'a> {
         unsafe {
             let start = self.start as *const u8 as *mut u8;
             let len = (self.end as *mut u8).offset_from_unsigned(start);
-            str::from_utf8_unchecked_mut(std::slice::from_raw_parts_mut(start, len))
+            str::from_utf8_unchecked_mut(core::slice::from_raw_parts_mut(start, len))
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::Letter;
 
+#[derive(Clone, Copy)]
 pub(crate) struct UnsafeParser<'a> {
     start: &'a u8,
     end: &'a u8,
@@ -15,7 +16,7 @@ impl<'a> UnsafeParser<'a> {
         unsafe { (self.end as *const u8).offset_from_unsigned(self.start as *const u8) }
     }
     pub const fn remaining(&self) -> &'a [u8] {
-        unsafe { std::slice::from_raw_parts(self.start, self.remaining_len()) }
+        unsafe { core::slice::from_raw_parts(self.start, self.remaining_len()) }
     }
     pub const fn remaining_letters(&self) -> &'a [Letter] {
         Letter::from_bytes(self.remaining())
@@ -38,6 +39,7 @@ impl<'a> UnsafeParser<'a> {
         if !self.finished() { Some(self.start) } else { None }
     }
 
+    #[allow(dead_code)]
     pub const fn read<const N: usize>(&mut self) -> Option<&'a [u8; N]> {
         if let Some(chunk) = self.remaining().first_chunk::<N>() {
             self.forward(N);
@@ -57,7 +59,7 @@ impl<'a> UnsafeParser<'a> {
     pub const fn skip_bytes(&mut self, bytes: &[u8]) -> bool {
         // FIXME(const-hack): Replace with `self.remaining().starts_with(bytes)`.
         if self.remaining_len() >= bytes.len() {
-            let peeked = unsafe { std::slice::from_raw_parts(self.start, bytes.len()) };
+            let peeked = unsafe { core::slice::from_raw_parts(self.start, bytes.len()) };
             if peeked == bytes {
                 self.forward(bytes.len());
                 return true;
@@ -68,18 +70,44 @@ impl<'a> UnsafeParser<'a> {
     pub const fn skip_str(&mut self, s: &str) -> bool {
         self.skip_bytes(s.as_bytes())
     }
+    /// Same as [`skip_str`][Self::skip_str], but ignores the case of the Cyrillic letters. The
+    /// other characters must match exactly.
+    pub const fn skip_str_ignore_case(&mut self, s: &str) -> bool {
+        let (bytes, remaining) = (s.as_bytes(), self.remaining());
+        if remaining.len() < bytes.len() {
+            return false;
+        }
+        let mut i = 0;
+        while i < bytes.len() {
+            // Cyrillic letters are 2 bytes long in both cases, starting with D0 or D1
+            if matches!(bytes[i], 0xD0 | 0xD1) {
+                let letter = Letter { utf8: [bytes[i], bytes[i + 1]] };
+                if !letter.eq_ignore_case(Letter { utf8: [remaining[i], remaining[i + 1]] }) {
+                    return false;
+                }
+                i += 2;
+            } else {
+                if bytes[i] != remaining[i] {
+                    return false;
+                }
+                i += 1;
+            }
+        }
+        self.forward(bytes.len());
+        true
+    }
     pub const fn skip(&mut self, ch: char) -> bool {
         self.skip_str(ch.encode_utf8(&mut [0; 4]))
     }
 }
 
-pub const trait PartialParse: std::str::FromStr + Sized {
+pub const trait PartialParse: core::str::FromStr + Sized {
     fn partial_parse(parser: &mut UnsafeParser) -> Result<Self, Self::Err>;
 
     fn from_str_or(s: &str, default_err: Self::Err) -> Result<Self, Self::Err>
     where
-        Self::Err: [const] std::marker::Destruct,
-        Result<Self, Self::Err>: [const] std::marker::Destruct,
+        Self::Err: [const] core::marker::Destruct,
+        Result<Self, Self::Err>: [const] core::marker::Destruct,
     {
         let mut parser = UnsafeParser::new(s);
 
@@ -0,0 +1,263 @@
+//! A correctness harness, that runs the noun inflection over a file of test vectors, and reports
+//! the accuracy along with a breakdown of the failures. It's meant for large external datasets
+//! (OpenCorpora, Wiktionary dumps, vectors of other Zaliznyak implementations), converted into
+//! the format below, and isn't run by default:
+//!
+//! ```sh
+//! GRAMMAR_VECTORS=path/to/nouns.tsv cargo test -p grammar_russian vectors -- --ignored --nocapture
+//! ```
+//!
+//! Without the `GRAMMAR_VECTORS` env var, the small sample in `tests/vectors/sample.tsv` is used.
+//!
+//! # Format
+//!
+//! A UTF-8 text file with one noun per line, and four tab-separated columns: the lemma, the
+//! Zaliznyak gender and animacy marker (м, мо, ж, мо-жо, ...), the declension index (1a, 3*b,
+//! п 4a, 0, ...), and the 12 expected forms in the order of [`CaseAndNumber::VALUES`], separated
+//! by commas. Alternative forms are separated by slashes, and any of them is accepted. Stress
+//! marks (U+0301, U+0300) are ignored. Empty lines and lines starting with '#' are skipped.
+//!
+//! See `tests/vectors/sample.tsv` for an example.
+//!
+//! # Thresholds
+//!
+//! The minimum accuracy of each dataset is recorded in `tests/vectors/thresholds.txt`, as lines
+//! of the dataset's file name (without the extension) and a percentage. The test fails if the
+//! accuracy drops below the threshold, and suggests raising the threshold when it's exceeded.
+//! Datasets without a threshold are only reported.
+
+use crate::{
+    categories::CaseAndNumber,
+    declension::{Declension, InflectError, Noun, NounInfo},
+};
+use std::{
+    collections::BTreeMap,
+    env, format, fs,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The result of checking a single vector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    /// All 12 forms match the expected ones.
+    Correct,
+    /// The line, the gender or the declension index couldn't be parsed.
+    ParseFailure(String),
+    /// The stem couldn't be extracted from the lemma with the specified declension.
+    StemFailure(String),
+    /// The stem doesn't match the alternations of the declension.
+    InflectFailure(InflectError),
+    /// The inflection panicked.
+    Panic,
+    /// Some of the forms don't match, their indices in [`CaseAndNumber::VALUES`].
+    Mismatch(Vec<usize>),
+}
+
+/// Checks a single line of a vectors file. Returns `None` for empty lines and comments.
+pub(crate) fn check_line(line: &str) -> Option<Outcome> {
+    let line = line.trim_end();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let [lemma, gender, index, forms] = line.split('\t').collect::<Vec<_>>()[..] else {
+        return Some(Outcome::ParseFailure("expected 4 tab-separated columns".to_string()));
+    };
+    let expected: Vec<Vec<String>> = forms
+        .split(',')
+        .map(|cell| cell.split('/').map(|x| strip_stress_marks(x.trim())).collect())
+        .collect();
+    if expected.len() != 12 {
+        return Some(Outcome::ParseFailure(format!("expected 12 forms, found {}", expected.len())));
+    }
+
+    let info = match format!("{gender} {index}").parse::<NounInfo>() {
+        Ok(info) => info,
+        Err(err) => return Some(Outcome::ParseFailure(err.to_string())),
+    };
+    let lemma = strip_stress_marks(lemma);
+    let stem = match info.declension {
+        None => lemma.as_str(),
+        Some(Declension::Noun(decl)) => match decl.extract_stem(&lemma, info.declension_gender) {
+            Ok(stem) => stem,
+            Err(err) => return Some(Outcome::StemFailure(err.to_string())),
+        },
+        Some(_) => {
+            let err = "stems of adjective declensions can't be extracted yet";
+            return Some(Outcome::StemFailure(err.to_string()));
+        },
+    };
+
+    let noun = Noun::new(stem, info);
+    let actual = match panic::catch_unwind(AssertUnwindSafe(|| {
+        noun.forms().map(|x| x.map(|(_, _, form)| form)).collect::<Result<Vec<_>, _>>()
+    })) {
+        Ok(Ok(forms)) => forms,
+        Ok(Err(err)) => return Some(Outcome::InflectFailure(err)),
+        Err(_) => return Some(Outcome::Panic),
+    };
+
+    let mismatched: Vec<usize> =
+        (0..12).filter(|&i| !expected[i].iter().any(|x| *x == actual[i])).collect();
+    Some(if mismatched.is_empty() { Outcome::Correct } else { Outcome::Mismatch(mismatched) })
+}
+
+fn strip_stress_marks(s: &str) -> String {
+    s.chars().filter(|ch| !matches!(ch, '\u{0301}' | '\u{0300}')).collect()
+}
+
+/// The accuracy and the failure breakdown of a vectors file.
+#[derive(Debug, Default)]
+pub(crate) struct Report {
+    pub total: usize,
+    pub correct: usize,
+    pub parse_failures: usize,
+    pub stem_failures: usize,
+    pub inflect_failures: usize,
+    pub panics: usize,
+    /// The number of vectors by the number of mismatched cells.
+    pub mismatches: BTreeMap<usize, usize>,
+    /// The number of mismatches by the cell.
+    pub mismatched_cells: [usize; 12],
+    /// The first few failures, for the summary.
+    pub examples: Vec<(String, Outcome)>,
+}
+
+impl Report {
+    const MAX_EXAMPLES: usize = 20;
+
+    pub fn from_lines(text: &str) -> Self {
+        let mut report = Report::default();
+
+        for line in text.lines() {
+            let Some(outcome) = check_line(line) else { continue };
+            report.total += 1;
+
+            match &outcome {
+                Outcome::Correct => report.correct += 1,
+                Outcome::ParseFailure(_) => report.parse_failures += 1,
+                Outcome::StemFailure(_) => report.stem_failures += 1,
+                Outcome::InflectFailure(_) => report.inflect_failures += 1,
+                Outcome::Panic => report.panics += 1,
+                Outcome::Mismatch(cells) => {
+                    *report.mismatches.entry(cells.len()).or_default() += 1;
+                    cells.iter().for_each(|&i| report.mismatched_cells[i] += 1);
+                },
+            }
+            if outcome != Outcome::Correct && report.examples.len() < Self::MAX_EXAMPLES {
+                let lemma = line.split('\t').next().unwrap_or_default();
+                report.examples.push((lemma.to_string(), outcome));
+            }
+        }
+        report
+    }
+
+    pub fn accuracy(&self) -> f64 {
+        if self.total == 0 { 0.0 } else { self.correct as f64 * 100.0 / self.total as f64 }
+    }
+
+    pub fn summary(&self, name: &str) -> String {
+        let mut s = format!(
+            "vectors `{name}`: {}/{} correct ({:.2}%)\n",
+            self.correct,
+            self.total,
+            self.accuracy(),
+        );
+        s += &format!("  parse failures: {}\n", self.parse_failures);
+        s += &format!("  stem extraction failures: {}\n", self.stem_failures);
+        s += &format!("  inflection failures: {}\n", self.inflect_failures);
+        s += &format!("  panics: {}\n", self.panics);
+        for (count, vectors) in &self.mismatches {
+            s += &format!("  {count} cell(s) mismatched: {vectors}\n");
+        }
+
+        if self.mismatched_cells.iter().any(|x| *x > 0) {
+            s += "  mismatches by cell:";
+            for (key, count) in CaseAndNumber::VALUES.iter().zip(self.mismatched_cells) {
+                if count > 0 {
+                    s += &format!(" {key}={count}");
+                }
+            }
+            s += "\n";
+        }
+        for (lemma, outcome) in &self.examples {
+            s += &format!("  {lemma}: {outcome:?}\n");
+        }
+        s
+    }
+}
+
+/// Returns the committed minimum accuracy of the dataset, if there is one.
+pub(crate) fn threshold(name: &str) -> Option<f64> {
+    let text = fs::read_to_string(vectors_dir().join("thresholds.txt")).ok()?;
+
+    text.lines().filter(|x| !x.starts_with('#')).find_map(|line| {
+        let (key, value) = line.split_once(' ')?;
+        (key == name).then(|| value.trim().parse().unwrap())
+    })
+}
+
+fn vectors_dir() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "tests", "vectors"].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{boxed::Box, vec};
+
+    #[test]
+    #[ignore = "runs over a vectors file, set GRAMMAR_VECTORS to the path of a dataset"]
+    fn vectors() {
+        let path = match env::var_os("GRAMMAR_VECTORS") {
+            Some(path) => PathBuf::from(path),
+            None => vectors_dir().join("sample.tsv"),
+        };
+        let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+        let text = fs::read_to_string(&path).unwrap();
+
+        // Silence the panic messages of the failing vectors, they're counted in the report
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let report = Report::from_lines(&text);
+        panic::set_hook(hook);
+
+        std::println!("{}", report.summary(&name));
+
+        // The thresholds are recorded with 2 decimal places
+        let accuracy = (report.accuracy() * 100.0).round() / 100.0;
+        match threshold(&name) {
+            Some(min) if accuracy < min => {
+                panic!("accuracy of `{name}` dropped below the threshold: {accuracy:.2}% < {min}%")
+            },
+            Some(min) if accuracy > min => std::println!(
+                "accuracy of `{name}` is above the threshold, raise it in thresholds.txt: {accuracy:.2}% > {min}%"
+            ),
+            Some(_) => {},
+            None => std::println!(
+                "no threshold for `{name}`, add it to thresholds.txt: {name} {accuracy:.2}"
+            ),
+        }
+    }
+
+    #[test]
+    fn outcomes() {
+        let check = |line: &str| check_line(line).unwrap();
+
+        let line = "стол\tм\t1b\tстол, стола́, столу́, стол, столо́м, столе́, \
+                    столы́, столо́в, стола́м, столы́, стола́ми, стола́х";
+        assert_eq!(check(line), Outcome::Correct);
+        let line = "дно\tс\t1d\tдно, дна, дну, дно, дном, дне, донья, доньев, доньям, донья/днища, доньями, доньях";
+        assert_eq!(check(line), Outcome::Mismatch(vec![6, 7, 8, 9, 10, 11]));
+
+        assert_eq!(check_line(""), None);
+        assert_eq!(check_line("# comment"), None);
+        assert!(matches!(check("стол\tм\t1b"), Outcome::ParseFailure(_)));
+        assert!(matches!(check("стол\tм\t1b\tстол, стола"), Outcome::ParseFailure(_)));
+        assert!(matches!(check("стол\tх\t1b\t,,,,,,,,,,,"), Outcome::ParseFailure(_)));
+        assert!(matches!(check("стол\tж\t1b\t,,,,,,,,,,,"), Outcome::StemFailure(_)));
+    }
+}
@@ -0,0 +1,80 @@
+//! Checks that the buffer APIs inflect the words without allocating, so that they can be used
+//! in `no_std` environments with a limited (or a failing) allocator. Runs with and without the
+//! default features: `cargo test --no-default-features --test no_alloc`.
+
+use grammar_russian::{
+    FORM_MAX_LEN,
+    categories::{Animacy, Case, CaseAndNumber, Gender, Number},
+    declension::{AdjectiveDeclension, DeclInfo, NounDeclension},
+    stress::AnyStress,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+// Counts the allocations made by the current thread, so that the other tests don't interfere
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|x| x.set(x.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn noun_inflection() {
+    let decls: [NounDeclension; 4] = ["3*a", "1d, ё", "8°c, ё", "6*b"].map(|x| x.parse().unwrap());
+    let stems = ["кошк", "жен", "врем", "стать"];
+    let genders = [Gender::Feminine, Gender::Feminine, Gender::Neuter, Gender::Feminine];
+    let mut out = [0; FORM_MAX_LEN];
+
+    let allocations = count_allocations(|| {
+        for ((decl, stem), gender) in decls.into_iter().zip(stems).zip(genders) {
+            for cell in CaseAndNumber::VALUES {
+                let (case, number) = cell.parts();
+                let info = DeclInfo::new(case, number, gender, Animacy::Inanimate);
+                decl.inflect_into(info, stem, &mut out).unwrap();
+            }
+        }
+    });
+    assert_eq!(allocations, 0);
+
+    let info = DeclInfo::new(Case::Genitive, Number::Plural, Gender::Feminine, Animacy::Animate);
+    assert_eq!(decls[0].inflect_into(info, "кошк", &mut out).unwrap(), "кошек");
+}
+
+#[test]
+fn formatting_and_parsing() {
+    let mut out = [0; FORM_MAX_LEN];
+
+    let allocations = count_allocations(|| {
+        let decl: AdjectiveDeclension = "1*a/c″, ё".parse().unwrap();
+        assert_eq!(&*decl.fmt_to_buf(), "1*a/c″, ё");
+
+        let info =
+            DeclInfo::new(Case::Dative, Number::Singular, Gender::Neuter, Animacy::Inanimate);
+        assert_eq!(decl.inflect_into(info, "нов", &mut out).unwrap(), "новому");
+
+        let stress: AnyStress = "f″".parse().unwrap();
+        assert_eq!(stress.fmt_to_buf(), "f″");
+    });
+    assert_eq!(allocations, 0);
+}
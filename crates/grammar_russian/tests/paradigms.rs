@@ -0,0 +1,232 @@
+//! Checks the full paradigms of nouns and adjectives against the hand-verified reference data in
+//! `tests/paradigms/`, using only the public API: the headwords and the Zaliznyak indices are
+//! parsed, the stems are extracted, and all of the forms are generated and compared.
+//!
+//! # Format
+//!
+//! Tab-separated columns, with the forms separated by commas. Alternative forms are separated by
+//! slashes, and any of them is accepted. Stress marks are ignored. Empty lines and lines starting
+//! with '#' are skipped.
+//!
+//! - `nouns.tsv`: the lemma, the gender and animacy marker, the declension index, and the 12
+//!   forms in the order of [`CaseAndNumber::VALUES`].
+//! - `adjectives.tsv`: the lemma, the declension index, the 6 masculine, neuter, feminine and
+//!   plural forms (with the inanimate accusative), and the 4 short forms ('—' if there are none).
+//!
+//! Lines starting with '!' are the known failures of the inflection, with the bug described in a
+//! comment above them. They're still checked, and must keep failing, so that the '!' is removed
+//! when the inflection gets fixed. The data itself must be correct: a wrong index isn't a known
+//! failure.
+
+use grammar_russian::{
+    categories::{Animacy, CaseAndNumber, Gender, Number},
+    declension::{Adjective, AdjectiveInfo, Declension, Noun, NounInfo, ShortFormAvailability},
+    stress::NounStress,
+};
+use std::{collections::BTreeSet, fmt::Write};
+
+const NOUNS: &str = include_str!("paradigms/nouns.tsv");
+const ADJECTIVES: &str = include_str!("paradigms/adjectives.tsv");
+
+// A form, that doesn't match the expected one
+struct Mismatch {
+    slot: String,
+    expected: String,
+    got: String,
+}
+
+// The result of checking a single word
+enum Outcome {
+    Passed,
+    Failed(String),
+    Mismatches(Vec<Mismatch>),
+}
+
+fn parse_cells(column: &str) -> Vec<String> {
+    column.split(',').map(|x| x.trim().replace(['\u{301}', '\u{300}'], "")).collect()
+}
+
+fn compare(slots: &[String], expected: &[String], got: &[String]) -> Outcome {
+    let mismatches: Vec<Mismatch> = (slots.iter().zip(expected).zip(got))
+        .filter(|((_, expected), got)| {
+            // Any of the expected alternatives is accepted, and all of the returned ones must be
+            // expected (искренен/искрен)
+            !got.split('/').all(|got| expected.split('/').any(|x| x == got))
+        })
+        .map(|((slot, expected), got)| Mismatch {
+            slot: slot.clone(),
+            expected: expected.clone(),
+            got: got.clone(),
+        })
+        .collect();
+
+    if mismatches.is_empty() { Outcome::Passed } else { Outcome::Mismatches(mismatches) }
+}
+
+fn noun_slots() -> Vec<String> {
+    CaseAndNumber::VALUES.iter().map(|x| x.to_string()).collect()
+}
+
+fn check_noun(columns: &[&str]) -> Outcome {
+    let [lemma, gender, index, forms] = columns[..] else {
+        return Outcome::Failed("expected 4 tab-separated columns".into());
+    };
+    let expected = parse_cells(forms);
+    if expected.len() != 12 {
+        return Outcome::Failed(format!("expected 12 forms, found {}", expected.len()));
+    }
+
+    let info: NounInfo = match format!("{gender} {index}").parse() {
+        Ok(info) => info,
+        Err(err) => return Outcome::Failed(format!("couldn't parse the index: {err}")),
+    };
+    let noun = match Noun::from_headword(lemma, info) {
+        Ok(noun) => noun,
+        Err(err) => return Outcome::Failed(format!("couldn't extract the stem: {err}")),
+    };
+
+    let got = match noun.forms().map(|x| x.map(|(_, _, form)| form)).collect::<Result<Vec<_>, _>>()
+    {
+        Ok(got) => got,
+        Err(err) => return Outcome::Failed(format!("couldn't inflect: {err}")),
+    };
+    compare(&noun_slots(), &expected, &got)
+}
+
+fn check_adjective(columns: &[&str]) -> Outcome {
+    let [lemma, index, masc, neut, fem, plural, short] = columns[..] else {
+        return Outcome::Failed("expected 7 tab-separated columns".into());
+    };
+    let full = [masc, neut, fem, plural].map(parse_cells);
+    if full.iter().any(|x| x.len() != 6) {
+        return Outcome::Failed("expected 6 forms in each gender".into());
+    }
+    let short = if short == "—" { None } else { Some(parse_cells(short)) };
+    if short.as_ref().is_some_and(|x| x.len() != 4) {
+        return Outcome::Failed("expected 4 short forms".into());
+    }
+
+    let declension: Declension = match index.parse() {
+        Ok(decl) => decl,
+        Err(err) => return Outcome::Failed(format!("couldn't parse the index: {err:?}")),
+    };
+    let short_forms = match short {
+        Some(_) => ShortFormAvailability::Available,
+        None => ShortFormAvailability::Unavailable,
+    };
+    let info = AdjectiveInfo { declension: Some(declension), short_forms };
+    let adj = match Adjective::from_headword(lemma, info) {
+        Ok(adj) => adj,
+        Err(err) => return Outcome::Failed(format!("couldn't extract the stem: {err}")),
+    };
+
+    let (mut slots, mut expected, mut got) = (Vec::new(), Vec::new(), Vec::new());
+
+    // The masculine, neuter and feminine singular, and then the plural (of any gender)
+    let columns = [
+        (Gender::Masculine, Number::Singular),
+        (Gender::Neuter, Number::Singular),
+        (Gender::Feminine, Number::Singular),
+        (Gender::Masculine, Number::Plural),
+    ];
+    for (i, ((gender, number), forms)) in columns.into_iter().zip(full).enumerate() {
+        let label = if number == Number::Plural { number.to_string() } else { gender.to_string() };
+
+        let inflected = match adj.forms(gender, Animacy::Inanimate) {
+            Ok(inflected) => inflected,
+            Err(err) => return Outcome::Failed(format!("couldn't inflect: {err}")),
+        };
+        for (key, form) in CaseAndNumber::VALUES.into_iter().zip(inflected) {
+            if let (case, key_number) = key.parts()
+                && key_number == number
+            {
+                slots.push(format!("{case} {label}"));
+                got.push(form);
+            }
+        }
+        expected.extend(forms);
+
+        let short_forms = match adj.short_forms(gender, number) {
+            Ok(forms) => forms,
+            Err(err) => return Outcome::Failed(format!("couldn't inflect short forms: {err}")),
+        };
+        slots.push(format!("short {label}"));
+        expected.push(short.as_ref().map_or("—".into(), |x| x[i].clone()));
+        got.push(if short_forms.is_empty() { "—".into() } else { short_forms.join("/") });
+    }
+
+    compare(&slots, &expected, &got)
+}
+
+fn check_file(data: &str, check: fn(&[&str]) -> Outcome) -> Vec<&str> {
+    let mut report = String::new();
+    let mut lemmas = Vec::new();
+
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (known_failure, line) = match line.strip_prefix('!') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+        let columns: Vec<&str> = line.split('\t').collect();
+        let word = format!("line {}: {}", i + 1, columns[0]);
+        lemmas.push(columns[0]);
+
+        match check(&columns) {
+            Outcome::Passed if known_failure => {
+                writeln!(report, "{word}\n    now passes, remove the '!'").unwrap();
+            },
+            Outcome::Passed => {},
+            _ if known_failure => {},
+            Outcome::Failed(err) => writeln!(report, "{word}\n    {err}").unwrap(),
+            Outcome::Mismatches(mismatches) => {
+                writeln!(report, "{word}").unwrap();
+                for Mismatch { slot, expected, got } in mismatches {
+                    writeln!(report, "    {slot:<24} expected {expected:<16} got {got}").unwrap();
+                }
+            },
+        }
+    }
+
+    assert!(report.is_empty(), "the paradigms don't match the reference data:\n{report}");
+    lemmas
+}
+
+#[test]
+fn nouns() {
+    let lemmas = check_file(NOUNS, check_noun);
+    assert!(lemmas.len() >= 150, "{}", lemmas.len());
+}
+
+#[test]
+fn adjectives() {
+    let lemmas = check_file(ADJECTIVES, check_adjective);
+    assert!(lemmas.len() >= 40, "{}", lemmas.len());
+}
+
+#[test]
+fn coverage() {
+    // The noun data covers all stem types, stress schemas and flags of the noun declension
+    let (mut stem_types, mut stresses, mut flags) =
+        (BTreeSet::new(), BTreeSet::new(), BTreeSet::new());
+
+    for line in NOUNS.lines().filter(|x| !x.is_empty() && !x.starts_with('#')) {
+        let columns: Vec<&str> = line.trim_start_matches('!').split('\t').collect();
+        let info: NounInfo = format!("{} {}", columns[1], columns[2]).parse().unwrap();
+        if let Some(Declension::Noun(decl)) = info.declension {
+            stem_types.insert(decl.stem_type as u8);
+            stresses.insert(decl.stress);
+            let all_flags = ['*', '°', '①', '②', '③', 'ё'];
+            flags.extend(all_flags.into_iter().filter(|x| columns[2].contains(*x)));
+        }
+    }
+
+    assert_eq!(stem_types.len(), 8);
+    let missing: Vec<_> =
+        NounStress::VALUES.into_iter().filter(|x| !stresses.contains(x)).collect();
+    assert_eq!(missing, []);
+    assert_eq!(flags.len(), 6);
+}
@@ -0,0 +1,51 @@
+//! Regression tests for the fixes to the noun ending table, the stem type discriminants, and the
+//! adjective full form inflection.
+
+use grammar_russian::{
+    InflectionBuffer,
+    categories::{Animacy, Case, Gender, Number},
+    declension::{
+        AdjectiveDeclension, AdjectiveStemType, DeclInfo, NounDeclension, NounStemType,
+        PronounStemType,
+    },
+};
+
+const fn info(case: Case, number: Number, gender: Gender) -> DeclInfo {
+    DeclInfo { case, number, gender, animacy: Animacy::Inanimate }
+}
+
+#[test]
+fn stem_type_discriminants() {
+    // The ending tables are indexed by the stem type's discriminant minus one
+    for digit in 1..=8 {
+        assert_eq!(NounStemType::from_digit(digit).map(|x| x as u8), Some(digit));
+    }
+    for digit in [1, 2, 4, 6] {
+        assert_eq!(PronounStemType::from_digit(digit).map(|x| x as u8), Some(digit));
+    }
+    for digit in 1..=7 {
+        assert_eq!(AdjectiveStemType::from_digit(digit).map(|x| x as u8), Some(digit));
+    }
+}
+
+#[test]
+fn noun_nominative_singular_endings() {
+    let nom = info(Case::Nominative, Number::Singular, Gender::Masculine);
+    let ending = |decl: &str| decl.parse::<NounDeclension>().unwrap().get_ending(nom);
+
+    // The masculine nominative singular of stem type 1 has a zero ending: стол, not столе
+    assert_eq!(ending("1a"), "");
+    assert_eq!(ending("3a"), "");
+    assert_eq!(ending("2a"), "ь");
+    assert_eq!(ending("6a"), "й");
+    assert_eq!(ending("8a"), "ь");
+}
+
+#[test]
+fn adjective_full_forms() {
+    // Full forms only append the ending to the stem
+    let decl: AdjectiveDeclension = "1a".parse().unwrap();
+    let mut buf = InflectionBuffer::from_stem("нов").unwrap();
+    decl.inflect(info(Case::Genitive, Number::Singular, Gender::Masculine), &mut buf);
+    assert_eq!(buf.as_str(), "нового");
+}
@@ -0,0 +1,7 @@
+use grammar_russian::{stress, stress::*};
+
+fn main() {
+    let _: NounStress = stress![a1];
+    let _: AdjectiveStress = stress![c];
+    let _: NounStress = stress![b / b];
+}
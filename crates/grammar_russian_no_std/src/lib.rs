@@ -0,0 +1,24 @@
+//! Checks that the parsing and formatting layers of `grammar_russian` compile without `std`.
+//! Build this crate on its own, so that the features aren't unified with the other crates:
+//! `cargo build -p grammar_russian_no_std`. The tests of `grammar_russian` itself are checked
+//! without `std` by `cargo test -p grammar_russian --no-default-features` (see the CI workflow).
+#![no_std]
+
+use grammar_russian::{
+    categories::GenderExAnimacy,
+    declension::{DECLENSION_MAX_LEN, Declension, ParseDeclensionError},
+    stress::{AnyDualStress, ParseStressError},
+};
+
+pub fn parse_declension(s: &str) -> Result<Declension, ParseDeclensionError> {
+    s.parse()
+}
+pub fn fmt_declension(decl: Declension, dst: &mut [u8; DECLENSION_MAX_LEN]) -> &mut str {
+    decl.fmt_to(dst)
+}
+pub fn parse_stress(s: &str) -> Result<AnyDualStress, ParseStressError> {
+    s.parse()
+}
+pub fn fmt_gender(gender: GenderExAnimacy) -> &'static str {
+    gender.abbr_zaliznyak()
+}